@@ -0,0 +1,281 @@
+#ifndef OPENLIMITS_H
+#define OPENLIMITS_H
+
+/* Generated by cbindgen from bindings/c/src, don't edit it by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// The outcome of a call.
+typedef enum OpenLimitsStatus {
+  OPEN_LIMITS_STATUS_OK = 0,
+  // A pointer was null, a string wasn't UTF-8 or a request didn't parse.
+  OPEN_LIMITS_STATUS_INVALID_ARGUMENT = 1,
+  // The exchange, or the way to it, failed.
+  OPEN_LIMITS_STATUS_EXCHANGE_ERROR = 2,
+  // The exchange doesn't support the operation.
+  OPEN_LIMITS_STATUS_UNSUPPORTED = 3,
+  // The stream was closed by the exchange.
+  OPEN_LIMITS_STATUS_DISCONNECTED = 4,
+  // The library panicked. The objects involved in the call shouldn't be used anymore.
+  OPEN_LIMITS_STATUS_PANIC = 5,
+} OpenLimitsStatus;
+
+// A client of one exchange, created by `openlimits_client_new`.
+typedef struct OpenLimitsClient OpenLimitsClient;
+
+// The subscriptions of a websocket, created by `openlimits_stream_new`.
+typedef struct OpenLimitsStream OpenLimitsStream;
+
+// Receives the messages of a stream: `OPEN_LIMITS_STATUS_OK` with an `OpenLimitsWebSocketMessage`
+// as JSON, an error status with its message, or `OPEN_LIMITS_STATUS_DISCONNECTED` with null once
+// the stream ended. `message` is only valid during the call.
+typedef void (*OpenLimitsCallback)(void *user_data,
+                                   enum OpenLimitsStatus status,
+                                   const char *message);
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Creates a client from a JSON `ExchangeConfig`, such as
+// `{"exchange": "coinbase", "sandbox": true, "credentials": {"api_key": "...", "api_secret": "...", "passphrase": "..."}}`.
+// The client is freed with `openlimits_client_free`.
+//
+// # Safety
+//
+// `config` must be a nul-terminated string and `client` must point to writable memory.
+enum OpenLimitsStatus openlimits_client_new(const char *config,
+                                            struct OpenLimitsClient **client);
+
+// Frees a client. Null is ignored. The streams of the client stay open until they are freed.
+//
+// # Safety
+//
+// `client` must be null or a client that wasn't freed yet.
+void openlimits_client_free(struct OpenLimitsClient *client);
+
+// The name of the exchange of the client, as in its config. The string is owned by the caller.
+//
+// # Safety
+//
+// `client` must be a live client and `response` must point to writable memory.
+enum OpenLimitsStatus openlimits_client_exchange(const struct OpenLimitsClient *client,
+                                                 char **response);
+
+// The `Capabilities` of the exchange as JSON.
+//
+// # Safety
+//
+// `client` must be a live client and `response` must point to writable memory.
+enum OpenLimitsStatus openlimits_client_capabilities(const struct OpenLimitsClient *client,
+                                                     char **response);
+
+// The `MarketPairInfo` of every market of the exchange as JSON.
+//
+// # Safety
+//
+// `client` must be a live client and `response` must point to writable memory.
+enum OpenLimitsStatus openlimits_retrieve_pairs(const struct OpenLimitsClient *client,
+                                                char **response);
+
+// Takes an `OrderBookRequest` and answers an `OrderBookResponse`.
+//
+// # Safety
+//
+// `client` must be a live client, `request` a nul-terminated string and `response` must point to
+// writable memory. The same goes for the other requests.
+enum OpenLimitsStatus openlimits_order_book(const struct OpenLimitsClient *client,
+                                            const char *request,
+                                            char **response);
+
+// Takes a `GetPriceTickerRequest` and answers a `Ticker`.
+//
+// # Safety
+//
+// See `openlimits_order_book`.
+enum OpenLimitsStatus openlimits_get_price_ticker(const struct OpenLimitsClient *client,
+                                                  const char *request,
+                                                  char **response);
+
+// Answers the `Ticker` of every market.
+//
+// # Safety
+//
+// See `openlimits_order_book`.
+enum OpenLimitsStatus openlimits_get_all_tickers(const struct OpenLimitsClient *client,
+                                                 char **response);
+
+// Takes a `GetHistoricRatesRequest` and answers the `Candle`s.
+//
+// # Safety
+//
+// See `openlimits_order_book`.
+enum OpenLimitsStatus openlimits_get_historic_rates(const struct OpenLimitsClient *client,
+                                                    const char *request,
+                                                    char **response);
+
+// Takes a `GetHistoricTradesRequest` and answers the `Trade`s.
+//
+// # Safety
+//
+// See `openlimits_order_book`.
+enum OpenLimitsStatus openlimits_get_historic_trades(const struct OpenLimitsClient *client,
+                                                     const char *request,
+                                                     char **response);
+
+// Takes an `OpenLimitOrderRequest` and answers the placed `Order`.
+//
+// # Safety
+//
+// See `openlimits_order_book`.
+enum OpenLimitsStatus openlimits_limit_buy(const struct OpenLimitsClient *client,
+                                           const char *request,
+                                           char **response);
+
+// Takes an `OpenLimitOrderRequest` and answers the placed `Order`.
+//
+// # Safety
+//
+// See `openlimits_order_book`.
+enum OpenLimitsStatus openlimits_limit_sell(const struct OpenLimitsClient *client,
+                                            const char *request,
+                                            char **response);
+
+// Takes an `OpenMarketOrderRequest` and answers the placed `Order`.
+//
+// # Safety
+//
+// See `openlimits_order_book`.
+enum OpenLimitsStatus openlimits_market_buy(const struct OpenLimitsClient *client,
+                                            const char *request,
+                                            char **response);
+
+// Takes an `OpenMarketOrderRequest` and answers the placed `Order`.
+//
+// # Safety
+//
+// See `openlimits_order_book`.
+enum OpenLimitsStatus openlimits_market_sell(const struct OpenLimitsClient *client,
+                                             const char *request,
+                                             char **response);
+
+// Takes a `CancelOrderRequest` and answers an `OrderCanceled`.
+//
+// # Safety
+//
+// See `openlimits_order_book`.
+enum OpenLimitsStatus openlimits_cancel_order(const struct OpenLimitsClient *client,
+                                              const char *request,
+                                              char **response);
+
+// Takes a `CancelAllOrdersRequest` and answers the `OrderCanceled`s.
+//
+// # Safety
+//
+// See `openlimits_order_book`.
+enum OpenLimitsStatus openlimits_cancel_all_orders(const struct OpenLimitsClient *client,
+                                                   const char *request,
+                                                   char **response);
+
+// Answers the open `Order`s.
+//
+// # Safety
+//
+// See `openlimits_order_book`.
+enum OpenLimitsStatus openlimits_get_all_open_orders(const struct OpenLimitsClient *client,
+                                                     char **response);
+
+// Takes a `GetOrderHistoryRequest` and answers the `Order`s.
+//
+// # Safety
+//
+// See `openlimits_order_book`.
+enum OpenLimitsStatus openlimits_get_order_history(const struct OpenLimitsClient *client,
+                                                   const char *request,
+                                                   char **response);
+
+// Takes a `TradeHistoryRequest` and answers the `Trade`s.
+//
+// # Safety
+//
+// See `openlimits_order_book`.
+enum OpenLimitsStatus openlimits_get_trade_history(const struct OpenLimitsClient *client,
+                                                   const char *request,
+                                                   char **response);
+
+// Takes a `Paginator`, or null for the first page, and answers the `Balance`s.
+//
+// # Safety
+//
+// See `openlimits_order_book`, `paginator` being allowed to be null.
+enum OpenLimitsStatus openlimits_get_account_balances(const struct OpenLimitsClient *client,
+                                                      const char *paginator,
+                                                      char **response);
+
+// Takes a `GetOrderRequest` and answers the `Order`.
+//
+// # Safety
+//
+// See `openlimits_order_book`.
+enum OpenLimitsStatus openlimits_get_order(const struct OpenLimitsClient *client,
+                                           const char *request,
+                                           char **response);
+
+// Takes a `GetLedgerRequest` and answers the `LedgerEntry`s.
+//
+// # Safety
+//
+// See `openlimits_order_book`.
+enum OpenLimitsStatus openlimits_get_ledger(const struct OpenLimitsClient *client,
+                                            const char *request,
+                                            char **response);
+
+// Checks that a string is a valid JSON `ExchangeConfig` without creating the client, so the
+// configuration of a desk can be validated offline.
+//
+// # Safety
+//
+// `config` must be a nul-terminated string.
+enum OpenLimitsStatus openlimits_config_validate(const char *config);
+
+// The message of the last error of the calling thread, or null if there was none. The string is
+// owned by the caller.
+char *openlimits_last_error(void);
+
+// Subscribes to a JSON array of `Subscription`s, such as `[{"Trades": ["BTC", "USDT"]}]`, on a
+// websocket of the client's exchange. The stream is freed with `openlimits_stream_free`.
+//
+// # Safety
+//
+// `client` must be a live client, `subscriptions` a nul-terminated string and `stream` must
+// point to writable memory. `callback` is called with `user_data` until the stream is freed.
+enum OpenLimitsStatus openlimits_stream_new(const struct OpenLimitsClient *client,
+                                            const char *subscriptions,
+                                            OpenLimitsCallback callback,
+                                            void *user_data,
+                                            struct OpenLimitsStream **stream);
+
+// Closes the websocket and frees the stream. No callback runs once it returns. Null is ignored.
+//
+// # Safety
+//
+// `stream` must be null or a stream that wasn't freed yet, and this mustn't be called from its
+// callback.
+void openlimits_stream_free(struct OpenLimitsStream *stream);
+
+// Frees a string returned by the library. Null is ignored.
+//
+// # Safety
+//
+// `string` must be null or a string returned by the library that wasn't freed yet.
+void openlimits_string_free(char *string);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* OPENLIMITS_H */
@@ -0,0 +1,18 @@
+//! Writes `include/openlimits.h`, the header cbindgen generates on every build. With `--check`,
+//! fails if the file is out of date instead.
+
+use std::{env, fs, process};
+
+const HEADER: &str = include_str!(concat!(env!("OUT_DIR"), "/openlimits.h"));
+
+fn main() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/include/openlimits.h");
+    if env::args().any(|argument| argument == "--check") {
+        if fs::read_to_string(path).ok().as_deref() != Some(HEADER) {
+            eprintln!("include/openlimits.h is out of date, run `cargo run --bin header`.");
+            process::exit(1);
+        }
+    } else {
+        fs::write(path, HEADER).expect("Couldn't write include/openlimits.h");
+    }
+}
@@ -0,0 +1,315 @@
+use std::convert::TryFrom;
+use std::os::raw::c_char;
+use futures::future::BoxFuture;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use openlimits::exchange::any::{AnyExchange, InitAnyExchange};
+use openlimits::exchange::config::ExchangeConfig;
+use openlimits::exchange::shared::Result;
+use openlimits::exchange::traits::info::ExchangeInfoRetrieval;
+use openlimits::exchange::traits::{Exchange, ExchangeAccount, ExchangeMarketData};
+use openlimits::model::{
+    CancelAllOrdersRequest, CancelOrderRequest, GetHistoricRatesRequest, GetHistoricTradesRequest,
+    GetLedgerRequest, GetOrderHistoryRequest, GetOrderRequest, GetPriceTickerRequest,
+    OpenLimitOrderRequest, OpenMarketOrderRequest, OrderBookRequest, Paginator, TradeHistoryRequest,
+};
+use crate::error::{ffi_call, FfiError, OpenLimitsStatus};
+use crate::runtime::RUNTIME;
+use crate::string::{into_c_string, output_pointer, parse_json, write_json};
+
+/// A client of one exchange, created by `openlimits_client_new`.
+pub struct OpenLimitsClient {
+    pub(crate) exchange: AnyExchange,
+    pub(crate) init: InitAnyExchange,
+}
+
+pub(crate) unsafe fn client_ref<'a>(client: *const OpenLimitsClient) -> std::result::Result<&'a OpenLimitsClient, FfiError> {
+    client.as_ref().ok_or_else(|| FfiError::invalid_argument("client is null"))
+}
+
+/// Runs an operation of the client and hands its result over as JSON.
+unsafe fn run<R, T, F>(client: *const OpenLimitsClient, request: R, response: *mut *mut c_char, operation: F) -> std::result::Result<(), FfiError>
+where
+    T: Serialize,
+    F: for<'a> FnOnce(&'a AnyExchange, &'a R) -> BoxFuture<'a, Result<T>>,
+{
+    let response = output_pointer(response, "response")?;
+    let client = client_ref(client)?;
+    let value = RUNTIME.block_on(operation(&client.exchange, &request))?;
+    write_json(response, &value)
+}
+
+unsafe fn call<R, T, F>(client: *const OpenLimitsClient, request: *const c_char, response: *mut *mut c_char, operation: F) -> OpenLimitsStatus
+where
+    R: DeserializeOwned,
+    T: Serialize,
+    F: for<'a> FnOnce(&'a AnyExchange, &'a R) -> BoxFuture<'a, Result<T>>,
+{
+    ffi_call(|| {
+        let request: R = parse_json(request, "request")?;
+        run(client, request, response, operation)
+    })
+}
+
+/// Creates a client from a JSON `ExchangeConfig`, such as
+/// `{"exchange": "coinbase", "sandbox": true, "credentials": {"api_key": "...", "api_secret": "...", "passphrase": "..."}}`.
+/// The client is freed with `openlimits_client_free`.
+///
+/// # Safety
+///
+/// `config` must be a nul-terminated string and `client` must point to writable memory.
+#[no_mangle]
+pub unsafe extern "C" fn openlimits_client_new(config: *const c_char, client: *mut *mut OpenLimitsClient) -> OpenLimitsStatus {
+    ffi_call(|| {
+        let client = output_pointer(client, "client")?;
+        let config: ExchangeConfig = parse_json(config, "config")?;
+        let init = InitAnyExchange::try_from(&config)?;
+        let exchange = RUNTIME.block_on(AnyExchange::new(init.clone()))?;
+        *client = Box::into_raw(Box::new(OpenLimitsClient { exchange, init }));
+        Ok(())
+    })
+}
+
+/// Frees a client. Null is ignored. The streams of the client stay open until they are freed.
+///
+/// # Safety
+///
+/// `client` must be null or a client that wasn't freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn openlimits_client_free(client: *mut OpenLimitsClient) {
+    if !client.is_null() {
+        drop(Box::from_raw(client));
+    }
+}
+
+/// The name of the exchange of the client, as in its config. The string is owned by the caller.
+///
+/// # Safety
+///
+/// `client` must be a live client and `response` must point to writable memory.
+#[no_mangle]
+pub unsafe extern "C" fn openlimits_client_exchange(client: *const OpenLimitsClient, response: *mut *mut c_char) -> OpenLimitsStatus {
+    ffi_call(|| {
+        let response = output_pointer(response, "response")?;
+        let name = match client_ref(client)?.exchange {
+            AnyExchange::Binance(_) => "binance",
+            AnyExchange::Coinbase(_) => "coinbase",
+            AnyExchange::Huobi(_) => "huobi",
+            AnyExchange::Nash(_) => "nash",
+        };
+        *response = into_c_string(name.into());
+        Ok(())
+    })
+}
+
+/// The `Capabilities` of the exchange as JSON.
+///
+/// # Safety
+///
+/// `client` must be a live client and `response` must point to writable memory.
+#[no_mangle]
+pub unsafe extern "C" fn openlimits_client_capabilities(client: *const OpenLimitsClient, response: *mut *mut c_char) -> OpenLimitsStatus {
+    ffi_call(|| {
+        let response = output_pointer(response, "response")?;
+        write_json(response, &client_ref(client)?.exchange.capabilities())
+    })
+}
+
+/// The `MarketPairInfo` of every market of the exchange as JSON.
+///
+/// # Safety
+///
+/// `client` must be a live client and `response` must point to writable memory.
+#[no_mangle]
+pub unsafe extern "C" fn openlimits_retrieve_pairs(client: *const OpenLimitsClient, response: *mut *mut c_char) -> OpenLimitsStatus {
+    ffi_call(|| run(client, (), response, |exchange, _| exchange.retrieve_pairs()))
+}
+
+/// Takes an `OrderBookRequest` and answers an `OrderBookResponse`.
+///
+/// # Safety
+///
+/// `client` must be a live client, `request` a nul-terminated string and `response` must point to
+/// writable memory. The same goes for the other requests.
+#[no_mangle]
+pub unsafe extern "C" fn openlimits_order_book(client: *const OpenLimitsClient, request: *const c_char, response: *mut *mut c_char) -> OpenLimitsStatus {
+    call(client, request, response, |exchange, request: &OrderBookRequest| exchange.order_book(request))
+}
+
+/// Takes a `GetPriceTickerRequest` and answers a `Ticker`.
+///
+/// # Safety
+///
+/// See `openlimits_order_book`.
+#[no_mangle]
+pub unsafe extern "C" fn openlimits_get_price_ticker(client: *const OpenLimitsClient, request: *const c_char, response: *mut *mut c_char) -> OpenLimitsStatus {
+    call(client, request, response, |exchange, request: &GetPriceTickerRequest| exchange.get_price_ticker(request))
+}
+
+/// Answers the `Ticker` of every market.
+///
+/// # Safety
+///
+/// See `openlimits_order_book`.
+#[no_mangle]
+pub unsafe extern "C" fn openlimits_get_all_tickers(client: *const OpenLimitsClient, response: *mut *mut c_char) -> OpenLimitsStatus {
+    ffi_call(|| run(client, (), response, |exchange, _| exchange.get_all_tickers()))
+}
+
+/// Takes a `GetHistoricRatesRequest` and answers the `Candle`s.
+///
+/// # Safety
+///
+/// See `openlimits_order_book`.
+#[no_mangle]
+pub unsafe extern "C" fn openlimits_get_historic_rates(client: *const OpenLimitsClient, request: *const c_char, response: *mut *mut c_char) -> OpenLimitsStatus {
+    call(client, request, response, |exchange, request: &GetHistoricRatesRequest| exchange.get_historic_rates(request))
+}
+
+/// Takes a `GetHistoricTradesRequest` and answers the `Trade`s.
+///
+/// # Safety
+///
+/// See `openlimits_order_book`.
+#[no_mangle]
+pub unsafe extern "C" fn openlimits_get_historic_trades(client: *const OpenLimitsClient, request: *const c_char, response: *mut *mut c_char) -> OpenLimitsStatus {
+    call(client, request, response, |exchange, request: &GetHistoricTradesRequest| exchange.get_historic_trades(request))
+}
+
+/// Takes an `OpenLimitOrderRequest` and answers the placed `Order`.
+///
+/// # Safety
+///
+/// See `openlimits_order_book`.
+#[no_mangle]
+pub unsafe extern "C" fn openlimits_limit_buy(client: *const OpenLimitsClient, request: *const c_char, response: *mut *mut c_char) -> OpenLimitsStatus {
+    call(client, request, response, |exchange, request: &OpenLimitOrderRequest| exchange.limit_buy(request))
+}
+
+/// Takes an `OpenLimitOrderRequest` and answers the placed `Order`.
+///
+/// # Safety
+///
+/// See `openlimits_order_book`.
+#[no_mangle]
+pub unsafe extern "C" fn openlimits_limit_sell(client: *const OpenLimitsClient, request: *const c_char, response: *mut *mut c_char) -> OpenLimitsStatus {
+    call(client, request, response, |exchange, request: &OpenLimitOrderRequest| exchange.limit_sell(request))
+}
+
+/// Takes an `OpenMarketOrderRequest` and answers the placed `Order`.
+///
+/// # Safety
+///
+/// See `openlimits_order_book`.
+#[no_mangle]
+pub unsafe extern "C" fn openlimits_market_buy(client: *const OpenLimitsClient, request: *const c_char, response: *mut *mut c_char) -> OpenLimitsStatus {
+    call(client, request, response, |exchange, request: &OpenMarketOrderRequest| exchange.market_buy(request))
+}
+
+/// Takes an `OpenMarketOrderRequest` and answers the placed `Order`.
+///
+/// # Safety
+///
+/// See `openlimits_order_book`.
+#[no_mangle]
+pub unsafe extern "C" fn openlimits_market_sell(client: *const OpenLimitsClient, request: *const c_char, response: *mut *mut c_char) -> OpenLimitsStatus {
+    call(client, request, response, |exchange, request: &OpenMarketOrderRequest| exchange.market_sell(request))
+}
+
+/// Takes a `CancelOrderRequest` and answers an `OrderCanceled`.
+///
+/// # Safety
+///
+/// See `openlimits_order_book`.
+#[no_mangle]
+pub unsafe extern "C" fn openlimits_cancel_order(client: *const OpenLimitsClient, request: *const c_char, response: *mut *mut c_char) -> OpenLimitsStatus {
+    call(client, request, response, |exchange, request: &CancelOrderRequest| exchange.cancel_order(request))
+}
+
+/// Takes a `CancelAllOrdersRequest` and answers the `OrderCanceled`s.
+///
+/// # Safety
+///
+/// See `openlimits_order_book`.
+#[no_mangle]
+pub unsafe extern "C" fn openlimits_cancel_all_orders(client: *const OpenLimitsClient, request: *const c_char, response: *mut *mut c_char) -> OpenLimitsStatus {
+    call(client, request, response, |exchange, request: &CancelAllOrdersRequest| exchange.cancel_all_orders(request))
+}
+
+/// Answers the open `Order`s.
+///
+/// # Safety
+///
+/// See `openlimits_order_book`.
+#[no_mangle]
+pub unsafe extern "C" fn openlimits_get_all_open_orders(client: *const OpenLimitsClient, response: *mut *mut c_char) -> OpenLimitsStatus {
+    ffi_call(|| run(client, (), response, |exchange, _| exchange.get_all_open_orders()))
+}
+
+/// Takes a `GetOrderHistoryRequest` and answers the `Order`s.
+///
+/// # Safety
+///
+/// See `openlimits_order_book`.
+#[no_mangle]
+pub unsafe extern "C" fn openlimits_get_order_history(client: *const OpenLimitsClient, request: *const c_char, response: *mut *mut c_char) -> OpenLimitsStatus {
+    call(client, request, response, |exchange, request: &GetOrderHistoryRequest| exchange.get_order_history(request))
+}
+
+/// Takes a `TradeHistoryRequest` and answers the `Trade`s.
+///
+/// # Safety
+///
+/// See `openlimits_order_book`.
+#[no_mangle]
+pub unsafe extern "C" fn openlimits_get_trade_history(client: *const OpenLimitsClient, request: *const c_char, response: *mut *mut c_char) -> OpenLimitsStatus {
+    call(client, request, response, |exchange, request: &TradeHistoryRequest| exchange.get_trade_history(request))
+}
+
+/// Takes a `Paginator`, or null for the first page, and answers the `Balance`s.
+///
+/// # Safety
+///
+/// See `openlimits_order_book`, `paginator` being allowed to be null.
+#[no_mangle]
+pub unsafe extern "C" fn openlimits_get_account_balances(client: *const OpenLimitsClient, paginator: *const c_char, response: *mut *mut c_char) -> OpenLimitsStatus {
+    ffi_call(|| {
+        let paginator: Option<Paginator> = if paginator.is_null() { None } else { parse_json(paginator, "paginator")? };
+        run(client, paginator, response, |exchange, paginator| exchange.get_account_balances(paginator.clone()))
+    })
+}
+
+/// Takes a `GetOrderRequest` and answers the `Order`.
+///
+/// # Safety
+///
+/// See `openlimits_order_book`.
+#[no_mangle]
+pub unsafe extern "C" fn openlimits_get_order(client: *const OpenLimitsClient, request: *const c_char, response: *mut *mut c_char) -> OpenLimitsStatus {
+    call(client, request, response, |exchange, request: &GetOrderRequest| exchange.get_order(request))
+}
+
+/// Takes a `GetLedgerRequest` and answers the `LedgerEntry`s.
+///
+/// # Safety
+///
+/// See `openlimits_order_book`.
+#[no_mangle]
+pub unsafe extern "C" fn openlimits_get_ledger(client: *const OpenLimitsClient, request: *const c_char, response: *mut *mut c_char) -> OpenLimitsStatus {
+    call(client, request, response, |exchange, request: &GetLedgerRequest| exchange.get_ledger(request))
+}
+
+/// Checks that a string is a valid JSON `ExchangeConfig` without creating the client, so the
+/// configuration of a desk can be validated offline.
+///
+/// # Safety
+///
+/// `config` must be a nul-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn openlimits_config_validate(config: *const c_char) -> OpenLimitsStatus {
+    ffi_call(|| {
+        let config: ExchangeConfig = parse_json(config, "config")?;
+        InitAnyExchange::try_from(&config)?;
+        Ok(())
+    })
+}
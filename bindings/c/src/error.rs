@@ -0,0 +1,110 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::os::raw::c_char;
+use std::panic::{self, AssertUnwindSafe};
+use openlimits::errors::OpenLimitsError;
+use crate::string::into_c_string;
+
+/// The outcome of a call.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OpenLimitsStatus {
+    Ok = 0,
+    /// A pointer was null, a string wasn't UTF-8 or a request didn't parse.
+    InvalidArgument = 1,
+    /// The exchange, or the way to it, failed.
+    ExchangeError = 2,
+    /// The exchange doesn't support the operation.
+    Unsupported = 3,
+    /// The stream was closed by the exchange.
+    Disconnected = 4,
+    /// The library panicked. The objects involved in the call shouldn't be used anymore.
+    Panic = 5,
+}
+
+/// An error of a call, its message being kept for `openlimits_last_error`.
+#[derive(Debug)]
+pub(crate) struct FfiError {
+    pub status: OpenLimitsStatus,
+    pub message: String,
+}
+
+impl FfiError {
+    pub fn invalid_argument(message: impl Into<String>) -> Self {
+        Self {
+            status: OpenLimitsStatus::InvalidArgument,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<OpenLimitsError> for FfiError {
+    fn from(error: OpenLimitsError) -> Self {
+        let status = match error {
+            OpenLimitsError::MissingImplementation(_) => OpenLimitsStatus::Unsupported,
+            OpenLimitsError::InvalidParameter(_) | OpenLimitsError::MissingParameter(_) => {
+                OpenLimitsStatus::InvalidArgument
+            }
+            _ => OpenLimitsStatus::ExchangeError,
+        };
+        Self {
+            status,
+            message: error_message(&error),
+        }
+    }
+}
+
+/// Most of the `OpenLimitsError` variants display as an empty string, so those fall back to `Debug`.
+pub(crate) fn error_message(error: &OpenLimitsError) -> String {
+    let message = error.to_string();
+    if message.is_empty() {
+        format!("{:?}", error)
+    } else {
+        message
+    }
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Runs the body of an exported function, recording its error and catching its panics so they
+/// don't unwind into the caller.
+pub(crate) fn ffi_call<F>(body: F) -> OpenLimitsStatus
+where
+    F: FnOnce() -> Result<(), FfiError>,
+{
+    let error = match panic::catch_unwind(AssertUnwindSafe(body)) {
+        Ok(Ok(())) => None,
+        Ok(Err(error)) => Some(error),
+        Err(payload) => Some(FfiError {
+            status: OpenLimitsStatus::Panic,
+            message: panic_message(payload),
+        }),
+    };
+    match error {
+        Some(FfiError { status, message }) => {
+            LAST_ERROR.with(|last_error| *last_error.borrow_mut() = Some(message));
+            status
+        }
+        None => OpenLimitsStatus::Ok,
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "panic".into())
+}
+
+/// The message of the last error of the calling thread, or null if there was none. The string is
+/// owned by the caller.
+#[no_mangle]
+pub extern "C" fn openlimits_last_error() -> *mut c_char {
+    LAST_ERROR.with(|last_error| match last_error.borrow().as_ref() {
+        Some(message) => into_c_string(message.clone()),
+        None => std::ptr::null_mut(),
+    })
+}
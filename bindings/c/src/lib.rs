@@ -0,0 +1,25 @@
+//! The C ABI of OpenLimits, so the C, C++ and C# desks can use one library for every exchange.
+//! The generated header is `include/openlimits.h`.
+//!
+//! ## Conventions
+//!
+//! * Requests and responses are JSON documents of the `openlimits::model` types, such as
+//!   `{"market_pair": ["BTC", "USDT"]}` for `openlimits_order_book`.
+//! * Every function returns an `OpenLimitsStatus`, `OPEN_LIMITS_STATUS_OK` on success. On failure,
+//!   `openlimits_last_error` describes the error of the calling thread.
+//! * Strings returned by the library are owned by the caller and freed with
+//!   `openlimits_string_free`. Clients and streams are freed with `openlimits_client_free` and
+//!   `openlimits_stream_free`. Strings passed to the library stay owned by the caller.
+//! * Calls block until the exchange answers. Stream callbacks are called from a thread of the
+//!   library.
+
+mod client;
+mod error;
+mod runtime;
+mod stream;
+mod string;
+
+pub use client::*;
+pub use error::*;
+pub use stream::*;
+pub use string::*;
@@ -0,0 +1,12 @@
+use once_cell::sync::Lazy;
+use tokio::runtime::{Builder, Runtime};
+
+/// Drives the exchange clients and streams. The exported functions block on it, so they must not
+/// be called from a stream callback.
+pub(crate) static RUNTIME: Lazy<Runtime> = Lazy::new(|| {
+    Builder::new_multi_thread()
+        .enable_all()
+        .thread_name("openlimits")
+        .build()
+        .expect("Failed to start the openlimits runtime.")
+});
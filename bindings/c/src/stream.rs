@@ -0,0 +1,106 @@
+use std::os::raw::{c_char, c_void};
+use std::ptr;
+use futures::StreamExt;
+use tokio::task::JoinHandle;
+use openlimits::exchange::any::AnyWebsocket;
+use openlimits::model::websocket::Subscription;
+use crate::client::{client_ref, OpenLimitsClient};
+use crate::error::{ffi_call, FfiError, OpenLimitsStatus};
+use crate::runtime::RUNTIME;
+use crate::string::{into_c_string, openlimits_string_free, output_pointer, parse_json};
+
+/// Receives the messages of a stream: `OPEN_LIMITS_STATUS_OK` with an `OpenLimitsWebSocketMessage`
+/// as JSON, an error status with its message, or `OPEN_LIMITS_STATUS_DISCONNECTED` with null once
+/// the stream ended. `message` is only valid during the call.
+pub type OpenLimitsCallback = Option<extern "C" fn(user_data: *mut c_void, status: OpenLimitsStatus, message: *const c_char)>;
+
+/// The subscriptions of a websocket, created by `openlimits_stream_new`.
+pub struct OpenLimitsStream {
+    websocket: AnyWebsocket,
+    task: JoinHandle<()>,
+}
+
+/// The user data is only handed back to the callback, the caller vouching it can be used from the
+/// thread of the stream.
+struct UserData(*mut c_void);
+
+unsafe impl Send for UserData {}
+
+fn notify(callback: extern "C" fn(*mut c_void, OpenLimitsStatus, *const c_char), user_data: &UserData, status: OpenLimitsStatus, message: Option<String>) {
+    match message {
+        Some(message) => {
+            let message = into_c_string(message);
+            callback(user_data.0, status, message);
+            unsafe { openlimits_string_free(message) };
+        }
+        None => callback(user_data.0, status, ptr::null()),
+    }
+}
+
+/// Subscribes to a JSON array of `Subscription`s, such as `[{"Trades": ["BTC", "USDT"]}]`, on a
+/// websocket of the client's exchange. The stream is freed with `openlimits_stream_free`.
+///
+/// # Safety
+///
+/// `client` must be a live client, `subscriptions` a nul-terminated string and `stream` must
+/// point to writable memory. `callback` is called with `user_data` until the stream is freed.
+#[no_mangle]
+pub unsafe extern "C" fn openlimits_stream_new(
+    client: *const OpenLimitsClient,
+    subscriptions: *const c_char,
+    callback: OpenLimitsCallback,
+    user_data: *mut c_void,
+    stream: *mut *mut OpenLimitsStream,
+) -> OpenLimitsStatus {
+    ffi_call(|| {
+        let stream = output_pointer(stream, "stream")?;
+        let client = client_ref(client)?;
+        let subscriptions: Vec<Subscription> = parse_json(subscriptions, "subscriptions")?;
+        let callback = callback.ok_or_else(|| FfiError::invalid_argument("callback is null"))?;
+        let user_data = UserData(user_data);
+        let (websocket, mut messages) = RUNTIME.block_on(async {
+            let mut websocket = AnyWebsocket::new(client.init.clone()).await?;
+            let messages = websocket.create_stream(&subscriptions).await?;
+            openlimits::exchange::shared::Result::Ok((websocket, messages))
+        })?;
+        let task = RUNTIME.spawn(async move {
+            while let Some(message) = messages.next().await {
+                match message.and_then(|message| Ok(serde_json::to_string(&message)?)) {
+                    Ok(message) => notify(callback, &user_data, OpenLimitsStatus::Ok, Some(message)),
+                    Err(error) => {
+                        let error = FfiError::from(error);
+                        notify(callback, &user_data, error.status, Some(error.message))
+                    }
+                }
+            }
+            notify(callback, &user_data, OpenLimitsStatus::Disconnected, None);
+        });
+        *stream = Box::into_raw(Box::new(OpenLimitsStream {
+            websocket,
+            task,
+        }));
+        Ok(())
+    })
+}
+
+/// Closes the websocket and frees the stream. No callback runs once it returns. Null is ignored.
+///
+/// # Safety
+///
+/// `stream` must be null or a stream that wasn't freed yet, and this mustn't be called from its
+/// callback.
+#[no_mangle]
+pub unsafe extern "C" fn openlimits_stream_free(stream: *mut OpenLimitsStream) {
+    if stream.is_null() {
+        return;
+    }
+    let OpenLimitsStream { websocket, task } = *Box::from_raw(stream);
+    ffi_call(|| {
+        task.abort();
+        RUNTIME.block_on(async {
+            task.await.ok();
+            websocket.disconnect().await;
+        });
+        Ok(())
+    });
+}
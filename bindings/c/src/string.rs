@@ -0,0 +1,64 @@
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use crate::error::{FfiError, OpenLimitsStatus};
+
+/// Hands a string over to the caller, who frees it with `openlimits_string_free`.
+pub(crate) fn into_c_string(string: String) -> *mut c_char {
+    let string = CString::new(string).unwrap_or_else(|error| {
+        let mut bytes = error.into_vec();
+        bytes.retain(|byte| *byte != 0);
+        CString::new(bytes).expect("the nul bytes were removed")
+    });
+    string.into_raw()
+}
+
+/// Borrows a string argument of the caller.
+pub(crate) unsafe fn borrow_str<'a>(string: *const c_char, name: &str) -> Result<&'a str, FfiError> {
+    if string.is_null() {
+        return Err(FfiError::invalid_argument(format!("{} is null", name)));
+    }
+    CStr::from_ptr(string)
+        .to_str()
+        .map_err(|_| FfiError::invalid_argument(format!("{} isn't UTF-8", name)))
+}
+
+/// Parses a JSON argument of the caller.
+pub(crate) unsafe fn parse_json<T: DeserializeOwned>(json: *const c_char, name: &str) -> Result<T, FfiError> {
+    serde_json::from_str(borrow_str(json, name)?)
+        .map_err(|error| FfiError::invalid_argument(format!("{} is invalid: {}", name, error)))
+}
+
+/// Hands a value over to the caller as JSON.
+pub(crate) fn write_json<T: Serialize>(output: &mut *mut c_char, value: &T) -> Result<(), FfiError> {
+    let json = serde_json::to_string(value).map_err(|error| FfiError {
+        status: OpenLimitsStatus::ExchangeError,
+        message: error.to_string(),
+    })?;
+    *output = into_c_string(json);
+    Ok(())
+}
+
+/// Checks an output argument, clearing what it points to until the call succeeds.
+pub(crate) unsafe fn output_pointer<'a, T>(output: *mut *mut T, name: &str) -> Result<&'a mut *mut T, FfiError> {
+    match output.as_mut() {
+        Some(output) => {
+            *output = std::ptr::null_mut();
+            Ok(output)
+        }
+        None => Err(FfiError::invalid_argument(format!("{} is null", name))),
+    }
+}
+
+/// Frees a string returned by the library. Null is ignored.
+///
+/// # Safety
+///
+/// `string` must be null or a string returned by the library that wasn't freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn openlimits_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
@@ -0,0 +1,65 @@
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_void};
+use std::ptr;
+use openlimits_c::*;
+
+unsafe fn last_error() -> String {
+    let error = openlimits_last_error();
+    assert!(!error.is_null());
+    let message = CStr::from_ptr(error).to_string_lossy().into_owned();
+    openlimits_string_free(error);
+    message
+}
+
+#[test]
+fn invalid_config() {
+    let config = CString::new(r#"{"exchange": "kraken"}"#).unwrap();
+    let mut client = ptr::null_mut();
+    unsafe {
+        let status = openlimits_client_new(config.as_ptr(), &mut client);
+        assert_eq!(status, OpenLimitsStatus::InvalidArgument);
+        assert!(client.is_null());
+        assert!(last_error().contains("config is invalid"));
+    }
+}
+
+#[test]
+fn config_validation() {
+    let valid = CString::new(r#"{"exchange": "binance", "sandbox": true}"#).unwrap();
+    let invalid = CString::new(r#"{"exchange": "huobi", "sandbox": true}"#).unwrap();
+    unsafe {
+        assert_eq!(openlimits_config_validate(valid.as_ptr()), OpenLimitsStatus::Ok);
+        assert_eq!(openlimits_config_validate(invalid.as_ptr()), OpenLimitsStatus::InvalidArgument);
+        assert_eq!(openlimits_config_validate(ptr::null()), OpenLimitsStatus::InvalidArgument);
+        assert_eq!(last_error(), "config is null");
+    }
+}
+
+#[test]
+fn null_arguments() {
+    let request = CString::new(r#"{"market_pair": ["BTC", "USDT"]}"#).unwrap();
+    let mut response = ptr::null_mut();
+    unsafe {
+        assert_eq!(openlimits_order_book(ptr::null(), request.as_ptr(), &mut response), OpenLimitsStatus::InvalidArgument);
+        assert!(response.is_null());
+        assert_eq!(last_error(), "client is null");
+        assert_eq!(openlimits_get_all_tickers(ptr::null(), ptr::null_mut()), OpenLimitsStatus::InvalidArgument);
+        assert_eq!(last_error(), "response is null");
+        openlimits_client_free(ptr::null_mut());
+        openlimits_stream_free(ptr::null_mut());
+        openlimits_string_free(ptr::null_mut());
+    }
+}
+
+extern "C" fn ignore(_: *mut c_void, _: OpenLimitsStatus, _: *const c_char) {}
+
+#[test]
+fn stream_without_client() {
+    let subscriptions = CString::new(r#"[{"Trades": ["BTC", "USDT"]}]"#).unwrap();
+    let mut stream = ptr::null_mut();
+    unsafe {
+        let status = openlimits_stream_new(ptr::null(), subscriptions.as_ptr(), Some(ignore), ptr::null_mut(), &mut stream);
+        assert_eq!(status, OpenLimitsStatus::InvalidArgument);
+        assert!(stream.is_null());
+    }
+}
@@ -0,0 +1,170 @@
+//! Drives the exported functions against a local fake of Binance, so the bindings built on top of
+//! them are exercised end to end without a network.
+
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_void};
+use std::ptr;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::Mutex;
+use std::time::Duration;
+use serde_json::{json, Value};
+use openlimits_c::*;
+use openlimits_test_support::FakeBinance;
+
+unsafe fn last_error() -> String {
+    let error = openlimits_last_error();
+    assert!(!error.is_null());
+    let message = CStr::from_ptr(error).to_string_lossy().into_owned();
+    openlimits_string_free(error);
+    message
+}
+
+/// Calls an exported function with a JSON request, returning its JSON response.
+unsafe fn call(
+    function: unsafe extern "C" fn(*const OpenLimitsClient, *const c_char, *mut *mut c_char) -> OpenLimitsStatus,
+    client: *const OpenLimitsClient,
+    request: Value,
+) -> Value {
+    let request = CString::new(request.to_string()).unwrap();
+    let mut response = ptr::null_mut();
+    let status = function(client, request.as_ptr(), &mut response);
+    assert_eq!(status, OpenLimitsStatus::Ok, "{}", last_error());
+    let json = CStr::from_ptr(response).to_str().expect("The response isn't UTF-8").to_owned();
+    openlimits_string_free(response);
+    serde_json::from_str(&json).expect("The response isn't JSON")
+}
+
+unsafe fn new_client(fake: &FakeBinance) -> *mut OpenLimitsClient {
+    let mut client = ptr::null_mut();
+    let config = CString::new(fake.config().to_string()).unwrap();
+    let status = openlimits_client_new(config.as_ptr(), &mut client);
+    assert_eq!(status, OpenLimitsStatus::Ok, "{}", last_error());
+    assert!(!client.is_null());
+    client
+}
+
+#[test]
+fn market_data() {
+    let fake = FakeBinance::start();
+    unsafe {
+        let client = new_client(&fake);
+        let market_pair = json!({"market_pair": ["BTC", "USDT"]});
+
+        let mut pairs = ptr::null_mut();
+        assert_eq!(openlimits_retrieve_pairs(client, &mut pairs), OpenLimitsStatus::Ok, "{}", last_error());
+        let json = CStr::from_ptr(pairs).to_str().unwrap().to_owned();
+        openlimits_string_free(pairs);
+        let pairs: Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(pairs[0]["symbol"], "BTCUSDT");
+        assert_eq!(pairs[0]["quote_increment"], "0.01");
+
+        let order_book = call(openlimits_order_book, client, market_pair.clone());
+        assert_eq!(order_book["bids"][0]["price"], "50000.00");
+        assert_eq!(order_book["asks"][0]["qty"], "2.0");
+
+        let ticker = call(openlimits_get_price_ticker, client, market_pair);
+        assert_eq!(ticker["price"], "50000.50");
+
+        let candles = call(
+            openlimits_get_historic_rates,
+            client,
+            json!({"market_pair": ["BTC", "USDT"], "interval": "1m", "paginator": null}),
+        );
+        assert_eq!(candles.as_array().map(Vec::len), Some(1));
+        assert_eq!(candles[0]["close"], "50100.00");
+
+        openlimits_client_free(client);
+    }
+    assert!(fake.served("GET /api/v1/exchangeInfo"));
+    assert!(fake.served("GET /api/v3/klines"));
+}
+
+#[test]
+fn orders() {
+    let fake = FakeBinance::start();
+    unsafe {
+        let client = new_client(&fake);
+        let order = call(
+            openlimits_limit_buy,
+            client,
+            json!({
+                "market_pair": ["BTC", "USDT"],
+                "size": "0.1",
+                "price": "50000.00",
+                "time_in_force": "GTC",
+                "post_only": false
+            }),
+        );
+        assert_eq!(order["id"], "42");
+        assert_eq!(order["client_order_id"], "harness");
+        assert_eq!(order["order_type"], "LIMIT");
+
+        let canceled = call(openlimits_cancel_order, client, json!({"id": "42", "market_pair": ["BTC", "USDT"]}));
+        assert_eq!(canceled["id"], "42");
+
+        openlimits_client_free(client);
+    }
+    assert!(fake.served("GET /api/v3/time"));
+    assert!(fake.served("POST /api/v3/order"));
+    assert!(fake.served("DELETE /api/v3/order"));
+}
+
+#[test]
+fn unsupported_market() {
+    let fake = FakeBinance::start();
+    unsafe {
+        let client = new_client(&fake);
+        let request = CString::new(r#"{"market_pair": ["ETH", "USDT"], "size": "1", "price": "1", "time_in_force": "GTC", "post_only": false}"#).unwrap();
+        let mut response = ptr::null_mut();
+        let status = openlimits_limit_buy(client, request.as_ptr(), &mut response);
+        assert_ne!(status, OpenLimitsStatus::Ok);
+        assert!(response.is_null());
+        assert!(!last_error().is_empty());
+        openlimits_client_free(client);
+    }
+}
+
+extern "C" fn forward(user_data: *mut c_void, status: OpenLimitsStatus, message: *const c_char) {
+    let sender = unsafe { &*(user_data as *const Mutex<Sender<(OpenLimitsStatus, Option<String>)>>) };
+    let message = (!message.is_null()).then(|| unsafe { CStr::from_ptr(message) }.to_string_lossy().into_owned());
+    sender.lock().unwrap().send((status, message)).ok();
+}
+
+fn next_message(receiver: &Receiver<(OpenLimitsStatus, Option<String>)>) -> (OpenLimitsStatus, Option<String>) {
+    match receiver.recv_timeout(Duration::from_secs(10)) {
+        Ok(message) => message,
+        Err(RecvTimeoutError::Timeout) => panic!("No message was received."),
+        Err(RecvTimeoutError::Disconnected) => panic!("The stream was dropped."),
+    }
+}
+
+#[test]
+fn trades_stream() {
+    let fake = FakeBinance::start();
+    let (sender, receiver) = mpsc::channel();
+    let sender = Mutex::new(sender);
+    unsafe {
+        let client = new_client(&fake);
+        let subscriptions = CString::new(r#"[{"Trades": ["BTC", "USDT"]}]"#).unwrap();
+        let mut stream = ptr::null_mut();
+        let status = openlimits_stream_new(
+            client,
+            subscriptions.as_ptr(),
+            Some(forward),
+            &sender as *const _ as *mut c_void,
+            &mut stream,
+        );
+        assert_eq!(status, OpenLimitsStatus::Ok, "{}", last_error());
+
+        let (status, message) = next_message(&receiver);
+        assert_eq!(status, OpenLimitsStatus::Ok);
+        let message: Value = serde_json::from_str(&message.expect("The message is null")).unwrap();
+        let trade = &message["Trades"][0];
+        assert_eq!(trade["id"], "12345");
+        assert_eq!(trade["market_pair"], json!(["BTC", "USDT"]));
+        assert_eq!(trade["qty"], "0.25");
+
+        openlimits_stream_free(stream);
+        openlimits_client_free(client);
+    }
+}
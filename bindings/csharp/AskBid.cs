@@ -1,32 +1,8 @@
 namespace OpenLimits
 {
     using System;
-    using System.Runtime.InteropServices;
     using System.Globalization;
-    [StructLayout(LayoutKind.Sequential, Pack = 1)]
-    internal struct FFIAskBid
-    {
-        public readonly IntPtr price;
-        public readonly IntPtr qty;
-
-        public FFIAskBid(IntPtr price, IntPtr qty)
-        {
-            this.price = price;
-            this.qty = qty;
-        }
-
-        public void Dispose() {
-            ExchangeClient.FreeString(price);
-            ExchangeClient.FreeString(qty);
-        }
-
-        public AskBid ToAskBid() {
-            return new AskBid(
-                CString.ToString(this.price),
-                CString.ToString(this.qty)
-            );
-        }
-    }
+    using System.Text.Json;
 
     public struct AskBid
     {
@@ -39,6 +15,11 @@ namespace OpenLimits
             this.qty = Decimal.Parse(qty, System.Globalization.NumberStyles.AllowDecimalPoint, CultureInfo.InvariantCulture);
         }
 
+        internal static AskBid FromJson(JsonElement askBid)
+        {
+            return new AskBid(Json.String(askBid, "price"), Json.String(askBid, "qty"));
+        }
+
         public override string ToString()
         {
             return "AskBid { price=" + price + ", qty=" + qty + "}";
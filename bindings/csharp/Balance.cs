@@ -1,29 +1,6 @@
 namespace OpenLimits
 {
-    using System.Runtime.InteropServices;
-    using System;
-
-    [StructLayout(LayoutKind.Sequential, Pack = 1)]
-    public struct FFIBalance
-    {
-        public readonly IntPtr asset;
-        public readonly IntPtr total;
-        public readonly IntPtr free;
-
-        public void Dispose() {
-            ExchangeClient.FreeString(asset);
-            ExchangeClient.FreeString(total);
-            ExchangeClient.FreeString(free);
-        }
-
-        public Balance ToBalance() {
-            return new Balance(
-                CString.ToString(this.asset),
-                CString.ToString(this.total),
-                CString.ToString(this.free)
-            );
-        }
-    }
+    using System.Text.Json;
 
     public struct Balance
     {
@@ -37,5 +14,10 @@ namespace OpenLimits
             this.total = total;
             this.free = free;
         }
+
+        internal static Balance FromJson(JsonElement balance)
+        {
+            return new Balance(Json.String(balance, "asset"), Json.String(balance, "total"), Json.String(balance, "free"));
+        }
     }
 }
\ No newline at end of file
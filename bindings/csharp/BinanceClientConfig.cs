@@ -1,8 +1,6 @@
 
 namespace OpenLimits
 {
-    using System.Runtime.InteropServices;
-    [StructLayout(LayoutKind.Sequential, Pack = 1)]
     public struct BinanceClientConfig
     {
         public readonly string apikey;
@@ -23,5 +21,13 @@ namespace OpenLimits
         public static BinanceClientConfig Unauthenticated(bool sandbox) {
             return new BinanceClientConfig(null, null, sandbox);
         }
+
+        internal string ToJson() {
+            return Json.Serialize(new {
+                exchange = "binance",
+                sandbox,
+                credentials = apikey == null ? null : new { api_key = apikey, api_secret = secret },
+            });
+        }
     }
 }
\ No newline at end of file
@@ -1,7 +1,8 @@
 namespace OpenLimits
 {
-    using System.Runtime.InteropServices;
-    [StructLayout(LayoutKind.Sequential, Pack = 1)]
+    using System.Globalization;
+    using System.Text.Json;
+
     public struct Candle
     {
         public readonly ulong time;
@@ -20,5 +21,18 @@ namespace OpenLimits
             this.close = close;
             this.volume = volume;
         }
+
+        internal static Candle FromJson(JsonElement candle)
+        {
+            double Field(string property) => double.Parse(Json.String(candle, property), CultureInfo.InvariantCulture);
+            return new Candle(
+                Json.Millis(candle, "time"),
+                Field("low"),
+                Field("high"),
+                Field("open"),
+                Field("close"),
+                Field("volume")
+            );
+        }
     }
 }
\ No newline at end of file
@@ -1,8 +1,6 @@
 
 namespace OpenLimits
 {
-    using System.Runtime.InteropServices;
-    [StructLayout(LayoutKind.Sequential, Pack = 1)]
     public struct CoinbaseClientConfig
     {
         public readonly string apikey;
@@ -25,5 +23,13 @@ namespace OpenLimits
         public static CoinbaseClientConfig Unauthenticated(bool sandbox) {
             return new CoinbaseClientConfig(null, null, null, sandbox);
         }
+
+        internal string ToJson() {
+            return Json.Serialize(new {
+                exchange = "coinbase",
+                sandbox,
+                credentials = apikey == null ? null : new { api_key = apikey, api_secret = secret, passphrase },
+            });
+        }
     }
 }
\ No newline at end of file
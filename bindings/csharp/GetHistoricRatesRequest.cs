@@ -1,6 +1,5 @@
 namespace OpenLimits
 {
-    using System.Runtime.InteropServices;
 
     public enum Interval {
         OneMinute,
@@ -20,7 +19,6 @@ namespace OpenLimits
         OneMonth,
     }
 
-    [StructLayout(LayoutKind.Sequential)]
     public struct GetHistoricRatesRequest
     {
         public readonly string market;
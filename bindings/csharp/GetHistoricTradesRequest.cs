@@ -1,9 +1,8 @@
 namespace OpenLimits
 {
-    using System.Runtime.InteropServices;
-    [StructLayout(LayoutKind.Sequential, Pack=1)]
     public struct GetHistoricTradesRequest
     {
+        // Unlike the other requests, the market is the symbol of the exchange, such as BTCUSDT.
         public readonly string market;
         public readonly Paginator paginator;
 
@@ -0,0 +1,114 @@
+namespace OpenLimits
+{
+    using System;
+    using System.Collections.Generic;
+    using System.Globalization;
+    using System.Text.Json;
+
+    // Converts between the C# types and the JSON documents of the openlimits model.
+    internal static class Json
+    {
+        static readonly char[] MarketSeparators = { '_', '-', '/' };
+
+        // Markets are written as "base_quote", such as "btc_usdt". The model has them as ["BTC", "USDT"].
+        internal static string[] MarketPair(string market)
+        {
+            if (market == null)
+            {
+                return null;
+            }
+            var symbols = market.Split(MarketSeparators);
+            if (symbols.Length != 2 || symbols[0].Length == 0 || symbols[1].Length == 0)
+            {
+                throw new ArgumentException("Invalid market " + market + ", expected base_quote such as btc_usdt");
+            }
+            return new[] { symbols[0].ToUpperInvariant(), symbols[1].ToUpperInvariant() };
+        }
+
+        // The market in its canonical form, such as "btc_usdt" for "BTC-USDT".
+        internal static string Market(string market)
+        {
+            var pair = MarketPair(market);
+            return (pair[0] + "_" + pair[1]).ToLowerInvariant();
+        }
+
+        internal static string Market(JsonElement marketPair)
+        {
+            return (marketPair[0].GetString() + "_" + marketPair[1].GetString()).ToLowerInvariant();
+        }
+
+        internal static object Paginator(Paginator paginator)
+        {
+            if (paginator == null)
+            {
+                return null;
+            }
+            return new
+            {
+                START_TIME = paginator.startTime == 0 ? (ulong?)null : paginator.startTime,
+                END_TIME = paginator.endTime == 0 ? (ulong?)null : paginator.endTime,
+                LIMIT = paginator.limit == 0 ? (ulong?)null : paginator.limit,
+                BEFORE = paginator.before,
+                AFTER = paginator.after,
+            };
+        }
+
+        internal static string Serialize(object value)
+        {
+            return JsonSerializer.Serialize(value);
+        }
+
+        internal static string String(JsonElement element, string property)
+        {
+            return element.TryGetProperty(property, out var value) && value.ValueKind != JsonValueKind.Null
+                ? (value.ValueKind == JsonValueKind.String ? value.GetString() : value.GetRawText())
+                : null;
+        }
+
+        internal static decimal? Decimal(JsonElement element, string property)
+        {
+            var value = String(element, property);
+            return value == null ? default(decimal?) : decimal.Parse(value, NumberStyles.Float, CultureInfo.InvariantCulture);
+        }
+
+        // Timestamps are RFC 3339 strings, returned as milliseconds since the epoch.
+        internal static ulong Millis(JsonElement element, string property)
+        {
+            if (!element.TryGetProperty(property, out var value))
+            {
+                return 0;
+            }
+            switch (value.ValueKind)
+            {
+                case JsonValueKind.Number:
+                    return value.GetUInt64();
+                case JsonValueKind.String:
+                    var time = DateTimeOffset.Parse(value.GetString(), CultureInfo.InvariantCulture);
+                    return (ulong)time.ToUnixTimeMilliseconds();
+                default:
+                    return 0;
+            }
+        }
+
+        // Enums are SCREAMING_SNAKE_CASE, such as PARTIALLY_FILLED for OrderStatus.PartiallyFilled.
+        internal static T Enum<T>(JsonElement element, string property, T fallback) where T : struct
+        {
+            var value = String(element, property);
+            if (value != null && System.Enum.TryParse<T>(value.Replace("_", ""), true, out var parsed))
+            {
+                return parsed;
+            }
+            return fallback;
+        }
+
+        internal static List<T> List<T>(JsonElement array, Func<JsonElement, T> convert)
+        {
+            var list = new List<T>();
+            foreach (var element in array.EnumerateArray())
+            {
+                list.Add(convert(element));
+            }
+            return list;
+        }
+    }
+}
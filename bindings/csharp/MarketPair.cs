@@ -1,41 +1,6 @@
 namespace OpenLimits
 {
-    using System.Runtime.InteropServices;
-    using System;
-
-    [StructLayout(LayoutKind.Sequential, Pack = 1)]
-    public struct FFIMarketPair
-    {
-        public readonly IntPtr baseSymbol;
-        public readonly IntPtr quote;
-        public readonly IntPtr symbol;
-        public readonly IntPtr baseIncrement;
-        public readonly IntPtr quoteIncrement;
-        public readonly IntPtr baseMinPrice;
-        public readonly IntPtr quoteMinPrice;
-
-        public void Dispose() {
-            ExchangeClient.FreeString(baseSymbol);
-            ExchangeClient.FreeString(quote);
-            ExchangeClient.FreeString(symbol);
-            ExchangeClient.FreeString(baseIncrement);
-            ExchangeClient.FreeString(quoteIncrement);
-            ExchangeClient.FreeString(baseMinPrice);
-            ExchangeClient.FreeString(quoteMinPrice);
-        }
-
-        public MarketPair ToMarketPair() {
-            return new MarketPair(
-                CString.ToString(this.baseSymbol),
-                CString.ToString(this.quote),
-                CString.ToString(this.symbol),
-                CString.ToString(this.baseIncrement),
-                CString.ToString(this.quoteIncrement),
-                CString.ToString(this.baseMinPrice),
-                CString.ToString(this.quoteMinPrice)
-            );
-        }
-    }
+    using System.Text.Json;
 
     public struct MarketPair
     {
@@ -57,5 +22,18 @@ namespace OpenLimits
             this.baseMinPrice = baseMinPrice;
             this.quoteMinPrice = quoteMinPrice;
         }
+
+        internal static MarketPair FromJson(JsonElement pair)
+        {
+            return new MarketPair(
+                Json.String(pair, "base"),
+                Json.String(pair, "quote"),
+                Json.String(pair, "symbol"),
+                Json.String(pair, "base_increment"),
+                Json.String(pair, "quote_increment"),
+                Json.String(pair, "min_base_trade_size"),
+                Json.String(pair, "min_quote_trade_size")
+            );
+        }
     }
 }
\ No newline at end of file
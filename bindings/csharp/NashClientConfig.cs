@@ -1,12 +1,9 @@
-using System.Diagnostics;
-using System.Runtime.InteropServices;
 namespace OpenLimits
 {
     public enum NashEnvironment {
         Sandbox,
         Production
     }
-    [StructLayout(LayoutKind.Sequential, Pack = 1)]
     public struct NashClientConfig
     {
         public readonly string apikey;
@@ -38,5 +35,17 @@ namespace OpenLimits
         static public NashClientConfig Unauthenticated(ulong clientId, NashEnvironment environment, ulong timeout) {
             return new NashClientConfig(null, null, clientId, environment, timeout, null);
         }
+
+        // The timeout is in milliseconds.
+        internal string ToJson() {
+            return Json.Serialize(new {
+                exchange = "nash",
+                sandbox = environment == NashEnvironment.Sandbox,
+                credentials = apikey == null ? null : new { api_key = apikey, api_secret = secret },
+                client_id = clientId,
+                timeout_ms = timeout,
+                affiliate_code = affiliateCode,
+            });
+        }
     }
 }
\ No newline at end of file
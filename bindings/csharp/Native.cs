@@ -0,0 +1,138 @@
+namespace OpenLimits
+{
+    using System;
+    using System.Runtime.InteropServices;
+
+    // The status codes of openlimits_c, see bindings/c/include/openlimits.h.
+    internal enum Status
+    {
+        Ok = 0,
+        InvalidArgument = 1,
+        ExchangeError = 2,
+        Unsupported = 3,
+        Disconnected = 4,
+        Panic = 5,
+    }
+
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    internal delegate void NativeCallback(IntPtr userData, Status status, IntPtr message);
+
+    internal delegate Status NativeRequest(IntPtr client, string request, out IntPtr response);
+
+    // The C ABI of openlimits. Requests and responses are JSON documents of the openlimits model,
+    // and the strings returned by the library must be freed with StringFree.
+    internal static class Native
+    {
+        const string NativeLib = "openlimits_c";
+
+        [DllImport(NativeLib, EntryPoint = "openlimits_last_error", ExactSpelling = true, CallingConvention = CallingConvention.Cdecl)]
+        private static extern IntPtr LastError();
+
+        [DllImport(NativeLib, EntryPoint = "openlimits_string_free", ExactSpelling = true, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern void StringFree(IntPtr handle);
+
+        [DllImport(NativeLib, EntryPoint = "openlimits_client_new", ExactSpelling = true, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern Status ClientNew([MarshalAs(UnmanagedType.LPUTF8Str)] string config, out IntPtr client);
+
+        [DllImport(NativeLib, EntryPoint = "openlimits_client_free", ExactSpelling = true, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern void ClientFree(IntPtr client);
+
+        [DllImport(NativeLib, EntryPoint = "openlimits_retrieve_pairs", ExactSpelling = true, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern Status RetrievePairs(IntPtr client, out IntPtr response);
+
+        [DllImport(NativeLib, EntryPoint = "openlimits_order_book", ExactSpelling = true, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern Status OrderBook(IntPtr client, [MarshalAs(UnmanagedType.LPUTF8Str)] string request, out IntPtr response);
+
+        [DllImport(NativeLib, EntryPoint = "openlimits_get_price_ticker", ExactSpelling = true, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern Status GetPriceTicker(IntPtr client, [MarshalAs(UnmanagedType.LPUTF8Str)] string request, out IntPtr response);
+
+        [DllImport(NativeLib, EntryPoint = "openlimits_get_historic_rates", ExactSpelling = true, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern Status GetHistoricRates(IntPtr client, [MarshalAs(UnmanagedType.LPUTF8Str)] string request, out IntPtr response);
+
+        [DllImport(NativeLib, EntryPoint = "openlimits_get_historic_trades", ExactSpelling = true, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern Status GetHistoricTrades(IntPtr client, [MarshalAs(UnmanagedType.LPUTF8Str)] string request, out IntPtr response);
+
+        [DllImport(NativeLib, EntryPoint = "openlimits_limit_buy", ExactSpelling = true, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern Status LimitBuy(IntPtr client, [MarshalAs(UnmanagedType.LPUTF8Str)] string request, out IntPtr response);
+
+        [DllImport(NativeLib, EntryPoint = "openlimits_limit_sell", ExactSpelling = true, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern Status LimitSell(IntPtr client, [MarshalAs(UnmanagedType.LPUTF8Str)] string request, out IntPtr response);
+
+        [DllImport(NativeLib, EntryPoint = "openlimits_market_buy", ExactSpelling = true, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern Status MarketBuy(IntPtr client, [MarshalAs(UnmanagedType.LPUTF8Str)] string request, out IntPtr response);
+
+        [DllImport(NativeLib, EntryPoint = "openlimits_market_sell", ExactSpelling = true, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern Status MarketSell(IntPtr client, [MarshalAs(UnmanagedType.LPUTF8Str)] string request, out IntPtr response);
+
+        [DllImport(NativeLib, EntryPoint = "openlimits_cancel_order", ExactSpelling = true, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern Status CancelOrder(IntPtr client, [MarshalAs(UnmanagedType.LPUTF8Str)] string request, out IntPtr response);
+
+        [DllImport(NativeLib, EntryPoint = "openlimits_cancel_all_orders", ExactSpelling = true, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern Status CancelAllOrders(IntPtr client, [MarshalAs(UnmanagedType.LPUTF8Str)] string request, out IntPtr response);
+
+        [DllImport(NativeLib, EntryPoint = "openlimits_get_all_open_orders", ExactSpelling = true, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern Status GetAllOpenOrders(IntPtr client, out IntPtr response);
+
+        [DllImport(NativeLib, EntryPoint = "openlimits_get_order_history", ExactSpelling = true, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern Status GetOrderHistory(IntPtr client, [MarshalAs(UnmanagedType.LPUTF8Str)] string request, out IntPtr response);
+
+        [DllImport(NativeLib, EntryPoint = "openlimits_get_trade_history", ExactSpelling = true, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern Status GetTradeHistory(IntPtr client, [MarshalAs(UnmanagedType.LPUTF8Str)] string request, out IntPtr response);
+
+        [DllImport(NativeLib, EntryPoint = "openlimits_get_account_balances", ExactSpelling = true, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern Status GetAccountBalances(IntPtr client, [MarshalAs(UnmanagedType.LPUTF8Str)] string paginator, out IntPtr response);
+
+        [DllImport(NativeLib, EntryPoint = "openlimits_get_order", ExactSpelling = true, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern Status GetOrder(IntPtr client, [MarshalAs(UnmanagedType.LPUTF8Str)] string request, out IntPtr response);
+
+        [DllImport(NativeLib, EntryPoint = "openlimits_stream_new", ExactSpelling = true, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern Status StreamNew(IntPtr client, [MarshalAs(UnmanagedType.LPUTF8Str)] string subscriptions, NativeCallback callback, IntPtr userData, out IntPtr stream);
+
+        [DllImport(NativeLib, EntryPoint = "openlimits_stream_free", ExactSpelling = true, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern void StreamFree(IntPtr stream);
+
+        // Copies a string of the library, which stays owned by the library.
+        internal static string CopyString(IntPtr handle)
+        {
+            return handle == IntPtr.Zero ? null : Marshal.PtrToStringUTF8(handle);
+        }
+
+        // Copies a string returned by the library and frees it.
+        internal static string TakeString(IntPtr handle)
+        {
+            var value = CopyString(handle);
+            if (handle != IntPtr.Zero)
+            {
+                StringFree(handle);
+            }
+            return value;
+        }
+
+        // Throws the error of the last call of this thread unless it succeeded.
+        internal static void Check(Status status)
+        {
+            if (status == Status.Ok)
+            {
+                return;
+            }
+            throw ToException(status, TakeString(LastError()) ?? "Unknown error");
+        }
+
+        internal static Exception ToException(Status status, string message)
+        {
+            switch (status)
+            {
+                case Status.InvalidArgument:
+                    return new ArgumentException(message);
+                case Status.Unsupported:
+                    return new MissingImplementation(message);
+                case Status.Disconnected:
+                    return new Disconnected(message);
+                case Status.Panic:
+                    return new NativePanic(message);
+                default:
+                    return new ExchangeError(message);
+            }
+        }
+    }
+}
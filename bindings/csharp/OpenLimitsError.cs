@@ -0,0 +1,28 @@
+namespace OpenLimits
+{
+    using System;
+
+    public class OpenLimitsError: Exception {
+        public OpenLimitsError(string message): base(message) { }
+    }
+
+    // The exchange, or the way to it, failed.
+    public class ExchangeError : OpenLimitsError {
+        public ExchangeError(string message): base(message) { }
+    };
+
+    // The exchange doesn't support the operation.
+    public class MissingImplementation : OpenLimitsError {
+        public MissingImplementation(string message): base(message) { }
+    };
+
+    // The websocket was closed by the exchange.
+    public class Disconnected : OpenLimitsError {
+        public Disconnected(string message): base(message) { }
+    };
+
+    // The native library panicked, the client shouldn't be used anymore.
+    public class NativePanic : OpenLimitsError {
+        public NativePanic(string message): base(message) { }
+    };
+}
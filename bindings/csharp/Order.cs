@@ -2,43 +2,7 @@ namespace OpenLimits
 {
     using System;
     using System.Globalization;
-
-    internal struct FFIOrder
-    {
-        public readonly IntPtr id;
-        public readonly IntPtr marketPair;
-        public readonly IntPtr clientOrderId;
-        public readonly ulong createdAt;
-        public readonly OrderType orderType;
-        public readonly Side side;
-        public readonly OrderStatus status;
-        public readonly IntPtr size;
-        public readonly IntPtr price;
-        public readonly IntPtr remaining;
-
-        public void Dispose() {
-            ExchangeClient.FreeString(id);
-            ExchangeClient.FreeString(marketPair);
-            ExchangeClient.FreeString(size);
-            ExchangeClient.FreeString(price);
-            ExchangeClient.FreeString(remaining);
-        }
-
-        public Order ToOrder() {
-            return new Order(
-                CString.ToString(this.id),
-                CString.ToString(this.marketPair),
-                CString.ToString(this.clientOrderId),
-                this.createdAt,
-                this.orderType,
-                this.side,
-                this.status,
-                CString.ToString(this.size),
-                CString.ToString(this.price),
-                CString.ToString(this.remaining)
-            );
-        }
-    }
+    using System.Text.Json;
 
     public struct Order
     {
@@ -68,6 +32,22 @@ namespace OpenLimits
             this.remaining = remaining == null ? default(decimal?) : decimal.Parse(remaining, System.Globalization.NumberStyles.AllowDecimalPoint, CultureInfo.InvariantCulture);
         }
 
+        internal static Order FromJson(JsonElement order)
+        {
+            return new Order(
+                Json.String(order, "id"),
+                Json.Market(order.GetProperty("market_pair")),
+                Json.String(order, "client_order_id"),
+                Json.Millis(order, "created_at"),
+                Json.Enum(order, "order_type", OrderType.Unknown),
+                Json.Enum(order, "side", Side.Buy),
+                Json.Enum(order, "status", OrderStatus.New),
+                Json.String(order, "size"),
+                Json.String(order, "price"),
+                Json.String(order, "remaining")
+            );
+        }
+
         public override bool Equals(object obj)
         {
             return base.Equals(obj);
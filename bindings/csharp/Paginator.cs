@@ -1,7 +1,5 @@
 namespace OpenLimits
 {
-    using System.Runtime.InteropServices;
-    [StructLayout(LayoutKind.Sequential, Pack = 1)]
     public class Paginator
     {
         public readonly ulong startTime;
@@ -1,49 +1,8 @@
 namespace OpenLimits
 {
-    using System.Runtime.InteropServices;
     using System;
     using System.Globalization;
-
-
-    [StructLayout(LayoutKind.Sequential)]
-    internal struct FFITrade {
-        public readonly IntPtr id;
-        public readonly IntPtr buyerOrderId;
-        public readonly IntPtr sellerOrderId;
-        public readonly IntPtr marketPair;
-
-        public readonly IntPtr price;
-        public readonly IntPtr qty;
-        public readonly IntPtr fees;
-        public readonly Side side;
-        public readonly Liquidity liquidity;
-        public readonly ulong createdAt;
-
-        public void Dispose() {
-            ExchangeClient.FreeString(id);
-            ExchangeClient.FreeString(buyerOrderId);
-            ExchangeClient.FreeString(sellerOrderId);
-            ExchangeClient.FreeString(marketPair);
-            ExchangeClient.FreeString(price);
-            ExchangeClient.FreeString(qty);
-            ExchangeClient.FreeString(fees);
-        }
-
-        public Trade ToTrade() {
-            return new Trade(
-                CString.ToString(this.id),
-                CString.ToString(this.buyerOrderId),
-                CString.ToString(this.sellerOrderId),
-                CString.ToString(this.marketPair),
-                CString.ToString(this.price),
-                CString.ToString(this.qty),
-                CString.ToString(this.fees),
-                this.side,
-                this.liquidity,
-                this.createdAt
-            );
-        }
-    }
+    using System.Text.Json;
 
     public struct Trade {
         public readonly string id;
@@ -72,6 +31,22 @@ namespace OpenLimits
             this.createdAt = createdAt;
         }
 
+        internal static Trade FromJson(JsonElement trade)
+        {
+            return new Trade(
+                Json.String(trade, "id"),
+                Json.String(trade, "buyer_order_id"),
+                Json.String(trade, "seller_order_id"),
+                Json.Market(trade.GetProperty("market_pair")),
+                Json.String(trade, "price"),
+                Json.String(trade, "qty"),
+                Json.String(trade, "fees"),
+                Json.Enum(trade, "side", Side.Buy),
+                Json.Enum(trade, "liquidity", Liquidity.Unknown),
+                Json.Millis(trade, "created_at")
+            );
+        }
+
         public override string ToString()
         {
             return "Trade{" +
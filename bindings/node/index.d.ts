@@ -0,0 +1,102 @@
+/* Generated by `cargo run --bin typings`, do not edit. */
+
+import { EventEmitter } from "events";
+
+export type ExchangeKind = "binance" | "coinbase" | "huobi" | "nash";
+export type ExchangeConfig = { exchange: ExchangeKind, sandbox: boolean, credentials?: { [key in string]?: string } | null, 
+/**
+ * Overrides the REST base URL of the environment.
+ */
+rest_endpoint?: string | null, 
+/**
+ * Overrides the websocket URL of the environment.
+ */
+websocket_endpoint?: string | null, 
+/**
+ * The client id of a Nash session.
+ */
+client_id?: number | null, 
+/**
+ * The request timeout of a Nash session, in milliseconds.
+ */
+timeout_ms?: number | null, 
+/**
+ * The Nash affiliate code credited with the orders.
+ */
+affiliate_code?: string | null, };
+export type MarketPair = [string, string];
+export type Side = "BUY" | "SELL";
+export type OrderType = "LIMIT" | "MARKET" | "STOP_LIMIT" | "STOP_MARKET" | "UNKNOWN";
+export type OrderStatus = "NEW" | "PARTIALLY_FILLED" | "FILLED" | "CANCELED" | "PENDING_CANCEL" | "REJECTED" | "EXPIRED" | "OPEN" | "PENDING" | "ACTIVE";
+export type Liquidity = "MAKER" | "TAKER";
+export type TimeInForce = "GTC" | "IOC" | "FOK" | `GTT,${number}`;
+export type Interval = "1m" | "3m" | "5m" | "15m" | "30m" | "1h" | "2h" | "4h" | "6h" | "8h" | "12h" | "1d" | "3d" | "1w" | "1mo";
+export type Operation = "ORDER_BOOK" | "GET_PRICE_TICKER" | "GET_ALL_TICKERS" | "GET_HISTORIC_RATES" | "GET_HISTORIC_TRADES" | "LIMIT_BUY" | "LIMIT_SELL" | "MARKET_BUY" | "MARKET_SELL" | "CANCEL_ORDER" | "CANCEL_ALL_ORDERS" | "GET_ALL_OPEN_ORDERS" | "GET_ORDER_HISTORY" | "GET_TRADE_HISTORY" | "GET_ACCOUNT_BALANCES" | "GET_ORDER" | "GET_LEDGER" | "GET_DEPOSIT_ADDRESS" | "GET_DEPOSIT_HISTORY" | "WITHDRAW" | "GET_WITHDRAWAL_HISTORY";
+export type BatchOperation = "CANCEL_ALL_ORDERS" | "CANCEL_ORDERS" | "PLACE_ORDERS";
+export type LedgerEntryType = "TRADE" | "FEE" | "REBATE" | "DEPOSIT" | "WITHDRAWAL" | "TRANSFER" | "CONVERSION" | "OTHER";
+export type SubscriptionKind = "OrderBookUpdates" | "Trades";
+export type Paginator = { START_TIME?: string | number | null, END_TIME?: string | number | null, LIMIT?: number | null, BEFORE?: string | null, AFTER?: string | null, };
+export type AskBid = { price: string, qty: string, };
+export type Balance = { asset: string, total: string, free: string, };
+export type Batch = { operation: BatchOperation, max_orders: number | null, };
+export type Candle = { time: string, low: string, high: string, open: string, close: string, volume: string, };
+export type Capabilities = { operations: Array<Operation>, order_types: Array<OrderType>, time_in_force: Array<TimeInForce>, intervals: Array<Interval>, subscriptions: Array<SubscriptionKind>, 
+/**
+ * The operations the venue runs as one request. A `CancelAllOrders` missing here is done by
+ * listing the open orders and canceling them.
+ */
+batch_operations: Array<Batch>, max_book_depth: number | null, };
+export type LedgerEntry = { id: string, asset: string, entry_type: LedgerEntryType, amount: string, balance: string | null, reference_id: string | null, market_pair: MarketPair | null, created_at: string | null, };
+export type MarketPairInfo = { base: string, quote: string, symbol: string, base_increment: string, quote_increment: string, min_base_trade_size: string | null, min_quote_trade_size: string | null, };
+export type Order = { id: string, market_pair: MarketPair, client_order_id: string | null, created_at: string | null, order_type: OrderType, side: Side, status: OrderStatus, size: string, price: string | null, remaining: string | null, trades: Array<Trade>, };
+export type OrderBookResponse = { update_id: number | null, last_update_id: number | null, bids: Array<AskBid>, asks: Array<AskBid>, };
+export type OrderCanceled = { id: string, };
+export type Ticker = { market_pair: MarketPair, price: string | null, price_24h: string | null, bid: string | null, bid_qty: string | null, ask: string | null, ask_qty: string | null, high_24h: string | null, low_24h: string | null, volume_24h: string | null, quote_volume_24h: string | null, created_at: string | null, };
+export type Trade = { id: string, buyer_order_id: string | null, seller_order_id: string | null, market_pair: MarketPair, price: string, qty: string, fees: string | null, side: Side, liquidity: Liquidity | null, created_at: string, };
+export type Subscription = { "OrderBookUpdates": MarketPair } | { "Trades": MarketPair };
+export type OpenLimitsWebSocketMessage = "Ping" | { "OrderBook": OrderBookResponse } | { "Trades": Array<Trade> };
+export type CancelAllOrdersRequest = { market_pair?: MarketPair | null, };
+export type CancelOrderRequest = { id: string, market_pair?: MarketPair | null, };
+export type GetHistoricRatesRequest = { market_pair: MarketPair, paginator?: Paginator | null, interval: Interval, };
+export type GetHistoricTradesRequest = { market_pair: string, paginator?: Paginator | null, };
+export type GetLedgerRequest = { asset: string, market_pair?: MarketPair | null, paginator?: Paginator | null, };
+export type GetOrderHistoryRequest = { market_pair?: MarketPair | null, order_status?: Array<OrderStatus> | null, paginator?: Paginator | null, };
+export type GetOrderRequest = { id: string, market_pair?: MarketPair | null, };
+export type GetPriceTickerRequest = { market_pair: MarketPair, };
+export type OpenLimitOrderRequest = { client_order_id?: string | null, market_pair: MarketPair, size: string, price: string, time_in_force: TimeInForce, post_only: boolean, };
+export type OpenMarketOrderRequest = { client_order_id?: string | null, market_pair: MarketPair, size: string, };
+export type OrderBookRequest = { market_pair: MarketPair, };
+export type TradeHistoryRequest = { market_pair?: MarketPair | null, order_id?: string | null, paginator?: Paginator | null, };
+
+export declare class ExchangeClient {
+  static connect(config: ExchangeConfig): Promise<ExchangeClient>;
+  capabilities(): Capabilities;
+  retrievePairs(): Promise<Array<MarketPairInfo>>;
+  orderBook(request: OrderBookRequest): Promise<OrderBookResponse>;
+  getPriceTicker(request: GetPriceTickerRequest): Promise<Ticker>;
+  getAllTickers(): Promise<Array<Ticker>>;
+  getHistoricRates(request: GetHistoricRatesRequest): Promise<Array<Candle>>;
+  getHistoricTrades(request: GetHistoricTradesRequest): Promise<Array<Trade>>;
+  limitBuy(request: OpenLimitOrderRequest): Promise<Order>;
+  limitSell(request: OpenLimitOrderRequest): Promise<Order>;
+  marketBuy(request: OpenMarketOrderRequest): Promise<Order>;
+  marketSell(request: OpenMarketOrderRequest): Promise<Order>;
+  cancelOrder(request: CancelOrderRequest): Promise<OrderCanceled>;
+  cancelAllOrders(request: CancelAllOrdersRequest): Promise<Array<OrderCanceled>>;
+  getAllOpenOrders(): Promise<Array<Order>>;
+  getOrderHistory(request: GetOrderHistoryRequest): Promise<Array<Order>>;
+  getTradeHistory(request: TradeHistoryRequest): Promise<Array<Trade>>;
+  getAccountBalances(paginator?: Paginator | null): Promise<Array<Balance>>;
+  getOrder(request: GetOrderRequest): Promise<Order>;
+  getLedger(request: GetLedgerRequest): Promise<Array<LedgerEntry>>;
+  subscribe(subscriptions: Array<Subscription>): Promise<Stream>;
+}
+
+export declare class Stream extends EventEmitter {
+  on(event: "message", listener: (message: OpenLimitsWebSocketMessage) => void): this;
+  on(event: "orderBook", listener: (orderBook: OrderBookResponse) => void): this;
+  on(event: "trades", listener: (trades: Array<Trade>) => void): this;
+  on(event: "error", listener: (error: Error) => void): this;
+  on(event: "close", listener: () => void): this;
+  close(): Promise<void>;
+}
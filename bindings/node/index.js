@@ -0,0 +1,60 @@
+'use strict'
+
+const { EventEmitter } = require('events')
+const { ExchangeClient } = require('./openlimits.node')
+
+// The subscriptions of a websocket. Every message is emitted as `message`, and its order books
+// and trades as `orderBook` and `trades`. `close` is emitted once the websocket is closed.
+class Stream extends EventEmitter {
+  constructor () {
+    super()
+    this.native = null
+    this.pending = []
+  }
+
+  // The messages received before `subscribe` resolved are held until its caller could listen.
+  start (native) {
+    this.native = native
+    setImmediate(() => {
+      const pending = this.pending
+      this.pending = null
+      pending.forEach(([error, message]) => this.dispatch(error, message))
+    })
+  }
+
+  receive (error, message) {
+    if (this.pending) {
+      this.pending.push([error, message])
+    } else {
+      this.dispatch(error, message)
+    }
+  }
+
+  dispatch (error, message) {
+    if (error) {
+      this.emit('error', error)
+    } else if (message === null) {
+      this.emit('close')
+    } else {
+      this.emit('message', message)
+      if (message.OrderBook) {
+        this.emit('orderBook', message.OrderBook)
+      }
+      if (message.Trades) {
+        this.emit('trades', message.Trades)
+      }
+    }
+  }
+
+  close () {
+    return this.native.close()
+  }
+}
+
+ExchangeClient.prototype.subscribe = async function subscribe (subscriptions) {
+  const stream = new Stream()
+  stream.start(await this.openStream(subscriptions, (error, message) => stream.receive(error, message)))
+  return stream
+}
+
+module.exports = { ExchangeClient, Stream }
@@ -0,0 +1,14 @@
+'use strict'
+
+// Copies the library built by cargo to `openlimits.node`, the addon loaded by `index.js`.
+const fs = require('fs')
+const path = require('path')
+
+const profile = process.argv[2] || 'release'
+const target = process.env.CARGO_TARGET_DIR || path.join(__dirname, '..', 'target')
+const library = {
+  darwin: 'libopenlimits_node.dylib',
+  win32: 'openlimits_node.dll'
+}[process.platform] || 'libopenlimits_node.so'
+
+fs.copyFileSync(path.join(target, profile, library), path.join(__dirname, '..', 'openlimits.node'))
@@ -0,0 +1,68 @@
+//! Generates `index.d.ts`, the declarations of the models derived from their structs followed by
+//! the declarations of the client. With `--check`, fails if the file is out of date instead.
+
+use std::{env, fs, process};
+use openlimits::exchange::config::{ExchangeConfig, ExchangeKind};
+use openlimits::model::typescript::declarations;
+use ts_rs::TS;
+
+const HEADER: &str = "/* Generated by `cargo run --bin typings`, do not edit. */
+
+import { EventEmitter } from \"events\";
+";
+
+const CLIENT: &str = "export declare class ExchangeClient {
+  static connect(config: ExchangeConfig): Promise<ExchangeClient>;
+  capabilities(): Capabilities;
+  retrievePairs(): Promise<Array<MarketPairInfo>>;
+  orderBook(request: OrderBookRequest): Promise<OrderBookResponse>;
+  getPriceTicker(request: GetPriceTickerRequest): Promise<Ticker>;
+  getAllTickers(): Promise<Array<Ticker>>;
+  getHistoricRates(request: GetHistoricRatesRequest): Promise<Array<Candle>>;
+  getHistoricTrades(request: GetHistoricTradesRequest): Promise<Array<Trade>>;
+  limitBuy(request: OpenLimitOrderRequest): Promise<Order>;
+  limitSell(request: OpenLimitOrderRequest): Promise<Order>;
+  marketBuy(request: OpenMarketOrderRequest): Promise<Order>;
+  marketSell(request: OpenMarketOrderRequest): Promise<Order>;
+  cancelOrder(request: CancelOrderRequest): Promise<OrderCanceled>;
+  cancelAllOrders(request: CancelAllOrdersRequest): Promise<Array<OrderCanceled>>;
+  getAllOpenOrders(): Promise<Array<Order>>;
+  getOrderHistory(request: GetOrderHistoryRequest): Promise<Array<Order>>;
+  getTradeHistory(request: TradeHistoryRequest): Promise<Array<Trade>>;
+  getAccountBalances(paginator?: Paginator | null): Promise<Array<Balance>>;
+  getOrder(request: GetOrderRequest): Promise<Order>;
+  getLedger(request: GetLedgerRequest): Promise<Array<LedgerEntry>>;
+  subscribe(subscriptions: Array<Subscription>): Promise<Stream>;
+}
+
+export declare class Stream extends EventEmitter {
+  on(event: \"message\", listener: (message: OpenLimitsWebSocketMessage) => void): this;
+  on(event: \"orderBook\", listener: (orderBook: OrderBookResponse) => void): this;
+  on(event: \"trades\", listener: (trades: Array<Trade>) => void): this;
+  on(event: \"error\", listener: (error: Error) => void): this;
+  on(event: \"close\", listener: () => void): this;
+  close(): Promise<void>;
+}
+";
+
+fn typings() -> String {
+    let mut typings = vec![HEADER.to_string()];
+    typings.push(format!("export {}", ExchangeKind::decl()));
+    typings.push(format!("export {}", ExchangeConfig::decl()));
+    typings.extend(declarations());
+    typings.push(format!("\n{}", CLIENT));
+    typings.join("\n")
+}
+
+fn main() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/index.d.ts");
+    let typings = typings();
+    if env::args().any(|argument| argument == "--check") {
+        if fs::read_to_string(path).ok().as_deref() != Some(typings.as_str()) {
+            eprintln!("index.d.ts is out of date, run `cargo run --bin typings`.");
+            process::exit(1);
+        }
+    } else {
+        fs::write(path, typings).expect("Couldn't write index.d.ts");
+    }
+}
@@ -0,0 +1,169 @@
+use std::convert::TryFrom;
+use napi::threadsafe_function::{ErrorStrategy, ThreadsafeFunction};
+use napi::Result;
+use napi_derive::napi;
+use serde::Serialize;
+use serde_json::Value;
+use openlimits::exchange::any::{AnyExchange, AnyWebsocket, InitAnyExchange};
+use openlimits::exchange::config::ExchangeConfig;
+use openlimits::exchange::traits::info::ExchangeInfoRetrieval;
+use openlimits::exchange::traits::{Exchange, ExchangeAccount, ExchangeMarketData};
+use openlimits::model::websocket::Subscription;
+use openlimits::model::{
+    CancelAllOrdersRequest, CancelOrderRequest, GetHistoricRatesRequest, GetHistoricTradesRequest,
+    GetLedgerRequest, GetOrderHistoryRequest, GetOrderRequest, GetPriceTickerRequest,
+    OpenLimitOrderRequest, OpenMarketOrderRequest, OrderBookRequest, Paginator, TradeHistoryRequest,
+};
+use crate::stream::NativeStream;
+use crate::{from_json, to_json, to_napi_error};
+
+/// A client of one exchange, created by `ExchangeClient.connect(config)`.
+#[napi]
+pub struct ExchangeClient {
+    exchange: AnyExchange,
+    init: InitAnyExchange,
+}
+
+/// Hands the result of an operation over as JSON.
+fn respond<T: Serialize>(result: openlimits::exchange::shared::Result<T>) -> Result<Value> {
+    to_json(&result.map_err(to_napi_error)?)
+}
+
+#[napi]
+impl ExchangeClient {
+    /// Connects to the exchange of an `ExchangeConfig`, such as
+    /// `{exchange: "coinbase", sandbox: true, credentials: {api_key: "...", api_secret: "...", passphrase: "..."}}`.
+    #[napi]
+    pub async fn connect(config: Value) -> Result<ExchangeClient> {
+        let config: ExchangeConfig = from_json(config, "config")?;
+        let init = InitAnyExchange::try_from(&config).map_err(to_napi_error)?;
+        let exchange = AnyExchange::new(init.clone()).await.map_err(to_napi_error)?;
+        Ok(ExchangeClient { exchange, init })
+    }
+
+    /// The operations, order types and subscriptions the exchange supports.
+    #[napi]
+    pub fn capabilities(&self) -> Result<Value> {
+        to_json(&self.exchange.capabilities())
+    }
+
+    #[napi]
+    pub async fn retrieve_pairs(&self) -> Result<Value> {
+        respond(self.exchange.retrieve_pairs().await)
+    }
+
+    #[napi]
+    pub async fn order_book(&self, request: Value) -> Result<Value> {
+        let request: OrderBookRequest = from_json(request, "request")?;
+        respond(self.exchange.order_book(&request).await)
+    }
+
+    #[napi]
+    pub async fn get_price_ticker(&self, request: Value) -> Result<Value> {
+        let request: GetPriceTickerRequest = from_json(request, "request")?;
+        respond(self.exchange.get_price_ticker(&request).await)
+    }
+
+    #[napi]
+    pub async fn get_all_tickers(&self) -> Result<Value> {
+        respond(self.exchange.get_all_tickers().await)
+    }
+
+    #[napi]
+    pub async fn get_historic_rates(&self, request: Value) -> Result<Value> {
+        let request: GetHistoricRatesRequest = from_json(request, "request")?;
+        respond(self.exchange.get_historic_rates(&request).await)
+    }
+
+    #[napi]
+    pub async fn get_historic_trades(&self, request: Value) -> Result<Value> {
+        let request: GetHistoricTradesRequest = from_json(request, "request")?;
+        respond(self.exchange.get_historic_trades(&request).await)
+    }
+
+    #[napi]
+    pub async fn limit_buy(&self, request: Value) -> Result<Value> {
+        let request: OpenLimitOrderRequest = from_json(request, "request")?;
+        respond(self.exchange.limit_buy(&request).await)
+    }
+
+    #[napi]
+    pub async fn limit_sell(&self, request: Value) -> Result<Value> {
+        let request: OpenLimitOrderRequest = from_json(request, "request")?;
+        respond(self.exchange.limit_sell(&request).await)
+    }
+
+    #[napi]
+    pub async fn market_buy(&self, request: Value) -> Result<Value> {
+        let request: OpenMarketOrderRequest = from_json(request, "request")?;
+        respond(self.exchange.market_buy(&request).await)
+    }
+
+    #[napi]
+    pub async fn market_sell(&self, request: Value) -> Result<Value> {
+        let request: OpenMarketOrderRequest = from_json(request, "request")?;
+        respond(self.exchange.market_sell(&request).await)
+    }
+
+    #[napi]
+    pub async fn cancel_order(&self, request: Value) -> Result<Value> {
+        let request: CancelOrderRequest = from_json(request, "request")?;
+        respond(self.exchange.cancel_order(&request).await)
+    }
+
+    #[napi]
+    pub async fn cancel_all_orders(&self, request: Value) -> Result<Value> {
+        let request: CancelAllOrdersRequest = from_json(request, "request")?;
+        respond(self.exchange.cancel_all_orders(&request).await)
+    }
+
+    #[napi]
+    pub async fn get_all_open_orders(&self) -> Result<Value> {
+        respond(self.exchange.get_all_open_orders().await)
+    }
+
+    #[napi]
+    pub async fn get_order_history(&self, request: Value) -> Result<Value> {
+        let request: GetOrderHistoryRequest = from_json(request, "request")?;
+        respond(self.exchange.get_order_history(&request).await)
+    }
+
+    #[napi]
+    pub async fn get_trade_history(&self, request: Value) -> Result<Value> {
+        let request: TradeHistoryRequest = from_json(request, "request")?;
+        respond(self.exchange.get_trade_history(&request).await)
+    }
+
+    #[napi]
+    pub async fn get_account_balances(&self, paginator: Option<Value>) -> Result<Value> {
+        let paginator: Option<Paginator> = paginator.map(|paginator| from_json(paginator, "paginator")).transpose()?;
+        respond(self.exchange.get_account_balances(paginator).await)
+    }
+
+    #[napi]
+    pub async fn get_order(&self, request: Value) -> Result<Value> {
+        let request: GetOrderRequest = from_json(request, "request")?;
+        respond(self.exchange.get_order(&request).await)
+    }
+
+    #[napi]
+    pub async fn get_ledger(&self, request: Value) -> Result<Value> {
+        let request: GetLedgerRequest = from_json(request, "request")?;
+        respond(self.exchange.get_ledger(&request).await)
+    }
+
+    /// Subscribes to a list of `Subscription`s, such as `[{Trades: ["BTC", "USDT"]}]`, on a
+    /// websocket of the exchange. `callback` is called with each message, with the errors, and
+    /// with null once the websocket is closed. `subscribe` of `index.js` wraps it in a `Stream`.
+    #[napi]
+    pub async fn open_stream(
+        &self,
+        subscriptions: Value,
+        callback: ThreadsafeFunction<Value, ErrorStrategy::CalleeHandled>,
+    ) -> Result<NativeStream> {
+        let subscriptions: Vec<Subscription> = from_json(subscriptions, "subscriptions")?;
+        let mut websocket = AnyWebsocket::new(self.init.clone()).await.map_err(to_napi_error)?;
+        let messages = websocket.create_stream(&subscriptions).await.map_err(to_napi_error)?;
+        Ok(NativeStream::new(websocket, messages, callback))
+    }
+}
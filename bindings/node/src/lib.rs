@@ -0,0 +1,51 @@
+//! The Node.js bindings of OpenLimits, a Promise based client of every exchange built as the
+//! `openlimits.node` addon and loaded by `index.js`.
+//!
+//! ## Conventions
+//!
+//! * Requests and responses are the JSON of the `openlimits::model` types, such as
+//!   `{market_pair: ["BTC", "USDT"]}` for `orderBook`. `index.d.ts` declares them and is generated
+//!   from the models by `cargo run --bin typings`.
+//! * The methods of `ExchangeClient` return Promises, run on the tokio runtime of napi.
+//! * Subscriptions are delivered by the `Stream` EventEmitter of `index.js`, which the addon feeds
+//!   through `openStream`.
+//! * Invalid requests reject with the `InvalidArg` code, the other failures with `GenericFailure`.
+//!   Operations the exchange doesn't support have a message starting with "Unsupported".
+
+use napi::{Error, Status};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use openlimits::errors::OpenLimitsError;
+
+mod client;
+mod stream;
+
+pub use client::ExchangeClient;
+pub use stream::NativeStream;
+
+pub(crate) fn to_napi_error(error: OpenLimitsError) -> Error {
+    // Most of the variants display as an empty string, so those fall back to `Debug`.
+    let message = match error.to_string() {
+        message if message.is_empty() => format!("{:?}", error),
+        message => message,
+    };
+    match error {
+        OpenLimitsError::MissingImplementation(_) => {
+            Error::new(Status::GenericFailure, format!("Unsupported: {}", message))
+        }
+        OpenLimitsError::InvalidParameter(_) | OpenLimitsError::MissingParameter(_) => {
+            Error::new(Status::InvalidArg, message)
+        }
+        _ => Error::new(Status::GenericFailure, message),
+    }
+}
+
+pub(crate) fn from_json<T: DeserializeOwned>(value: Value, name: &str) -> napi::Result<T> {
+    serde_json::from_value(value)
+        .map_err(|error| Error::new(Status::InvalidArg, format!("The {} is invalid: {}", name, error)))
+}
+
+pub(crate) fn to_json<T: Serialize>(value: &T) -> napi::Result<Value> {
+    serde_json::to_value(value).map_err(|error| Error::from_reason(error.to_string()))
+}
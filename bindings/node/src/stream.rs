@@ -0,0 +1,64 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use napi::threadsafe_function::{ErrorStrategy, ThreadsafeFunction, ThreadsafeFunctionCallMode};
+use napi::Result;
+use napi_derive::napi;
+use serde_json::Value;
+use tokio::task::JoinHandle;
+use openlimits::exchange::any::AnyWebsocket;
+use openlimits::model::websocket::OpenLimitsWebSocketMessage;
+use crate::{to_json, to_napi_error};
+
+/// The subscriptions of a websocket, created by `ExchangeClient.openStream`.
+#[napi]
+pub struct NativeStream {
+    websocket: Arc<AnyWebsocket>,
+    closed: Arc<AtomicBool>,
+    task: JoinHandle<()>,
+}
+
+impl NativeStream {
+    pub(crate) fn new(
+        websocket: AnyWebsocket,
+        mut messages: BoxStream<'static, openlimits::exchange::shared::Result<OpenLimitsWebSocketMessage>>,
+        callback: ThreadsafeFunction<Value, ErrorStrategy::CalleeHandled>,
+    ) -> Self {
+        let closed = Arc::new(AtomicBool::new(false));
+        let stream_closed = closed.clone();
+        let task = tokio::spawn(async move {
+            while let Some(message) = messages.next().await {
+                // The websocket may fail its pending reads once it was closed.
+                if stream_closed.load(Ordering::SeqCst) {
+                    break;
+                }
+                let message = message.map_err(to_napi_error).and_then(|message| to_json(&message));
+                callback.call(message, ThreadsafeFunctionCallMode::NonBlocking);
+            }
+            callback.call(Ok(Value::Null), ThreadsafeFunctionCallMode::NonBlocking);
+        });
+        Self {
+            websocket: Arc::new(websocket),
+            closed,
+            task,
+        }
+    }
+}
+
+#[napi]
+impl NativeStream {
+    /// Closes the websocket. The callback is then called one last time, with null.
+    #[napi]
+    pub async fn close(&self) -> Result<()> {
+        self.closed.store(true, Ordering::SeqCst);
+        self.websocket.disconnect().await;
+        Ok(())
+    }
+}
+
+impl Drop for NativeStream {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
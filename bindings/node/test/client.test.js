@@ -0,0 +1,67 @@
+'use strict'
+
+// Drives the addon against a local fake of Binance, without a network. Run `npm run build:debug`
+// first; the fake is built by cargo on the first run.
+const assert = require('assert')
+const { after, before, test } = require('node:test')
+const { ExchangeClient, Stream } = require('..')
+const fakeBinance = require('./fake-binance')
+
+let fake
+
+before(async () => {
+  fake = await fakeBinance.start()
+})
+
+after(() => fake.stop())
+
+test('market data and orders', async () => {
+  const client = await ExchangeClient.connect(fake.config)
+  assert.ok(client.capabilities().operations.includes('ORDER_BOOK'))
+
+  const pairs = await client.retrievePairs()
+  assert.strictEqual(pairs[0].symbol, 'BTCUSDT')
+
+  const orderBook = await client.orderBook({ market_pair: ['BTC', 'USDT'] })
+  assert.strictEqual(orderBook.bids[0].price, '50000.00')
+  assert.strictEqual(orderBook.asks[0].qty, '2.0')
+
+  const order = await client.limitBuy({
+    market_pair: ['BTC', 'USDT'],
+    size: '0.1',
+    price: '50000',
+    time_in_force: 'GTC',
+    post_only: false
+  })
+  assert.strictEqual(order.id, '42')
+  assert.strictEqual(order.order_type, 'LIMIT')
+
+  assert.ok(await fake.served('GET /api/v3/depth'))
+  assert.ok(await fake.served('POST /api/v3/order'))
+})
+
+test('errors', async () => {
+  await assert.rejects(ExchangeClient.connect({ exchange: 'kraken' }), { code: 'InvalidArg' })
+
+  const client = await ExchangeClient.connect(fake.config)
+  await assert.rejects(client.orderBook({ market: 'BTCUSDT' }), { code: 'InvalidArg' })
+  await assert.rejects(client.getHistoricTrades({ market_pair: 'BTCUSDT' }), { code: 'GenericFailure' })
+})
+
+test('trades stream', async () => {
+  const client = await ExchangeClient.connect(fake.config)
+  const stream = await client.subscribe([{ Trades: ['BTC', 'USDT'] }])
+  assert.ok(stream instanceof Stream)
+
+  const [trades] = await new Promise((resolve, reject) => {
+    stream.once('trades', (...args) => resolve(args))
+    stream.once('error', reject)
+  })
+  assert.strictEqual(trades[0].id, '12345')
+  assert.deepStrictEqual(trades[0].market_pair, ['BTC', 'USDT'])
+  assert.strictEqual(trades[0].qty, '0.25')
+
+  const closed = new Promise(resolve => stream.once('close', resolve))
+  await stream.close()
+  await closed
+})
@@ -0,0 +1,37 @@
+'use strict'
+
+// Runs the fake of Binance shared with the Rust tests, the `fake-binance` binary of
+// `test-support`, which prints the config of a client and then each request it serves.
+const { spawn } = require('child_process')
+const path = require('path')
+const readline = require('readline')
+
+const MANIFEST = path.join(__dirname, '..', '..', '..', 'test-support', 'Cargo.toml')
+
+function start () {
+  const child = spawn('cargo', ['run', '--quiet', '--manifest-path', MANIFEST, '--bin', 'fake-binance'], {
+    stdio: ['pipe', 'pipe', 'inherit']
+  })
+  const requests = []
+  const lines = readline.createInterface({ input: child.stdout })
+  return new Promise((resolve, reject) => {
+    child.once('error', reject)
+    child.once('exit', code => reject(new Error(`fake-binance exited with ${code}`)))
+    lines.once('line', line => {
+      lines.on('line', request => requests.push(request))
+      resolve({
+        config: JSON.parse(line),
+        // The requests are printed as they are served, so one can arrive shortly after its response.
+        served: async request => {
+          for (let attempt = 0; attempt < 100 && !requests.includes(request); attempt++) {
+            await new Promise(resolve => setTimeout(resolve, 10))
+          }
+          return requests.includes(request)
+        },
+        stop: () => child.stdin.end()
+      })
+    })
+  })
+}
+
+module.exports = { start }
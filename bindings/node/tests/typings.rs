@@ -0,0 +1,10 @@
+use std::process::Command;
+
+#[test]
+fn typings_are_up_to_date() {
+    let status = Command::new(env!("CARGO_BIN_EXE_typings"))
+        .arg("--check")
+        .status()
+        .expect("Couldn't run the typings generator");
+    assert!(status.success(), "index.d.ts is out of date, run `cargo run --bin typings`.");
+}
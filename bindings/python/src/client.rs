@@ -0,0 +1,145 @@
+use std::convert::TryFrom;
+use std::future::Future;
+use std::sync::Arc;
+use pyo3::prelude::*;
+use pyo3_async_runtimes::tokio::future_into_py;
+use openlimits::exchange::any::{AnyExchange, AnyWebsocket, InitAnyExchange};
+use openlimits::exchange::config::ExchangeConfig;
+use openlimits::exchange::shared::Result;
+use openlimits::exchange::traits::info::ExchangeInfoRetrieval;
+use openlimits::exchange::traits::{Exchange, ExchangeAccount, ExchangeMarketData};
+use openlimits::model::python::{from_python, to_python};
+use openlimits::model::websocket::Subscription;
+use openlimits::model::{
+    CancelAllOrdersRequest, CancelOrderRequest, GetHistoricRatesRequest, GetHistoricTradesRequest,
+    GetLedgerRequest, GetOrderHistoryRequest, GetOrderRequest, GetPriceTickerRequest,
+    OpenLimitOrderRequest, OpenMarketOrderRequest, OrderBookRequest, Paginator, TradeHistoryRequest,
+};
+use crate::stream::Stream;
+
+/// A client of one exchange, created by `await ExchangeClient.connect(config)`.
+#[pyclass(module = "openlimits", frozen)]
+pub struct ExchangeClient {
+    exchange: Arc<AnyExchange>,
+    init: InitAnyExchange,
+}
+
+impl ExchangeClient {
+    /// Runs an operation of the exchange as an awaitable of its result.
+    fn run<'py, T, F, Fut>(&self, py: Python<'py>, operation: F) -> PyResult<Bound<'py, PyAny>>
+    where
+        T: for<'a> IntoPyObject<'a> + Send + 'static,
+        F: FnOnce(Arc<AnyExchange>) -> Fut,
+        Fut: Future<Output = Result<T>> + Send + 'static,
+    {
+        let future = operation(self.exchange.clone());
+        future_into_py(py, async move { Ok(future.await?) })
+    }
+}
+
+#[pymethods]
+impl ExchangeClient {
+    /// Connects to the exchange of an `ExchangeConfig`, such as
+    /// `{"exchange": "coinbase", "sandbox": True, "credentials": {"api_key": "...", "api_secret": "...", "passphrase": "..."}}`.
+    #[staticmethod]
+    fn connect<'py>(py: Python<'py>, config: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        let config: ExchangeConfig = from_python(config)?;
+        let init = InitAnyExchange::try_from(&config)?;
+        future_into_py(py, async move {
+            let exchange = AnyExchange::new(init.clone()).await?;
+            Ok(ExchangeClient {
+                exchange: Arc::new(exchange),
+                init,
+            })
+        })
+    }
+
+    /// The operations, order types and subscriptions the exchange supports.
+    fn capabilities<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        to_python(py, &self.exchange.capabilities())
+    }
+
+    fn retrieve_pairs<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        self.run(py, |exchange| async move { exchange.retrieve_pairs().await })
+    }
+
+    fn order_book<'py>(&self, py: Python<'py>, request: OrderBookRequest) -> PyResult<Bound<'py, PyAny>> {
+        self.run(py, |exchange| async move { exchange.order_book(&request).await })
+    }
+
+    fn get_price_ticker<'py>(&self, py: Python<'py>, request: GetPriceTickerRequest) -> PyResult<Bound<'py, PyAny>> {
+        self.run(py, |exchange| async move { exchange.get_price_ticker(&request).await })
+    }
+
+    fn get_all_tickers<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        self.run(py, |exchange| async move { exchange.get_all_tickers().await })
+    }
+
+    fn get_historic_rates<'py>(&self, py: Python<'py>, request: GetHistoricRatesRequest) -> PyResult<Bound<'py, PyAny>> {
+        self.run(py, |exchange| async move { exchange.get_historic_rates(&request).await })
+    }
+
+    fn get_historic_trades<'py>(&self, py: Python<'py>, request: GetHistoricTradesRequest) -> PyResult<Bound<'py, PyAny>> {
+        self.run(py, |exchange| async move { exchange.get_historic_trades(&request).await })
+    }
+
+    fn limit_buy<'py>(&self, py: Python<'py>, request: OpenLimitOrderRequest) -> PyResult<Bound<'py, PyAny>> {
+        self.run(py, |exchange| async move { exchange.limit_buy(&request).await })
+    }
+
+    fn limit_sell<'py>(&self, py: Python<'py>, request: OpenLimitOrderRequest) -> PyResult<Bound<'py, PyAny>> {
+        self.run(py, |exchange| async move { exchange.limit_sell(&request).await })
+    }
+
+    fn market_buy<'py>(&self, py: Python<'py>, request: OpenMarketOrderRequest) -> PyResult<Bound<'py, PyAny>> {
+        self.run(py, |exchange| async move { exchange.market_buy(&request).await })
+    }
+
+    fn market_sell<'py>(&self, py: Python<'py>, request: OpenMarketOrderRequest) -> PyResult<Bound<'py, PyAny>> {
+        self.run(py, |exchange| async move { exchange.market_sell(&request).await })
+    }
+
+    fn cancel_order<'py>(&self, py: Python<'py>, request: CancelOrderRequest) -> PyResult<Bound<'py, PyAny>> {
+        self.run(py, |exchange| async move { exchange.cancel_order(&request).await })
+    }
+
+    fn cancel_all_orders<'py>(&self, py: Python<'py>, request: CancelAllOrdersRequest) -> PyResult<Bound<'py, PyAny>> {
+        self.run(py, |exchange| async move { exchange.cancel_all_orders(&request).await })
+    }
+
+    fn get_all_open_orders<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        self.run(py, |exchange| async move { exchange.get_all_open_orders().await })
+    }
+
+    fn get_order_history<'py>(&self, py: Python<'py>, request: GetOrderHistoryRequest) -> PyResult<Bound<'py, PyAny>> {
+        self.run(py, |exchange| async move { exchange.get_order_history(&request).await })
+    }
+
+    fn get_trade_history<'py>(&self, py: Python<'py>, request: TradeHistoryRequest) -> PyResult<Bound<'py, PyAny>> {
+        self.run(py, |exchange| async move { exchange.get_trade_history(&request).await })
+    }
+
+    #[pyo3(signature = (paginator = None))]
+    fn get_account_balances<'py>(&self, py: Python<'py>, paginator: Option<Paginator>) -> PyResult<Bound<'py, PyAny>> {
+        self.run(py, |exchange| async move { exchange.get_account_balances(paginator).await })
+    }
+
+    fn get_order<'py>(&self, py: Python<'py>, request: GetOrderRequest) -> PyResult<Bound<'py, PyAny>> {
+        self.run(py, |exchange| async move { exchange.get_order(&request).await })
+    }
+
+    fn get_ledger<'py>(&self, py: Python<'py>, request: GetLedgerRequest) -> PyResult<Bound<'py, PyAny>> {
+        self.run(py, |exchange| async move { exchange.get_ledger(&request).await })
+    }
+
+    /// Subscribes to a list of `Subscription`s, such as `[{"Trades": ["BTC", "USDT"]}]`, on a
+    /// websocket of the exchange. The messages are read with `async for`.
+    fn subscribe<'py>(&self, py: Python<'py>, subscriptions: Vec<Subscription>) -> PyResult<Bound<'py, PyAny>> {
+        let init = self.init.clone();
+        future_into_py(py, async move {
+            let mut websocket = AnyWebsocket::new(init).await?;
+            let messages = websocket.create_stream(&subscriptions).await?;
+            Ok(Stream::new(websocket, messages))
+        })
+    }
+}
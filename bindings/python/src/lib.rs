@@ -0,0 +1,30 @@
+//! The Python bindings of OpenLimits, an asyncio client of every exchange built with maturin as
+//! the `openlimits` module.
+//!
+//! ## Conventions
+//!
+//! * Requests and responses are the Python counterpart of the JSON of the `openlimits::model`
+//!   types, such as `{"market_pair": ["BTC", "USDT"]}` for `order_book`. Decimals are strings and
+//!   can be given as `decimal.Decimal`.
+//! * The methods of `ExchangeClient` return awaitables, run on a tokio runtime of the library.
+//! * Subscriptions are streamed by a `Stream`, an async iterator of `OpenLimitsWebSocketMessage`s
+//!   such as `{"Trades": [...]}`.
+//! * Invalid requests raise `ValueError`, operations the exchange doesn't support raise
+//!   `NotImplementedError` and the other failures raise `openlimits.ExchangeError`.
+
+use pyo3::prelude::*;
+use openlimits::model::python::ExchangeError;
+
+mod client;
+mod stream;
+
+pub use client::ExchangeClient;
+pub use stream::Stream;
+
+#[pymodule(name = "openlimits")]
+pub fn openlimits_module(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<ExchangeClient>()?;
+    module.add_class::<Stream>()?;
+    module.add("ExchangeError", module.py().get_type::<ExchangeError>())?;
+    Ok(())
+}
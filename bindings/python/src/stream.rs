@@ -0,0 +1,60 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use pyo3::exceptions::PyStopAsyncIteration;
+use pyo3::prelude::*;
+use pyo3_async_runtimes::tokio::future_into_py;
+use tokio::sync::Mutex;
+use openlimits::exchange::any::AnyWebsocket;
+use openlimits::exchange::shared::Result;
+use openlimits::model::websocket::OpenLimitsWebSocketMessage;
+
+/// The subscriptions of a websocket, created by `ExchangeClient.subscribe`. Iterating it awaits
+/// the next message, and the iteration stops once the websocket is closed.
+#[pyclass(module = "openlimits", frozen)]
+pub struct Stream {
+    websocket: Arc<AnyWebsocket>,
+    messages: Arc<Mutex<BoxStream<'static, Result<OpenLimitsWebSocketMessage>>>>,
+    closed: Arc<AtomicBool>,
+}
+
+impl Stream {
+    pub(crate) fn new(websocket: AnyWebsocket, messages: BoxStream<'static, Result<OpenLimitsWebSocketMessage>>) -> Self {
+        Self {
+            websocket: Arc::new(websocket),
+            messages: Arc::new(Mutex::new(messages)),
+            closed: Arc::new(AtomicBool::new(false)),
+        }
+    }
+}
+
+#[pymethods]
+impl Stream {
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __anext__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let messages = self.messages.clone();
+        let closed = self.closed.clone();
+        future_into_py(py, async move {
+            let message = messages.lock().await.next().await;
+            // The websocket may fail its pending reads once it was closed.
+            match message {
+                Some(message) if !closed.load(Ordering::SeqCst) => Ok(message?),
+                _ => Err(PyStopAsyncIteration::new_err(())),
+            }
+        })
+    }
+
+    /// Closes the websocket, ending the iteration.
+    fn close<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let websocket = self.websocket.clone();
+        self.closed.store(true, Ordering::SeqCst);
+        future_into_py(py, async move {
+            websocket.disconnect().await;
+            Ok(())
+        })
+    }
+}
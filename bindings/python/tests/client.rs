@@ -0,0 +1,111 @@
+//! Drives the module from asyncio against a local fake of Binance, without a network.
+
+use std::ffi::CString;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyModule};
+use openlimits_python::openlimits_module;
+use openlimits_test_support::FakeBinance;
+
+/// Runs a Python script importing the module, with `config` set to the config of the fake.
+fn run(fake: &FakeBinance, script: &str) -> PyResult<()> {
+    Python::with_gil(|py| {
+        let module = PyModule::new(py, "openlimits")?;
+        openlimits_module(&module)?;
+        py.import("sys")?.getattr("modules")?.set_item("openlimits", module)?;
+        let globals = PyDict::new(py);
+        globals.set_item("config", py.import("json")?.call_method1("loads", (fake.config().to_string(),))?)?;
+        py.run(&CString::new(script).unwrap(), Some(&globals), None)
+    })
+}
+
+#[test]
+fn market_data_and_orders() {
+    let fake = FakeBinance::start();
+    let script = r#"
+import asyncio
+from decimal import Decimal
+from openlimits import ExchangeClient
+
+async def main():
+    client = await ExchangeClient.connect(config)
+    assert "ORDER_BOOK" in client.capabilities()["operations"]
+
+    pairs = await client.retrieve_pairs()
+    assert pairs[0]["symbol"] == "BTCUSDT"
+
+    order_book = await client.order_book({"market_pair": ("BTC", "USDT")})
+    assert order_book["bids"][0]["price"] == "50000.00"
+    assert Decimal(order_book["asks"][0]["qty"]) == 2
+
+    order = await client.limit_buy({
+        "client_order_id": None,
+        "market_pair": ("BTC", "USDT"),
+        "size": Decimal("0.1"),
+        "price": Decimal("50000"),
+        "time_in_force": "GTC",
+        "post_only": False,
+    })
+    assert order["id"] == "42"
+    assert order["order_type"] == "LIMIT"
+
+asyncio.run(main())
+"#;
+    run(&fake, script).unwrap();
+    assert!(fake.served("GET /api/v3/depth"));
+    assert!(fake.served("POST /api/v3/order"));
+}
+
+#[test]
+fn errors() {
+    let fake = FakeBinance::start();
+    let script = r#"
+import asyncio
+from openlimits import ExchangeClient, ExchangeError
+
+async def main():
+    try:
+        await ExchangeClient.connect({"exchange": "kraken"})
+        raise AssertionError("kraken isn't supported")
+    except ValueError:
+        pass
+
+    client = await ExchangeClient.connect(config)
+    try:
+        await client.order_book({"market": "BTCUSDT"})
+        raise AssertionError("the request has no market_pair")
+    except ValueError:
+        pass
+    try:
+        await client.get_historic_trades({"market_pair": "BTCUSDT"})
+        raise AssertionError("the fake has no historic trades")
+    except ExchangeError:
+        pass
+
+asyncio.run(main())
+"#;
+    run(&fake, script).unwrap();
+}
+
+#[test]
+fn trades_stream() {
+    let fake = FakeBinance::start();
+    let script = r#"
+import asyncio
+from openlimits import ExchangeClient
+
+async def main():
+    client = await ExchangeClient.connect(config)
+    stream = await client.subscribe([{"Trades": ["BTC", "USDT"]}])
+    message = await asyncio.wait_for(stream.__anext__(), 10)
+    trade = message["Trades"][0]
+    assert trade["id"] == "12345"
+    assert trade["market_pair"] == ["BTC", "USDT"]
+    assert trade["qty"] == "0.25"
+    await stream.close()
+    async for message in stream:
+        pass
+
+asyncio.run(main())
+"#;
+    run(&fake, script).unwrap();
+}
@@ -0,0 +1,93 @@
+use std::ffi::CString;
+use pyo3::exceptions::{PyNotImplementedError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use openlimits::errors::{MissingImplementationContent, OpenLimitsError};
+use openlimits::model::market_pair::MarketPair;
+use openlimits::model::python::ExchangeError;
+use openlimits::model::websocket::Subscription;
+use openlimits::model::{OpenLimitOrderRequest, Paginator, TimeInForce};
+use rust_decimal::Decimal;
+
+fn btc_usdt() -> MarketPair {
+    "BTC/USDT".parse().unwrap()
+}
+
+fn eval<'py>(py: Python<'py>, expression: &str) -> Bound<'py, PyAny> {
+    let expression = CString::new(expression).unwrap();
+    py.eval(&expression, None, None).expect("Couldn't evaluate the expression")
+}
+
+#[test]
+fn requests_from_python() {
+    Python::with_gil(|py| {
+        let request: OpenLimitOrderRequest = eval(
+            py,
+            "{'client_order_id': None, 'market_pair': ('BTC', 'USDT'), 'size': __import__('decimal').Decimal('0.1'), \
+             'price': 50000, 'time_in_force': 'GTC', 'post_only': False}",
+        )
+        .extract()
+        .unwrap();
+        assert_eq!(request.market_pair, btc_usdt());
+        assert_eq!(request.size, Decimal::new(1, 1));
+        assert_eq!(request.price, Decimal::new(50000, 0));
+        assert_eq!(request.time_in_force, TimeInForce::GoodTillCancelled);
+
+        let paginator: Paginator = eval(py, "{'START_TIME': 1609459200000, 'LIMIT': 10}").extract().unwrap();
+        assert_eq!(paginator.start_time.map(|time| time.timestamp_millis()), Some(1609459200000));
+        assert_eq!(paginator.limit, Some(10));
+
+        let subscriptions: Vec<Subscription> = eval(py, "[{'Trades': ['BTC', 'USDT']}]").extract().unwrap();
+        assert_eq!(subscriptions, vec![Subscription::Trades(btc_usdt())]);
+    });
+}
+
+#[test]
+fn invalid_requests() {
+    Python::with_gil(|py| {
+        let missing_field = eval(py, "{'market_pair': ['BTC', 'USDT']}").extract::<OpenLimitOrderRequest>();
+        assert!(missing_field.unwrap_err().is_instance_of::<PyValueError>(py));
+        let not_a_model = eval(py, "object()").extract::<Paginator>();
+        assert!(not_a_model.is_err());
+    });
+}
+
+#[test]
+fn responses_to_python() {
+    Python::with_gil(|py| {
+        let request = OpenLimitOrderRequest {
+            client_order_id: None,
+            market_pair: btc_usdt(),
+            size: Decimal::new(1, 1),
+            price: Decimal::new(50000, 0),
+            time_in_force: TimeInForce::GoodTillCancelled,
+            post_only: true,
+        };
+        let market_pair = btc_usdt().into_pyobject(py).unwrap();
+        assert_eq!(market_pair.extract::<Vec<String>>().unwrap(), vec!["BTC", "USDT"]);
+
+        let dict = openlimits::model::python::to_python(py, &request).unwrap();
+        let dict = dict.downcast::<PyDict>().unwrap();
+        assert_eq!(dict.get_item("size").unwrap().unwrap().extract::<String>().unwrap(), "0.1");
+        assert!(dict.get_item("client_order_id").unwrap().unwrap().is_none());
+        assert!(dict.get_item("post_only").unwrap().unwrap().extract::<bool>().unwrap());
+
+        let roundtrip: OpenLimitOrderRequest = dict.extract().unwrap();
+        assert_eq!(roundtrip, request);
+    });
+}
+
+#[test]
+fn errors_to_python() {
+    Python::with_gil(|py| {
+        let unsupported = PyErr::from(OpenLimitsError::MissingImplementation(MissingImplementationContent {
+            message: "no ledger".into(),
+        }));
+        assert!(unsupported.is_instance_of::<PyNotImplementedError>(py));
+        let invalid = PyErr::from(OpenLimitsError::InvalidParameter("size".into()));
+        assert!(invalid.is_instance_of::<PyValueError>(py));
+        let failed = PyErr::from(OpenLimitsError::Unauthorized());
+        assert!(failed.is_instance_of::<ExchangeError>(py));
+        assert!(failed.to_string().contains("Unauthorized"));
+    });
+}
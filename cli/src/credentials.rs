@@ -0,0 +1,46 @@
+use std::env;
+use std::path::PathBuf;
+use clap::Args;
+use openlimits::exchange::config::ExchangeKind;
+use openlimits::exchange::credentials::{CredentialProvider, EnvProvider, KeystoreProvider, Profile, TomlProvider};
+use openlimits::exchange::shared::Result;
+
+/// The environment variable a keystore passphrase is read from before it is prompted for.
+const PASSPHRASE_VARIABLE: &str = "OPENLIMITS_PASSPHRASE";
+
+/// Where the credentials profile is read from. Without a file, the profile is read from the
+/// environment, and the market data commands run unauthenticated if it isn't set.
+#[derive(Args, Debug)]
+pub struct CredentialArgs {
+    /// The credentials profile [default: the name of the exchange]
+    #[arg(long, global = true)]
+    profile: Option<String>,
+    /// Reads the profile from a TOML file of credentials
+    #[arg(long, global = true, value_name = "FILE", conflicts_with = "keystore")]
+    credentials: Option<PathBuf>,
+    /// Reads the profile from a keystore, decrypted with $OPENLIMITS_PASSPHRASE or a prompted passphrase
+    #[arg(long, global = true, value_name = "FILE")]
+    keystore: Option<PathBuf>,
+}
+
+impl CredentialArgs {
+    pub fn profile(&self, exchange: ExchangeKind) -> Result<Option<Profile>> {
+        let name = self.profile.as_deref().unwrap_or_else(|| exchange.name());
+        if let Some(path) = &self.credentials {
+            return TomlProvider::from_path(path)?.profile(name).map(Some);
+        }
+        if let Some(path) = &self.keystore {
+            let passphrase = match env::var(PASSPHRASE_VARIABLE) {
+                Ok(passphrase) => passphrase,
+                Err(_) => rpassword::prompt_password(format!("Passphrase of {}: ", path.display()))?,
+            };
+            return KeystoreProvider::open(path, &passphrase)?.profile(name).map(Some);
+        }
+        match EnvProvider.profile(name) {
+            Ok(profile) => Ok(Some(profile)),
+            // Only a profile asked for by name has to exist.
+            Err(_) if self.profile.is_none() => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+}
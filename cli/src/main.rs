@@ -0,0 +1,287 @@
+//! `openlimits`, a command line tool for ad-hoc operations on any supported exchange, such as
+//! looking at an order book or canceling the orders left open by a bot.
+
+mod credentials;
+mod output;
+
+use std::io;
+use std::process;
+use std::str::FromStr;
+use clap::{Parser, Subcommand, ValueEnum};
+use futures::StreamExt;
+use rust_decimal::Decimal;
+use serde::de::DeserializeOwned;
+use openlimits::exchange::any::{AnyExchange, AnyWebsocket};
+use openlimits::exchange::config::{ExchangeConfig, ExchangeKind};
+use openlimits::exchange::errors::OpenLimitsError;
+use openlimits::exchange::shared::Result;
+use openlimits::exchange::traits::info::ExchangeInfoRetrieval;
+use openlimits::exchange::traits::{Exchange, ExchangeAccount, ExchangeMarketData};
+use openlimits::model::market_pair::MarketPair;
+use openlimits::model::websocket::{OpenLimitsWebSocketMessage, Subscription};
+use openlimits::model::{
+    CancelAllOrdersRequest, CancelOrderRequest, GetHistoricRatesRequest, GetPriceTickerRequest,
+    Interval, OpenLimitOrderRequest, OpenMarketOrderRequest, OrderBookRequest, Paginator, TimeInForce,
+};
+use crate::credentials::CredentialArgs;
+use crate::output::{BookLevel, Format};
+
+#[derive(Parser, Debug)]
+#[command(name = "openlimits", version, about = "Ad-hoc operations on the exchanges supported by openlimits")]
+struct Cli {
+    /// The exchange: binance, coinbase, huobi or nash
+    #[arg(short, long, global = true, value_parser = parse::<ExchangeKind>)]
+    exchange: Option<ExchangeKind>,
+    /// Uses the sandbox of the exchange
+    #[arg(long, global = true)]
+    sandbox: bool,
+    #[command(flatten)]
+    credentials: CredentialArgs,
+    /// Overrides the REST base URL of the exchange
+    #[arg(long, global = true, value_name = "URL")]
+    rest_endpoint: Option<String>,
+    /// Overrides the websocket URL of the exchange
+    #[arg(long, global = true, value_name = "URL")]
+    websocket_endpoint: Option<String>,
+    /// Prints a table, or a JSON object per line
+    #[arg(short, long, global = true, value_enum, default_value_t = Format::Table)]
+    output: Format,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Lists the market pairs of the exchange
+    Pairs,
+    /// Shows the 24h ticker of a market, such as BTC-USDT
+    Ticker {
+        #[arg(value_parser = parse::<MarketPair>)]
+        market: MarketPair,
+    },
+    /// Shows the best levels of the order book of a market
+    Book {
+        #[arg(value_parser = parse::<MarketPair>)]
+        market: MarketPair,
+        /// The number of levels on each side
+        #[arg(long, default_value_t = 10)]
+        depth: usize,
+    },
+    /// Shows the candles of a market
+    Candles {
+        #[arg(value_parser = parse::<MarketPair>)]
+        market: MarketPair,
+        /// 1m, 3m, 5m, 15m, 30m, 1h, 2h, 4h, 6h, 8h, 12h, 1d, 3d, 1w or 1mo
+        #[arg(long, default_value = "1h", value_parser = deserialize::<Interval>)]
+        interval: Interval,
+        /// The number of candles
+        #[arg(long)]
+        limit: Option<u64>,
+    },
+    /// Shows the balances of the account
+    Balances,
+    /// Lists the open orders of the account
+    OpenOrders {
+        /// Only lists the orders of this market
+        #[arg(value_parser = parse::<MarketPair>)]
+        market: Option<MarketPair>,
+    },
+    /// Places an order, a limit order if it has a price and a market order otherwise
+    Place {
+        #[arg(value_parser = parse::<MarketPair>)]
+        market: MarketPair,
+        #[arg(value_enum)]
+        side: Side,
+        /// The size in the base currency
+        size: Decimal,
+        /// The limit price
+        #[arg(long)]
+        price: Option<Decimal>,
+        /// GTC, IOC, FOK or GTT,<milliseconds>, for limit orders
+        #[arg(long, default_value = "GTC", value_parser = deserialize::<TimeInForce>, requires = "price")]
+        time_in_force: TimeInForce,
+        /// Only adds liquidity, for limit orders
+        #[arg(long, requires = "price")]
+        post_only: bool,
+        /// The id the order is also known by
+        #[arg(long)]
+        client_order_id: Option<String>,
+    },
+    /// Cancels an order
+    Cancel {
+        /// The id of the order
+        id: String,
+        /// The market of the order, which some exchanges require
+        #[arg(long, value_parser = parse::<MarketPair>)]
+        market: Option<MarketPair>,
+    },
+    /// Cancels the open orders, of every market unless one is given
+    CancelAll {
+        #[arg(value_parser = parse::<MarketPair>)]
+        market: Option<MarketPair>,
+    },
+    /// Prints the trades or the order book updates of markets until interrupted
+    Stream {
+        #[arg(value_enum)]
+        kind: StreamKind,
+        #[arg(required = true, value_parser = parse::<MarketPair>)]
+        markets: Vec<MarketPair>,
+    },
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum Side {
+    Buy,
+    Sell,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum StreamKind {
+    Trades,
+    Book,
+}
+
+fn parse<T: FromStr<Err = OpenLimitsError>>(value: &str) -> std::result::Result<T, String> {
+    value.parse().map_err(|error| output::error_message(&error))
+}
+
+/// Parses the values the model only deserializes, such as intervals.
+fn deserialize<T: DeserializeOwned>(value: &str) -> std::result::Result<T, String> {
+    serde_json::from_value(serde_json::Value::String(value.to_string())).map_err(|error| error.to_string())
+}
+
+impl Cli {
+    fn config(&self) -> Result<ExchangeConfig> {
+        let exchange = self
+            .exchange
+            .ok_or_else(|| OpenLimitsError::MissingParameter("--exchange".into()))?;
+        let mut config = ExchangeConfig::new(exchange);
+        config.sandbox = self.sandbox;
+        config.rest_endpoint = self.rest_endpoint.clone();
+        config.websocket_endpoint = self.websocket_endpoint.clone();
+        Ok(config)
+    }
+}
+
+async fn run(cli: Cli) -> Result<()> {
+    let config = cli.config()?;
+    let profile = cli.credentials.profile(config.exchange)?;
+    let init = config.init_with(profile.as_ref())?;
+    let format = cli.output;
+
+    if let Command::Stream { kind, markets } = cli.command {
+        let subscriptions: Vec<Subscription> = markets
+            .into_iter()
+            .map(|market| match kind {
+                StreamKind::Trades => Subscription::Trades(market),
+                StreamKind::Book => Subscription::OrderBookUpdates(market),
+            })
+            .collect();
+        return stream(AnyWebsocket::new(init).await?, &subscriptions, format).await;
+    }
+
+    let exchange = AnyExchange::new(init).await?;
+    match cli.command {
+        Command::Pairs => output::print(format, &exchange.retrieve_pairs().await?, true),
+        Command::Ticker { market } => {
+            let ticker = exchange.get_price_ticker(&GetPriceTickerRequest { market_pair: market }).await?;
+            output::print(format, &[ticker], true)
+        }
+        Command::Book { market, depth } => {
+            let book = exchange.order_book(&OrderBookRequest { market_pair: market }).await?;
+            let bids = &book.bids[..depth.min(book.bids.len())];
+            let asks = &book.asks[..depth.min(book.asks.len())];
+            output::print(format, &BookLevel::levels(bids, asks), true)
+        }
+        Command::Candles { market, interval, limit } => {
+            let paginator = limit.map(|limit| Paginator { limit: Some(limit), ..Default::default() });
+            let request = GetHistoricRatesRequest { market_pair: market, paginator, interval };
+            output::print(format, &exchange.get_historic_rates(&request).await?, true)
+        }
+        Command::Balances => output::print(format, &exchange.get_account_balances(None).await?, true),
+        Command::OpenOrders { market } => {
+            let mut orders = exchange.get_all_open_orders().await?;
+            if let Some(market) = market {
+                orders.retain(|order| order.market_pair == market);
+            }
+            output::print(format, &orders, true)
+        }
+        Command::Place { market, side, size, price, time_in_force, post_only, client_order_id } => {
+            let order = match price {
+                Some(price) => {
+                    let request = OpenLimitOrderRequest {
+                        client_order_id,
+                        market_pair: market,
+                        size,
+                        price,
+                        time_in_force,
+                        post_only,
+                    };
+                    match side {
+                        Side::Buy => exchange.limit_buy(&request).await?,
+                        Side::Sell => exchange.limit_sell(&request).await?,
+                    }
+                }
+                None => {
+                    let request = OpenMarketOrderRequest { client_order_id, market_pair: market, size };
+                    match side {
+                        Side::Buy => exchange.market_buy(&request).await?,
+                        Side::Sell => exchange.market_sell(&request).await?,
+                    }
+                }
+            };
+            output::print(format, &[order], true)
+        }
+        Command::Cancel { id, market } => {
+            let canceled = exchange.cancel_order(&CancelOrderRequest { id, market_pair: market }).await?;
+            output::print(format, &[canceled], true)
+        }
+        Command::CancelAll { market } => {
+            let canceled = exchange.cancel_all_orders(&CancelAllOrdersRequest { market_pair: market }).await?;
+            output::print(format, &canceled, true)
+        }
+        Command::Stream { .. } => unreachable!("The streams are handled above"),
+    }
+}
+
+/// Prints the messages of the subscriptions until the stream ends or Ctrl-C is pressed.
+async fn stream(mut websocket: AnyWebsocket, subscriptions: &[Subscription], format: Format) -> Result<()> {
+    let mut messages = websocket.create_stream(subscriptions).await?;
+    let mut header = true;
+    let result = loop {
+        let message = tokio::select! {
+            message = messages.next() => message,
+            _ = tokio::signal::ctrl_c() => None,
+        };
+        let printed = match message {
+            None => break Ok(()),
+            Some(Err(error)) => break Err(error),
+            Some(Ok(OpenLimitsWebSocketMessage::Ping)) => continue,
+            Some(Ok(OpenLimitsWebSocketMessage::Trades(trades))) => output::print(format, &trades, header),
+            Some(Ok(OpenLimitsWebSocketMessage::OrderBook(book))) => {
+                output::print(format, &BookLevel::levels(&book.bids, &book.asks), header)
+            }
+        };
+        if let Err(error) = printed {
+            break Err(error);
+        }
+        header = false;
+    };
+    websocket.disconnect().await;
+    result
+}
+
+#[tokio::main]
+async fn main() {
+    let cli = Cli::parse();
+    if let Err(error) = run(cli).await {
+        // Piping into `head` closes stdout early, which isn't a failure.
+        if let OpenLimitsError::IoError(error) = &error {
+            if error.kind() == io::ErrorKind::BrokenPipe {
+                return;
+            }
+        }
+        eprintln!("error: {}", output::error_message(&error));
+        process::exit(1);
+    }
+}
@@ -0,0 +1,206 @@
+//! Prints the responses as aligned tables for people, or as JSON lines, one object per row, for
+//! scripts.
+
+use std::io::{self, Write};
+use clap::ValueEnum;
+use rust_decimal::Decimal;
+use serde::Serialize;
+use openlimits::exchange::errors::OpenLimitsError;
+use openlimits::exchange::shared::Result;
+use openlimits::exchange::traits::info::MarketPairInfo;
+use openlimits::model::{AskBid, Balance, Candle, Order, OrderCanceled, Ticker, Trade};
+
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum Format {
+    Table,
+    Json,
+}
+
+/// A response printed as a row of a table.
+pub trait Row: Serialize {
+    const COLUMNS: &'static [&'static str];
+
+    fn cells(&self) -> Vec<String>;
+}
+
+/// A level of an order book, on the side it belongs to.
+#[derive(Serialize)]
+pub struct BookLevel {
+    pub side: &'static str,
+    pub price: Decimal,
+    pub qty: Decimal,
+}
+
+impl BookLevel {
+    pub fn levels(bids: &[AskBid], asks: &[AskBid]) -> Vec<Self> {
+        let bids = bids.iter().map(|level| Self { side: "bid", price: level.price, qty: level.qty });
+        let asks = asks.iter().map(|level| Self { side: "ask", price: level.price, qty: level.qty });
+        bids.chain(asks).collect()
+    }
+}
+
+/// Writes the rows to stdout. A table only has a header if `header` is set, so the rows of a
+/// stream can follow the first ones.
+pub fn print<T: Row>(format: Format, rows: &[T], header: bool) -> Result<()> {
+    let stdout = io::stdout();
+    let mut stdout = stdout.lock();
+    match format {
+        Format::Json => {
+            for row in rows {
+                writeln!(stdout, "{}", serde_json::to_string(row)?)?;
+            }
+        }
+        Format::Table => {
+            let cells: Vec<Vec<String>> = rows.iter().map(Row::cells).collect();
+            let mut widths: Vec<usize> = T::COLUMNS.iter().map(|column| column.len()).collect();
+            for row in &cells {
+                for (width, cell) in widths.iter_mut().zip(row) {
+                    *width = (*width).max(cell.len());
+                }
+            }
+            if header {
+                write_line(&mut stdout, T::COLUMNS, &widths)?;
+            }
+            for row in &cells {
+                write_line(&mut stdout, row, &widths)?;
+            }
+        }
+    }
+    stdout.flush()?;
+    Ok(())
+}
+
+fn write_line<S: AsRef<str>>(out: &mut impl Write, cells: &[S], widths: &[usize]) -> io::Result<()> {
+    let line: Vec<String> = cells
+        .iter()
+        .zip(widths)
+        .map(|(cell, width)| format!("{:width$}", cell.as_ref(), width = width))
+        .collect();
+    writeln!(out, "{}", line.join("  ").trim_end())
+}
+
+/// Most of the `OpenLimitsError` variants display as an empty string, so those fall back to `Debug`.
+pub fn error_message(error: &OpenLimitsError) -> String {
+    let message = error.to_string();
+    if message.is_empty() {
+        format!("{:?}", error)
+    } else {
+        message
+    }
+}
+
+fn optional<T: ToString>(value: &Option<T>) -> String {
+    value.as_ref().map(ToString::to_string).unwrap_or_default()
+}
+
+/// The serialized name of an enum variant, such as `PARTIALLY_FILLED`.
+fn label<T: Serialize>(value: &T) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(label)) => label,
+        Ok(value) => value.to_string(),
+        Err(_) => String::new(),
+    }
+}
+
+impl Row for MarketPairInfo {
+    const COLUMNS: &'static [&'static str] = &["SYMBOL", "BASE", "QUOTE", "BASE_INCREMENT", "QUOTE_INCREMENT", "MIN_BASE_SIZE"];
+
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.symbol.clone(),
+            self.base.clone(),
+            self.quote.clone(),
+            self.base_increment.to_string(),
+            self.quote_increment.to_string(),
+            optional(&self.min_base_trade_size),
+        ]
+    }
+}
+
+impl Row for Ticker {
+    const COLUMNS: &'static [&'static str] = &["MARKET", "PRICE", "BID", "ASK", "HIGH_24H", "LOW_24H", "VOLUME_24H"];
+
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.market_pair.to_string(),
+            optional(&self.price),
+            optional(&self.bid),
+            optional(&self.ask),
+            optional(&self.high_24h),
+            optional(&self.low_24h),
+            optional(&self.volume_24h),
+        ]
+    }
+}
+
+impl Row for BookLevel {
+    const COLUMNS: &'static [&'static str] = &["SIDE", "PRICE", "QTY"];
+
+    fn cells(&self) -> Vec<String> {
+        vec![self.side.to_string(), self.price.to_string(), self.qty.to_string()]
+    }
+}
+
+impl Row for Candle {
+    const COLUMNS: &'static [&'static str] = &["TIME", "OPEN", "HIGH", "LOW", "CLOSE", "VOLUME"];
+
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.time.to_rfc3339(),
+            self.open.to_string(),
+            self.high.to_string(),
+            self.low.to_string(),
+            self.close.to_string(),
+            self.volume.to_string(),
+        ]
+    }
+}
+
+impl Row for Balance {
+    const COLUMNS: &'static [&'static str] = &["ASSET", "TOTAL", "FREE"];
+
+    fn cells(&self) -> Vec<String> {
+        vec![self.asset.clone(), self.total.to_string(), self.free.to_string()]
+    }
+}
+
+impl Row for Order {
+    const COLUMNS: &'static [&'static str] = &["ID", "MARKET", "SIDE", "TYPE", "STATUS", "SIZE", "PRICE", "REMAINING", "CLIENT_ORDER_ID"];
+
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.id.clone(),
+            self.market_pair.to_string(),
+            label(&self.side),
+            label(&self.order_type),
+            label(&self.status),
+            self.size.to_string(),
+            optional(&self.price),
+            optional(&self.remaining),
+            optional(&self.client_order_id),
+        ]
+    }
+}
+
+impl Row for OrderCanceled {
+    const COLUMNS: &'static [&'static str] = &["ID"];
+
+    fn cells(&self) -> Vec<String> {
+        vec![self.id.clone()]
+    }
+}
+
+impl Row for Trade {
+    const COLUMNS: &'static [&'static str] = &["ID", "MARKET", "SIDE", "PRICE", "QTY", "TIME"];
+
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.id.clone(),
+            self.market_pair.to_string(),
+            label(&self.side),
+            self.price.to_string(),
+            self.qty.to_string(),
+            self.created_at.to_rfc3339(),
+        ]
+    }
+}
@@ -0,0 +1,147 @@
+//! Runs the `openlimits` binary against a local fake of Binance, without a network.
+
+use std::io::{BufRead, BufReader as LineReader};
+use std::process::{Command, Stdio};
+use serde_json::{json, Value};
+use openlimits_test_support::FakeBinance;
+
+const CREDENTIALS: [(&str, &str); 2] = [("BINANCE_API_KEY", "key"), ("BINANCE_API_SECRET", "secret")];
+
+/// Runs `openlimits` on the fake, with the credentials in `env`, returning its exit status, stdout
+/// and stderr.
+fn run(fake: &FakeBinance, args: &[&str], env: &[(&str, &str)]) -> (bool, String, String) {
+    let output = command(fake, args)
+        .envs(env.iter().copied())
+        .output()
+        .expect("Couldn't run openlimits");
+    let stdout = String::from_utf8(output.stdout).expect("The output isn't UTF-8");
+    let stderr = String::from_utf8(output.stderr).expect("The errors aren't UTF-8");
+    (output.status.success(), stdout, stderr)
+}
+
+fn command(fake: &FakeBinance, args: &[&str]) -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_openlimits"));
+    command
+        .args(["--exchange", "binance", "--sandbox"])
+        .args(["--rest-endpoint", &fake.rest, "--websocket-endpoint", &fake.websocket])
+        .args(args)
+        .env_remove("BINANCE_API_KEY")
+        .env_remove("BINANCE_API_SECRET");
+    command
+}
+
+/// Parses the JSON lines of an output.
+fn json_lines(output: &str) -> Vec<Value> {
+    output
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("A line isn't JSON"))
+        .collect()
+}
+
+#[test]
+fn market_data() {
+    let fake = FakeBinance::start();
+
+    let (success, output, errors) = run(&fake, &["pairs", "--output", "json"], &[]);
+    assert!(success, "{}", errors);
+    let pairs = json_lines(&output);
+    assert_eq!(pairs.len(), 1);
+    assert_eq!(pairs[0]["symbol"], "BTCUSDT");
+
+    let (success, output, errors) = run(&fake, &["ticker", "BTC-USDT"], &[]);
+    assert!(success, "{}", errors);
+    let lines: Vec<&str> = output.lines().collect();
+    assert!(lines[0].starts_with("MARKET"));
+    assert!(lines[1].starts_with("BTC/USDT  50000.50"));
+
+    let (success, output, errors) = run(&fake, &["book", "btc_usdt", "--depth", "1", "-o", "json"], &[]);
+    assert!(success, "{}", errors);
+    assert_eq!(
+        json_lines(&output),
+        vec![
+            json!({"side": "bid", "price": "50000.00", "qty": "1.5"}),
+            json!({"side": "ask", "price": "50001.00", "qty": "2.0"}),
+        ]
+    );
+
+    let (success, output, errors) = run(&fake, &["candles", "BTC/USDT", "--interval", "1m", "--limit", "1", "-o", "json"], &[]);
+    assert!(success, "{}", errors);
+    assert_eq!(json_lines(&output)[0]["close"], "50100.00");
+    assert!(fake.served("GET /api/v3/klines"));
+}
+
+#[test]
+fn account() {
+    let fake = FakeBinance::start();
+
+    let (success, output, errors) = run(&fake, &["balances"], &CREDENTIALS);
+    assert!(success, "{}", errors);
+    assert_eq!(output, "ASSET  TOTAL  FREE\nBTC    2.0    1.5\n");
+
+    let (success, output, errors) = run(&fake, &["open-orders", "ETH-USDT", "-o", "json"], &CREDENTIALS);
+    assert!(success, "{}", errors);
+    assert!(output.is_empty());
+
+    let (success, output, errors) = run(&fake, &["place", "BTC-USDT", "buy", "0.1", "--price", "50000", "-o", "json"], &CREDENTIALS);
+    assert!(success, "{}", errors);
+    let order = &json_lines(&output)[0];
+    assert_eq!(order["id"], "42");
+    assert_eq!(order["order_type"], "LIMIT");
+
+    let (success, output, errors) = run(&fake, &["cancel", "42", "--market", "BTC-USDT", "-o", "json"], &CREDENTIALS);
+    assert!(success, "{}", errors);
+    assert_eq!(json_lines(&output), vec![json!({"id": "42"})]);
+    assert!(fake.served("GET /api/v3/openOrders"));
+    assert!(fake.served("DELETE /api/v3/order"));
+}
+
+#[test]
+fn credentials_file() {
+    let fake = FakeBinance::start();
+    let path = std::env::temp_dir().join(format!("openlimits-cli-{}.toml", std::process::id()));
+    std::fs::write(&path, "[desk]\napi_key = \"key\"\napi_secret = \"secret\"\n").unwrap();
+    let path = path.to_str().unwrap();
+
+    let (success, output, errors) = run(&fake, &["balances", "--credentials", path, "--profile", "desk", "-o", "json"], &[]);
+    assert!(success, "{}", errors);
+    assert_eq!(json_lines(&output)[0]["asset"], "BTC");
+
+    let (success, _, errors) = run(&fake, &["balances", "--credentials", path], &[]);
+    assert!(!success);
+    assert!(errors.contains("binance profile"), "{}", errors);
+    std::fs::remove_file(path).ok();
+}
+
+#[test]
+fn errors() {
+    let fake = FakeBinance::start();
+
+    let (success, _, errors) = run(&fake, &["balances"], &[]);
+    assert!(!success);
+    assert!(errors.starts_with("error: "), "{}", errors);
+
+    let (success, _, errors) = run(&fake, &["ticker", "BTCUSDT"], &[]);
+    assert!(!success);
+    assert!(errors.contains("not a valid market pair"), "{}", errors);
+
+    let output = Command::new(env!("CARGO_BIN_EXE_openlimits")).arg("pairs").output().unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--exchange"));
+}
+
+#[test]
+fn trades_stream() {
+    let fake = FakeBinance::start();
+    let mut child = command(&fake, &["stream", "trades", "BTC-USDT", "-o", "json"])
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("Couldn't run openlimits");
+    let mut line = String::new();
+    LineReader::new(child.stdout.take().unwrap()).read_line(&mut line).expect("Couldn't read a trade");
+    child.kill().ok();
+    child.wait().ok();
+
+    let trade: Value = serde_json::from_str(&line).expect("The trade isn't JSON");
+    assert_eq!(trade["id"], "12345");
+    assert_eq!(trade["qty"], "0.25");
+}
@@ -0,0 +1,13 @@
+use std::future::Future;
+use std::time::Duration;
+
+pub fn spawn<T>(task: T)
+where
+    T: Future<Output = ()> + Send + 'static,
+{
+    async_std::task::spawn(task);
+}
+
+pub async fn sleep(duration: Duration) {
+    async_std::task::sleep(duration).await
+}
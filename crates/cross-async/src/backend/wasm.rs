@@ -0,0 +1,14 @@
+use std::future::Future;
+use std::time::Duration;
+
+// The browser runs everything on one thread, so the task is spawned locally.
+pub fn spawn<T>(task: T)
+where
+    T: Future<Output = ()> + Send + 'static,
+{
+    wasm_bindgen_futures::spawn_local(task);
+}
+
+pub async fn sleep(duration: Duration) {
+    futures_timer::Delay::new(duration).await
+}
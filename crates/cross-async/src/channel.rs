@@ -0,0 +1,4 @@
+//! Channels that work across every runtime backend.
+
+pub use futures::channel::mpsc::{channel, unbounded, Receiver, Sender, UnboundedReceiver, UnboundedSender};
+pub use futures::channel::oneshot;
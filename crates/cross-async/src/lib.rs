@@ -1,10 +1,70 @@
+//! Runtime agnostic async primitives. The runtime backing them is selected by cargo feature:
+//! `tokio` (the default), `async-std` or `wasm`, which spawns with `spawn_local`. As features
+//! add up across a dependency graph, `wasm` wins over `async-std`, which wins over `tokio`, so
+//! that choosing a runtime doesn't require every crate on the way to disable default features.
+
+#[cfg(not(any(feature = "tokio", feature = "async-std", feature = "wasm")))]
+compile_error!("cross-async needs one of the `tokio`, `async-std` or `wasm` features.");
+
+#[cfg(feature = "wasm")]
+#[path = "backend/wasm.rs"]
+mod backend;
+#[cfg(all(feature = "async-std", not(feature = "wasm")))]
+#[path = "backend/async_std.rs"]
+mod backend;
+#[cfg(all(feature = "tokio", not(any(feature = "async-std", feature = "wasm"))))]
+#[path = "backend/tokio.rs"]
+mod backend;
+
+pub mod channel;
+
 use std::future::Future;
-use tokio::task::JoinHandle;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use futures::channel::oneshot;
+use futures::future::{self, Either};
+use futures::FutureExt;
+
+/// A handle to a spawned task that resolves to the task output, or to `None` if the task panicked
+/// or was dropped by the runtime. Dropping the handle detaches the task.
+pub struct JoinHandle<T> {
+    receiver: oneshot::Receiver<T>,
+}
+
+impl<T> Future for JoinHandle<T> {
+    type Output = Option<T>;
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.receiver.poll_unpin(cx).map(Result::ok)
+    }
+}
 
+/// Runs the task in the background on the selected runtime.
 pub fn spawn<T>(task: T) -> JoinHandle<T::Output>
 where
     T: Future + Send + 'static,
     T::Output: Send + 'static,
 {
-    tokio::spawn(task)
+    let (sender, receiver) = oneshot::channel();
+    backend::spawn(async move {
+        sender.send(task.await).ok();
+    });
+    JoinHandle { receiver }
 }
+
+/// Waits for the given duration without blocking the thread.
+pub async fn sleep(duration: Duration) {
+    backend::sleep(duration).await
+}
+
+/// Awaits the task for at most the given duration, returning `None` if it elapses first.
+pub async fn timeout<T>(duration: Duration, task: T) -> Option<T::Output>
+where
+    T: Future,
+{
+    match future::select(Box::pin(task), Box::pin(sleep(duration))).await {
+        Either::Left((output, _)) => Some(output),
+        Either::Right(_) => None,
+    }
+}
+
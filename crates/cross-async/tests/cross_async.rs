@@ -0,0 +1,23 @@
+use std::time::Duration;
+use futures::StreamExt;
+use cross_async::{channel, sleep, spawn, timeout};
+
+#[tokio::test]
+async fn spawn_returns_the_output() {
+    assert_eq!(spawn(async { 1 + 1 }).await, Some(2));
+}
+
+#[tokio::test]
+async fn timeout_elapses() {
+    assert_eq!(timeout(Duration::from_millis(10), sleep(Duration::from_secs(10))).await, None);
+    assert_eq!(timeout(Duration::from_secs(10), async { 1 }).await, Some(1));
+}
+
+#[tokio::test]
+async fn channels_cross_tasks() {
+    let (sender, mut receiver) = channel::unbounded();
+    spawn(async move {
+        sender.unbounded_send(1).ok();
+    });
+    assert_eq!(receiver.next().await, Some(1));
+}
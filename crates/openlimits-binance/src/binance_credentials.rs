@@ -1,6 +1,32 @@
+use std::convert::TryFrom;
+use std::fmt;
+use openlimits_exchange::credentials::{Profile, Zeroizing, REDACTED};
+use openlimits_exchange::errors::OpenLimitsError;
+
 /// This struct represents the credentials and receives the api key and api secret as parameters.
-#[derive(Clone, Debug)]
+/// The secret is overwritten when the credentials are dropped.
+#[derive(Clone)]
 pub struct BinanceCredentials {
     pub api_key: String,
-    pub api_secret: String,
-}
\ No newline at end of file
+    pub api_secret: Zeroizing<String>,
+}
+
+impl fmt::Debug for BinanceCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BinanceCredentials")
+            .field("api_key", &self.api_key)
+            .field("api_secret", &REDACTED)
+            .finish()
+    }
+}
+
+impl TryFrom<&Profile> for BinanceCredentials {
+    type Error = OpenLimitsError;
+
+    fn try_from(profile: &Profile) -> Result<Self, Self::Error> {
+        Ok(Self {
+            api_key: profile.require("api_key")?.into(),
+            api_secret: Zeroizing::new(profile.require("api_secret")?.into()),
+        })
+    }
+}
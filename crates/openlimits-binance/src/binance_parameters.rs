@@ -1,11 +1,20 @@
+use std::sync::Arc;
 use super::BinanceCredentials;
-use openlimits_exchange::exchange::Environment;
+use openlimits_exchange::exchange::{Endpoints, Environment};
+use openlimits_exchange::http::HttpTransport;
+use openlimits_exchange::signer::RequestSigner;
 
 /// This struct represents the type of environment that will be used and receives a boolean and the credentials as parameters.
 #[derive(Default, Clone, Debug)]
 pub struct BinanceParameters {
     pub environment: Environment,
     pub credentials: Option<BinanceCredentials>,
+    /// Signs the requests in place of the credentials' secret, which is then left unused.
+    pub signer: Option<Arc<dyn RequestSigner>>,
+    /// Overrides the REST and websocket base URLs of the environment.
+    pub endpoints: Endpoints,
+    /// Sends the REST requests through this transport instead of the default reqwest client.
+    pub http_transport: Option<Arc<dyn HttpTransport>>,
 }
 
 impl BinanceParameters {
@@ -24,4 +33,16 @@ impl BinanceParameters {
             ..Default::default()
         }
     }
-}
\ No newline at end of file
+
+    /// Production environment of a regional domain, such as `binance.us`.
+    pub fn regional(domain: &str) -> Self {
+        Self {
+            environment: Environment::Production,
+            endpoints: Endpoints::new(
+                format!("https://api.{}", domain),
+                format!("wss://stream.{}:9443/stream", domain),
+            ),
+            ..Default::default()
+        }
+    }
+}
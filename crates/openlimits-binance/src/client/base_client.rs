@@ -4,4 +4,13 @@ use super::Transport;
 #[derive(Clone)]
 pub struct BaseClient {
     pub transport: Transport,
-}
\ No newline at end of file
+}
+
+impl BaseClient {
+    /// Returns a client whose signed requests use the given recvWindow, in milliseconds.
+    pub fn with_recv_window(&self, recv_window: usize) -> Self {
+        Self {
+            transport: self.transport.with_recv_window(recv_window),
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use crate::model::{CapitalHistoryReq, DepositAddress, DepositAddressReq, DepositRecord, WithdrawRecord, WithdrawReq, WithdrawResponse};
+use super::BaseClient;
+use super::shared::Result;
+
+// Wallet (capital) endpoints
+impl BaseClient {
+    // Deposit address for ONE coin, optionally on a specific network
+    pub async fn get_deposit_address(&self, params: &DepositAddressReq) -> Result<DepositAddress> {
+        self.transport
+            .signed_get("/sapi/v1/capital/deposit/address", Some(params))
+            .await
+    }
+
+    pub async fn get_deposit_history(&self, params: &CapitalHistoryReq) -> Result<Vec<DepositRecord>> {
+        self.transport
+            .signed_get("/sapi/v1/capital/deposit/hisrec", Some(params))
+            .await
+    }
+
+    pub async fn withdraw(&self, params: &WithdrawReq) -> Result<WithdrawResponse> {
+        self.transport
+            .signed_post("/sapi/v1/capital/withdraw/apply", Some(params))
+            .await
+    }
+
+    pub async fn get_withdraw_history(&self, params: &CapitalHistoryReq) -> Result<Vec<WithdrawRecord>> {
+        self.transport
+            .signed_get("/sapi/v1/capital/withdraw/history", Some(params))
+            .await
+    }
+}
@@ -14,7 +14,7 @@ impl BaseClient {
 
     // Check server time
     pub async fn get_server_time(&self) -> Result<ServerTime> {
-        self.transport.get::<_, ()>("/api/v1/time", None).await
+        self.transport.get::<_, ()>("/api/v3/time", None).await
     }
 
     pub async fn get_exchange_info(&self) -> Result<ExchangeInformation> {
@@ -1,7 +1,7 @@
 use serde_json::json;
 use serde_json::Value;
 use super::BaseClient;
-use crate::model::{BookTickers, KlineParams, KlineSummaries, KlineSummary, OrderBook, PriceStats, Prices, SymbolPrice, Ticker, MarketPair};
+use crate::model::{AggTrade, AggTradesParams, BookTickers, KlineParams, KlineSummaries, KlineSummary, OrderBook, PriceStats, Prices, SymbolPrice, Ticker, MarketPair};
 pub use openlimits_exchange::OpenLimitsError;
 use rust_decimal::prelude::Decimal;
 use super::shared::Result;
@@ -64,6 +64,11 @@ impl BaseClient {
         )
     }
 
+    // Compressed, aggregate trades (Default 500; max 1000)
+    pub async fn get_agg_trades(&self, params: &AggTradesParams) -> Result<Vec<AggTrade>> {
+        self.transport.get("/api/v3/aggTrades", Some(params)).await
+    }
+
     // 24hr ticker price change statistics
     pub async fn get_24h_price_stats(&self, symbol: &str) -> Result<PriceStats> {
         let params = json! {{"symbol": symbol}};
@@ -1,5 +1,6 @@
 //! This module is used to make calls to api and connect to the websockets
 mod account;
+mod capital;
 mod general;
 mod market;
 mod userstream;
@@ -1,14 +1,17 @@
-use std::{convert::TryFrom, fmt::Display};
+use std::{convert::{TryFrom, TryInto}, fmt::Display};
 use std::sync::Mutex;
+use std::time::Duration;
 use async_trait::async_trait;
 use futures::{SinkExt, stream::BoxStream, StreamExt};
 use serde::{de, Deserialize, Serialize};
 use serde_json::Value;
-use tokio::sync::mpsc::{unbounded_channel, UnboundedSender};
-use tokio_tungstenite::{connect_async, tungstenite::protocol::Message};
+use cross_async::channel::{unbounded, UnboundedSender};
 use openlimits_exchange::errors::OpenLimitsError;
+use openlimits_exchange::websocket::{self, Message};
 use crate::{
+    Binance,
     BinanceParameters,
+    message::Connection,
     model::websocket::{BinanceSubscription, BinanceWebsocketMessage},
 };
 use openlimits_exchange::{
@@ -16,6 +19,8 @@ use openlimits_exchange::{
     model::websocket::Subscription,
     model::websocket::WebSocketResponse,
 };
+use openlimits_exchange::traits::info::ExchangeInfoRetrieval;
+use openlimits_exchange::traits::info::ExchangeInfo;
 use openlimits_exchange::traits::stream::{ExchangeStream, Subscriptions};
 use super::shared::Result;
 use openlimits_exchange::exchange::Environment;
@@ -23,6 +28,9 @@ use openlimits_exchange::exchange::Environment;
 const WS_URL_PROD: &str = "wss://stream.binance.com:9443/stream";
 const WS_URL_SANDBOX: &str = "wss://testnet.binance.vision/stream";
 
+/// How long a request waits for its response by default.
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(untagged)]
 enum Either<L, R> {
@@ -30,10 +38,64 @@ enum Either<L, R> {
     Right(R),
 }
 
-/// This struct is used for websocket communications with openlimits-binance openlimits-exchange
+/// This struct is used for websocket communications with openlimits-binance openlimits-exchange.
+/// Besides `ExchangeStream`, it implements the messaging `Requester` and `Subscriber` traits, whose
+/// subscriptions all share one connection opened on the first request.
 pub struct BinanceWebsocket {
     parameters: BinanceParameters,
+    exchange_info: ExchangeInfo,
     disconnection_senders: Mutex<Vec<UnboundedSender<()>>>,
+    pub(crate) connection: Option<Connection>,
+    pub(crate) request_timeout: Duration,
+}
+
+impl BinanceWebsocket {
+    /// Sets how long requests, subscriptions included, wait for their response before failing
+    /// with `RequestTimeout`.
+    pub fn with_request_timeout(mut self, request_timeout: Duration) -> Self {
+        self.request_timeout = request_timeout;
+        self
+    }
+
+    /// Shares the markets of a `Binance` client, which are otherwise loaded when the first trade
+    /// stream is created.
+    pub fn with_exchange_info(mut self, exchange_info: ExchangeInfo) -> Self {
+        self.exchange_info = exchange_info;
+        self
+    }
+
+    /// Loads the markets unless they are already known. Trade messages name their market by the
+    /// concatenated symbol, which only the markets can split back into a market pair.
+    async fn load_exchange_info(&self) -> Result<()> {
+        if !self.exchange_info.list_pairs().is_empty() {
+            return Ok(());
+        }
+        // The markets are public, so the client needs neither the credentials nor a clock.
+        let mut binance = Binance::from_parameters(BinanceParameters {
+            credentials: None,
+            signer: None,
+            ..self.parameters.clone()
+        })?;
+        binance.exchange_info = self.exchange_info.clone();
+        binance.refresh_market_info().await?;
+        Ok(())
+    }
+
+    /// The messaging connection, opened again if it was closed.
+    pub(crate) async fn connection(&mut self) -> Result<&Connection> {
+        if !self.connection.as_ref().is_some_and(Connection::is_connected) {
+            self.connection = Some(Connection::new(self.ws_url()).await?);
+        }
+        self.connection.as_ref().ok_or(OpenLimitsError::Disconnected())
+    }
+
+    fn ws_url(&self) -> &str {
+        match (&self.parameters.endpoints.websocket, self.parameters.environment) {
+            (Some(url), _) => url,
+            (None, Environment::Sandbox) => WS_URL_SANDBOX,
+            (None, Environment::Production) => WS_URL_PROD,
+        }
+    }
 }
 
 #[async_trait]
@@ -45,41 +107,44 @@ impl ExchangeStream for BinanceWebsocket {
     async fn new(parameters: Self::InitParams) -> Result<Self> {
         Ok(BinanceWebsocket {
             parameters,
+            exchange_info: ExchangeInfo::with_venue("binance"),
             disconnection_senders: Default::default(),
+            connection: None,
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
         })
     }
 
     async fn disconnect(&self) {
         if let Ok(mut senders) = self.disconnection_senders.lock() {
             for sender in senders.iter() {
-                sender.send(()).ok();
+                sender.unbounded_send(()).ok();
             }
             senders.clear();
         }
+        if let Some(connection) = &self.connection {
+            connection.close();
+        }
     }
 
     async fn create_stream_specific(
         &self,
         subscriptions: Subscriptions<Self::Subscription>,
     ) -> Result<BoxStream<'static, Result<Self::Response>>> {
+        if subscriptions.as_slice().iter().any(|s| matches!(s, BinanceSubscription::Trade(_))) {
+            self.load_exchange_info().await?;
+        }
         let streams = subscriptions
             .into_iter()
             .map(|bs| bs.to_string())
             .collect::<Vec<String>>()
             .join("/");
 
-        let ws_url = match self.parameters.environment {
-            Environment::Sandbox => WS_URL_SANDBOX,
-            Environment::Production => WS_URL_PROD,
-        };
-        let endpoint = url::Url::parse(&format!("{}?streams={}", ws_url, streams.to_lowercase()))
+        let endpoint = url::Url::parse(&format!("{}?streams={}", self.ws_url(), streams.to_lowercase()))
             .map_err(OpenLimitsError::UrlParserError)?;
-        let (ws_stream, _) = connect_async(endpoint).await?;
-
-        let (mut sink, stream) = ws_stream.split();
-        let (disconnection_sender, mut disconnection_receiver) = unbounded_channel();
-        tokio::spawn(async move {
-            if disconnection_receiver.recv().await.is_some() {
+        let (mut sink, stream) = websocket::connect(endpoint.as_str()).await?;
+        let (disconnection_sender, mut disconnection_receiver) = unbounded();
+        cross_async::spawn(async move {
+            if disconnection_receiver.next().await.is_some() {
                 sink.close().await.ok();
             }
         });
@@ -88,8 +153,9 @@ impl ExchangeStream for BinanceWebsocket {
             senders.push(disconnection_sender);
         }
 
-        let s = stream.map(|message| match message {
-            Ok(msg) => parse_message(msg),
+        let exchange_info = self.exchange_info.clone();
+        let s = stream.map(move |message| match message {
+            Ok(msg) => parse_message(msg, &exchange_info),
             Err(_) => Err(OpenLimitsError::SocketError()),
         });
 
@@ -148,7 +214,7 @@ impl<'de> Deserialize<'de> for BinanceWebsocketMessage {
                 serde_json::from_value(stream.data).map_err(de::Error::custom)?,
             ))
         } else {
-            panic!("Not supported Subscription");
+            Err(de::Error::custom(format!("Not supported subscription {}", stream.name)))
         }
     }
 }
@@ -195,7 +261,7 @@ impl TryFrom<BinanceWebsocketMessage> for WebSocketResponse<BinanceWebsocketMess
                 OpenLimitsWebSocketMessage::OrderBook(orderbook.into()),
             )),
             BinanceWebsocketMessage::Trade(trade) => Ok(WebSocketResponse::Generic(
-                OpenLimitsWebSocketMessage::Trades(trade.into()),
+                OpenLimitsWebSocketMessage::Trades(vec![trade.try_into()?]),
             )),
             BinanceWebsocketMessage::Ping => {
                 Ok(WebSocketResponse::Generic(OpenLimitsWebSocketMessage::Ping))
@@ -206,14 +272,18 @@ impl TryFrom<BinanceWebsocketMessage> for WebSocketResponse<BinanceWebsocketMess
     }
 }
 
-fn parse_message(ws_message: Message) -> Result<BinanceWebsocketMessage> {
+fn parse_message(ws_message: Message, exchange_info: &ExchangeInfo) -> Result<BinanceWebsocketMessage> {
     let msg = match ws_message {
         Message::Text(m) => m,
         Message::Binary(b) => return Ok(BinanceWebsocketMessage::Binary(b)),
         Message::Pong(..) => return Ok(BinanceWebsocketMessage::Pong),
         Message::Ping(..) => return Ok(BinanceWebsocketMessage::Ping),
-        Message::Close(..) => return Ok(BinanceWebsocketMessage::Close),
+        Message::Close => return Ok(BinanceWebsocketMessage::Close),
     };
 
-    serde_json::from_str(&msg).map_err(OpenLimitsError::JsonError)
+    let mut message = serde_json::from_str(&msg).map_err(OpenLimitsError::JsonError)?;
+    if let BinanceWebsocketMessage::Trade(ref mut trade) = message {
+        trade.market_pair = Some(exchange_info.market_pair(&trade.symbol)?);
+    }
+    Ok(message)
 }
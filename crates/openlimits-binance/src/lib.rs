@@ -9,15 +9,21 @@ use model::KlineSummaries;
 use transport::Transport;
 use client::BaseClient;
 use std::convert::TryFrom;
+use rust_decimal::prelude::Decimal;
 use model::{websocket::TradeMessage, SymbolFilter, ORDER_TYPE_LIMIT, ORDER_TYPE_MARKET};
 use openlimits_exchange::{
-    errors::OpenLimitsError,
+    errors::{MissingImplementationContent, OpenLimitsError},
     model::{
-        AskBid, Balance, CancelAllOrdersRequest, CancelOrderRequest, Candle,
+        AskBid, Balance, Batch, BatchOperation, CancelAllOrdersRequest, CancelOrderRequest, Candle,
+        Capabilities, Deposit,
+        DepositAddress, FundingStatus, GetDepositAddressRequest, GetDepositHistoryRequest,
         GetHistoricRatesRequest, GetHistoricTradesRequest, GetOrderHistoryRequest, GetOrderRequest,
-        GetPriceTickerRequest, Liquidity, OpenLimitOrderRequest, OpenMarketOrderRequest,
-        Order, OrderBookRequest, OrderBookResponse, OrderCanceled, OrderStatus, OrderType,
-        Paginator, Side, Ticker, TimeInForce, Trade, TradeHistoryRequest, Transaction,
+        GetLedgerRequest, GetPriceTickerRequest, GetWithdrawalHistoryRequest, Interval, LedgerEntry,
+        LedgerEntryType, Liquidity, OpenLimitOrderRequest, Operation,
+        OpenMarketOrderRequest, Order, OrderBookRequest, OrderBookResponse, OrderCanceled,
+        OrderStatus, OrderType, Paginator, Side, Ticker, TimeInForce, Trade, TradeHistoryRequest,
+        Transaction, WithdrawRequest, Withdrawal,
+        websocket::SubscriptionKind,
     }
 };
 
@@ -34,10 +40,11 @@ pub use binance_parameters::*;
 pub use transport::*;
 
 pub mod client;
+pub mod message;
 
 pub use client::stream::BinanceWebsocket;
 use openlimits_exchange::traits::info::{ExchangeInfo, ExchangeInfoRetrieval, MarketPairInfo, MarketPairHandle};
-use openlimits_exchange::traits::{Exchange, ExchangeMarketData, ExchangeAccount};
+use openlimits_exchange::traits::{Exchange, ExchangeMarketData, ExchangeAccount, ExchangeFunding};
 use openlimits_exchange::exchange::Environment;
 use openlimits_exchange::model::market_pair::MarketPair;
 
@@ -48,31 +55,54 @@ pub struct Binance {
     pub client: BaseClient,
 }
 
+impl Binance {
+    /// Returns a client sharing the connection, markets and clock of this one whose signed
+    /// requests carry another recvWindow, for the calls that need a tighter or looser window than
+    /// the default 7 seconds: `binance.with_recv_window(Duration::from_secs(2)).limit_buy(&req)`.
+    pub fn with_recv_window(&self, recv_window: std::time::Duration) -> Self {
+        Self {
+            exchange_info: self.exchange_info.clone(),
+            client: self.client.with_recv_window(recv_window.as_millis() as usize),
+        }
+    }
+
+    /// The client of the parameters, before it loads the markets or starts syncing its clock.
+    pub(crate) fn from_parameters(parameters: BinanceParameters) -> Result<Self> {
+        let sandbox = parameters.environment == Environment::Sandbox;
+        let mut transport = match (parameters.credentials, parameters.signer) {
+            (Some(credentials), Some(signer)) => {
+                Transport::with_signer(&credentials.api_key, signer, sandbox)?
+            }
+            (Some(credentials), None) => Transport::with_credential(
+                &credentials.api_key,
+                &credentials.api_secret,
+                sandbox,
+            )?,
+            (None, _) => Transport::new(sandbox)?,
+        };
+        if let Some(base_url) = parameters.endpoints.rest {
+            transport = transport.with_base_url(base_url);
+        }
+        if let Some(http_transport) = parameters.http_transport {
+            transport = transport.with_http_transport(http_transport);
+        }
+        Ok(Binance {
+            exchange_info: ExchangeInfo::with_venue("binance"),
+            client: BaseClient { transport },
+        })
+    }
+}
+
 #[async_trait]
 impl Exchange for Binance {
     type InitParams = BinanceParameters;
     type InnerClient = BaseClient;
 
     async fn new(parameters: Self::InitParams) -> Result<Self> {
-        let binance = match parameters.credentials {
-            Some(credentials) => Binance {
-                exchange_info: ExchangeInfo::new(),
-                client: BaseClient {
-                    transport: Transport::with_credential(
-                        &credentials.api_key,
-                        &credentials.api_secret,
-                        parameters.environment == Environment::Sandbox,
-                    )?,
-                },
-            },
-            None => Binance {
-                exchange_info: ExchangeInfo::new(),
-                client: BaseClient {
-                    transport: Transport::new(parameters.environment == Environment::Sandbox)?,
-                },
-            },
-        };
-
+        let binance = Binance::from_parameters(parameters)?;
+        if binance.client.transport.is_signed() {
+            binance.client.transport.spawn_clock_sync();
+        }
         binance.refresh_market_info().await?;
         Ok(binance)
     }
@@ -80,6 +110,62 @@ impl Exchange for Binance {
     fn inner_client(&self) -> Option<&Self::InnerClient> {
         Some(&self.client)
     }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            operations: vec![
+                Operation::OrderBook,
+                Operation::GetPriceTicker,
+                Operation::GetAllTickers,
+                Operation::GetHistoricRates,
+                Operation::GetHistoricTrades,
+                Operation::LimitBuy,
+                Operation::LimitSell,
+                Operation::MarketBuy,
+                Operation::MarketSell,
+                Operation::CancelOrder,
+                Operation::CancelAllOrders,
+                Operation::GetAllOpenOrders,
+                Operation::GetOrderHistory,
+                Operation::GetTradeHistory,
+                Operation::GetAccountBalances,
+                Operation::GetOrder,
+                Operation::GetLedger,
+                Operation::GetDepositAddress,
+                Operation::GetDepositHistory,
+                Operation::Withdraw,
+                Operation::GetWithdrawalHistory,
+            ],
+            order_types: vec![OrderType::Limit, OrderType::Market],
+            time_in_force: vec![
+                TimeInForce::GoodTillCancelled,
+                TimeInForce::ImmediateOrCancelled,
+                TimeInForce::FillOrKill,
+            ],
+            intervals: vec![
+                Interval::OneMinute,
+                Interval::ThreeMinutes,
+                Interval::FiveMinutes,
+                Interval::FifteenMinutes,
+                Interval::ThirtyMinutes,
+                Interval::OneHour,
+                Interval::TwoHours,
+                Interval::FourHours,
+                Interval::SixHours,
+                Interval::EightHours,
+                Interval::TwelveHours,
+                Interval::OneDay,
+                Interval::ThreeDays,
+                Interval::OneWeek,
+                Interval::OneMonth,
+            ],
+            subscriptions: vec![SubscriptionKind::OrderBookUpdates, SubscriptionKind::Trades],
+            // openOrders cancels the orders of one market.
+            batch_operations: vec![Batch::new(BatchOperation::CancelAllOrders, None)],
+            // order_book requests Binance's default depth.
+            max_book_depth: Some(100),
+        }
+    }
 }
 
 #[async_trait]
@@ -151,14 +237,29 @@ impl ExchangeMarketData for Binance {
     }
 
     async fn get_price_ticker(&self, req: &GetPriceTickerRequest) -> Result<Ticker> {
-        self.client
-            .get_price(req.market_pair.clone())
-            .await
-            .map(Into::into)
+        let symbol = model::MarketPair::from(req.market_pair.clone()).0;
+        let stats = self.client.get_24h_price_stats(&symbol).await?;
+        Ok(to_ticker(stats, req.market_pair.clone()))
+    }
+
+    async fn get_all_tickers(&self) -> Result<Vec<Ticker>> {
+        let stats = self.client.get_24h_price_stats_all().await?;
+        // The 24h statistics also list delisted symbols, which are not in the exchange info and
+        // are left out, as are symbols listed since the exchange info was last refreshed.
+        Ok(stats
+            .into_iter()
+            .filter_map(|stats| match self.exchange_info.market_pair(&stats.symbol) {
+                Ok(market_pair) => Some(to_ticker(stats, market_pair)),
+                Err(_) => {
+                    tracing::debug!(symbol = %stats.symbol, "Skipping the ticker of a symbol missing from the exchange info");
+                    None
+                }
+            })
+            .collect())
     }
 
     async fn get_historic_rates(&self, req: &GetHistoricRatesRequest) -> Result<Vec<Candle>> {
-        let params = req.into();
+        let params = model::KlineParams::try_from(req)?;
 
         self.client
             .get_klines(&params)
@@ -166,8 +267,44 @@ impl ExchangeMarketData for Binance {
             .map(|KlineSummaries::AllKlineSummaries(v)| v.into_iter().map(Into::into).collect())
     }
 
-    async fn get_historic_trades(&self, _req: &GetHistoricTradesRequest) -> Result<Vec<Trade>> {
-        unimplemented!("Only implemented for Nash right now");
+    // Binance pages aggregate trades forward from an id, so "after" maps to fromId and "before"
+    // is served by starting a page earlier and dropping the trades at or after it.
+    async fn get_historic_trades(&self, req: &GetHistoricTradesRequest) -> Result<Vec<Trade>> {
+        let market_pair = self.exchange_info.market_pair(&req.market_pair)?;
+        let paginator = req.paginator.as_ref();
+        let parse_id = |id: &String| {
+            id.parse::<u64>().map_err(|_| {
+                OpenLimitsError::InvalidParameter(format!("{} is not a valid trade id.", id))
+            })
+        };
+        let after = paginator.and_then(|p| p.after.as_ref()).map(parse_id).transpose()?;
+        let before = paginator.and_then(|p| p.before.as_ref()).map(parse_id).transpose()?;
+        let limit = paginator.and_then(|p| p.limit).unwrap_or(AGG_TRADES_LIMIT);
+        let from_id = after.or_else(|| before.map(|before| before.saturating_sub(limit)));
+        // Binance rejects fromId combined with a time range.
+        let (start_time, end_time) = match from_id {
+            Some(_) => (None, None),
+            None => (
+                paginator.and_then(|p| p.start_time.as_ref().map(shared::datetime_to_timestamp)),
+                paginator.and_then(|p| p.end_time.as_ref().map(shared::datetime_to_timestamp)),
+            ),
+        };
+        let params = model::AggTradesParams {
+            symbol: model::MarketPair::from(market_pair.clone()).0,
+            paginator: Some(model::Paginator {
+                start_time,
+                end_time,
+                limit: Some(limit),
+                from_id,
+                order_id: None,
+            }),
+        };
+        let trades = self.client.get_agg_trades(&params).await?;
+        Ok(trades
+            .into_iter()
+            .filter(|trade| before.is_none_or(|before| trade.aggregated_trade_id < before))
+            .map(|trade| to_agg_trade(trade, market_pair.clone()))
+            .collect())
     }
 }
 
@@ -180,11 +317,11 @@ impl ExchangeAccount for Binance {
                 pair,
                 req.size,
                 req.price,
-                model::TimeInForce::from(req.time_in_force),
+                model::TimeInForce::try_from(req.time_in_force)?,
                 req.post_only,
             )
             .await
-            .map(Into::into)
+            .map(|order| to_order(order, req.market_pair.clone()))
     }
     async fn limit_sell(&self, req: &OpenLimitOrderRequest) -> Result<Order> {
         let pair = self.get_pair(&req.market_pair).await?.read()?;
@@ -193,32 +330,33 @@ impl ExchangeAccount for Binance {
                 pair,
                 req.size,
                 req.price,
-                model::TimeInForce::from(req.time_in_force),
+                model::TimeInForce::try_from(req.time_in_force)?,
                 req.post_only,
             )
             .await
-            .map(Into::into)
+            .map(|order| to_order(order, req.market_pair.clone()))
     }
 
     async fn market_buy(&self, req: &OpenMarketOrderRequest) -> Result<Order> {
         let pair = self.get_pair(&req.market_pair).await?.read()?;
-        self.client.market_buy(pair, req.size).await.map(Into::into)
+        self.client
+            .market_buy(pair, req.size)
+            .await
+            .map(|order| to_order(order, req.market_pair.clone()))
     }
     async fn market_sell(&self, req: &OpenMarketOrderRequest) -> Result<Order> {
         let pair = self.get_pair(&req.market_pair).await?.read()?;
         self.client
             .market_sell(pair, req.size)
             .await
-            .map(Into::into)
+            .map(|order| to_order(order, req.market_pair.clone()))
     }
     async fn cancel_order(&self, req: &CancelOrderRequest) -> Result<OrderCanceled> {
         if let Some(pair) = req.market_pair.as_ref() {
-            let u64_id = req
-                .id
-                .parse::<u64>()
-                .expect("openlimits-binance order id did not parse as u64");
+            let u64_id = parse_order_id(&req.id)?;
+            let symbol = model::MarketPair::from(pair.clone()).0;
             self.client
-                .cancel_order(pair.as_ref(), u64_id)
+                .cancel_order(&symbol, u64_id)
                 .await
                 .map(Into::into)
         } else {
@@ -242,24 +380,30 @@ impl ExchangeAccount for Binance {
     async fn get_all_open_orders(&self) -> Result<Vec<Order>> {
         self.client
             .get_all_open_orders()
-            .await
-            .map(|v| v.into_iter().map(Into::into).collect())
+            .await?
+            .into_iter()
+            .map(|order| self.order(order))
+            .collect()
     }
 
     async fn get_order_history(&self, req: &GetOrderHistoryRequest) -> Result<Vec<Order>> {
         let req = model::AllOrderReq::try_from(req)?;
         self.client
             .get_all_orders(&req)
-            .await
-            .map(|v| v.into_iter().map(Into::into).collect())
+            .await?
+            .into_iter()
+            .map(|order| self.order(order))
+            .collect()
     }
 
     async fn get_trade_history(&self, req: &TradeHistoryRequest) -> Result<Vec<Trade>> {
         let req = model::TradeHistoryReq::try_from(req)?;
         self.client
             .trade_history(&req)
-            .await
-            .map(|v| v.into_iter().map(Into::into).collect())
+            .await?
+            .into_iter()
+            .map(|trade| self.trade(trade))
+            .collect()
     }
 
     async fn get_account_balances(&self, _paginator: Option<Paginator>) -> Result<Vec<Balance>> {
@@ -270,17 +414,317 @@ impl ExchangeAccount for Binance {
     }
 
     async fn get_order(&self, req: &GetOrderRequest) -> Result<Order> {
-        let pair = req.market_pair.clone().ok_or_else(|| {
+        let market_pair = req.market_pair.clone().ok_or_else(|| {
+            OpenLimitsError::MissingParameter("market_pair parameter is required.".to_string())
+        })?;
+        let symbol = model::MarketPair::from(market_pair.clone()).0;
+        let u64_id = parse_order_id(&req.id)?;
+        let order = self.client.get_order(&symbol, u64_id).await?;
+        Ok(to_order(order, market_pair))
+    }
+
+    // Binance has no ledger endpoint, so the ledger is rebuilt from the account trades of the
+    // requested market and from the deposit and withdrawal history of the asset. It is paged
+    // forward with `after`, the `created_at` in milliseconds of the last entry of the previous
+    // page, and a page never ends within a millisecond, which would then be split between pages.
+    async fn get_ledger(&self, req: &GetLedgerRequest) -> Result<Vec<LedgerEntry>> {
+        let market_pair = req.market_pair.as_ref().ok_or_else(|| {
             OpenLimitsError::MissingParameter("market_pair parameter is required.".to_string())
         })?;
-        let u64_id = req
-            .id
-            .parse::<u64>()
-            .expect("openlimits-binance order id did not parse as u64");
-        self.client.get_order(&pair, u64_id).await.map(Into::into)
+        let pair = self.get_pair(market_pair).await?.read()?;
+        let asset = req.asset.to_uppercase();
+        let paginator = req.paginator.as_ref();
+        if paginator.and_then(|p| p.before.as_ref()).is_some() {
+            return Err(OpenLimitsError::InvalidParameter(
+                "Binance ledger is only paged forward with after.".to_string(),
+            ));
+        }
+        let start_time = match paginator.and_then(|p| p.after.as_deref()) {
+            Some(after) => Some(ledger_cursor(after)? + 1),
+            None => paginator.and_then(|p| p.start_time.as_ref().map(shared::datetime_to_timestamp)),
+        };
+        let mut end_time = paginator.and_then(|p| p.end_time.as_ref().map(shared::datetime_to_timestamp));
+        let limit = paginator.and_then(|p| p.limit);
+
+        let mut trades = self.trade_page(&pair.symbol, start_time, end_time, limit).await?;
+        // A full page of trades may end within a millisecond, so the deposits and withdrawals
+        // are read up to the one before, unless it holds every trade, which are then all read.
+        if let (Some(limit), Some(first), Some(last)) = (limit, trades.first(), trades.last()) {
+            if trades.len() as u64 >= limit {
+                let (first, last) = (first.time, last.time);
+                if first == last {
+                    trades = self.trade_page(&pair.symbol, Some(last), Some(last), Some(HISTORY_LIMIT)).await?;
+                    end_time = Some(last);
+                } else {
+                    trades.retain(|trade| trade.time < last);
+                    end_time = Some(last - 1);
+                }
+            }
+        }
+
+        let mut entries = Vec::new();
+        for trade in &trades {
+            entries.extend(trade_ledger_entries(trade, &pair, &asset)?);
+        }
+        let mut params = model::CapitalHistoryReq {
+            coin: Some(asset.clone()),
+            start_time,
+            end_time,
+            offset: None,
+            limit: Some(HISTORY_LIMIT),
+        };
+        loop {
+            let deposits = self.client.get_deposit_history(&params).await?;
+            let full = deposits.len() as u64 == HISTORY_LIMIT;
+            params.offset = Some(params.offset.unwrap_or(0) + HISTORY_LIMIT);
+            entries.extend(deposits.into_iter().filter_map(deposit_ledger_entry));
+            if !full {
+                break;
+            }
+        }
+        params.offset = None;
+        loop {
+            let withdrawals = self.client.get_withdraw_history(&params).await?;
+            let full = withdrawals.len() as u64 == HISTORY_LIMIT;
+            params.offset = Some(params.offset.unwrap_or(0) + HISTORY_LIMIT);
+            entries.extend(withdrawals.into_iter().flat_map(withdraw_ledger_entries));
+            if !full {
+                break;
+            }
+        }
+        entries.sort_by_key(|entry| entry.created_at);
+
+        if let Some(limit) = limit.map(|limit| limit as usize).filter(|limit| entries.len() > *limit) {
+            // The page ends before the millisecond it would split, unless that is its first one.
+            let split = entries[limit].created_at;
+            let end = match entries.partition_point(|entry| entry.created_at < split) {
+                0 => entries.partition_point(|entry| entry.created_at <= split),
+                end => end,
+            };
+            entries.truncate(end);
+        }
+        Ok(entries)
+    }
+}
+
+#[async_trait]
+impl ExchangeFunding for Binance {
+    async fn get_deposit_address(&self, req: &GetDepositAddressRequest) -> Result<DepositAddress> {
+        let params = model::DepositAddressReq {
+            coin: req.asset.clone(),
+            network: req.network.clone(),
+        };
+        let address = self.client.get_deposit_address(&params).await?;
+        Ok(DepositAddress {
+            asset: address.coin,
+            address: address.address,
+            tag: Some(address.tag).filter(|tag| !tag.is_empty()),
+            network: req.network.clone(),
+        })
+    }
+
+    async fn get_deposit_history(&self, req: &GetDepositHistoryRequest) -> Result<Vec<Deposit>> {
+        let params = capital_history_req(req.asset.clone(), req.paginator.as_ref())?;
+        self.client
+            .get_deposit_history(&params)
+            .await
+            .map(|v| v.into_iter().map(Into::into).collect())
+    }
+
+    async fn withdraw(&self, req: &WithdrawRequest) -> Result<Withdrawal> {
+        let params = model::WithdrawReq {
+            coin: req.asset.clone(),
+            withdraw_order_id: req.client_withdrawal_id.clone(),
+            network: req.network.clone(),
+            address: req.address.clone(),
+            address_tag: req.tag.clone(),
+            amount: req.amount,
+        };
+        let response = self.client.withdraw(&params).await?;
+        Ok(Withdrawal {
+            id: response.id,
+            client_withdrawal_id: req.client_withdrawal_id.clone(),
+            asset: req.asset.clone(),
+            amount: req.amount,
+            fee: None,
+            network: req.network.clone(),
+            address: req.address.clone(),
+            tag: req.tag.clone(),
+            tx_id: None,
+            status: FundingStatus::Pending,
+            created_at: None,
+        })
+    }
+
+    async fn get_withdrawal_history(&self, req: &GetWithdrawalHistoryRequest) -> Result<Vec<Withdrawal>> {
+        let params = capital_history_req(req.asset.clone(), req.paginator.as_ref())?;
+        self.client
+            .get_withdraw_history(&params)
+            .await
+            .map(|v| v.into_iter().map(Into::into).collect())
+    }
+}
+
+/// Binance's default page size for aggregate trades.
+const AGG_TRADES_LIMIT: u64 = 500;
+
+/// The most records Binance returns from the trade, deposit and withdrawal history at once.
+const HISTORY_LIMIT: u64 = 1000;
+
+impl Binance {
+    async fn trade_page(
+        &self,
+        symbol: &str,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u64>,
+    ) -> Result<Vec<model::TradeHistory>> {
+        let params = model::TradeHistoryReq {
+            symbol: symbol.to_string(),
+            paginator: Some(model::Paginator {
+                start_time,
+                end_time,
+                limit,
+                from_id: None,
+                order_id: None,
+            }),
+        };
+        self.client.trade_history(&params).await
+    }
+
+    fn order(&self, order: model::Order) -> Result<Order> {
+        let market_pair = self.exchange_info.market_pair(&order.symbol)?;
+        Ok(to_order(order, market_pair))
+    }
+
+    fn trade(&self, trade: model::TradeHistory) -> Result<Trade> {
+        let market_pair = self.exchange_info.market_pair(&trade.symbol)?;
+        Ok(to_trade(trade, market_pair))
     }
 }
 
+/// The time in milliseconds of a ledger cursor, the `created_at` of the last entry of a page.
+fn ledger_cursor(after: &str) -> Result<u64> {
+    after
+        .parse::<u64>()
+        .map_err(|_| OpenLimitsError::InvalidParameter(format!("{} is not a valid ledger cursor.", after)))
+}
+
+fn capital_history_req(coin: Option<String>, paginator: Option<&Paginator>) -> Result<model::CapitalHistoryReq> {
+    let offset = match paginator.and_then(|p| p.after.as_ref()) {
+        Some(after) => Some(after.parse::<u64>().map_err(|_| {
+            OpenLimitsError::InvalidParameter(format!("{} is not a valid offset.", after))
+        })?),
+        None => None,
+    };
+    Ok(model::CapitalHistoryReq {
+        coin,
+        start_time: paginator.and_then(|p| p.start_time.as_ref().map(shared::datetime_to_timestamp)),
+        end_time: paginator.and_then(|p| p.end_time.as_ref().map(shared::datetime_to_timestamp)),
+        offset,
+        limit: paginator.and_then(|p| p.limit),
+    })
+}
+
+/// The entries of a trade in the requested asset: the amount of it the trade moved, if it is one
+/// of the market, and the fee, if it was paid in it, which may be a third asset such as BNB.
+fn trade_ledger_entries(trade: &model::TradeHistory, pair: &MarketPairInfo, asset: &str) -> Result<Vec<LedgerEntry>> {
+    let market_pair = pair.market_pair()?;
+    let quote_qty = trade.price * trade.qty;
+    let (base_amount, quote_amount) = if trade.is_buyer {
+        (trade.qty, -quote_qty)
+    } else {
+        (-trade.qty, quote_qty)
+    };
+    let amount = if pair.base == asset {
+        Some(base_amount)
+    } else if pair.quote == asset {
+        Some(quote_amount)
+    } else {
+        None
+    };
+
+    let mut entries = Vec::new();
+    if let Some(amount) = amount {
+        entries.push(LedgerEntry {
+            id: format!("trade:{}", trade.id),
+            asset: asset.to_string(),
+            entry_type: LedgerEntryType::Trade,
+            amount,
+            balance: None,
+            reference_id: Some(trade.order_id.to_string()),
+            market_pair: Some(market_pair.clone()),
+            created_at: Some(shared::timestamp_to_utc_datetime(trade.time)),
+        });
+    }
+    if !trade.commission.is_zero() && trade.commission_asset == asset {
+        entries.push(LedgerEntry {
+            id: format!("fee:{}", trade.id),
+            asset: trade.commission_asset.clone(),
+            entry_type: LedgerEntryType::Fee,
+            amount: -trade.commission,
+            balance: None,
+            reference_id: Some(trade.order_id.to_string()),
+            market_pair: Some(market_pair),
+            created_at: Some(shared::timestamp_to_utc_datetime(trade.time)),
+        });
+    }
+    Ok(entries)
+}
+
+fn deposit_ledger_entry(deposit: model::DepositRecord) -> Option<LedgerEntry> {
+    match deposit.status {
+        model::DepositStatus::Success | model::DepositStatus::CreditedCannotWithdraw => {
+            let deposit = Deposit::from(deposit);
+            Some(LedgerEntry {
+                id: format!("deposit:{}", deposit.id),
+                asset: deposit.asset,
+                entry_type: LedgerEntryType::Deposit,
+                amount: deposit.amount,
+                balance: None,
+                reference_id: deposit.tx_id,
+                market_pair: None,
+                created_at: deposit.created_at,
+            })
+        }
+        _ => None,
+    }
+}
+
+fn withdraw_ledger_entries(withdraw: model::WithdrawRecord) -> Vec<LedgerEntry> {
+    // The balance is debited when the withdrawal is applied and refunded if it doesn't go through.
+    match withdraw.status {
+        model::WithdrawStatus::Cancelled
+        | model::WithdrawStatus::Rejected
+        | model::WithdrawStatus::Failure => return Vec::new(),
+        _ => {}
+    }
+    let withdrawal = Withdrawal::from(withdraw);
+    let mut entries = vec![LedgerEntry {
+        id: format!("withdrawal:{}", withdrawal.id),
+        asset: withdrawal.asset.clone(),
+        entry_type: LedgerEntryType::Withdrawal,
+        amount: -withdrawal.amount,
+        balance: None,
+        reference_id: withdrawal.tx_id.clone(),
+        market_pair: None,
+        created_at: withdrawal.created_at,
+    }];
+    if let Some(fee) = withdrawal.fee.filter(|fee| !fee.is_zero()) {
+        entries.push(LedgerEntry {
+            id: format!("withdrawal-fee:{}", withdrawal.id),
+            asset: withdrawal.asset,
+            entry_type: LedgerEntryType::Fee,
+            amount: -fee,
+            balance: None,
+            reference_id: withdrawal.tx_id,
+            market_pair: None,
+            created_at: withdrawal.created_at,
+        });
+    }
+    entries
+}
+
 impl From<model::OrderBook> for OrderBookResponse {
     fn from(book: model::OrderBook) -> Self {
         Self {
@@ -303,33 +747,15 @@ impl From<model::websocket::Depth> for OrderBookResponse {
     }
 }
 
-impl From<model::websocket::TradeMessage> for Vec<Trade> {
-    fn from(trade_message: model::websocket::TradeMessage) -> Self {
-        vec![Trade {
-            id: trade_message.trade_id.to_string(),
-            buyer_order_id: Some(trade_message.buyer_order_id.to_string()),
-            seller_order_id: Some(trade_message.buyer_order_id.to_string()),
-            market_pair: trade_message.symbol,
-            price: trade_message.price,
-            qty: trade_message.qty,
-            fees: None,
-            side: match trade_message.is_buyer_maker {
-                true => Side::Buy,
-                false => Side::Sell,
-            },
-            liquidity: None,
-            created_at: trade_message.event_time.to_string(),
-        }]
-    }
-}
-
-impl From<TradeMessage> for Trade {
-    fn from(trade: TradeMessage) -> Self {
-        Self {
+impl TryFrom<TradeMessage> for Trade {
+    type Error = OpenLimitsError;
+    fn try_from(trade: TradeMessage) -> Result<Self> {
+        let market_pair = trade.market_pair.ok_or(OpenLimitsError::SymbolNotFound())?;
+        Ok(Self {
             id: trade.trade_id.to_string(),
             buyer_order_id: Some(trade.buyer_order_id.to_string()),
             seller_order_id: Some(trade.seller_order_id.to_string()),
-            market_pair: trade.symbol,
+            market_pair,
             price: trade.price,
             qty: trade.qty,
             fees: None, // Binance does not return fee on trades over WS stream
@@ -339,8 +765,8 @@ impl From<TradeMessage> for Trade {
                 false => Side::Buy,
             },
             liquidity: None,
-            created_at: trade.trade_order_time.to_string(),
-        }
+            created_at: shared::timestamp_to_utc_datetime(trade.trade_order_time),
+        })
     }
 }
 
@@ -359,32 +785,30 @@ impl From<model::Transaction> for Transaction<u64> {
             id: order.order_id,
             market_pair: order.symbol,
             client_order_id: Some(order.client_order_id),
-            created_at: order.transact_time,
+            created_at: shared::timestamp_to_utc_datetime(order.transact_time),
         }
     }
 }
 
-impl From<model::Order> for Order {
-    fn from(order: model::Order) -> Self {
-        let order_type = match order.type_name.as_str() {
-            ORDER_TYPE_LIMIT => OrderType::Limit,
-            ORDER_TYPE_MARKET => OrderType::Market,
-            _ => OrderType::Unknown,
-        };
+fn to_order(order: model::Order, market_pair: MarketPair) -> Order {
+    let order_type = match order.type_name.as_str() {
+        ORDER_TYPE_LIMIT => OrderType::Limit,
+        ORDER_TYPE_MARKET => OrderType::Market,
+        _ => OrderType::Unknown,
+    };
 
-        Self {
-            id: order.order_id.to_string(),
-            market_pair: order.symbol,
-            client_order_id: Some(order.client_order_id),
-            created_at: order.time,
-            order_type,
-            side: order.side.into(),
-            status: order.status.into(),
-            size: order.orig_qty,
-            price: Some(order.price),
-            remaining: Some(order.orig_qty - order.executed_qty),
-            trades: Vec::new(),
-        }
+    Order {
+        id: order.order_id.to_string(),
+        market_pair,
+        client_order_id: Some(order.client_order_id),
+        created_at: order.time.map(shared::timestamp_to_utc_datetime),
+        order_type,
+        side: order.side.into(),
+        status: order.status.into(),
+        size: order.orig_qty,
+        price: Some(order.price),
+        remaining: Some(order.orig_qty - order.executed_qty),
+        trades: Vec::new(),
     }
 }
 
@@ -406,47 +830,106 @@ impl From<model::Balance> for Balance {
     }
 }
 
-impl From<model::TradeHistory> for Trade {
-    fn from(trade_history: model::TradeHistory) -> Self {
-        let (buyer_order_id, seller_order_id) = match trade_history.is_buyer {
-            true => (Some(trade_history.order_id.to_string()), None),
-            false => (None, Some(trade_history.order_id.to_string())),
-        };
-        Self {
-            id: trade_history.id.to_string(),
-            buyer_order_id,
-            seller_order_id,
-            market_pair: trade_history.symbol,
-            price: trade_history.price,
-            qty: trade_history.qty,
-            fees: Some(trade_history.commission),
-            side: match trade_history.is_buyer {
-                true => Side::Buy,
-                false => Side::Sell,
-            },
-            liquidity: match trade_history.is_maker {
-                true => Some(Liquidity::Maker),
-                false => Some(Liquidity::Taker),
-            },
-            created_at: trade_history.time.to_string(),
-        }
+fn to_trade(trade_history: model::TradeHistory, market_pair: MarketPair) -> Trade {
+    let (buyer_order_id, seller_order_id) = match trade_history.is_buyer {
+        true => (Some(trade_history.order_id.to_string()), None),
+        false => (None, Some(trade_history.order_id.to_string())),
+    };
+    Trade {
+        id: trade_history.id.to_string(),
+        buyer_order_id,
+        seller_order_id,
+        market_pair,
+        price: trade_history.price,
+        qty: trade_history.qty,
+        fees: Some(trade_history.commission),
+        side: match trade_history.is_buyer {
+            true => Side::Buy,
+            false => Side::Sell,
+        },
+        liquidity: match trade_history.is_maker {
+            true => Some(Liquidity::Maker),
+            false => Some(Liquidity::Taker),
+        },
+        created_at: shared::timestamp_to_utc_datetime(trade_history.time),
     }
 }
 
-impl From<model::SymbolPrice> for Ticker {
-    fn from(ticker: model::SymbolPrice) -> Self {
-        Self {
-            price: Some(ticker.price),
-            price_24h: None,
-        }
+fn to_agg_trade(trade: model::AggTrade, market_pair: MarketPair) -> Trade {
+    Trade {
+        id: trade.aggregated_trade_id.to_string(),
+        buyer_order_id: None,
+        seller_order_id: None,
+        market_pair,
+        price: trade.price,
+        qty: trade.qty,
+        fees: None,
+        // The taker sold into a resting buy order when the buyer is the maker.
+        side: match trade.is_buyer_maker {
+            true => Side::Sell,
+            false => Side::Buy,
+        },
+        liquidity: None,
+        created_at: shared::timestamp_to_utc_datetime(trade.time),
+    }
+}
+
+fn to_ticker(stats: model::PriceStats, market_pair: MarketPair) -> Ticker {
+    Ticker {
+        market_pair,
+        price: Some(stats.last_price),
+        price_24h: Some(stats.open_price),
+        bid: Some(stats.bid_price),
+        bid_qty: Some(stats.bid_qty),
+        ask: Some(stats.ask_price),
+        ask_qty: Some(stats.ask_qty),
+        high_24h: Some(stats.high_price),
+        low_24h: Some(stats.low_price),
+        volume_24h: Some(stats.volume),
+        quote_volume_24h: Some(stats.quote_volume),
+        created_at: Some(shared::timestamp_to_utc_datetime(stats.close_time)),
+    }
+}
+
+fn to_stream_ticker(ticker: model::websocket::Ticker, market_pair: MarketPair) -> Ticker {
+    Ticker {
+        market_pair,
+        price: Some(ticker.current_close),
+        price_24h: Some(ticker.open),
+        bid: Some(ticker.best_bid),
+        bid_qty: Some(ticker.best_bid_qty),
+        ask: Some(ticker.best_ask),
+        ask_qty: Some(ticker.best_ask_qty),
+        high_24h: Some(ticker.high),
+        low_24h: Some(ticker.low),
+        volume_24h: Some(ticker.volume),
+        quote_volume_24h: Some(ticker.quote_volume),
+        created_at: Some(shared::timestamp_to_utc_datetime(ticker.event_time)),
     }
 }
 
+// The stream klines carry their prices and volume as strings.
+fn to_candle(kline: model::Kline) -> Result<Candle> {
+    let decimal = |value: &str| {
+        value
+            .parse::<Decimal>()
+            .map_err(|_| OpenLimitsError::NotParsableResponse(format!("{} is not a valid decimal.", value)))
+    };
+    Ok(Candle {
+        time: shared::timestamp_to_utc_datetime(kline.start_time as u64),
+        low: decimal(&kline.low)?,
+        high: decimal(&kline.high)?,
+        open: decimal(&kline.open)?,
+        close: decimal(&kline.close)?,
+        volume: decimal(&kline.volume)?,
+    })
+}
+
 impl TryFrom<&GetOrderHistoryRequest> for model::AllOrderReq {
     type Error = OpenLimitsError;
     fn try_from(req: &GetOrderHistoryRequest) -> Result<Self> {
         Ok(Self {
-            paginator: req.paginator.clone().map(|p| p.into()),
+            paginator: req.paginator.as_ref().map(model::Paginator::try_from).transpose()?,
             symbol: req.market_pair
                 .clone()
                 .map(|market| crate::model::MarketPair::from(market).0)
@@ -459,7 +942,7 @@ impl TryFrom<&TradeHistoryRequest> for model::TradeHistoryReq {
     type Error = OpenLimitsError;
     fn try_from(trade_history: &TradeHistoryRequest) -> Result<Self> {
         Ok(Self {
-            paginator: trade_history.paginator.clone().map(|p| p.into()),
+            paginator: trade_history.paginator.as_ref().map(model::Paginator::try_from).transpose()?,
             symbol: trade_history.market_pair
                 .clone()
                 .map(|market| crate::model::MarketPair::from(market).0)
@@ -468,22 +951,23 @@ impl TryFrom<&TradeHistoryRequest> for model::TradeHistoryReq {
     }
 }
 
-impl From<&GetHistoricRatesRequest> for model::KlineParams {
-    fn from(req: &GetHistoricRatesRequest) -> Self {
+impl TryFrom<&GetHistoricRatesRequest> for model::KlineParams {
+    type Error = OpenLimitsError;
+    fn try_from(req: &GetHistoricRatesRequest) -> Result<Self> {
         let interval: &str = req.interval.into();
         let symbol = crate::model::MarketPair::from(req.market_pair.clone()).0;
-        Self {
+        Ok(Self {
             interval: String::from(interval),
-            paginator: req.paginator.clone().map(|d| d.into()),
+            paginator: req.paginator.as_ref().map(model::Paginator::try_from).transpose()?,
             symbol,
-        }
+        })
     }
 }
 
 impl From<model::KlineSummary> for Candle {
     fn from(kline_summary: model::KlineSummary) -> Self {
         Self {
-            time: kline_summary.open_time as u64,
+            time: shared::timestamp_to_utc_datetime(kline_summary.open_time as u64),
             low: kline_summary.low,
             high: kline_summary.high,
             open: kline_summary.open,
@@ -493,35 +977,41 @@ impl From<model::KlineSummary> for Candle {
     }
 }
 
-impl From<TimeInForce> for model::TimeInForce {
-    fn from(tif: TimeInForce) -> Self {
+impl TryFrom<TimeInForce> for model::TimeInForce {
+    type Error = OpenLimitsError;
+    fn try_from(tif: TimeInForce) -> Result<Self> {
         match tif {
-            TimeInForce::GoodTillCancelled => model::TimeInForce::GTC,
-            TimeInForce::FillOrKill => model::TimeInForce::FOK,
-            TimeInForce::ImmediateOrCancelled => model::TimeInForce::IOC,
-            _ => panic!("Binance does not support GoodTillTime policy"),
+            TimeInForce::GoodTillCancelled => Ok(model::TimeInForce::GTC),
+            TimeInForce::FillOrKill => Ok(model::TimeInForce::FOK),
+            TimeInForce::ImmediateOrCancelled => Ok(model::TimeInForce::IOC),
+            TimeInForce::GoodTillTime(_) => {
+                let message = "Binance does not support GoodTillTime policy".into();
+                Err(OpenLimitsError::MissingImplementation(MissingImplementationContent { message }))
+            }
         }
     }
 }
 
-impl From<Paginator> for model::Paginator {
-    fn from(paginator: Paginator) -> Self {
-        Self {
-            from_id: paginator
-                .after
-                .as_ref()
-                .map(|s| s.parse().expect("openlimits-binance page id did not parse as u64")),
+impl TryFrom<&Paginator> for model::Paginator {
+    type Error = OpenLimitsError;
+    fn try_from(paginator: &Paginator) -> Result<Self> {
+        let after = paginator.after.as_deref().map(parse_order_id).transpose()?;
+        Ok(Self {
+            from_id: after,
             // TODO: what is this, and why do we reuse "after"?
-            order_id: paginator
-                .after
-                .map(|s| s.parse().expect("openlimits-binance order id did not parse as u64")),
-            end_time: paginator.end_time,
-            start_time: paginator.start_time,
+            order_id: after,
+            end_time: paginator.end_time.as_ref().map(shared::datetime_to_timestamp),
+            start_time: paginator.start_time.as_ref().map(shared::datetime_to_timestamp),
             limit: paginator.limit,
-        }
+        })
     }
 }
 
+fn parse_order_id(id: &str) -> Result<u64> {
+    id.parse::<u64>()
+        .map_err(|_| OpenLimitsError::InvalidParameter(format!("{} is not a valid order id.", id)))
+}
+
 
 impl From<model::OrderStatus> for OrderStatus {
     fn from(status: model::OrderStatus) -> OrderStatus {
@@ -536,3 +1026,74 @@ impl From<model::OrderStatus> for OrderStatus {
         }
     }
 }
+
+impl From<model::DepositStatus> for FundingStatus {
+    fn from(status: model::DepositStatus) -> Self {
+        match status {
+            model::DepositStatus::Pending => FundingStatus::Pending,
+            model::DepositStatus::CreditedCannotWithdraw => FundingStatus::Processing,
+            model::DepositStatus::Success => FundingStatus::Completed,
+            model::DepositStatus::WaitingUserConfirm => FundingStatus::AwaitingApproval,
+            model::DepositStatus::Rejected => FundingStatus::Rejected,
+            model::DepositStatus::WrongDeposit => FundingStatus::Failed,
+            model::DepositStatus::Unknown(_) => FundingStatus::Pending,
+        }
+    }
+}
+
+impl From<model::WithdrawStatus> for FundingStatus {
+    fn from(status: model::WithdrawStatus) -> Self {
+        match status {
+            model::WithdrawStatus::EmailSent => FundingStatus::Pending,
+            model::WithdrawStatus::AwaitingApproval => FundingStatus::AwaitingApproval,
+            model::WithdrawStatus::Processing => FundingStatus::Processing,
+            model::WithdrawStatus::Completed => FundingStatus::Completed,
+            model::WithdrawStatus::Cancelled => FundingStatus::Canceled,
+            model::WithdrawStatus::Rejected => FundingStatus::Rejected,
+            model::WithdrawStatus::Failure => FundingStatus::Failed,
+            model::WithdrawStatus::Unknown(_) => FundingStatus::Pending,
+        }
+    }
+}
+
+impl From<model::DepositRecord> for Deposit {
+    fn from(deposit: model::DepositRecord) -> Self {
+        let id = match deposit.id {
+            Some(id) => id,
+            None => deposit.tx_id.clone(),
+        };
+        Self {
+            id,
+            asset: deposit.coin,
+            amount: deposit.amount,
+            network: Some(deposit.network),
+            address: Some(deposit.address),
+            tag: Some(deposit.address_tag).filter(|tag| !tag.is_empty()),
+            tx_id: Some(deposit.tx_id),
+            status: deposit.status.into(),
+            created_at: Some(shared::timestamp_to_utc_datetime(deposit.insert_time)),
+        }
+    }
+}
+
+impl From<model::WithdrawRecord> for Withdrawal {
+    fn from(withdraw: model::WithdrawRecord) -> Self {
+        // Binance returns the apply time as an UTC "yyyy-MM-dd HH:mm:ss" string.
+        let created_at = chrono::NaiveDateTime::parse_from_str(&withdraw.apply_time, "%Y-%m-%d %H:%M:%S")
+            .map(|time| time.and_utc())
+            .ok();
+        Self {
+            id: withdraw.id,
+            client_withdrawal_id: withdraw.withdraw_order_id,
+            asset: withdraw.coin,
+            amount: withdraw.amount,
+            fee: Some(withdraw.transaction_fee),
+            network: Some(withdraw.network),
+            address: withdraw.address,
+            tag: withdraw.address_tag.filter(|tag| !tag.is_empty()),
+            tx_id: withdraw.tx_id.filter(|tx_id| !tx_id.is_empty()),
+            status: withdraw.status.into(),
+            created_at,
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use serde_json::Value;
+use openlimits_exchange::message::subscription::{Publication, Subscription};
+use openlimits_exchange::websocket::{self, Codec, Frame, Message};
+use crate::BinanceWebsocket;
+use super::{BinanceMessage, BinanceRequest, BinanceResponse};
+use crate::shared::Result;
+
+/// The connection to the combined stream endpoint, which every subscription of a
+/// `BinanceWebsocket` shares.
+pub(crate) type Connection = websocket::Connection<BinanceCodec>;
+
+/// Reads the frames of the combined stream endpoint, whose updates are keyed by stream name.
+pub(crate) struct BinanceCodec;
+
+impl Codec for BinanceCodec {
+    type Channel = String;
+    type Update = Value;
+    type Response = BinanceResponse;
+
+    const VENUE: &'static str = "binance";
+
+    fn decode(message: Message) -> Frame<String, Value, BinanceResponse> {
+        let text = match message {
+            Message::Text(text) => text,
+            _ => return Frame::Ignored,
+        };
+        match serde_json::from_str(&text) {
+            Ok(BinanceMessage::StreamUpdate(update)) => Frame::Update(update.stream, update.data),
+            Ok(BinanceMessage::Response(response)) => Frame::Response(Some(response.id), Ok(response)),
+            // The stream of the frame is told when the frame is readable enough.
+            Err(error) => {
+                let stream = serde_json::from_str::<Value>(&text)
+                    .ok()
+                    .and_then(|value| value.get("stream").and_then(Value::as_str).map(String::from));
+                Frame::Error(stream, error.into())
+            }
+        }
+    }
+
+    fn publication(subscription: &Subscription, data: Value) -> Result<Publication> {
+        BinanceWebsocket::publication(subscription, data)
+    }
+
+    fn unsubscribe(id: u64, stream: &String) -> Result<Message> {
+        let request = BinanceRequest { method: String::from("UNSUBSCRIBE"), params: vec![stream.clone()], id };
+        Ok(Message::Text(serde_json::to_string(&request)?))
+    }
+}
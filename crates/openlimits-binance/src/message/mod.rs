@@ -0,0 +1,85 @@
+//! This module implements the messaging `Requester` and `Subscriber` traits for `BinanceWebsocket`
+//! on top of the combined stream endpoint.
+mod codec;
+mod requester;
+mod subscriber;
+
+pub(crate) use codec::Connection;
+
+use std::convert::TryInto;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use openlimits_exchange::message::subscription::{Publication, Subscription};
+use crate::model::websocket::{CandlestickMessage, Depth, Ticker, TradeMessage};
+use crate::{to_candle, to_stream_ticker, BinanceContentError, BinanceWebsocket};
+use crate::shared::Result;
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum BinanceMessage {
+    Response(BinanceResponse),
+    StreamUpdate(StreamUpdate),
+}
+
+/// The answer to a request, `error` being set on failure.
+#[derive(Debug, Deserialize)]
+pub struct BinanceResponse {
+    pub id: u64,
+    #[serde(default)]
+    pub result: Option<Value>,
+    #[serde(default)]
+    pub error: Option<BinanceContentError>,
+}
+
+/// An update of a subscribed stream. The shape of the data depends on the stream kind.
+#[derive(Debug, Deserialize)]
+pub struct StreamUpdate {
+    pub stream: String,
+    pub data: Value,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BinanceRequest {
+    pub method: String,
+    pub params: Vec<String>,
+    pub id: u64,
+}
+
+impl BinanceWebsocket {
+    /// The stream Binance publishes the subscription on, e.g. "ethbtc@kline_1m".
+    pub fn stream_name(subscription: &Subscription) -> String {
+        let (market_pair, kind) = match subscription {
+            Subscription::Kline(market_pair, interval) => {
+                let interval: &str = (*interval).into();
+                (market_pair, format!("kline_{}", interval))
+            }
+            Subscription::Depth(market_pair) => (market_pair, String::from("depth")),
+            Subscription::Trades(market_pair) => (market_pair, String::from("trade")),
+            Subscription::Ticker(market_pair) => (market_pair, String::from("ticker")),
+        };
+        let symbol = crate::model::MarketPair::from(market_pair.clone()).0;
+        format!("{}@{}", symbol.to_lowercase(), kind)
+    }
+
+    pub(crate) fn publication(subscription: &Subscription, data: Value) -> Result<Publication> {
+        Ok(match subscription {
+            Subscription::Kline(_, _) => {
+                let candlestick: CandlestickMessage = serde_json::from_value(data)?;
+                Publication::Kline(to_candle(candlestick.kline)?)
+            }
+            Subscription::Depth(_) => {
+                let depth: Depth = serde_json::from_value(data)?;
+                Publication::Depth(depth.into())
+            }
+            Subscription::Trades(market_pair) => {
+                let mut trade: TradeMessage = serde_json::from_value(data)?;
+                trade.market_pair = Some(market_pair.clone());
+                Publication::Trades(vec![trade.try_into()?])
+            }
+            Subscription::Ticker(market_pair) => {
+                let ticker: Ticker = serde_json::from_value(data)?;
+                Publication::Ticker(to_stream_ticker(ticker, market_pair.clone()))
+            }
+        })
+    }
+}
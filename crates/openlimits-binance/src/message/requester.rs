@@ -0,0 +1,28 @@
+use openlimits_messaging::prelude::*;
+use openlimits_exchange::OpenLimitsError;
+use openlimits_exchange::message::request::{Request, Response};
+use openlimits_exchange::message::subscription::SubscriptionResponse;
+use openlimits_exchange::websocket::Message;
+use crate::BinanceWebsocket;
+use super::BinanceRequest;
+
+#[async_trait]
+impl Requester for BinanceWebsocket {
+    type Request = Request;
+    type Response = Response;
+    type Error = OpenLimitsError;
+    async fn request(&mut self, request: &Self::Request) -> Result<Self::Response, Self::Error> {
+        let (method, params) = match request {
+            Request::Subscription(subscription) => ("SUBSCRIBE", vec![BinanceWebsocket::stream_name(subscription)]),
+        };
+        let request_timeout = self.request_timeout;
+        let request = |id| -> openlimits_exchange::Result<Message> {
+            Ok(Message::Text(serde_json::to_string(&BinanceRequest { method: method.to_string(), params, id })?))
+        };
+        let response = self.connection().await?.request(request, request_timeout).await?;
+        match response.error {
+            Some(error) => Err(OpenLimitsError::Generic(Box::new(error))),
+            None => Ok(Response::Subscription(SubscriptionResponse {})),
+        }
+    }
+}
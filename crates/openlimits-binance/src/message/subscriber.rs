@@ -0,0 +1,18 @@
+use openlimits_messaging::prelude::*;
+use openlimits_exchange::OpenLimitsError;
+use openlimits_exchange::message::request::Request;
+use crate::BinanceWebsocket;
+
+#[async_trait]
+impl Subscriber for BinanceWebsocket {
+    type SubscriptionRequest = openlimits_exchange::message::subscription::Subscription;
+    type Publication = openlimits_exchange::Result<openlimits_exchange::message::subscription::Publication>;
+    type Error = OpenLimitsError;
+    async fn subscribe(&mut self, subscription: &Self::SubscriptionRequest) -> Result<Subscription<Self::Publication>, Self::Error> {
+        let stream = BinanceWebsocket::stream_name(subscription);
+        // A failed subscription drops its stream, which unsubscribes unless the channel has others.
+        let publications = self.connection().await?.register_subscription(stream, subscription.clone()).await;
+        self.request(&Request::Subscription(subscription.clone())).await?;
+        Ok(Box::pin(publications))
+    }
+}
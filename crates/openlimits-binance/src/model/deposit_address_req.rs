@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This struct represents a deposit address request
+#[derive(Clone, Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositAddressReq {
+    pub coin: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
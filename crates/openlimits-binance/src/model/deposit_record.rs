@@ -0,0 +1,27 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::DepositStatus;
+use super::shared::string_to_decimal;
+
+/// This struct represents a deposit history record
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositRecord {
+    #[serde(default)]
+    pub id: Option<String>,
+    #[serde(with = "string_to_decimal")]
+    pub amount: Decimal,
+    pub coin: String,
+    pub network: String,
+    pub status: DepositStatus,
+    pub address: String,
+    #[serde(default)]
+    pub address_tag: String,
+    pub tx_id: String,
+    pub insert_time: u64,
+    #[serde(default)]
+    pub transfer_type: u8,
+    #[serde(default)]
+    pub confirm_times: String,
+}
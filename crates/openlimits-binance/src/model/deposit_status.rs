@@ -0,0 +1,44 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This enum represents a deposit status. The statuses Binance may add later are kept as
+/// `Unknown` rather than failing the whole history.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(from = "u8", into = "u8")]
+pub enum DepositStatus {
+    Pending,
+    Success,
+    Rejected,
+    CreditedCannotWithdraw,
+    WrongDeposit,
+    WaitingUserConfirm,
+    Unknown(u8),
+}
+
+impl From<u8> for DepositStatus {
+    fn from(status: u8) -> Self {
+        match status {
+            0 => Self::Pending,
+            1 => Self::Success,
+            2 => Self::Rejected,
+            6 => Self::CreditedCannotWithdraw,
+            7 => Self::WrongDeposit,
+            8 => Self::WaitingUserConfirm,
+            status => Self::Unknown(status),
+        }
+    }
+}
+
+impl From<DepositStatus> for u8 {
+    fn from(status: DepositStatus) -> Self {
+        match status {
+            DepositStatus::Pending => 0,
+            DepositStatus::Success => 1,
+            DepositStatus::Rejected => 2,
+            DepositStatus::CreditedCannotWithdraw => 6,
+            DepositStatus::WrongDeposit => 7,
+            DepositStatus::WaitingUserConfirm => 8,
+            DepositStatus::Unknown(status) => status,
+        }
+    }
+}
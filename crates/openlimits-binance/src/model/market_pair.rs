@@ -1,11 +1,15 @@
 use openlimits_exchange::model::market_pair::MarketPair as OMarketPair;
+use openlimits_exchange::model::SymbolFormat;
 use serde::{Serialize, Deserialize};
 
+/// Binance concatenates the uppercase base and quote, e.g. "ETHBTC".
+pub const SYMBOL_FORMAT: SymbolFormat = SymbolFormat::new(None, false);
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct MarketPair(pub String);
 
 impl From<OMarketPair> for MarketPair {
     fn from(from: OMarketPair) -> MarketPair {
-        MarketPair(format!("{}{}", from.0, from.1).to_uppercase())
+        MarketPair(SYMBOL_FORMAT.format(&from))
     }
-}
\ No newline at end of file
+}
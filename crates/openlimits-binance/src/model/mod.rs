@@ -9,10 +9,17 @@ pub const TIME_IN_FORCE_GTC: &str = "GTC";
 
 mod market_pair;
 mod account_information;
+mod agg_trade;
+mod agg_trades_params;
 mod all_order_req;
 mod ask_bid;
 mod balance;
 mod book_tickers;
+mod capital_history_req;
+mod deposit_address;
+mod deposit_address_req;
+mod deposit_record;
+mod deposit_status;
 mod exchange_filter;
 mod exchange_information;
 mod interval;
@@ -45,14 +52,25 @@ mod trade_history;
 mod trade_history_req;
 mod transaction;
 mod user_data_stream;
+mod withdraw_record;
+mod withdraw_req;
+mod withdraw_response;
+mod withdraw_status;
 pub mod websocket;
 
-pub use market_pair::MarketPair;
+pub use market_pair::{MarketPair, SYMBOL_FORMAT};
 pub use account_information::AccountInformation;
+pub use agg_trade::AggTrade;
+pub use agg_trades_params::AggTradesParams;
 pub use all_order_req::AllOrderReq;
 pub use ask_bid::AskBid;
 pub use balance::Balance;
 pub use book_tickers::BookTickers;
+pub use capital_history_req::CapitalHistoryReq;
+pub use deposit_address::DepositAddress;
+pub use deposit_address_req::DepositAddressReq;
+pub use deposit_record::DepositRecord;
+pub use deposit_status::DepositStatus;
 pub use exchange_filter::ExchangeFilter;
 pub use exchange_information::ExchangeInformation;
 pub use interval::Interval;
@@ -85,4 +103,8 @@ pub use trade_history::TradeHistory;
 pub use trade_history_req::TradeHistoryReq;
 pub use transaction::Transaction;
 pub use user_data_stream::UserDataStream;
+pub use withdraw_record::WithdrawRecord;
+pub use withdraw_req::WithdrawReq;
+pub use withdraw_response::WithdrawResponse;
+pub use withdraw_status::WithdrawStatus;
 pub use super::shared;
\ No newline at end of file
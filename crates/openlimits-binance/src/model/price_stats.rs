@@ -19,10 +19,16 @@ pub struct PriceStats {
     #[serde(with = "string_to_decimal")]
     pub last_price: Decimal,
     #[serde(with = "string_to_decimal")]
+    pub last_qty: Decimal,
+    #[serde(with = "string_to_decimal")]
     pub bid_price: Decimal,
     #[serde(with = "string_to_decimal")]
+    pub bid_qty: Decimal,
+    #[serde(with = "string_to_decimal")]
     pub ask_price: Decimal,
     #[serde(with = "string_to_decimal")]
+    pub ask_qty: Decimal,
+    #[serde(with = "string_to_decimal")]
     pub open_price: Decimal,
     #[serde(with = "string_to_decimal")]
     pub high_price: Decimal,
@@ -30,6 +36,8 @@ pub struct PriceStats {
     pub low_price: Decimal,
     #[serde(with = "string_to_decimal")]
     pub volume: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub quote_volume: Decimal,
     pub open_time: u64,
     pub close_time: u64,
     pub first_id: i64, // For dummy symbol "123456", it is -1
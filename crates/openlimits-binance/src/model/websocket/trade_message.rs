@@ -1,6 +1,7 @@
 use serde::Deserialize;
 use serde::Serialize;
 use rust_decimal::prelude::Decimal;
+use openlimits_exchange::model::market_pair::MarketPair;
 use super::shared::string_to_decimal;
 
 /// This struct represents a trade message
@@ -29,4 +30,7 @@ pub struct TradeMessage {
     pub is_buyer_maker: bool,
     #[serde(skip_serializing, rename = "M")]
     pub m_ignore: bool,
+    /// Market pair of the symbol, resolved by the websocket from the exchange information.
+    #[serde(skip)]
+    pub market_pair: Option<MarketPair>,
 }
\ No newline at end of file
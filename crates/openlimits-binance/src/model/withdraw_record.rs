@@ -0,0 +1,31 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::WithdrawStatus;
+use super::shared::string_to_decimal;
+
+/// This struct represents a withdraw history record
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawRecord {
+    pub id: String,
+    #[serde(default)]
+    pub withdraw_order_id: Option<String>,
+    #[serde(with = "string_to_decimal")]
+    pub amount: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub transaction_fee: Decimal,
+    pub coin: String,
+    pub status: WithdrawStatus,
+    pub address: String,
+    #[serde(default)]
+    pub address_tag: Option<String>,
+    #[serde(default)]
+    pub tx_id: Option<String>,
+    pub apply_time: String,
+    pub network: String,
+    #[serde(default)]
+    pub transfer_type: u8,
+    #[serde(default)]
+    pub info: Option<String>,
+}
@@ -0,0 +1,20 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_decimal;
+
+/// This struct represents a withdraw request
+#[derive(Clone, Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawReq {
+    pub coin: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub withdraw_order_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+    pub address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_tag: Option<String>,
+    #[serde(with = "string_to_decimal")]
+    pub amount: Decimal,
+}
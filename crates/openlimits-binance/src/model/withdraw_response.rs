@@ -0,0 +1,8 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This struct represents a withdraw response
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct WithdrawResponse {
+    pub id: String,
+}
@@ -0,0 +1,47 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This enum represents a withdraw status. The statuses Binance may add later are kept as
+/// `Unknown` rather than failing the whole history.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(from = "u8", into = "u8")]
+pub enum WithdrawStatus {
+    EmailSent,
+    Cancelled,
+    AwaitingApproval,
+    Rejected,
+    Processing,
+    Failure,
+    Completed,
+    Unknown(u8),
+}
+
+impl From<u8> for WithdrawStatus {
+    fn from(status: u8) -> Self {
+        match status {
+            0 => Self::EmailSent,
+            1 => Self::Cancelled,
+            2 => Self::AwaitingApproval,
+            3 => Self::Rejected,
+            4 => Self::Processing,
+            5 => Self::Failure,
+            6 => Self::Completed,
+            status => Self::Unknown(status),
+        }
+    }
+}
+
+impl From<WithdrawStatus> for u8 {
+    fn from(status: WithdrawStatus) -> Self {
+        match status {
+            WithdrawStatus::EmailSent => 0,
+            WithdrawStatus::Cancelled => 1,
+            WithdrawStatus::AwaitingApproval => 2,
+            WithdrawStatus::Rejected => 3,
+            WithdrawStatus::Processing => 4,
+            WithdrawStatus::Failure => 5,
+            WithdrawStatus::Completed => 6,
+            WithdrawStatus::Unknown(status) => status,
+        }
+    }
+}
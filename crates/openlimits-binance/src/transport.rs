@@ -1,58 +1,124 @@
-use chrono::Utc;
+use std::sync::Arc;
 use hex::encode as hexify;
-use hmac::{Hmac, Mac, NewMac};
-use reqwest::header;
-use reqwest::Response;
-use reqwest::StatusCode;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
-use sha2::Sha256;
 use url::Url;
 use crate::BinanceContentError;
+use crate::model::ServerTime;
+use openlimits_exchange::clock::ClockSync;
+use openlimits_exchange::http::{header, HttpRequest, HttpResponse, HttpTransport, InstrumentedTransport, Method, ReqwestTransport, StatusCode};
+use openlimits_exchange::signer::{HmacSigner, RequestSigner};
 use openlimits_exchange::OpenLimitsError;
 use super::shared::Result;
 
-type HmacSha256 = Hmac<Sha256>;
-
 static RECV_WINDOW: usize = 7000;
 
+// Timestamp for this request is outside of the recvWindow.
+const INVALID_TIMESTAMP_CODE: i16 = -1021;
+
 #[derive(Clone, Debug)]
 pub struct Transport {
-    credential: Option<(String, String)>,
-    client: reqwest::Client,
+    signer: Option<Arc<dyn RequestSigner>>,
+    http: Arc<dyn HttpTransport>,
+    headers: header::HeaderMap,
     pub recv_window: usize,
+    clock: ClockSync,
     base_url: String,
 }
 
 impl Transport {
     pub fn new(sandbox: bool) -> Result<Self> {
-        let default_headers = Transport::default_headers(None);
-        let client = reqwest::Client::builder()
-            .default_headers(default_headers)
-            .build()?;
-
         Ok(Transport {
-            credential: None,
-            client,
+            signer: None,
+            http: Transport::instrumented(Arc::new(ReqwestTransport::default())),
+            headers: Transport::default_headers(None),
             recv_window: RECV_WINDOW,
+            clock: ClockSync::default(),
             base_url: Transport::get_base_url(sandbox),
         })
     }
 
     pub fn with_credential(api_key: &str, api_secret: &str, sandbox: bool) -> Result<Self> {
-        let default_headers = Transport::default_headers(Some(api_key));
-        let client = reqwest::Client::builder()
-            .default_headers(default_headers)
-            .build()?;
+        let signer = Arc::new(HmacSigner::new(api_secret));
+        Transport::with_signer(api_key, signer, sandbox)
+    }
 
+    /// Returns a transport whose requests are signed by the given signer instead of a secret.
+    pub fn with_signer(api_key: &str, signer: Arc<dyn RequestSigner>, sandbox: bool) -> Result<Self> {
         Ok(Transport {
-            client,
-            credential: Some((api_key.into(), api_secret.into())),
+            http: Transport::instrumented(Arc::new(ReqwestTransport::default())),
+            headers: Transport::default_headers(Some(api_key)),
+            signer: Some(signer),
             recv_window: RECV_WINDOW,
+            clock: ClockSync::default(),
             base_url: Transport::get_base_url(sandbox),
         })
     }
 
+    /// Returns a transport that sends its requests to another base URL.
+    pub fn with_base_url(self, base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+            ..self
+        }
+    }
+
+    /// Returns a transport that sends its requests through the given HTTP transport.
+    pub fn with_http_transport(self, http: Arc<dyn HttpTransport>) -> Self {
+        Self {
+            http: Transport::instrumented(http),
+            ..self
+        }
+    }
+
+    fn instrumented(http: Arc<dyn HttpTransport>) -> Arc<dyn HttpTransport> {
+        Arc::new(InstrumentedTransport::new("binance", http).with_weight_header("x-mbx-used-weight-1m"))
+    }
+
+    /// Returns a transport sharing the same client and clock that signs with another recvWindow.
+    pub fn with_recv_window(&self, recv_window: usize) -> Self {
+        Self {
+            recv_window,
+            ..self.clone()
+        }
+    }
+
+    pub fn clock(&self) -> &ClockSync {
+        &self.clock
+    }
+
+    /// Whether the transport can send signed requests.
+    pub fn is_signed(&self) -> bool {
+        self.signer.is_some()
+    }
+
+    /// Measures the offset against the server time when the last measurement is stale. A failed
+    /// measurement keeps the previous offset rather than failing the signed request.
+    pub async fn sync_clock(&self) {
+        if self.clock.needs_sync() {
+            self.clock.sync(self.server_time()).await;
+        }
+    }
+
+    /// Measures the offset every sync interval in the background, for as long as the transport
+    /// or one of its clones is alive.
+    pub fn spawn_clock_sync(&self) {
+        // The task's transport has a clock of its own, so it doesn't keep this one alive.
+        let transport = Self {
+            clock: ClockSync::default(),
+            ..self.clone()
+        };
+        self.clock.spawn_refresh(move || {
+            let transport = transport.clone();
+            async move { transport.server_time().await }
+        });
+    }
+
+    async fn server_time(&self) -> Result<i64> {
+        let time: ServerTime = self.get::<_, ()>("/api/v3/time", None).await?;
+        Ok(time.server_time as i64)
+    }
+
     fn get_base_url(sandbox: bool) -> String {
         if sandbox {
             String::from("https://testnet.binance.vision")
@@ -88,9 +154,7 @@ impl Transport {
         S: Serialize,
     {
         let url = self.get_url(endpoint, params, false)?;
-        let request = self.client.get(url).send().await?;
-
-        Ok(self.response_handler(request).await?)
+        self.send::<_, ()>(Method::GET, url, None).await
     }
 
     pub async fn post<O, D>(&self, endpoint: &str, data: Option<&D>) -> Result<O>
@@ -99,9 +163,7 @@ impl Transport {
         D: Serialize,
     {
         let url = self.get_url::<()>(endpoint, None, false)?;
-        let request = self.client.post(url).form(&data).send().await?;
-
-        Ok(self.response_handler(request).await?)
+        self.send(Method::POST, url, data).await
     }
 
     pub async fn put<O, D>(&self, endpoint: &str, data: Option<D>) -> Result<O>
@@ -110,9 +172,7 @@ impl Transport {
         D: Serialize,
     {
         let url = self.get_url::<()>(endpoint, None, false)?;
-        let request = self.client.put(url).form(&data).send().await?;
-
-        Ok(self.response_handler(request).await?)
+        self.send(Method::PUT, url, data.as_ref()).await
     }
 
     pub async fn delete<O, Q>(&self, endpoint: &str, data: Option<&Q>) -> Result<O>
@@ -121,9 +181,7 @@ impl Transport {
         Q: Serialize,
     {
         let url = self.get_url::<()>(endpoint, None, false)?;
-        let request = self.client.delete(url).form(&data).send().await?;
-
-        Ok(self.response_handler(request).await?)
+        self.send(Method::DELETE, url, data).await
     }
 
     pub async fn signed_get<O, S>(&self, endpoint: &str, params: Option<&S>) -> Result<O>
@@ -131,14 +189,13 @@ impl Transport {
         O: DeserializeOwned,
         S: Serialize,
     {
+        self.sync_clock().await;
         let mut url = self.get_url(endpoint, params, true)?;
 
-        let (_, signature) = self.signature::<()>(&url, None)?;
+        let signature = self.signature::<()>(&url, None).await?;
         url.query_pairs_mut().append_pair("signature", &signature);
 
-        let request = self.client.get(url).send().await?;
-
-        Ok(self.response_handler(request).await?)
+        self.send::<_, ()>(Method::GET, url, None).await
     }
 
     pub async fn signed_post<D, O>(&self, endpoint: &str, data: Option<&D>) -> Result<O>
@@ -146,13 +203,13 @@ impl Transport {
         O: DeserializeOwned,
         D: Serialize,
     {
+        self.sync_clock().await;
         let mut url = self.get_url::<()>(endpoint, None, true)?;
 
-        let (_, signature) = self.signature(&url, data)?;
+        let signature = self.signature(&url, data).await?;
         url.query_pairs_mut().append_pair("signature", &signature);
 
-        let request = self.client.post(url).form(&data).send().await?;
-        Ok(self.response_handler(request).await?)
+        self.send(Method::POST, url, data).await
     }
 
     pub async fn signed_put<O, Q>(&self, endpoint: &str, data: Option<&Q>) -> Result<O>
@@ -160,14 +217,13 @@ impl Transport {
         O: DeserializeOwned,
         Q: Serialize,
     {
+        self.sync_clock().await;
         let mut url = self.get_url::<()>(endpoint, None, true)?;
 
-        let (_, signature) = self.signature(&url, data)?;
+        let signature = self.signature(&url, data).await?;
         url.query_pairs_mut().append_pair("signature", &signature);
 
-        let request = self.client.put(url).form(&data).send().await?;
-
-        Ok(self.response_handler(request).await?)
+        self.send(Method::PUT, url, data).await
     }
 
     pub async fn signed_delete<O, Q>(&self, endpoint: &str, data: Option<&Q>) -> Result<O>
@@ -175,14 +231,28 @@ impl Transport {
         O: DeserializeOwned,
         Q: Serialize,
     {
+        self.sync_clock().await;
         let mut url = self.get_url::<()>(endpoint, None, true)?;
 
-        let (_, signature) = self.signature(&url, data)?;
+        let signature = self.signature(&url, data).await?;
         url.query_pairs_mut().append_pair("signature", &signature);
 
-        let request = self.client.delete(url).form(&data).send().await?;
+        self.send(Method::DELETE, url, data).await
+    }
 
-        Ok(self.response_handler(request).await?)
+    /// Sends the request with the default headers, the data being form encoded in the body.
+    async fn send<O, D>(&self, method: Method, url: Url, data: Option<&D>) -> Result<O>
+    where
+        O: DeserializeOwned,
+        D: Serialize,
+    {
+        let mut request = HttpRequest::new(method, url);
+        request.headers = self.headers.clone();
+        if let Some(data) = data {
+            request.body = Some(serde_urlencoded::to_string(data)?.into_bytes());
+        }
+        let response = self.http.send(request).await?;
+        self.response_handler(response)
     }
 
     pub fn get_url<Q>(
@@ -205,27 +275,25 @@ impl Transport {
 
         if add_recv_window {
             url.query_pairs_mut()
-                .append_pair("timestamp", &Utc::now().timestamp_millis().to_string());
+                .append_pair("timestamp", &self.clock.now_millis().to_string());
             url.query_pairs_mut()
                 .append_pair("recvWindow", &self.recv_window.to_string());
         };
         Ok(url)
     }
 
-    fn check_key(&self) -> Result<(&str, &str)> {
-        match self.credential.as_ref() {
+    fn check_key(&self) -> Result<&dyn RequestSigner> {
+        match self.signer.as_ref() {
             None => Err(OpenLimitsError::NoApiKeySet()),
-            Some((k, s)) => Ok((k, s)),
+            Some(signer) => Ok(signer.as_ref()),
         }
     }
 
-    pub fn signature<D>(&self, url: &Url, body: Option<&D>) -> Result<(&str, String)>
+    pub async fn signature<D>(&self, url: &Url, body: Option<&D>) -> Result<String>
     where
         D: Serialize,
     {
-        let (key, secret) = self.check_key()?;
-        let mut mac =
-            HmacSha256::new_varkey(secret.as_bytes()).expect("Couldn't construct hmac from bytes.");
+        let signer = self.check_key()?;
         let body = if body.is_some() {
             serde_urlencoded::to_string(body)?
         } else {
@@ -237,22 +305,24 @@ impl Transport {
             None => body,
         };
 
-        mac.update(sign_message.as_bytes());
-        let signature = hexify(mac.finalize().into_bytes());
-        Ok((key, signature))
+        let signature = signer.sign(sign_message.as_bytes()).await?;
+        Ok(hexify(signature))
     }
 
-    async fn response_handler<O>(&self, response: Response) -> Result<O>
+    fn response_handler<O>(&self, response: HttpResponse) -> Result<O>
     where
         O: DeserializeOwned,
     {
-        match response.status() {
-            StatusCode::OK => Ok(response.json::<O>().await?),
+        match response.status {
+            StatusCode::OK => response.json::<O>(),
             StatusCode::INTERNAL_SERVER_ERROR => Err(OpenLimitsError::InternalServerError()),
             StatusCode::SERVICE_UNAVAILABLE => Err(OpenLimitsError::ServiceUnavailable()),
             StatusCode::UNAUTHORIZED => Err(OpenLimitsError::Unauthorized()),
             StatusCode::BAD_REQUEST => {
-                let error: BinanceContentError = response.json().await?;
+                let error: BinanceContentError = response.json()?;
+                if error.code == INVALID_TIMESTAMP_CODE {
+                    self.clock.invalidate();
+                }
 
                 Err(OpenLimitsError::Generic(Box::new(error)))
             }
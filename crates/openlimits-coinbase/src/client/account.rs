@@ -1,6 +1,7 @@
 use rust_decimal::prelude::*;
 use crate::model::{
-    Account, CancelAllOrders, CancelOrder, Fill, GetFillsReq, GetOrderRequest, Order,
+    Account, CancelAllOrders, CancelOrder, Fill, GetFillsReq, GetLedgerReq, GetOrderRequest,
+    LedgerEntry, Order,
     OrderRequest, OrderRequestMarketType, OrderRequestType, OrderSide, OrderTimeInForce,
     Paginator,
 };
@@ -14,6 +15,11 @@ impl BaseClient {
         self.transport.signed_get("/accounts", paginator).await
     }
 
+    pub async fn get_ledger(&self, account_id: &str, params: &GetLedgerReq) -> Result<Vec<LedgerEntry>> {
+        let path = format!("/accounts/{}/ledger", account_id);
+        self.transport.signed_get(&path, Some(params)).await
+    }
+
     pub async fn get_orders(&self, params: Option<&GetOrderRequest>) -> Result<Vec<Order>> {
         self.transport.signed_get::<_, _>("/orders", params).await
     }
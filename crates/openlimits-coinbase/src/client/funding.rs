@@ -0,0 +1,39 @@
+use crate::model::{
+    CoinbaseAccount, CryptoAddress, CryptoAddressRequest, GetTransfersReq, Transfer,
+    WithdrawCryptoRequest, WithdrawCryptoResponse,
+};
+use super::BaseClient;
+use super::shared::Result;
+
+impl BaseClient {
+    pub async fn get_coinbase_accounts(&self) -> Result<Vec<CoinbaseAccount>> {
+        self.transport
+            .signed_get::<_, ()>("/coinbase-accounts", None)
+            .await
+    }
+
+    pub async fn generate_crypto_address(
+        &self,
+        coinbase_account_id: &str,
+        params: &CryptoAddressRequest,
+    ) -> Result<CryptoAddress> {
+        let path = format!("/coinbase-accounts/{}/addresses", coinbase_account_id);
+        self.transport
+            .signed_post::<_, (), _>(&path, None, Some(params))
+            .await
+    }
+
+    pub async fn get_deposits(&self, params: &GetTransfersReq) -> Result<Vec<Transfer>> {
+        self.transport.signed_get("/deposits", Some(params)).await
+    }
+
+    pub async fn get_withdrawals(&self, params: &GetTransfersReq) -> Result<Vec<Transfer>> {
+        self.transport.signed_get("/withdrawals", Some(params)).await
+    }
+
+    pub async fn withdraw_crypto(&self, params: &WithdrawCryptoRequest) -> Result<WithdrawCryptoResponse> {
+        self.transport
+            .signed_post::<_, (), _>("/withdrawals/crypto", None, Some(params))
+            .await
+    }
+}
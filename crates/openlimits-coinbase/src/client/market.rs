@@ -1,13 +1,18 @@
 use serde::Deserialize;
 use std::fmt::Debug;
 use crate::model::{
-    Book, BookLevel, Candle, CandleRequestParams, Paginator, Product, Ticker, Trade,
+    Book, BookLevel, Candle, CandleRequestParams, Paginator, Product, ProductStats, ServerTime,
+    Ticker, Trade,
 };
 use super::shared::Result;
 use super::BaseClient;
 use crate::model::MarketPair;
 
 impl BaseClient {
+    pub async fn get_server_time(&self) -> Result<ServerTime> {
+        self.transport.get::<_, ()>("/time", None).await
+    }
+
     pub async fn products(&self) -> Result<Vec<Product>> {
         self.transport.get::<_, ()>("/products", None).await
     }
@@ -37,6 +42,11 @@ impl BaseClient {
         self.transport.get::<_, ()>(&endpoint, None).await
     }
 
+    pub async fn stats<P: Into<MarketPair>>(&self, pair: P) -> Result<ProductStats> {
+        let endpoint = format!("/products/{}/stats", pair.into().0);
+        self.transport.get::<_, ()>(&endpoint, None).await
+    }
+
     pub async fn candles<P: Into<MarketPair>>(
         &self,
         pair: P,
@@ -1,6 +1,7 @@
 //! This module is used to make calls to api and connect to the websockets
 
 mod account;
+mod funding;
 mod market;
 mod base_client;
 pub mod stream;
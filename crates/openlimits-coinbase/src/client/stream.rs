@@ -1,27 +1,27 @@
 use async_trait::async_trait;
 use std::{collections::HashMap, pin::Pin, task::Poll};
-use futures::{
-    stream::{SplitStream, Stream},
-    SinkExt, StreamExt,
-};
+use futures::{stream::Stream, SinkExt, StreamExt};
 use serde::{Deserialize, Serialize};
-use tokio::net::TcpStream;
-use tokio_tungstenite::tungstenite::Message;
-use tokio_tungstenite::{connect_async, MaybeTlsStream, WebSocketStream};
 use crate::model::websocket::{Channel, CoinbaseSubscription, CoinbaseWebsocketMessage, Subscribe, SubscribeCmd};
 use openlimits_exchange::errors::OpenLimitsError;
+use openlimits_exchange::websocket::{self, Message, WebSocketStream};
 use crate::model::websocket::ChannelType;
 use crate::CoinbaseParameters;
+use crate::message::Connection;
 use openlimits_exchange::traits::stream::{ExchangeStream, Subscriptions};
 use futures::stream::BoxStream;
 use std::sync::Mutex;
-use tokio::sync::mpsc::{unbounded_channel, UnboundedSender};
+use std::time::Duration;
+use cross_async::channel::{unbounded, UnboundedSender};
 use super::shared::Result;
 use openlimits_exchange::exchange::Environment;
 
 const WS_URL_PROD: &str = "wss://ws-feed.exchange.coinbase.com";
 const WS_URL_SANDBOX: &str = "wss://ws-feed-public.sandbox.exchange.coinbase.com";
 
+/// How long a request waits for its response by default.
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(untagged)]
 enum Either<L, R> {
@@ -29,16 +29,41 @@ enum Either<L, R> {
     Right(R),
 }
 
-type WSStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
-
-/// A websocket connection to Coinbase
+/// A websocket connection to Coinbase. Besides `ExchangeStream`, it implements the messaging
+/// `Requester` and `Subscriber` traits, whose subscriptions all share one connection opened on the
+/// first request.
 pub struct CoinbaseWebsocket {
-    pub subscriptions: HashMap<CoinbaseSubscription, SplitStream<WSStream>>,
+    pub subscriptions: HashMap<CoinbaseSubscription, WebSocketStream>,
     pub parameters: CoinbaseParameters,
     disconnection_senders: Mutex<Vec<UnboundedSender<()>>>,
+    pub(crate) connection: Option<Connection>,
+    pub(crate) request_timeout: Duration,
 }
 
 impl CoinbaseWebsocket {
+    /// Sets how long requests, subscriptions included, wait for their response before failing
+    /// with `RequestTimeout`.
+    pub fn with_request_timeout(mut self, request_timeout: Duration) -> Self {
+        self.request_timeout = request_timeout;
+        self
+    }
+
+    /// The messaging connection, opened again if it was closed.
+    pub(crate) async fn connection(&mut self) -> Result<&Connection> {
+        if !self.connection.as_ref().is_some_and(Connection::is_connected) {
+            self.connection = Some(Connection::new(self.ws_url()).await?);
+        }
+        self.connection.as_ref().ok_or(OpenLimitsError::Disconnected())
+    }
+
+    fn ws_url(&self) -> &str {
+        match (&self.parameters.endpoints.websocket, self.parameters.environment) {
+            (Some(url), _) => url,
+            (None, Environment::Sandbox) => WS_URL_SANDBOX,
+            (None, Environment::Production) => WS_URL_PROD,
+        }
+    }
+
     pub async fn subscribe_(&mut self, subscription: CoinbaseSubscription) -> Result<()> {
         let (channels, product_ids) = match &subscription {
             CoinbaseSubscription::Level2(product_id) => (
@@ -66,15 +91,8 @@ impl CoinbaseWebsocket {
         Ok(())
     }
 
-    pub async fn connect(&self, subscribe: Subscribe) -> Result<SplitStream<WSStream>> {
-        let ws_url = if self.parameters.environment == Environment::Sandbox {
-            WS_URL_SANDBOX
-        } else {
-            WS_URL_PROD
-        };
-        let url = url::Url::parse(ws_url).expect("Couldn't parse url.");
-        let (ws_stream, _) = connect_async(&url).await?;
-        let (mut sink, stream) = ws_stream.split();
+    pub async fn connect(&self, subscribe: Subscribe) -> Result<WebSocketStream> {
+        let (mut sink, stream) = websocket::connect(self.ws_url()).await?;
         let subscribe = serde_json::to_string(&subscribe)?;
 
         sink.send(Message::Text(subscribe)).await?;
@@ -119,29 +137,28 @@ impl ExchangeStream for CoinbaseWebsocket {
             subscriptions: Default::default(),
             parameters,
             disconnection_senders: Default::default(),
+            connection: None,
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
         })
     }
 
     async fn disconnect(&self) {
         if let Ok(mut senders) = self.disconnection_senders.lock() {
             for sender in senders.iter() {
-                sender.send(()).ok();
+                sender.unbounded_send(()).ok();
             }
             senders.clear();
         }
+        if let Some(connection) = &self.connection {
+            connection.close();
+        }
     }
 
     async fn create_stream_specific(
         &self,
         subscription: Subscriptions<Self::Subscription>,
     ) -> Result<BoxStream<'static, Result<Self::Response>>> {
-        let ws_url = if self.parameters.environment == Environment::Sandbox {
-            WS_URL_SANDBOX
-        } else {
-            WS_URL_PROD
-        };
-        let endpoint = url::Url::parse(ws_url).expect("Couldn't parse url.");
-        let (ws_stream, _) = connect_async(endpoint).await?;
+        let (mut sink, stream) = websocket::connect(self.ws_url()).await?;
 
         let (channel_name, product_ids) = match &subscription.as_slice()[0] {
             CoinbaseSubscription::Level2(product_id) => (
@@ -165,11 +182,10 @@ impl ExchangeStream for CoinbaseWebsocket {
             product_ids: product_ids.clone(),
         };
         let subscribe = serde_json::to_string(&subscribe)?;
-        let (mut sink, stream) = ws_stream.split();
-        let (disconnection_sender, mut disconnection_receiver) = unbounded_channel();
+        let (disconnection_sender, mut disconnection_receiver) = unbounded();
         sink.send(Message::Text(subscribe)).await?;
-        tokio::spawn(async move {
-            if disconnection_receiver.recv().await.is_some() {
+        cross_async::spawn(async move {
+            if disconnection_receiver.next().await.is_some() {
                 sink.close().await.ok();
             }
         });
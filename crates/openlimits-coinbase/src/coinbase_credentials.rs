@@ -1,7 +1,35 @@
-/// This struct represents the coinbase credentials
-#[derive(Clone, Debug)]
+use std::convert::TryFrom;
+use std::fmt;
+use openlimits_exchange::credentials::{Profile, Zeroizing, REDACTED};
+use openlimits_exchange::errors::OpenLimitsError;
+
+/// This struct represents the coinbase credentials. The secret and passphrase are overwritten
+/// when the credentials are dropped.
+#[derive(Clone)]
 pub struct CoinbaseCredentials {
     pub api_key: String,
-    pub api_secret: String,
-    pub passphrase: String,
-}
\ No newline at end of file
+    pub api_secret: Zeroizing<String>,
+    pub passphrase: Zeroizing<String>,
+}
+
+impl fmt::Debug for CoinbaseCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CoinbaseCredentials")
+            .field("api_key", &self.api_key)
+            .field("api_secret", &REDACTED)
+            .field("passphrase", &REDACTED)
+            .finish()
+    }
+}
+
+impl TryFrom<&Profile> for CoinbaseCredentials {
+    type Error = OpenLimitsError;
+
+    fn try_from(profile: &Profile) -> Result<Self, Self::Error> {
+        Ok(Self {
+            api_key: profile.require("api_key")?.into(),
+            api_secret: Zeroizing::new(profile.require("api_secret")?.into()),
+            passphrase: Zeroizing::new(profile.require("passphrase")?.into()),
+        })
+    }
+}
@@ -1,11 +1,20 @@
+use std::sync::Arc;
 use super::CoinbaseCredentials;
-use openlimits_exchange::exchange::Environment;
+use openlimits_exchange::exchange::{Endpoints, Environment};
+use openlimits_exchange::http::HttpTransport;
+use openlimits_exchange::signer::RequestSigner;
 
 /// This struct represents the coinbase parameters
 #[derive(Default, Clone, Debug)]
 pub struct CoinbaseParameters {
     pub environment: Environment,
     pub credentials: Option<CoinbaseCredentials>,
+    /// Signs the requests in place of the credentials' secret, which is then left unused.
+    pub signer: Option<Arc<dyn RequestSigner>>,
+    /// Overrides the REST and websocket base URLs of the environment.
+    pub endpoints: Endpoints,
+    /// Sends the REST requests through this transport instead of the default reqwest client.
+    pub http_transport: Option<Arc<dyn HttpTransport>>,
 }
 
 impl CoinbaseParameters {
@@ -1,53 +1,50 @@
 //! This module provides functionality for communicating with the coinbase API.
 //! # Example
 //! ```no_run
-//! use openlimits::exchange::coinbase::Coinbase;
-//! use openlimits::exchange::coinbase::CoinbaseParameters;
-//! use openlimits::prelude::*;
-//! use openlimits::model::market_pair::*;
+//! use openlimits_coinbase::{Coinbase, CoinbaseParameters};
+//! use openlimits_exchange::model::OrderBookRequest;
+//! use openlimits_exchange::traits::{Exchange, ExchangeMarketData};
 //!
-//! #[tokio::main]
-//! async fn main() {
-//!     let coinbase = Coinbase::new(CoinbaseParameters::production())
-//!                         .await
-//!                         .expect("Couldn't create coinbase client");
+//! # async fn example() -> openlimits_exchange::shared::Result<()> {
+//! let coinbase = Coinbase::new(CoinbaseParameters::production()).await?;
 //!
-//!     let market_pair = MarketPair(Currency::BTC, Currency::ETH);
-//!     let order_book = coinbase.order_book(&OrderBookRequest { market_pair })
-//!                         .await
-//!                         .expect("Couldn't get order book");
-
-//!     println!("{:?}", order_book);
-//! }
+//! let market_pair = "BTC-USD".parse()?;
+//! let order_book = coinbase.order_book(&OrderBookRequest { market_pair }).await?;
+//!
+//! println!("{:?}", order_book);
+//! # Ok(())
+//! # }
 //! ```
 
-#[cfg(feature = "bindings")]
-use ligen_macro::inner_ligen;
-
-#[cfg(feature = "bindings")]
-inner_ligen!(ignore);
-
-use std::convert::TryFrom;
+use std::collections::HashMap;
+use std::convert::{TryFrom, TryInto};
+use std::sync::Arc;
 use async_trait::async_trait;
 use chrono::Duration;
+use futures::lock::Mutex;
 use client::BaseClient;
 use transport::Transport;
 use openlimits_exchange::{
-    errors::OpenLimitsError,
+    errors::{MissingImplementationContent, OpenLimitsError},
     model::{
-        AskBid, Balance, CancelAllOrdersRequest, CancelOrderRequest, Candle,
+        AskBid, Balance, Batch, BatchOperation, CancelAllOrdersRequest, CancelOrderRequest, Candle,
+        Capabilities, Deposit,
+        DepositAddress, FundingStatus, GetDepositAddressRequest, GetDepositHistoryRequest,
         GetHistoricRatesRequest, GetHistoricTradesRequest, GetOrderHistoryRequest, GetOrderRequest,
-        GetPriceTickerRequest, Liquidity, OpenLimitOrderRequest, OpenMarketOrderRequest,
-        Order, OrderBookRequest, OrderBookResponse, OrderCanceled, OrderStatus, OrderType,
-        Paginator, Side, Ticker, TimeInForce, Trade, TradeHistoryRequest,
+        GetLedgerRequest, GetPriceTickerRequest, GetWithdrawalHistoryRequest, Interval, LedgerEntry,
+        LedgerEntryType, Liquidity, OpenLimitOrderRequest, Operation,
+        OpenMarketOrderRequest, Order, OrderBookRequest, OrderBookResponse, OrderCanceled,
+        OrderStatus, OrderType, Paginator, Side, Ticker, TimeInForce, Trade, TradeHistoryRequest,
+        WithdrawRequest, Withdrawal,
+        websocket::SubscriptionKind,
     },
 };
 use openlimits_exchange::traits::info::*;
 use openlimits_exchange::traits::*;
 use openlimits_exchange::shared::Result;
-use openlimits_exchange::shared::timestamp_to_naive_datetime;
 
 pub mod client;
+pub mod message;
 pub mod model;
 mod transport;
 mod coinbase_content_error;
@@ -62,10 +59,15 @@ use openlimits_exchange::exchange::Environment;
 pub use crate::client::stream::CoinbaseWebsocket;
 use openlimits_exchange::model::market_pair::MarketPair;
 
+/// Requests per second Coinbase allows on its public endpoints.
+const PUBLIC_REQUESTS_PER_SECOND: u32 = 10;
+
 #[derive(Clone)]
 pub struct Coinbase {
     pub exchange_info: ExchangeInfo,
     pub client: BaseClient,
+    /// The currencies of the profile accounts by id, for the transfers that don't name theirs.
+    account_currencies: Arc<Mutex<HashMap<String, String>>>,
 }
 
 #[async_trait]
@@ -74,24 +76,35 @@ impl Exchange for Coinbase {
     type InnerClient = BaseClient;
 
     async fn new(parameters: Self::InitParams) -> Result<Self> {
-        let coinbase = match parameters.credentials {
-            Some(credentials) => Coinbase {
-                exchange_info: ExchangeInfo::new(),
-                client: BaseClient {
-                    transport: Transport::with_credential(
-                        &credentials.api_key,
-                        &credentials.api_secret,
-                        &credentials.passphrase,
-                        parameters.environment == Environment::Sandbox,
-                    )?,
-                },
-            },
-            None => Coinbase {
-                exchange_info: ExchangeInfo::new(),
-                client: BaseClient {
-                    transport: Transport::new(parameters.environment == Environment::Sandbox)?,
-                },
-            },
+        let sandbox = parameters.environment == Environment::Sandbox;
+        let mut transport = match (parameters.credentials, parameters.signer) {
+            (Some(credentials), Some(signer)) => Transport::with_signer(
+                &credentials.api_key,
+                signer,
+                &credentials.passphrase,
+                sandbox,
+            )?,
+            (Some(credentials), None) => Transport::with_credential(
+                &credentials.api_key,
+                &credentials.api_secret,
+                &credentials.passphrase,
+                sandbox,
+            )?,
+            (None, _) => Transport::new(sandbox)?,
+        };
+        if let Some(base_url) = parameters.endpoints.rest {
+            transport = transport.with_base_url(base_url);
+        }
+        if let Some(http_transport) = parameters.http_transport {
+            transport = transport.with_http_transport(http_transport);
+        }
+        if transport.is_signed() {
+            transport.spawn_clock_sync();
+        }
+        let coinbase = Coinbase {
+            exchange_info: ExchangeInfo::with_venue("coinbase"),
+            client: BaseClient { transport },
+            account_currencies: Default::default(),
         };
 
         coinbase.refresh_market_info().await?;
@@ -101,6 +114,55 @@ impl Exchange for Coinbase {
     fn inner_client(&self) -> Option<&Self::InnerClient> {
         Some(&self.client)
     }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            operations: vec![
+                Operation::OrderBook,
+                Operation::GetPriceTicker,
+                Operation::GetAllTickers,
+                Operation::GetHistoricRates,
+                Operation::GetHistoricTrades,
+                Operation::LimitBuy,
+                Operation::LimitSell,
+                Operation::MarketBuy,
+                Operation::MarketSell,
+                Operation::CancelOrder,
+                Operation::CancelAllOrders,
+                Operation::GetAllOpenOrders,
+                Operation::GetOrderHistory,
+                Operation::GetTradeHistory,
+                Operation::GetAccountBalances,
+                Operation::GetOrder,
+                Operation::GetLedger,
+                Operation::GetDepositAddress,
+                Operation::GetDepositHistory,
+                Operation::Withdraw,
+                Operation::GetWithdrawalHistory,
+            ],
+            order_types: vec![OrderType::Limit, OrderType::Market],
+            time_in_force: vec![
+                TimeInForce::GoodTillCancelled,
+                TimeInForce::ImmediateOrCancelled,
+                TimeInForce::FillOrKill,
+                TimeInForce::GoodTillTime(Duration::minutes(1)),
+                TimeInForce::GoodTillTime(Duration::hours(1)),
+                TimeInForce::GoodTillTime(Duration::days(1)),
+            ],
+            intervals: vec![
+                Interval::OneMinute,
+                Interval::FiveMinutes,
+                Interval::FifteenMinutes,
+                Interval::OneHour,
+                Interval::SixHours,
+                Interval::OneDay,
+            ],
+            subscriptions: vec![SubscriptionKind::OrderBookUpdates, SubscriptionKind::Trades],
+            batch_operations: vec![Batch::new(BatchOperation::CancelAllOrders, None)],
+            // The level 2 book is aggregated to the best 50 bids and asks.
+            max_book_depth: Some(50),
+        }
+    }
 }
 
 #[async_trait]
@@ -143,7 +205,27 @@ impl ExchangeMarketData for Coinbase {
     }
 
     async fn get_price_ticker(&self, req: &GetPriceTickerRequest) -> Result<Ticker> {
-        self.client.ticker(req.market_pair.clone()).await.map(Into::into)
+        let (ticker, stats) = futures::try_join!(
+            self.client.ticker(req.market_pair.clone()),
+            self.client.stats(req.market_pair.clone())
+        )?;
+        Ok(to_ticker(ticker, stats, req.market_pair.clone()))
+    }
+
+    // Coinbase has no endpoint returning the tickers of every product, so each product costs a
+    // ticker and a stats request, paced to stay under the public rate limit. A product whose
+    // ticker fails fails them all, rather than going missing from them.
+    async fn get_all_tickers(&self) -> Result<Vec<Ticker>> {
+        let pace = std::time::Duration::from_secs(2) / PUBLIC_REQUESTS_PER_SECOND;
+        let mut tickers = Vec::new();
+        for (index, pair) in self.exchange_info.list_pairs().into_iter().enumerate() {
+            if index > 0 {
+                cross_async::sleep(pace).await;
+            }
+            let market_pair = pair.read()?.market_pair()?;
+            tickers.push(self.get_price_ticker(&GetPriceTickerRequest { market_pair }).await?);
+        }
+        Ok(tickers)
     }
 
     async fn get_historic_rates(&self, req: &GetHistoricRatesRequest) -> Result<Vec<Candle>> {
@@ -154,8 +236,15 @@ impl ExchangeMarketData for Coinbase {
             .map(|v| v.into_iter().map(Into::into).collect())
     }
 
-    async fn get_historic_trades(&self, _req: &GetHistoricTradesRequest) -> Result<Vec<Trade>> {
-        unimplemented!("Only implemented for Nash right now");
+    async fn get_historic_trades(&self, req: &GetHistoricTradesRequest) -> Result<Vec<Trade>> {
+        let market_pair = self.exchange_info.market_pair(&req.market_pair)?;
+        let paginator = req.paginator.as_ref().map(model::Paginator::try_from).transpose()?;
+        self.client
+            .trades(market_pair.clone(), paginator.as_ref())
+            .await?
+            .into_iter()
+            .map(|trade| to_trade(trade, market_pair.clone()))
+            .collect()
     }
 }
 
@@ -179,8 +268,9 @@ impl From<model::BookRecordL2> for AskBid {
     }
 }
 
-impl From<model::Order> for Order {
-    fn from(order: model::Order) -> Self {
+impl TryFrom<model::Order> for Order {
+    type Error = OpenLimitsError;
+    fn try_from(order: model::Order) -> Result<Self> {
         let (price, size, order_type) = match order._type {
             model::OrderType::Limit {
                 price,
@@ -190,11 +280,11 @@ impl From<model::Order> for Order {
             model::OrderType::Market { size, funds: _ } => (None, size, OrderType::Market),
         };
 
-        Self {
+        Ok(Self {
             id: order.id,
-            market_pair: order.product_id,
+            market_pair: model::SYMBOL_FORMAT.parse(&order.product_id)?,
             client_order_id: None,
-            created_at: Some((order.created_at.timestamp_millis()) as u64),
+            created_at: Some(utc_datetime(order.created_at)),
             order_type,
             side: order.side.into(),
             status: order.status.into(),
@@ -202,7 +292,7 @@ impl From<model::Order> for Order {
             price,
             remaining: Some(size - order.filled_size),
             trades: Vec::new(),
-        }
+        })
     }
 }
 
@@ -215,11 +305,11 @@ impl ExchangeAccount for Coinbase {
                 pair,
                 req.size,
                 req.price,
-                model::OrderTimeInForce::from(req.time_in_force),
+                model::OrderTimeInForce::try_from(req.time_in_force)?,
                 req.post_only,
             )
             .await
-            .map(Into::into)
+            .and_then(TryInto::try_into)
     }
 
     async fn limit_sell(&self, req: &OpenLimitOrderRequest) -> Result<Order> {
@@ -229,16 +319,16 @@ impl ExchangeAccount for Coinbase {
                 pair,
                 req.size,
                 req.price,
-                model::OrderTimeInForce::from(req.time_in_force),
+                model::OrderTimeInForce::try_from(req.time_in_force)?,
                 req.post_only,
             )
             .await
-            .map(Into::into)
+            .and_then(TryInto::try_into)
     }
 
     async fn market_buy(&self, req: &OpenMarketOrderRequest) -> Result<Order> {
         let pair = self.get_pair(&req.market_pair).await?.read()?;
-        self.client.market_buy(pair, req.size).await.map(Into::into)
+        self.client.market_buy(pair, req.size).await.and_then(TryInto::try_into)
     }
 
     async fn market_sell(&self, req: &OpenMarketOrderRequest) -> Result<Order> {
@@ -246,12 +336,13 @@ impl ExchangeAccount for Coinbase {
         self.client
             .market_sell(pair, req.size)
             .await
-            .map(Into::into)
+            .and_then(TryInto::try_into)
     }
 
     async fn cancel_order(&self, req: &CancelOrderRequest) -> Result<OrderCanceled> {
+        let product_id = req.market_pair.clone().map(|pair| model::MarketPair::from(pair).0);
         self.client
-            .cancel_order(req.id.clone(), req.market_pair.as_deref())
+            .cancel_order(req.id.clone(), product_id.as_deref())
             .await
             .map(Into::into)
     }
@@ -273,29 +364,29 @@ impl ExchangeAccount for Coinbase {
         self.client
             .get_orders(Some(&params))
             .await
-            .map(|v| v.into_iter().map(Into::into).collect())
+            .and_then(|v| v.into_iter().map(TryInto::try_into).collect())
     }
 
     async fn get_order_history(&self, req: &GetOrderHistoryRequest) -> Result<Vec<Order>> {
-        let req: model::GetOrderRequest = req.into();
+        let req = model::GetOrderRequest::try_from(req)?;
 
         self.client
             .get_orders(Some(&req))
             .await
-            .map(|v| v.into_iter().map(Into::into).collect())
+            .and_then(|v| v.into_iter().map(TryInto::try_into).collect())
     }
 
     async fn get_trade_history(&self, req: &TradeHistoryRequest) -> Result<Vec<Trade>> {
-        let req = req.into();
+        let req = model::GetFillsReq::try_from(req)?;
 
         self.client
             .get_fills(Some(&req))
             .await
-            .map(|v| v.into_iter().map(Into::into).collect())
+            .and_then(|v| v.into_iter().map(TryInto::try_into).collect())
     }
 
     async fn get_account_balances(&self, paginator: Option<Paginator>) -> Result<Vec<Balance>> {
-        let paginator: Option<model::Paginator> = paginator.map(|p| p.into());
+        let paginator = paginator.as_ref().map(model::Paginator::try_from).transpose()?;
 
         self.client
             .get_account(paginator.as_ref())
@@ -306,7 +397,162 @@ impl ExchangeAccount for Coinbase {
     async fn get_order(&self, req: &GetOrderRequest) -> Result<Order> {
         let id = req.id.clone();
 
-        self.client.get_order(id).await.map(Into::into)
+        self.client.get_order(id).await.and_then(TryInto::try_into)
+    }
+
+    async fn get_ledger(&self, req: &GetLedgerRequest) -> Result<Vec<LedgerEntry>> {
+        let account = self
+            .client
+            .get_account(None)
+            .await?
+            .into_iter()
+            .find(|account| account.currency.eq_ignore_ascii_case(&req.asset))
+            .ok_or(OpenLimitsError::AssetNotFound())?;
+        let params = req
+            .paginator
+            .as_ref()
+            .map(model::GetLedgerReq::from)
+            .unwrap_or_default();
+        let entries = self
+            .client
+            .get_ledger(&account.id, &params)
+            .await?
+            .into_iter()
+            .map(|entry| ledger_entry(entry, &account.currency))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(match &req.market_pair {
+            Some(market_pair) => entries
+                .into_iter()
+                .filter(|entry| entry.market_pair.as_ref().is_none_or(|pair| pair == market_pair))
+                .collect(),
+            None => entries,
+        })
+    }
+}
+
+#[async_trait]
+impl ExchangeFunding for Coinbase {
+    async fn get_deposit_address(&self, req: &GetDepositAddressRequest) -> Result<DepositAddress> {
+        let account = self
+            .client
+            .get_coinbase_accounts()
+            .await?
+            .into_iter()
+            .find(|account| account.currency.eq_ignore_ascii_case(&req.asset))
+            .ok_or(OpenLimitsError::AssetNotFound())?;
+        let params = model::CryptoAddressRequest {
+            network: req.network.clone(),
+        };
+        let address = self.client.generate_crypto_address(&account.id, &params).await?;
+        Ok(DepositAddress {
+            asset: account.currency,
+            address: address.address,
+            tag: address.destination_tag,
+            network: address.network.or_else(|| req.network.clone()),
+        })
+    }
+
+    async fn get_deposit_history(&self, req: &GetDepositHistoryRequest) -> Result<Vec<Deposit>> {
+        let params = model::GetTransfersReq::from(req.paginator.as_ref());
+        let deposits = self.client.get_deposits(&params).await?;
+        self.filter_transfers(deposits, req.asset.as_deref(), req.paginator.as_ref())
+            .await
+            .map(|v| v.into_iter().map(Into::into).collect())
+    }
+
+    async fn withdraw(&self, req: &WithdrawRequest) -> Result<Withdrawal> {
+        let params = model::WithdrawCryptoRequest {
+            amount: req.amount,
+            currency: req.asset.clone(),
+            crypto_address: req.address.clone(),
+            destination_tag: req.tag.clone(),
+            no_destination_tag: req.tag.is_none(),
+            network: req.network.clone(),
+        };
+        let response = self.client.withdraw_crypto(&params).await?;
+        // Coinbase has no client id for withdrawals, so it can't be looked up by one later.
+        Ok(Withdrawal {
+            id: response.id,
+            client_withdrawal_id: None,
+            asset: response.currency,
+            amount: response.amount,
+            fee: response.fee,
+            network: response.network.or_else(|| req.network.clone()),
+            address: req.address.clone(),
+            tag: req.tag.clone(),
+            tx_id: None,
+            status: FundingStatus::Pending,
+            created_at: None,
+        })
+    }
+
+    async fn get_withdrawal_history(&self, req: &GetWithdrawalHistoryRequest) -> Result<Vec<Withdrawal>> {
+        let params = model::GetTransfersReq::from(req.paginator.as_ref());
+        let withdrawals = self.client.get_withdrawals(&params).await?;
+        self.filter_transfers(withdrawals, req.asset.as_deref(), req.paginator.as_ref())
+            .await
+            .map(|v| v.into_iter().map(Into::into).collect())
+    }
+}
+
+impl Coinbase {
+    /// Keeps the transfers of the asset within the time window of the paginator, resolving the
+    /// currency of each one from the profile accounts when Coinbase doesn't return it. Coinbase
+    /// pages transfers by cursor only, so the window is applied to the returned page.
+    async fn filter_transfers(
+        &self,
+        transfers: Vec<model::Transfer>,
+        asset: Option<&str>,
+        paginator: Option<&Paginator>,
+    ) -> Result<Vec<model::Transfer>> {
+        let start_time = paginator.and_then(|p| p.start_time);
+        let end_time = paginator.and_then(|p| p.end_time);
+        let mut transfers: Vec<model::Transfer> = transfers
+            .into_iter()
+            .filter(|transfer| {
+                if start_time.is_none() && end_time.is_none() {
+                    return true;
+                }
+                match transfer.created_at.as_deref().and_then(parse_transfer_time) {
+                    Some(time) => {
+                        start_time.is_none_or(|start| time >= start) && end_time.is_none_or(|end| time <= end)
+                    }
+                    None => false,
+                }
+            })
+            .collect();
+        for transfer in transfers.iter_mut().filter(|transfer| transfer.currency.is_none()) {
+            transfer.currency = self.account_currency(&transfer.account_id).await?;
+        }
+        Ok(transfers
+            .into_iter()
+            .filter(|transfer| match (asset, transfer.currency.as_ref()) {
+                (Some(asset), Some(currency)) => currency.eq_ignore_ascii_case(asset),
+                (Some(_), None) => false,
+                (None, _) => true,
+            })
+            .collect())
+    }
+
+    /// The currency of a profile account, fetching the accounts the first time one isn't known.
+    async fn account_currency(&self, account_id: &str) -> Result<Option<String>> {
+        let mut currencies = self.account_currencies.lock().await;
+        if !currencies.contains_key(account_id) {
+            for account in self.client.get_account(None).await? {
+                currencies.insert(account.id, account.currency);
+            }
+        }
+        Ok(currencies.get(account_id).cloned())
+    }
+}
+
+impl From<Option<&Paginator>> for model::GetTransfersReq {
+    fn from(paginator: Option<&Paginator>) -> Self {
+        Self {
+            before: paginator.and_then(|p| p.before.clone()),
+            after: paginator.and_then(|p| p.after.clone()),
+            limit: paginator.and_then(|p| p.limit),
+        }
     }
 }
 
@@ -320,18 +566,19 @@ impl From<model::Account> for Balance {
     }
 }
 
-impl From<model::Fill> for Trade {
-    fn from(fill: model::Fill) -> Self {
+impl TryFrom<model::Fill> for Trade {
+    type Error = OpenLimitsError;
+    fn try_from(fill: model::Fill) -> Result<Self> {
         let (buyer_order_id, seller_order_id) = match fill.side.as_str() {
             "buy" => (Some(fill.order_id), None),
             _ => (None, Some(fill.order_id)),
         };
 
-        Self {
+        Ok(Self {
             id: fill.trade_id.to_string(),
             buyer_order_id,
             seller_order_id,
-            market_pair: fill.product_id,
+            market_pair: model::SYMBOL_FORMAT.parse(&fill.product_id)?,
             price: fill.price,
             qty: fill.size,
             fees: Some(fill.fee),
@@ -344,24 +591,95 @@ impl From<model::Fill> for Trade {
                 "T" => Some(Liquidity::Taker),
                 _ => None,
             },
-            created_at: fill.created_at.to_string(),
+            created_at: utc_datetime(fill.created_at),
+        })
+    }
+}
+
+fn to_trade(trade: model::Trade, market_pair: MarketPair) -> Result<Trade> {
+    let qty = trade.size.parse().map_err(|_| {
+        OpenLimitsError::NotParsableResponse(format!("{} is not a valid trade size.", trade.size))
+    })?;
+    // Coinbase reports the side of the maker order, the taker took the other side.
+    let side = match trade.side.as_str() {
+        "buy" => Side::Sell,
+        "sell" => Side::Buy,
+        side => {
+            return Err(OpenLimitsError::NotParsableResponse(format!(
+                "{} is not a valid trade side.",
+                side
+            )))
         }
+    };
+    Ok(Trade {
+        id: trade.trade_id.to_string(),
+        buyer_order_id: None,
+        seller_order_id: None,
+        market_pair,
+        price: trade.price,
+        qty,
+        fees: None,
+        side,
+        liquidity: None,
+        created_at: utc_datetime(trade.time),
+    })
+}
+
+fn to_ticker(ticker: model::Ticker, stats: model::ProductStats, market_pair: MarketPair) -> Ticker {
+    Ticker {
+        market_pair,
+        price: Some(ticker.price),
+        price_24h: Some(stats.open),
+        bid: Some(ticker.bid),
+        bid_qty: None,
+        ask: Some(ticker.ask),
+        ask_qty: None,
+        high_24h: Some(stats.high),
+        low_24h: Some(stats.low),
+        volume_24h: Some(ticker.volume),
+        quote_volume_24h: None,
+        created_at: Some(utc_datetime(ticker.time)),
     }
 }
 
-impl From<model::Ticker> for Ticker {
-    fn from(ticker: model::Ticker) -> Self {
-        Self {
+fn to_stream_ticker(ticker: model::websocket::Ticker, market_pair: MarketPair) -> Ticker {
+    match ticker {
+        model::websocket::Ticker::Full(ticker) => Ticker {
+            market_pair,
             price: Some(ticker.price),
+            price_24h: ticker.open_24h,
+            bid: ticker.best_bid,
+            bid_qty: ticker.best_bid_size,
+            ask: ticker.best_ask,
+            ask_qty: ticker.best_ask_size,
+            high_24h: ticker.high_24h,
+            low_24h: ticker.low_24h,
+            volume_24h: ticker.volume_24h,
+            quote_volume_24h: None,
+            created_at: shared::parse_timestamp(&ticker.time),
+        },
+        // The first ticker of a product without trades only has a sequence and maybe a price.
+        model::websocket::Ticker::Empty { price, .. } => Ticker {
+            market_pair,
+            price,
             price_24h: None,
-        }
+            bid: None,
+            bid_qty: None,
+            ask: None,
+            ask_qty: None,
+            high_24h: None,
+            low_24h: None,
+            volume_24h: None,
+            quote_volume_24h: None,
+            created_at: None,
+        },
     }
 }
 
 impl From<model::Candle> for Candle {
     fn from(candle: model::Candle) -> Self {
         Self {
-            time: candle.time * 1000,
+            time: shared::timestamp_to_utc_datetime(candle.time * 1000),
             low: candle.low,
             high: candle.high,
             open: candle.open,
@@ -382,51 +700,38 @@ impl TryFrom<&GetHistoricRatesRequest> for model::CandleRequestParams {
     }
 }
 
-impl From<&GetOrderHistoryRequest> for model::GetOrderRequest {
-    fn from(req: &GetOrderHistoryRequest) -> Self {
-        Self {
+impl TryFrom<&GetOrderHistoryRequest> for model::GetOrderRequest {
+    type Error = OpenLimitsError;
+    fn try_from(req: &GetOrderHistoryRequest) -> Result<Self> {
+        Ok(Self {
             product_id: req.market_pair.clone().map(|market| crate::model::MarketPair::from(market).0),
-            paginator: req.paginator.clone().map(|p| p.into()),
+            paginator: req.paginator.as_ref().map(model::Paginator::try_from).transpose()?,
             status: None,
-        }
+        })
     }
 }
 
-impl From<Paginator> for model::Paginator {
-    fn from(paginator: Paginator) -> Self {
-        Self {
-            after: paginator
-                .after
-                .map(|s| s.parse::<u64>().expect("Couldn't parse paginator.")),
-            before: paginator
-                .before
-                .map(|s| s.parse::<u64>().expect("Couldn't parse paginator.")),
+impl TryFrom<&Paginator> for model::Paginator {
+    type Error = OpenLimitsError;
+    fn try_from(paginator: &Paginator) -> Result<Self> {
+        Ok(Self {
+            after: paginator.after.as_deref().map(parse_page_id).transpose()?,
+            before: paginator.before.as_deref().map(parse_page_id).transpose()?,
             limit: paginator.limit,
-        }
+        })
     }
 }
 
-impl From<&Paginator> for model::Paginator {
-    fn from(paginator: &Paginator) -> Self {
-        Self {
-            after: paginator
-                .after
-                .as_ref()
-                .map(|s| s.parse().expect("coinbase page id did not parse as u64")),
-            before: paginator
-                .before
-                .as_ref()
-                .map(|s| s.parse().expect("coinbase page id did not parse as u64")),
-            limit: paginator.limit,
-        }
-    }
+fn parse_page_id(id: &str) -> Result<u64> {
+    id.parse::<u64>()
+        .map_err(|_| OpenLimitsError::InvalidParameter(format!("{} is not a valid page id.", id)))
 }
 
 impl From<Paginator> for model::DateRange {
     fn from(paginator: Paginator) -> Self {
         Self {
-            start: paginator.start_time.map(timestamp_to_naive_datetime),
-            end: paginator.end_time.map(timestamp_to_naive_datetime),
+            start: paginator.start_time.map(|time| time.naive_utc()),
+            end: paginator.end_time.map(|time| time.naive_utc()),
         }
     }
 }
@@ -434,50 +739,44 @@ impl From<Paginator> for model::DateRange {
 impl From<&Paginator> for model::DateRange {
     fn from(paginator: &Paginator) -> Self {
         Self {
-            start: paginator.start_time.map(timestamp_to_naive_datetime),
-            end: paginator.end_time.map(timestamp_to_naive_datetime),
+            start: paginator.start_time.map(|time| time.naive_utc()),
+            end: paginator.end_time.map(|time| time.naive_utc()),
         }
     }
 }
 
-impl From<TimeInForce> for model::OrderTimeInForce {
-    fn from(tif: TimeInForce) -> Self {
+impl TryFrom<TimeInForce> for model::OrderTimeInForce {
+    type Error = OpenLimitsError;
+    fn try_from(tif: TimeInForce) -> Result<Self> {
         match tif {
-            TimeInForce::GoodTillCancelled => model::OrderTimeInForce::GTC,
-            TimeInForce::FillOrKill => model::OrderTimeInForce::FOK,
-            TimeInForce::ImmediateOrCancelled => model::OrderTimeInForce::IOC,
+            TimeInForce::GoodTillCancelled => Ok(model::OrderTimeInForce::GTC),
+            TimeInForce::FillOrKill => Ok(model::OrderTimeInForce::FOK),
+            TimeInForce::ImmediateOrCancelled => Ok(model::OrderTimeInForce::IOC),
             TimeInForce::GoodTillTime(duration) => {
-                let day: Duration = Duration::days(1);
-                let hour: Duration = Duration::hours(1);
-                let minute: Duration = Duration::minutes(1);
-
-                if duration == day {
-                    model::OrderTimeInForce::GTT {
-                        cancel_after: model::CancelAfter::Day,
-                    }
-                } else if duration == hour {
-                    model::OrderTimeInForce::GTT {
-                        cancel_after: model::CancelAfter::Hour,
-                    }
-                } else if duration == minute {
-                    model::OrderTimeInForce::GTT {
-                        cancel_after: model::CancelAfter::Min,
-                    }
+                let cancel_after = if duration == Duration::days(1) {
+                    model::CancelAfter::Day
+                } else if duration == Duration::hours(1) {
+                    model::CancelAfter::Hour
+                } else if duration == Duration::minutes(1) {
+                    model::CancelAfter::Min
                 } else {
-                    panic!("Coinbase only supports durations of 1 day, 1 hour or 1 minute")
-                }
+                    let message = "Coinbase only supports durations of 1 day, 1 hour or 1 minute".into();
+                    return Err(OpenLimitsError::MissingImplementation(MissingImplementationContent { message }));
+                };
+                Ok(model::OrderTimeInForce::GTT { cancel_after })
             }
         }
     }
 }
 
-impl From<&TradeHistoryRequest> for model::GetFillsReq {
-    fn from(req: &TradeHistoryRequest) -> Self {
-        Self {
+impl TryFrom<&TradeHistoryRequest> for model::GetFillsReq {
+    type Error = OpenLimitsError;
+    fn try_from(req: &TradeHistoryRequest) -> Result<Self> {
+        Ok(Self {
             order_id: req.order_id.clone(),
-            paginator: req.paginator.clone().map(|p| p.into()),
+            paginator: req.paginator.as_ref().map(model::Paginator::try_from).transpose()?,
             product_id: req.market_pair.clone().map(|market| crate::model::MarketPair::from(market).0),
-        }
+        })
     }
 }
 
@@ -501,3 +800,105 @@ impl From<model::OrderStatus> for OrderStatus {
         }
     }
 }
+
+impl From<&model::Transfer> for FundingStatus {
+    fn from(transfer: &model::Transfer) -> Self {
+        if transfer.canceled_at.is_some() {
+            FundingStatus::Canceled
+        } else if transfer.completed_at.is_some() {
+            FundingStatus::Completed
+        } else if transfer.processed_at.is_some() {
+            FundingStatus::Processing
+        } else {
+            FundingStatus::Pending
+        }
+    }
+}
+
+impl From<model::Transfer> for Deposit {
+    fn from(transfer: model::Transfer) -> Self {
+        Self {
+            status: (&transfer).into(),
+            created_at: transfer.created_at.as_deref().and_then(parse_transfer_time),
+            id: transfer.id,
+            asset: transfer.currency.unwrap_or_default(),
+            amount: transfer.amount,
+            network: transfer.details.network,
+            address: transfer.details.crypto_address,
+            tag: transfer.details.destination_tag,
+            tx_id: transfer.details.crypto_transaction_hash,
+        }
+    }
+}
+
+impl From<model::Transfer> for Withdrawal {
+    fn from(transfer: model::Transfer) -> Self {
+        Self {
+            status: (&transfer).into(),
+            created_at: transfer.created_at.as_deref().and_then(parse_transfer_time),
+            fee: transfer.details.fee.as_deref().and_then(|fee| fee.parse().ok()),
+            id: transfer.id,
+            client_withdrawal_id: None,
+            asset: transfer.currency.unwrap_or_default(),
+            amount: transfer.amount,
+            network: transfer.details.network,
+            address: transfer.details.crypto_address.unwrap_or_default(),
+            tag: transfer.details.destination_tag,
+            tx_id: transfer.details.crypto_transaction_hash,
+        }
+    }
+}
+
+fn ledger_entry(entry: model::LedgerEntry, asset: &str) -> Result<LedgerEntry> {
+    let entry_type = match entry._type.as_str() {
+        "match" => LedgerEntryType::Trade,
+        "fee" => LedgerEntryType::Fee,
+        "rebate" => LedgerEntryType::Rebate,
+        "conversion" => LedgerEntryType::Conversion,
+        "transfer" => match entry.details.transfer_type.as_deref() {
+            Some("deposit") => LedgerEntryType::Deposit,
+            Some("withdraw") => LedgerEntryType::Withdrawal,
+            _ => LedgerEntryType::Transfer,
+        },
+        _ => LedgerEntryType::Other,
+    };
+    let details = entry.details;
+    Ok(LedgerEntry {
+        id: entry.id,
+        asset: asset.to_string(),
+        entry_type,
+        amount: entry.amount,
+        balance: Some(entry.balance),
+        reference_id: details.order_id.or(details.transfer_id),
+        market_pair: match details.product_id {
+            Some(product_id) => Some(model::SYMBOL_FORMAT.parse(&product_id)?),
+            None => None,
+        },
+        created_at: parse_transfer_time(&entry.created_at),
+    })
+}
+
+impl From<&Paginator> for model::GetLedgerReq {
+    fn from(paginator: &Paginator) -> Self {
+        Self {
+            start_date: paginator.start_time.map(|time| time.naive_utc()),
+            end_date: paginator.end_time.map(|time| time.naive_utc()),
+            before: paginator.before.clone(),
+            after: paginator.after.clone(),
+            limit: paginator.limit,
+        }
+    }
+}
+
+/// Transfers timestamps come as "2019-06-18 01:37:48.78953+00" instead of RFC 3339.
+fn parse_transfer_time(time: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+    chrono::DateTime::parse_from_rfc3339(time)
+        .or_else(|_| chrono::DateTime::parse_from_str(time, "%Y-%m-%d %H:%M:%S%.f%#z"))
+        .map(|time| shared::truncate_to_millis(time.with_timezone(&chrono::Utc)))
+        .ok()
+}
+
+/// Coinbase timestamps are UTC with microsecond precision.
+fn utc_datetime(time: chrono::NaiveDateTime) -> chrono::DateTime<chrono::Utc> {
+    shared::truncate_to_millis(time.and_utc())
+}
@@ -0,0 +1,69 @@
+use serde_json::Value;
+use openlimits_exchange::OpenLimitsError;
+use openlimits_exchange::message::subscription::{Publication, Subscription};
+use openlimits_exchange::websocket::{self, Codec, Frame, Message};
+use crate::model::websocket::{Channel, ChannelType, CoinbaseWebsocketMessage, Subscribe, SubscribeCmd};
+use crate::{CoinbaseContentError, CoinbaseWebsocket};
+use crate::shared::Result;
+use super::ProductChannel;
+
+/// The connection to the websocket feed, which every subscription of a `CoinbaseWebsocket`
+/// shares.
+pub(crate) type Connection = websocket::Connection<CoinbaseCodec>;
+
+/// Reads the frames of the websocket feed. Coinbase doesn't echo an id in its answers, so they
+/// answer the pending requests in the order those were sent.
+pub(crate) struct CoinbaseCodec;
+
+impl Codec for CoinbaseCodec {
+    type Channel = ProductChannel;
+    type Update = CoinbaseWebsocketMessage;
+    type Response = ();
+
+    const VENUE: &'static str = "coinbase";
+
+    fn decode(message: Message) -> Frame<ProductChannel, CoinbaseWebsocketMessage, ()> {
+        let text = match message {
+            Message::Text(text) => text,
+            _ => return Frame::Ignored,
+        };
+        match serde_json::from_str(&text) {
+            Ok(CoinbaseWebsocketMessage::Subscriptions { .. }) => Frame::Response(None, Ok(())),
+            Ok(CoinbaseWebsocketMessage::Error { message }) => {
+                Frame::Response(None, Err(OpenLimitsError::Generic(Box::new(CoinbaseContentError { message }))))
+            }
+            Ok(message) => match CoinbaseWebsocket::message_channel(&message) {
+                Some(channel) => Frame::Update(channel, message),
+                None => Frame::Ignored,
+            },
+            // The channel of the frame is told when the frame is readable enough.
+            Err(error) => {
+                let channel = serde_json::from_str::<Value>(&text).ok().and_then(|value| {
+                    let channel = match value.get("type").and_then(Value::as_str)? {
+                        "snapshot" | "l2update" => ChannelType::Level2,
+                        "match" | "last_match" => ChannelType::Matches,
+                        "ticker" => ChannelType::Ticker,
+                        _ => return None,
+                    };
+                    let product_id = value.get("product_id").and_then(Value::as_str)?;
+                    Some((channel, product_id.to_string()))
+                });
+                Frame::Error(channel, error.into())
+            }
+        }
+    }
+
+    fn publication(subscription: &Subscription, message: CoinbaseWebsocketMessage) -> Result<Publication> {
+        CoinbaseWebsocket::publication(subscription, message)
+    }
+
+    fn unsubscribe(_id: u64, (channel, product_id): &ProductChannel) -> Result<Message> {
+        let unsubscribe = Subscribe {
+            _type: SubscribeCmd::Unsubscribe,
+            product_ids: vec![product_id.clone()],
+            channels: vec![Channel::Name(channel.clone())],
+            auth: None,
+        };
+        Ok(Message::Text(serde_json::to_string(&unsubscribe)?))
+    }
+}
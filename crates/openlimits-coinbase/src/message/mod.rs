@@ -0,0 +1,68 @@
+//! This module implements the messaging `Requester` and `Subscriber` traits for
+//! `CoinbaseWebsocket`.
+mod codec;
+mod requester;
+mod subscriber;
+
+pub(crate) use codec::Connection;
+
+use std::convert::TryInto;
+use openlimits_exchange::errors::{MissingImplementationContent, OpenLimitsError};
+use openlimits_exchange::message::subscription::{Publication, Subscription};
+use crate::model::websocket::{ChannelType, CoinbaseWebsocketMessage, Full, Level2, Ticker};
+use crate::{to_stream_ticker, CoinbaseWebsocket};
+use crate::shared::Result;
+
+/// A channel of a product, e.g. (ChannelType::Matches, "ETH-BTC").
+pub type ProductChannel = (ChannelType, String);
+
+impl CoinbaseWebsocket {
+    /// The channel Coinbase publishes the subscription on. Coinbase doesn't publish candles.
+    pub fn channel(subscription: &Subscription) -> Result<ProductChannel> {
+        let (channel, market_pair) = match subscription {
+            Subscription::Kline(_, _) => {
+                let message = "Coinbase doesn't publish klines".into();
+                return Err(OpenLimitsError::MissingImplementation(MissingImplementationContent { message }));
+            }
+            Subscription::Depth(market_pair) => (ChannelType::Level2, market_pair),
+            Subscription::Trades(market_pair) => (ChannelType::Matches, market_pair),
+            Subscription::Ticker(market_pair) => (ChannelType::Ticker, market_pair),
+        };
+        Ok((channel, crate::model::MarketPair::from(market_pair.clone()).0))
+    }
+
+    /// The channel a message is published on, or `None` for the messages that belong to none of
+    /// the subscriptions.
+    pub(crate) fn message_channel(message: &CoinbaseWebsocketMessage) -> Option<ProductChannel> {
+        match message {
+            CoinbaseWebsocketMessage::Level2(Level2::Snapshot { product_id, .. })
+            | CoinbaseWebsocketMessage::Level2(Level2::L2update { product_id, .. }) => {
+                Some((ChannelType::Level2, product_id.clone()))
+            }
+            CoinbaseWebsocketMessage::Match(match_) | CoinbaseWebsocketMessage::Full(Full::Match(match_)) => {
+                Some((ChannelType::Matches, match_.product_id.clone()))
+            }
+            CoinbaseWebsocketMessage::Ticker(Ticker::Full(ticker)) => Some((ChannelType::Ticker, ticker.product_id.clone())),
+            CoinbaseWebsocketMessage::Ticker(Ticker::Empty { product_id, .. }) => {
+                Some((ChannelType::Ticker, product_id.clone()))
+            }
+            _ => None,
+        }
+    }
+
+    pub(crate) fn publication(subscription: &Subscription, message: CoinbaseWebsocketMessage) -> Result<Publication> {
+        Ok(match (subscription, message) {
+            (Subscription::Depth(_), CoinbaseWebsocketMessage::Level2(level2)) => Publication::Depth(level2.into()),
+            (Subscription::Trades(_), CoinbaseWebsocketMessage::Match(match_))
+            | (Subscription::Trades(_), CoinbaseWebsocketMessage::Full(Full::Match(match_))) => {
+                Publication::Trades(vec![match_.try_into()?])
+            }
+            (Subscription::Ticker(market_pair), CoinbaseWebsocketMessage::Ticker(ticker)) => {
+                Publication::Ticker(to_stream_ticker(ticker, market_pair.clone()))
+            }
+            (_, message) => {
+                return Err(OpenLimitsError::UnkownResponse(format!("Unexpected message {:?}", message)));
+            }
+        })
+    }
+}
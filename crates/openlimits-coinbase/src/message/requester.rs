@@ -0,0 +1,29 @@
+use openlimits_messaging::prelude::*;
+use openlimits_exchange::OpenLimitsError;
+use openlimits_exchange::message::request::{Request, Response};
+use openlimits_exchange::message::subscription::SubscriptionResponse;
+use openlimits_exchange::websocket::Message;
+use crate::model::websocket::{Channel, Subscribe, SubscribeCmd};
+use crate::CoinbaseWebsocket;
+
+#[async_trait]
+impl Requester for CoinbaseWebsocket {
+    type Request = Request;
+    type Response = Response;
+    type Error = OpenLimitsError;
+    async fn request(&mut self, request: &Self::Request) -> Result<Self::Response, Self::Error> {
+        let (channel, product_id) = match request {
+            Request::Subscription(subscription) => CoinbaseWebsocket::channel(subscription)?,
+        };
+        let subscribe = Subscribe {
+            _type: SubscribeCmd::Subscribe,
+            product_ids: vec![product_id],
+            channels: vec![Channel::Name(channel)],
+            auth: None,
+        };
+        let request_timeout = self.request_timeout;
+        let request = |_| Ok(Message::Text(serde_json::to_string(&subscribe)?));
+        self.connection().await?.request(request, request_timeout).await?;
+        Ok(Response::Subscription(SubscriptionResponse {}))
+    }
+}
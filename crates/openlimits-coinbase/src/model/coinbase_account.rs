@@ -0,0 +1,12 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This struct represents a Coinbase wallet account, used for deposits and withdrawals
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CoinbaseAccount {
+    pub id: String,
+    pub name: String,
+    pub currency: String,
+    #[serde(default)]
+    pub active: bool,
+}
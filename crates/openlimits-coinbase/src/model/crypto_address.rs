@@ -0,0 +1,12 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This struct represents a crypto deposit address
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CryptoAddress {
+    pub address: String,
+    #[serde(default)]
+    pub destination_tag: Option<String>,
+    #[serde(default)]
+    pub network: Option<String>,
+}
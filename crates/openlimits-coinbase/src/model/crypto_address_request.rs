@@ -0,0 +1,9 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This struct represents a request for a new crypto deposit address
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct CryptoAddressRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
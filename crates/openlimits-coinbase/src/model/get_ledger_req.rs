@@ -0,0 +1,21 @@
+use serde::Deserialize;
+use serde::Serialize;
+use chrono::naive::NaiveDateTime;
+use super::shared::opt_naive_datetime_from_string;
+
+/// This struct represents a request of account ledger entries
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct GetLedgerReq {
+    #[serde(with = "opt_naive_datetime_from_string")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<NaiveDateTime>,
+    #[serde(with = "opt_naive_datetime_from_string")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<NaiveDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+}
@@ -0,0 +1,12 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This struct represents the details of an account ledger entry
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct LedgerDetails {
+    pub order_id: Option<String>,
+    pub trade_id: Option<String>,
+    pub product_id: Option<String>,
+    pub transfer_id: Option<String>,
+    pub transfer_type: Option<String>,
+}
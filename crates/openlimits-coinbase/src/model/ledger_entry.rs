@@ -0,0 +1,20 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::LedgerDetails;
+use super::shared::string_to_decimal;
+
+/// This struct represents an account ledger entry
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LedgerEntry {
+    pub id: String,
+    pub created_at: String,
+    #[serde(with = "string_to_decimal")]
+    pub amount: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub balance: Decimal,
+    #[serde(rename = "type")]
+    pub _type: String,
+    #[serde(default)]
+    pub details: LedgerDetails,
+}
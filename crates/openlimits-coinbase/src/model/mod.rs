@@ -12,10 +12,17 @@ mod cancel_all_orders;
 mod cancel_order;
 mod candle_request_params;
 mod candle;
+mod coinbase_account;
+mod crypto_address;
+mod crypto_address_request;
 mod data_range;
 mod fill;
 mod get_fills_req;
+mod get_ledger_req;
+mod get_transfers_req;
 mod get_order_request;
+mod ledger_details;
+mod ledger_entry;
 mod order_request_market_type;
 mod order_request_type;
 mod order_request;
@@ -29,11 +36,17 @@ mod order_type;
 mod order;
 mod paginator;
 mod product;
+mod product_stats;
+mod server_time;
 mod ticker;
 mod trade;
+mod transfer;
+mod transfer_details;
+mod withdraw_crypto_request;
+mod withdraw_crypto_response;
 pub mod websocket;
 
-pub use market_pair::MarketPair;
+pub use market_pair::{MarketPair, SYMBOL_FORMAT};
 pub use account::Account;
 pub use book_level::BookLevel;
 pub use book_record_l1::BookRecordL1;
@@ -45,10 +58,17 @@ pub use cancel_all_orders::CancelAllOrders;
 pub use cancel_order::CancelOrder;
 pub use candle_request_params::CandleRequestParams;
 pub use candle::Candle;
+pub use coinbase_account::CoinbaseAccount;
+pub use crypto_address::CryptoAddress;
+pub use crypto_address_request::CryptoAddressRequest;
 pub use data_range::DateRange;
 pub use fill::Fill;
 pub use get_fills_req::GetFillsReq;
+pub use get_ledger_req::GetLedgerReq;
+pub use get_transfers_req::GetTransfersReq;
 pub use get_order_request::GetOrderRequest;
+pub use ledger_details::LedgerDetails;
+pub use ledger_entry::LedgerEntry;
 pub use order_request_market_type::OrderRequestMarketType;
 pub use order_request_type::OrderRequestType;
 pub use order_request::OrderRequest;
@@ -62,8 +82,14 @@ pub use order_type::OrderType;
 pub use order::Order;
 pub use paginator::Paginator;
 pub use product::Product;
+pub use product_stats::ProductStats;
+pub use server_time::ServerTime;
 pub use ticker::Ticker;
 pub use trade::Trade;
+pub use transfer::Transfer;
+pub use transfer_details::TransferDetails;
+pub use withdraw_crypto_request::WithdrawCryptoRequest;
+pub use withdraw_crypto_response::WithdrawCryptoResponse;
 pub use super::shared;
 
 
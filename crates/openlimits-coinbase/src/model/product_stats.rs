@@ -0,0 +1,19 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_decimal;
+
+/// This struct represents the 24 hour statistics of a product
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ProductStats {
+    #[serde(with = "string_to_decimal")]
+    pub open: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub high: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub low: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub last: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub volume: Decimal,
+}
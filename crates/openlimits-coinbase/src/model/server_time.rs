@@ -0,0 +1,9 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This struct represents the server time
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ServerTime {
+    pub iso: String,
+    pub epoch: f64,
+}
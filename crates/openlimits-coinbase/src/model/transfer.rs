@@ -0,0 +1,24 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::TransferDetails;
+use super::shared::string_to_decimal;
+
+/// This struct represents a deposit or a withdrawal
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Transfer {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub _type: String,
+    pub account_id: String,
+    #[serde(default)]
+    pub currency: Option<String>,
+    #[serde(with = "string_to_decimal")]
+    pub amount: Decimal,
+    pub created_at: Option<String>,
+    pub processed_at: Option<String>,
+    pub completed_at: Option<String>,
+    pub canceled_at: Option<String>,
+    #[serde(default)]
+    pub details: TransferDetails,
+}
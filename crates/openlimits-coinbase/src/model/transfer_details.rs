@@ -0,0 +1,13 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This struct represents the details of a transfer
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct TransferDetails {
+    pub crypto_address: Option<String>,
+    pub destination_tag: Option<String>,
+    pub crypto_transaction_hash: Option<String>,
+    pub network: Option<String>,
+    pub fee: Option<String>,
+}
@@ -0,0 +1,36 @@
+use rust_decimal::prelude::Decimal;
+use serde::Deserialize;
+use super::OrderSide;
+use super::shared::string_to_decimal;
+use super::shared::string_to_opt_decimal;
+
+/// This struct represents the ticker of a product with trades. The 24h statistics and the best
+/// bid and ask sizes are only sent by the current feed.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct FullTicker {
+    pub trade_id: usize,
+    pub sequence: usize,
+    pub time: String,
+    pub product_id: String,
+    #[serde(with = "string_to_decimal")]
+    pub price: Decimal,
+    pub side: OrderSide,
+    #[serde(with = "string_to_decimal")]
+    pub last_size: Decimal,
+    #[serde(with = "string_to_opt_decimal")]
+    pub best_bid: Option<Decimal>,
+    #[serde(with = "string_to_opt_decimal")]
+    pub best_ask: Option<Decimal>,
+    #[serde(default, with = "string_to_opt_decimal")]
+    pub best_bid_size: Option<Decimal>,
+    #[serde(default, with = "string_to_opt_decimal")]
+    pub best_ask_size: Option<Decimal>,
+    #[serde(default, with = "string_to_opt_decimal")]
+    pub open_24h: Option<Decimal>,
+    #[serde(default, with = "string_to_opt_decimal")]
+    pub high_24h: Option<Decimal>,
+    #[serde(default, with = "string_to_opt_decimal")]
+    pub low_24h: Option<Decimal>,
+    #[serde(default, with = "string_to_opt_decimal")]
+    pub volume_24h: Option<Decimal>,
+}
@@ -1,4 +1,4 @@
-use openlimits_exchange::errors::OpenLimitsError;
+use openlimits_exchange::errors::{MissingImplementationContent, OpenLimitsError};
 use openlimits_exchange::model::websocket::OpenLimitsWebSocketMessage;
 use openlimits_exchange::model::websocket::WebSocketResponse;
 use openlimits_exchange::model::AskBid;
@@ -17,6 +17,7 @@ mod coinbase_subscription;
 mod coinbase_websocket_message;
 mod done;
 mod full;
+mod full_ticker;
 mod input_message;
 mod level2_snapshot_record;
 mod level2_update_record;
@@ -39,6 +40,7 @@ pub use coinbase_subscription::CoinbaseSubscription;
 pub use coinbase_websocket_message::CoinbaseWebsocketMessage;
 pub use done::Done;
 pub use full::Full;
+pub use full_ticker::FullTicker;
 pub use input_message::InputMessage;
 pub use level2_snapshot_record::Level2SnapshotRecord;
 pub use level2_update_record::Level2UpdateRecord;
@@ -63,40 +65,55 @@ impl TryFrom<CoinbaseWebsocketMessage> for WebSocketResponse<CoinbaseWebsocketMe
                 Ok(WebSocketResponse::Generic(level2.try_into()?))
             },
             CoinbaseWebsocketMessage::Match(match_) => {
-                Ok(WebSocketResponse::Generic(match_.into()))
+                Ok(WebSocketResponse::Generic(match_.try_into()?))
             },
-            CoinbaseWebsocketMessage::Full(full) => {
-                Ok(WebSocketResponse::Generic(full.into()))
+            CoinbaseWebsocketMessage::Full(Full::Match(match_)) => {
+                Ok(WebSocketResponse::Generic(match_.try_into()?))
             },
             _ => Ok(WebSocketResponse::Raw(value))
         }
     }
 }
 
-impl From<Full> for OpenLimitsWebSocketMessage {
-    fn from(from: Full) -> Self {
+impl TryFrom<Full> for OpenLimitsWebSocketMessage {
+    type Error = OpenLimitsError;
+
+    fn try_from(from: Full) -> std::result::Result<Self, Self::Error> {
         match from {
-            Full::Match(match_) => match_.into(),
-            _ => todo!("Full is not fully implemented :)")
+            Full::Match(match_) => match_.try_into(),
+            _ => {
+                let message = "Only Full::Match has a generic websocket message".into();
+                Err(OpenLimitsError::MissingImplementation(MissingImplementationContent { message }))
+            }
         }
     }
 }
 
-impl From<Match> for OpenLimitsWebSocketMessage {
-    fn from(match_: Match) -> Self {
-        let market_pair = match_.product_id;
+impl TryFrom<Match> for OpenLimitsWebSocketMessage {
+    type Error = OpenLimitsError;
+
+    fn try_from(match_: Match) -> std::result::Result<Self, Self::Error> {
+        Ok(Self::Trades(vec![match_.try_into()?]))
+    }
+}
+
+impl TryFrom<Match> for Trade {
+    type Error = OpenLimitsError;
+
+    fn try_from(match_: Match) -> std::result::Result<Self, Self::Error> {
+        let market_pair = super::SYMBOL_FORMAT.parse(&match_.product_id)?;
+        let created_at = openlimits_exchange::shared::parse_timestamp(&match_.time).ok_or_else(|| {
+            OpenLimitsError::NotParsableResponse(format!("{} is not a valid match time.", match_.time))
+        })?;
         let price = match_.price;
         let qty = match_.size;
         let id = format!("{}", match_.trade_id);
         let buyer_order_id = Some(match_.taker_order_id);
         let seller_order_id = Some(match_.maker_order_id);
-        let created_at = match_.time;
         let fees = None;
         let liquidity = None;
         let side = match_.side.into();
-        let trade = Trade { market_pair, price, qty, id, buyer_order_id, created_at, fees, liquidity, seller_order_id, side };
-        let trades = vec![trade];
-        Self::Trades(trades)
+        Ok(Trade { market_pair, price, qty, id, buyer_order_id, created_at, fees, liquidity, seller_order_id, side })
     }
 }
 
@@ -104,20 +121,25 @@ impl TryFrom<Level2> for OpenLimitsWebSocketMessage {
     type Error = OpenLimitsError;
 
     fn try_from(level2: Level2) -> std::result::Result<Self, Self::Error> {
+        Ok(OpenLimitsWebSocketMessage::OrderBook(level2.into()))
+    }
+}
+
+impl From<Level2> for OrderBookResponse {
+    fn from(level2: Level2) -> Self {
         // FIXME: How can we get the update id?
         let last_update_id = None;
         let update_id = None;
-        Ok(match level2 {
+        match level2 {
             Level2::Snapshot { asks, bids, .. } => {
                 let bids = bids.iter().map(|bid| bid.into()).collect();
                 let asks = asks.iter().map(|ask| ask.into()).collect();
-                let order_book_response = OrderBookResponse {
+                OrderBookResponse {
                     bids,
                     asks,
                     update_id,
                     last_update_id,
-                };
-                OpenLimitsWebSocketMessage::OrderBook(order_book_response)
+                }
             }
             Level2::L2update { changes, .. } => {
                 let bids = changes
@@ -130,15 +152,14 @@ impl TryFrom<Level2> for OpenLimitsWebSocketMessage {
                     .filter(|change| change.side == OrderSide::Sell)
                     .map(|change| change.into())
                     .collect();
-                let order_book_response = OrderBookResponse {
+                OrderBookResponse {
                     bids,
                     asks,
                     update_id,
                     last_update_id,
-                };
-                OpenLimitsWebSocketMessage::OrderBook(order_book_response)
+                }
             }
-        })
+        }
     }
 }
 
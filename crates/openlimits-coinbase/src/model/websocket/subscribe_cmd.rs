@@ -1,9 +1,10 @@
 use serde::Deserialize;
 use serde::Serialize;
 
-/// This enum represents a subscribe command
+/// This enum represents a subscribe or unsubscribe command
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub enum SubscribeCmd {
     Subscribe,
+    Unsubscribe,
 }
\ No newline at end of file
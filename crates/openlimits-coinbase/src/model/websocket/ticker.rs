@@ -1,29 +1,14 @@
 use rust_decimal::prelude::Decimal;
 use serde::Deserialize;
-use super::OrderSide;
-use super::shared::string_to_decimal;
 use super::shared::string_to_opt_decimal;
+use super::FullTicker;
 
-/// This enum represents a ticker
+/// This enum represents a ticker, which is empty for a product without trades.
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 #[serde(untagged)]
 #[serde(rename_all = "camelCase")]
 pub enum Ticker {
-    Full {
-        trade_id: usize,
-        sequence: usize,
-        time: String,
-        product_id: String,
-        #[serde(with = "string_to_decimal")]
-        price: Decimal,
-        side: OrderSide,
-        #[serde(with = "string_to_decimal")]
-        last_size: Decimal,
-        #[serde(with = "string_to_opt_decimal")]
-        best_bid: Option<Decimal>,
-        #[serde(with = "string_to_opt_decimal")]
-        best_ask: Option<Decimal>,
-    },
+    Full(Box<FullTicker>),
     Empty {
         sequence: usize,
         product_id: String,
@@ -35,35 +20,35 @@ pub enum Ticker {
 impl Ticker {
     pub fn price(&self) -> Decimal {
         match self {
-            Ticker::Full { price, .. } => *price,
+            Ticker::Full(ticker) => ticker.price,
             Ticker::Empty { price, .. } => price.expect("Couldn't get price."),
         }
     }
 
     pub fn time(&self) -> Option<&String> {
         match self {
-            Ticker::Full { time, .. } => Some(time),
+            Ticker::Full(ticker) => Some(&ticker.time),
             Ticker::Empty { .. } => None,
         }
     }
 
     pub fn sequence(&self) -> &usize {
         match self {
-            Ticker::Full { sequence, .. } => sequence,
+            Ticker::Full(ticker) => &ticker.sequence,
             Ticker::Empty { sequence, .. } => sequence,
         }
     }
 
     pub fn bid(&self) -> Option<Decimal> {
         match self {
-            Ticker::Full { best_bid, .. } => Some(best_bid.expect("Couldn't get best bid.")),
+            Ticker::Full(ticker) => Some(ticker.best_bid.expect("Couldn't get best bid.")),
             Ticker::Empty { .. } => None,
         }
     }
 
     pub fn ask(&self) -> Option<Decimal> {
         match self {
-            Ticker::Full { best_ask, .. } => Some(best_ask.expect("Couldn't get best ask.")),
+            Ticker::Full(ticker) => Some(ticker.best_ask.expect("Couldn't get best ask.")),
             Ticker::Empty { .. } => None,
         }
     }
@@ -0,0 +1,18 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_decimal;
+
+/// This struct represents a withdrawal to a crypto address
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct WithdrawCryptoRequest {
+    #[serde(with = "string_to_decimal")]
+    pub amount: Decimal,
+    pub currency: String,
+    pub crypto_address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_tag: Option<String>,
+    pub no_destination_tag: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
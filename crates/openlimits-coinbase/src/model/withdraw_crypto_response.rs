@@ -0,0 +1,19 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_decimal;
+use super::shared::string_to_opt_decimal;
+
+/// This struct represents the response of a crypto withdrawal
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct WithdrawCryptoResponse {
+    pub id: String,
+    #[serde(with = "string_to_decimal")]
+    pub amount: Decimal,
+    pub currency: String,
+    #[serde(default)]
+    #[serde(with = "string_to_opt_decimal")]
+    pub fee: Option<Decimal>,
+    #[serde(default)]
+    pub network: Option<String>,
+}
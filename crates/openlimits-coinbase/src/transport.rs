@@ -1,35 +1,31 @@
-use hmac::{Hmac, Mac, NewMac};
-use reqwest::header;
-use reqwest::{Method, RequestBuilder, Response, StatusCode};
+use std::sync::Arc;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
-use sha2::Sha256;
-use std::time::SystemTime;
 use url::Url;
 use crate::CoinbaseContentError;
+use crate::model::ServerTime;
+use openlimits_exchange::clock::ClockSync;
 use openlimits_exchange::errors::OpenLimitsError;
+use openlimits_exchange::http::{header, HttpRequest, HttpResponse, HttpTransport, InstrumentedTransport, Method, ReqwestTransport, StatusCode};
+use openlimits_exchange::signer::{HmacSigner, RequestSigner};
 use super::shared::Result;
 
-type HmacSha256 = Hmac<Sha256>;
-
 #[derive(Clone, Debug)]
 pub struct Transport {
-    api_secret: Option<String>,
-    client: reqwest::Client,
+    signer: Option<Arc<dyn RequestSigner>>,
+    http: Arc<dyn HttpTransport>,
+    headers: header::HeaderMap,
+    clock: ClockSync,
     base_url: String,
 }
 
 impl Transport {
     pub fn new(sandbox: bool) -> Result<Self> {
-        let default_headers = Transport::default_headers();
-
-        let client = reqwest::Client::builder()
-            .default_headers(default_headers)
-            .build()?;
-
         Ok(Transport {
-            client,
-            api_secret: None,
+            http: Transport::instrumented(Arc::new(ReqwestTransport::default())),
+            headers: Transport::default_headers(),
+            signer: None,
+            clock: ClockSync::default(),
             base_url: Transport::get_base_url(sandbox),
         })
     }
@@ -40,18 +36,49 @@ impl Transport {
         passphrase: &str,
         sandbox: bool,
     ) -> Result<Self> {
-        let default_headers = Transport::default_headers_with_auth(&api_key, &passphrase);
-        let client = reqwest::Client::builder()
-            .default_headers(default_headers)
-            .build()?;
+        let key = base64::decode(api_secret).map_err(|_| {
+            OpenLimitsError::InvalidParameter(String::from("Coinbase API secret isn't base64 encoded"))
+        })?;
+        let signer = Arc::new(HmacSigner::new(key));
+        Transport::with_signer(api_key, signer, passphrase, sandbox)
+    }
 
+    /// Returns a transport whose requests are signed by the given signer instead of a secret.
+    pub fn with_signer(
+        api_key: &str,
+        signer: Arc<dyn RequestSigner>,
+        passphrase: &str,
+        sandbox: bool,
+    ) -> Result<Self> {
         Ok(Transport {
-            api_secret: Some(String::from(api_secret)),
-            client,
+            signer: Some(signer),
+            http: Transport::instrumented(Arc::new(ReqwestTransport::default())),
+            headers: Transport::default_headers_with_auth(api_key, passphrase),
+            clock: ClockSync::default(),
             base_url: Transport::get_base_url(sandbox),
         })
     }
 
+    /// Returns a transport that sends its requests to another base URL.
+    pub fn with_base_url(self, base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+            ..self
+        }
+    }
+
+    /// Returns a transport that sends its requests through the given HTTP transport.
+    pub fn with_http_transport(self, http: Arc<dyn HttpTransport>) -> Self {
+        Self {
+            http: Transport::instrumented(http),
+            ..self
+        }
+    }
+
+    fn instrumented(http: Arc<dyn HttpTransport>) -> Arc<dyn HttpTransport> {
+        Arc::new(InstrumentedTransport::new("coinbase", http))
+    }
+
     pub fn default_headers() -> header::HeaderMap<header::HeaderValue> {
         let mut headers = header::HeaderMap::new();
         headers.insert(
@@ -63,6 +90,42 @@ impl Transport {
         headers
     }
 
+    pub fn clock(&self) -> &ClockSync {
+        &self.clock
+    }
+
+    /// Whether the transport can send signed requests.
+    pub fn is_signed(&self) -> bool {
+        self.signer.is_some()
+    }
+
+    /// Measures the offset against the server time when the last measurement is stale. A failed
+    /// measurement keeps the previous offset rather than failing the signed request.
+    pub async fn sync_clock(&self) {
+        if self.clock.needs_sync() {
+            self.clock.sync(self.server_time()).await;
+        }
+    }
+
+    /// Measures the offset every sync interval in the background, for as long as the transport
+    /// or one of its clones is alive.
+    pub fn spawn_clock_sync(&self) {
+        // The task's transport has a clock of its own, so it doesn't keep this one alive.
+        let transport = Self {
+            clock: ClockSync::default(),
+            ..self.clone()
+        };
+        self.clock.spawn_refresh(move || {
+            let transport = transport.clone();
+            async move { transport.server_time().await }
+        });
+    }
+
+    async fn server_time(&self) -> Result<i64> {
+        let time: ServerTime = self.get::<_, ()>("/time", None).await?;
+        Ok((time.epoch * 1000.0) as i64)
+    }
+
     fn get_base_url(sandbox: bool) -> String {
         if sandbox {
             String::from("https://api-public.sandbox.exchange.coinbase.com")
@@ -103,9 +166,11 @@ impl Transport {
         S: Serialize,
     {
         let url = self.get_url(endpoint, params)?;
-        let request = self.client.get(url).send().await?;
+        let mut request = HttpRequest::new(Method::GET, url);
+        request.headers = self.headers.clone();
+        let response = self.http.send(request).await?;
 
-        Ok(self.response_handler(request).await?)
+        self.response_handler(response)
     }
 
     pub async fn signed_get<O, S>(&self, endpoint: &str, params: Option<&S>) -> Result<O>
@@ -113,13 +178,14 @@ impl Transport {
         O: DeserializeOwned,
         S: Serialize,
     {
+        self.sync_clock().await;
         let url = self.get_url(endpoint, params)?;
 
-        let request = self.build_request::<()>(url, Method::GET, None)?;
+        let request = self.build_request::<()>(url, Method::GET, None).await?;
 
-        let resp = request.send().await?;
+        let resp = self.http.send(request).await?;
 
-        Ok(self.response_handler(resp).await?)
+        self.response_handler(resp)
     }
 
     pub async fn signed_post<O, P, D>(
@@ -133,11 +199,12 @@ impl Transport {
         P: Serialize,
         D: Serialize,
     {
+        self.sync_clock().await;
         let url = self.get_url(endpoint, params)?;
-        let request = self.build_request(url, Method::POST, data)?;
-        let resp = request.send().await?;
+        let request = self.build_request(url, Method::POST, data).await?;
+        let resp = self.http.send(request).await?;
 
-        Ok(self.response_handler(resp).await?)
+        self.response_handler(resp)
     }
 
     pub async fn signed_delete<O, P, D>(
@@ -151,40 +218,46 @@ impl Transport {
         P: Serialize,
         D: Serialize + std::fmt::Debug,
     {
+        self.sync_clock().await;
         let url = self.get_url(endpoint, params)?;
-        let request = self.build_request(url, Method::DELETE, data)?;
-        let request = request.send().await?;
+        let request = self.build_request(url, Method::DELETE, data).await?;
+        let response = self.http.send(request).await?;
 
-        Ok(self.response_handler(request).await?)
+        self.response_handler(response)
     }
 
-    pub fn build_request<D>(
+    pub async fn build_request<D>(
         &self,
         url: Url,
         method: Method,
         data: Option<&D>,
-    ) -> Result<RequestBuilder>
+    ) -> Result<HttpRequest>
     where
         D: Serialize,
     {
-        let since_epoch_seconds = SystemTime::now()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .expect("Invalid SystemTime.")
-            .as_secs();
+        let since_epoch_seconds = (self.clock.now_millis() / 1000) as u64;
 
-        let signature = self.signature(&url, since_epoch_seconds, &method, data)?;
+        let signature = self.signature(&url, since_epoch_seconds, &method, data).await?;
 
-        let mut request = self
-            .client
-            .request(method, url)
-            .header("CB-ACCESS-SIGN", signature)
-            .header("CB-ACCESS-TIMESTAMP", since_epoch_seconds.to_string());
+        let mut request = HttpRequest::new(method, url);
+        request.headers = self.headers.clone();
+        request.headers.insert(
+            "CB-ACCESS-SIGN",
+            header::HeaderValue::from_str(&signature)
+                .expect("Couldn't create CB-ACCESS-SIGN header from string."),
+        );
+        request.headers.insert(
+            "CB-ACCESS-TIMESTAMP",
+            header::HeaderValue::from(since_epoch_seconds),
+        );
 
-        request = if data.is_some() {
-            request.json(&data)
-        } else {
-            request
-        };
+        if data.is_some() {
+            request.headers.insert(
+                header::CONTENT_TYPE,
+                header::HeaderValue::from_static("application/json"),
+            );
+            request.body = Some(serde_json::to_vec(&data)?);
+        }
 
         Ok(request)
     }
@@ -205,7 +278,7 @@ impl Transport {
         Ok(url)
     }
 
-    pub fn signature<D>(
+    pub async fn signature<D>(
         &self,
         url: &Url,
         timestamp: u64,
@@ -215,12 +288,10 @@ impl Transport {
     where
         D: Serialize,
     {
-        let api_secret = match self.api_secret.as_ref() {
+        let signer = match self.signer.as_ref() {
             None => Err(OpenLimitsError::NoApiKeySet()),
             Some(v) => Ok(v),
         }?;
-        let key = base64::decode(api_secret).expect("Failed to base64 decode Coinbase API secret");
-        let mut mac = HmacSha256::new_varkey(&key).expect("Couldn't create HMAC-SHA256.");
 
         let prefix: String = timestamp.to_string() + method.as_str();
 
@@ -236,18 +307,17 @@ impl Transport {
 
         let sign_message = format!("{}{}{}", prefix, path, body);
 
-        mac.update(sign_message.as_bytes());
-        let signature = base64::encode(mac.finalize().into_bytes());
-        Ok(signature)
+        let signature = signer.sign(sign_message.as_bytes()).await?;
+        Ok(base64::encode(signature))
     }
 
-    async fn response_handler<O>(&self, response: Response) -> Result<O>
+    fn response_handler<O>(&self, response: HttpResponse) -> Result<O>
     where
         O: DeserializeOwned,
     {
-        match response.status() {
+        match response.status {
             StatusCode::OK => {
-                let text = response.text().await?;
+                let text = response.text();
                 serde_json::from_str::<O>(&text).map_err(move |err| {
                     OpenLimitsError::NotParsableResponse(format!("Error:{} Payload: {}", err, text))
                 })
@@ -256,16 +326,15 @@ impl Transport {
             StatusCode::INTERNAL_SERVER_ERROR => Err(OpenLimitsError::InternalServerError()),
             StatusCode::SERVICE_UNAVAILABLE => Err(OpenLimitsError::ServiceUnavailable()),
             StatusCode::UNAUTHORIZED => {
-                let text = response.text().await?;
-                println!("{}", text);
+                tracing::debug!(body = %response.text(), "unauthorized request");
                 Err(OpenLimitsError::Unauthorized())
             }
             StatusCode::BAD_REQUEST => {
-                let error: CoinbaseContentError = response.json().await?;
+                let error: CoinbaseContentError = response.json()?;
                 Err(OpenLimitsError::Generic(Box::new(error)))
             }
             s => {
-                let text = response.text().await?;
+                let text = response.text();
                 Err(OpenLimitsError::UnkownResponse(format!(
                     "Received response: {:?}, value: {}",
                     s, text
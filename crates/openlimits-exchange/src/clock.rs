@@ -0,0 +1,129 @@
+//! This module keeps track of the drift between the local clock and an exchange server clock so
+//! signed requests carry a timestamp the exchange accepts.
+
+use std::future::Future;
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use chrono::Utc;
+use crate::shared::Result;
+
+/// Default interval between two server time measurements.
+pub const DEFAULT_SYNC_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Default)]
+struct Measurement {
+    offset: AtomicI64,
+    latency: AtomicU64,
+    /// The local time of the last measurement, or of the last failed attempt at one.
+    last_sync: AtomicI64,
+}
+
+/// This struct represents the estimated offset between the local clock and a server clock.
+/// Clones share the same measurements.
+#[derive(Clone, Debug)]
+pub struct ClockSync {
+    measurement: Arc<Measurement>,
+    interval: Duration,
+}
+
+impl ClockSync {
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            measurement: Default::default(),
+            interval,
+        }
+    }
+
+    /// Local time in milliseconds since the epoch.
+    pub fn local_millis() -> i64 {
+        Utc::now().timestamp_millis()
+    }
+
+    /// Estimated server time in milliseconds since the epoch.
+    pub fn now_millis(&self) -> i64 {
+        Self::local_millis() + self.offset_millis()
+    }
+
+    /// Milliseconds to add to the local clock to get the server clock.
+    pub fn offset_millis(&self) -> i64 {
+        self.measurement.offset.load(Ordering::Relaxed)
+    }
+
+    /// Round-trip time of the last measurement in milliseconds.
+    pub fn latency_millis(&self) -> u64 {
+        self.measurement.latency.load(Ordering::Relaxed)
+    }
+
+    /// Whether the offset was never measured or the last measurement, or failed attempt at one,
+    /// is older than the interval.
+    pub fn needs_sync(&self) -> bool {
+        self.needs_sync_at(Self::local_millis())
+    }
+
+    /// Whether the offset needs to be measured at the given local time.
+    pub fn needs_sync_at(&self, now: i64) -> bool {
+        let last_sync = self.measurement.last_sync.load(Ordering::Relaxed);
+        last_sync == 0 || now - last_sync >= self.interval.as_millis() as i64
+    }
+
+    /// Forces the next `needs_sync` call to return true.
+    pub fn invalidate(&self) {
+        self.measurement.last_sync.store(0, Ordering::Relaxed);
+    }
+
+    /// Records a measurement, where `sent_at` and `received_at` are the local times around the
+    /// request that returned `server_time`.
+    pub fn update(&self, sent_at: i64, server_time: i64, received_at: i64) {
+        let round_trip = (received_at - sent_at).max(0);
+        // The server reads its clock roughly halfway through the round trip.
+        let offset = server_time - (sent_at + round_trip / 2);
+        self.measurement.offset.store(offset, Ordering::Relaxed);
+        self.measurement.latency.store(round_trip as u64, Ordering::Relaxed);
+        self.measurement.last_sync.store(received_at, Ordering::Relaxed);
+    }
+
+    /// Measures the offset with `server_time`, which requests the server time in milliseconds.
+    /// A failed measurement is logged and keeps the previous offset, so it never fails the signed
+    /// request that triggered it, and isn't tried again before the interval passed.
+    pub async fn sync<F>(&self, server_time: F)
+    where
+        F: Future<Output = Result<i64>>,
+    {
+        let sent_at = Self::local_millis();
+        match server_time.await {
+            Ok(server_time) => self.update(sent_at, server_time, Self::local_millis()),
+            Err(error) => {
+                tracing::warn!(?error, "Couldn't measure the server clock offset");
+                self.measurement.last_sync.store(Self::local_millis(), Ordering::Relaxed);
+            }
+        }
+    }
+
+    /// Measures the offset every interval in the background, for as long as a clone of this clock
+    /// is alive. `server_time` must not hold a clone of the clock, or the task never ends.
+    pub fn spawn_refresh<F, T>(&self, server_time: F)
+    where
+        F: Fn() -> T + Send + 'static,
+        T: Future<Output = Result<i64>> + Send + 'static,
+    {
+        let measurement = Arc::downgrade(&self.measurement);
+        let interval = self.interval;
+        cross_async::spawn(async move {
+            loop {
+                cross_async::sleep(interval).await;
+                let clock = match measurement.upgrade() {
+                    Some(measurement) => ClockSync { measurement, interval },
+                    None => break,
+                };
+                clock.sync(server_time()).await;
+            }
+        });
+    }
+}
+
+impl Default for ClockSync {
+    fn default() -> Self {
+        Self::new(DEFAULT_SYNC_INTERVAL)
+    }
+}
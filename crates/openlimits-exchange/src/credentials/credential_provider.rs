@@ -0,0 +1,18 @@
+use std::convert::TryFrom;
+use super::Profile;
+use super::shared::Result;
+use crate::errors::OpenLimitsError;
+
+/// A source of named credential profiles.
+pub trait CredentialProvider {
+    fn profile(&self, name: &str) -> Result<Profile>;
+
+    /// Loads a profile as the credentials of an exchange, such as `BinanceCredentials`.
+    fn credentials<C>(&self, name: &str) -> Result<C>
+    where
+        C: for<'a> TryFrom<&'a Profile, Error = OpenLimitsError>,
+        Self: Sized,
+    {
+        C::try_from(&self.profile(name)?)
+    }
+}
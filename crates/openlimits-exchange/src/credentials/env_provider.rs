@@ -0,0 +1,28 @@
+use std::env;
+use super::{CredentialProvider, Profile};
+use super::shared::Result;
+use crate::errors::OpenLimitsError;
+
+/// Reads a profile from the environment variables prefixed by its name: the `binance` profile
+/// gets its `api_key` from `BINANCE_API_KEY` and its `api_secret` from `BINANCE_API_SECRET`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EnvProvider;
+
+impl CredentialProvider for EnvProvider {
+    fn profile(&self, name: &str) -> Result<Profile> {
+        let prefix = format!("{}_", name.to_uppercase().replace('-', "_"));
+        let mut profile = Profile::new(name);
+        for (variable, value) in env::vars() {
+            if let Some(field) = variable.strip_prefix(&prefix) {
+                profile.insert(field.to_lowercase(), value);
+            }
+        }
+        if profile.is_empty() {
+            return Err(OpenLimitsError::MissingParameter(format!(
+                "{}* environment variables",
+                prefix
+            )));
+        }
+        Ok(profile)
+    }
+}
@@ -0,0 +1,134 @@
+use std::fs;
+use std::path::Path;
+use chacha20poly1305::aead::{Aead, NewAead};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use scrypt::{scrypt, ScryptParams};
+use serde::{Deserialize, Serialize};
+use zeroize::{Zeroize, Zeroizing};
+use super::{CredentialProvider, Profile, TomlProvider};
+use super::shared::Result;
+use crate::errors::OpenLimitsError;
+
+const SALT_LENGTH: usize = 16;
+const NONCE_LENGTH: usize = 12;
+// The scrypt parameters recommended for interactive logins.
+const SCRYPT_LOG_N: u8 = 15;
+const SCRYPT_R: u32 = 8;
+const SCRYPT_P: u32 = 1;
+
+/// The JSON keystore file: a TOML document of profiles encrypted with ChaCha20-Poly1305, under a
+/// key derived from the passphrase with scrypt.
+#[derive(Serialize, Deserialize)]
+struct Keystore {
+    version: u8,
+    log_n: u8,
+    r: u32,
+    p: u32,
+    salt: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+/// Reads the profiles from a keystore encrypted with a passphrase. The keystore is written by
+/// `KeystoreProvider::seal` from a document in the `TomlProvider` format.
+#[derive(Clone, Debug)]
+pub struct KeystoreProvider {
+    profiles: TomlProvider,
+}
+
+impl KeystoreProvider {
+    pub fn open(path: impl AsRef<Path>, passphrase: &str) -> Result<Self> {
+        Self::decrypt(&fs::read_to_string(path)?, passphrase)
+    }
+
+    pub fn decrypt(keystore: &str, passphrase: &str) -> Result<Self> {
+        let keystore: Keystore = serde_json::from_str(keystore)?;
+        if keystore.version != 1 {
+            return Err(invalid_keystore("unsupported version"));
+        }
+        let salt = decode(&keystore.salt)?;
+        let nonce = nonce(&decode(&keystore.nonce)?)?;
+        let ciphertext = decode(&keystore.ciphertext)?;
+
+        let cipher = cipher(passphrase, &salt, keystore.log_n, keystore.r, keystore.p)?;
+        let document = cipher
+            .decrypt(&nonce, ciphertext.as_ref())
+            .map(Zeroizing::new)
+            .map_err(|_| OpenLimitsError::Unauthorized())?;
+        let document = std::str::from_utf8(&document)
+            .map_err(|_| invalid_keystore("the profiles aren't UTF-8"))?;
+        Ok(Self {
+            profiles: TomlProvider::parse(document)?,
+        })
+    }
+
+    /// Encrypts a document of profiles into a keystore.
+    pub fn seal(document: &str, passphrase: &str) -> Result<String> {
+        Self::seal_with_cost(document, passphrase, SCRYPT_LOG_N)
+    }
+
+    /// Encrypts with a scrypt cost of `2^log_n`. A lower cost makes the keystore faster to open,
+    /// and to brute force.
+    pub fn seal_with_cost(document: &str, passphrase: &str, log_n: u8) -> Result<String> {
+        TomlProvider::parse(document)?;
+        let (r, p) = (SCRYPT_R, SCRYPT_P);
+        let salt = random_bytes(SALT_LENGTH)?;
+        let nonce = random_bytes(NONCE_LENGTH)?;
+
+        let cipher = cipher(passphrase, &salt, log_n, r, p)?;
+        let ciphertext = cipher
+            .encrypt(&self::nonce(&nonce)?, document.as_bytes())
+            .map_err(|_| invalid_keystore("encryption failed"))?;
+        let keystore = Keystore {
+            version: 1,
+            log_n,
+            r,
+            p,
+            salt: hex::encode(salt),
+            nonce: hex::encode(nonce),
+            ciphertext: hex::encode(ciphertext),
+        };
+        Ok(serde_json::to_string_pretty(&keystore)?)
+    }
+}
+
+impl CredentialProvider for KeystoreProvider {
+    fn profile(&self, name: &str) -> Result<Profile> {
+        self.profiles.profile(name)
+    }
+}
+
+fn cipher(passphrase: &str, salt: &[u8], log_n: u8, r: u32, p: u32) -> Result<ChaCha20Poly1305> {
+    let params = ScryptParams::new(log_n, r, p)
+        .map_err(|_| invalid_keystore("invalid scrypt parameters"))?;
+    let mut key = Key::default();
+    let derived = scrypt(passphrase.as_bytes(), salt, &params, &mut key[..]);
+    let cipher = ChaCha20Poly1305::new(&key);
+    key[..].zeroize();
+    derived.map_err(|_| invalid_keystore("invalid key length"))?;
+    Ok(cipher)
+}
+
+fn nonce(bytes: &[u8]) -> Result<Nonce> {
+    if bytes.len() != NONCE_LENGTH {
+        return Err(invalid_keystore("invalid nonce"));
+    }
+    let mut nonce = Nonce::default();
+    nonce.copy_from_slice(bytes);
+    Ok(nonce)
+}
+
+fn random_bytes(length: usize) -> Result<Vec<u8>> {
+    let mut bytes = vec![0; length];
+    getrandom::getrandom(&mut bytes)
+        .map_err(|error| OpenLimitsError::Generic(Box::new(error)))?;
+    Ok(bytes)
+}
+
+fn decode(value: &str) -> Result<Vec<u8>> {
+    hex::decode(value).map_err(|_| invalid_keystore("invalid hex"))
+}
+
+fn invalid_keystore(reason: &str) -> OpenLimitsError {
+    OpenLimitsError::InvalidParameter(format!("Keystore: {}", reason))
+}
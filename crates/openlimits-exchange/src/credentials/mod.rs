@@ -0,0 +1,20 @@
+//! This module loads the exchanges' credentials from named profiles, so they don't have to be
+//! filled by hand. A profile is read from the environment variables, a TOML file or a keystore
+//! encrypted with a passphrase, and converted into the credentials of an exchange with `TryFrom`.
+
+mod credential_provider;
+mod env_provider;
+mod keystore_provider;
+mod profile;
+mod toml_provider;
+
+pub use credential_provider::CredentialProvider;
+pub use env_provider::EnvProvider;
+pub use keystore_provider::KeystoreProvider;
+pub use profile::Profile;
+pub use toml_provider::TomlProvider;
+pub use zeroize::Zeroizing;
+pub use super::shared;
+
+/// What the `Debug` output of credentials shows in place of a secret.
+pub const REDACTED: &str = "<redacted>";
@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+use std::fmt;
+use zeroize::Zeroizing;
+use super::shared::Result;
+use crate::errors::OpenLimitsError;
+
+/// The fields of a named profile, such as `api_key` and `api_secret`. The values are overwritten
+/// with zeros when the profile is dropped, and left out of its `Debug` output.
+#[derive(Clone, Default)]
+pub struct Profile {
+    name: String,
+    fields: HashMap<String, Zeroizing<String>>,
+}
+
+impl Profile {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            fields: HashMap::new(),
+        }
+    }
+
+    pub fn with(mut self, field: impl Into<String>, value: impl Into<String>) -> Self {
+        self.insert(field, value);
+        self
+    }
+
+    pub fn insert(&mut self, field: impl Into<String>, value: impl Into<String>) {
+        self.fields.insert(field.into(), Zeroizing::new(value.into()));
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get(&self, field: &str) -> Option<&str> {
+        self.fields.get(field).map(|value| value.as_str())
+    }
+
+    /// Gets a field the credentials can't do without.
+    pub fn require(&self, field: &str) -> Result<&str> {
+        self.get(field).ok_or_else(|| {
+            OpenLimitsError::MissingParameter(format!("{} of the {} profile", field, self.name))
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+}
+
+impl fmt::Debug for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut fields: Vec<&String> = self.fields.keys().collect();
+        fields.sort();
+        f.debug_struct("Profile")
+            .field("name", &self.name)
+            .field("fields", &fields)
+            .finish()
+    }
+}
@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use zeroize::Zeroizing;
+use super::{CredentialProvider, Profile};
+use super::shared::Result;
+use crate::errors::OpenLimitsError;
+
+/// Reads the profiles from the tables of a TOML document:
+///
+/// ```toml
+/// [binance]
+/// api_key = "..."
+/// api_secret = "..."
+/// ```
+#[derive(Clone)]
+pub struct TomlProvider {
+    profiles: HashMap<String, HashMap<String, Zeroizing<String>>>,
+}
+
+impl TomlProvider {
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self> {
+        let document = Zeroizing::new(fs::read_to_string(path)?);
+        Self::parse(&document)
+    }
+
+    pub fn parse(document: &str) -> Result<Self> {
+        let profiles: HashMap<String, HashMap<String, String>> = toml::from_str(document)
+            .map_err(|error| OpenLimitsError::InvalidParameter(format!("Credentials file: {}", error)))?;
+        let profiles = profiles
+            .into_iter()
+            .map(|(name, fields)| {
+                let fields = fields
+                    .into_iter()
+                    .map(|(field, value)| (field, Zeroizing::new(value)))
+                    .collect();
+                (name, fields)
+            })
+            .collect();
+        Ok(Self { profiles })
+    }
+}
+
+impl fmt::Debug for TomlProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut profiles: Vec<&String> = self.profiles.keys().collect();
+        profiles.sort();
+        f.debug_struct("TomlProvider").field("profiles", &profiles).finish()
+    }
+}
+
+impl CredentialProvider for TomlProvider {
+    fn profile(&self, name: &str) -> Result<Profile> {
+        let fields = self.profiles.get(name).ok_or_else(|| {
+            OpenLimitsError::MissingParameter(format!("{} profile", name))
+        })?;
+        let mut profile = Profile::new(name);
+        for (field, value) in fields {
+            profile.insert(field.as_str(), value.as_str());
+        }
+        Ok(profile)
+    }
+}
@@ -36,6 +36,10 @@ pub enum OpenLimitsError {
     #[error("")]
     SocketError(),
     #[error("")]
+    RequestTimeout(),
+    #[error("")]
+    Disconnected(),
+    #[error("")]
     WebSocketMessageNotSupported(),
     #[error("")]
     GetTimestampFailed(),
@@ -55,6 +59,7 @@ pub enum OpenLimitsError {
     ParseFloatError(#[from] std::num::ParseFloatError),
     #[error(transparent)]
     UrlParserError(#[from] url::ParseError),
+    #[cfg(not(target_arch = "wasm32"))]
     #[error(transparent)]
     Tungstenite(#[from] tokio_tungstenite::tungstenite::Error),
     #[error(transparent)]
@@ -20,3 +20,20 @@ impl Default for Environment {
         Self::Production
     }
 }
+
+/// Overrides the base URLs an exchange connects to, such as a regional domain or a local mock
+/// server. The URLs left unset are the ones of the `Environment`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Endpoints {
+    pub rest: Option<String>,
+    pub websocket: Option<String>,
+}
+
+impl Endpoints {
+    pub fn new(rest: impl Into<String>, websocket: impl Into<String>) -> Self {
+        Self {
+            rest: Some(rest.into()),
+            websocket: Some(websocket.into()),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use async_trait::async_trait;
+use super::{HttpRequest, HttpResponse, HttpTransport, StatusCode};
+use super::header::HeaderMap;
+use super::shared::Result;
+
+/// An in-memory transport for tests. It answers with the scripted responses in order, then with
+/// its default response, and keeps every request it was sent.
+#[derive(Debug)]
+pub struct FakeTransport {
+    default: HttpResponse,
+    responses: Mutex<VecDeque<HttpResponse>>,
+    requests: Mutex<Vec<HttpRequest>>,
+}
+
+impl FakeTransport {
+    /// Answers every request with `default` until responses are scripted.
+    pub fn new(default: HttpResponse) -> Self {
+        Self {
+            default,
+            responses: Default::default(),
+            requests: Default::default(),
+        }
+    }
+
+    /// Answers every request with the status and body.
+    pub fn answering(status: StatusCode, body: &[u8]) -> Self {
+        Self::new(HttpResponse {
+            status,
+            headers: HeaderMap::new(),
+            body: body.to_vec(),
+        })
+    }
+
+    /// Answers the next request not already answered by a scripted response with `response`.
+    pub fn with_response(self, response: HttpResponse) -> Self {
+        self.responses.lock().expect("Couldn't lock the responses").push_back(response);
+        self
+    }
+
+    /// The requests sent so far, in order.
+    pub fn requests(&self) -> Vec<HttpRequest> {
+        self.requests.lock().expect("Couldn't lock the requests").clone()
+    }
+}
+
+#[async_trait]
+impl HttpTransport for FakeTransport {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+        self.requests.lock().expect("Couldn't lock the requests").push(request);
+        let response = self.responses.lock().expect("Couldn't lock the responses").pop_front();
+        Ok(response.unwrap_or_else(|| self.default.clone()))
+    }
+}
@@ -0,0 +1,22 @@
+use serde::de::DeserializeOwned;
+use super::header::HeaderMap;
+use super::StatusCode;
+use super::shared::Result;
+
+/// This struct represents an HTTP response.
+#[derive(Clone, Debug)]
+pub struct HttpResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+    pub fn json<O: DeserializeOwned>(&self) -> Result<O> {
+        Ok(serde_json::from_slice(&self.body)?)
+    }
+
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+}
@@ -0,0 +1,11 @@
+use std::fmt::Debug;
+use async_trait::async_trait;
+use super::{HttpRequest, HttpResponse};
+use super::shared::Result;
+
+/// The HTTP client the exchanges send their requests through. A transport only sends the request
+/// it is given: building, signing and interpreting the response are left to the exchange.
+#[async_trait]
+pub trait HttpTransport: Debug + Send + Sync {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse>;
+}
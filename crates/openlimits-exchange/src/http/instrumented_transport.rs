@@ -0,0 +1,99 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use tracing::Instrument;
+use web_time::Instant;
+use super::{HttpRequest, HttpResponse, HttpTransport};
+use super::shared::Result;
+use crate::metrics;
+
+/// Wraps the transport of a venue to trace every request in a span and record its status,
+/// latency and, when the venue reports it in a header, the weight used in its rate limit window.
+#[derive(Clone, Debug)]
+pub struct InstrumentedTransport {
+    venue: &'static str,
+    weight_header: Option<&'static str>,
+    inner: Arc<dyn HttpTransport>,
+}
+
+impl InstrumentedTransport {
+    pub fn new(venue: &'static str, inner: Arc<dyn HttpTransport>) -> Self {
+        Self {
+            venue,
+            weight_header: None,
+            inner,
+        }
+    }
+
+    /// Reads the weight the requests used in the rate limit window of the venue from this response
+    /// header, such as the rolling total of the minute.
+    pub fn with_weight_header(self, weight_header: &'static str) -> Self {
+        Self {
+            weight_header: Some(weight_header),
+            ..self
+        }
+    }
+}
+
+#[async_trait]
+impl HttpTransport for InstrumentedTransport {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+        let endpoint = endpoint(request.url.path());
+        let span = tracing::info_span!(
+            "http_request",
+            venue = self.venue,
+            method = %request.method,
+            endpoint = %endpoint,
+        );
+        let started_at = Instant::now();
+        let result = self.inner.send(request).instrument(span.clone()).await;
+        let latency = started_at.elapsed();
+        let _entered = span.enter();
+
+        let status = match &result {
+            Ok(response) => response.status.as_u16().to_string(),
+            Err(_) => String::from("error"),
+        };
+        let used_weight = result.as_ref().ok().and_then(|response| {
+            let header = response.headers.get(self.weight_header?)?;
+            header.to_str().ok()?.parse::<f64>().ok()
+        });
+        match &result {
+            Ok(response) if response.status.is_success() => {
+                tracing::debug!(status = %status, latency_ms = latency.as_millis() as u64, used_weight, "request succeeded")
+            }
+            Ok(_) => tracing::warn!(status = %status, latency_ms = latency.as_millis() as u64, used_weight, "request failed"),
+            Err(error) => tracing::warn!(latency_ms = latency.as_millis() as u64, error = %error, "request failed"),
+        }
+
+        metrics::increment_counter(
+            metrics::HTTP_REQUESTS,
+            &[("venue", self.venue), ("endpoint", &endpoint), ("status", &status)],
+        );
+        metrics::record_histogram(
+            metrics::HTTP_REQUEST_DURATION,
+            latency.as_secs_f64(),
+            &[("venue", self.venue), ("endpoint", &endpoint)],
+        );
+        if let Some(used_weight) = used_weight {
+            metrics::set_gauge(metrics::HTTP_USED_WEIGHT, used_weight, &[("venue", self.venue)]);
+        }
+        result
+    }
+}
+
+/// The path with its identifiers, numbers or long tokens holding digits, replaced by `{id}`, so
+/// the requests of an endpoint share their metrics.
+fn endpoint(path: &str) -> String {
+    path.split('/')
+        .map(|segment| {
+            let numeric = !segment.is_empty() && segment.chars().all(|c| c.is_ascii_digit());
+            let token = segment.len() >= 16 && segment.chars().any(|c| c.is_ascii_digit());
+            if numeric || token {
+                "{id}"
+            } else {
+                segment
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
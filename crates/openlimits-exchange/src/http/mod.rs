@@ -0,0 +1,21 @@
+//! This module abstracts the HTTP client the exchanges send their REST requests with, so it can
+//! be replaced by one with its own connection pool, DNS, proxies or instrumentation, or by a
+//! fake in tests. The exchanges build and sign the requests and use `ReqwestTransport` unless
+//! given another transport.
+
+mod fake_transport;
+mod http_request;
+mod http_response;
+mod http_transport;
+mod instrumented_transport;
+mod reqwest_transport;
+
+pub use fake_transport::FakeTransport;
+pub use http_request::HttpRequest;
+pub use http_response::HttpResponse;
+pub use http_transport::HttpTransport;
+pub use instrumented_transport::InstrumentedTransport;
+pub use reqwest_transport::ReqwestTransport;
+pub use async_trait::async_trait;
+pub use reqwest::{header, Method, StatusCode};
+pub use super::shared;
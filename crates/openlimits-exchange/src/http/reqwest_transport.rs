@@ -0,0 +1,50 @@
+use async_trait::async_trait;
+use super::{HttpRequest, HttpResponse, HttpTransport};
+use super::shared::Result;
+
+/// The default transport, sending the requests with a `reqwest::Client`, which uses fetch in the
+/// browser.
+#[derive(Clone, Debug, Default)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    pub fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl HttpTransport for ReqwestTransport {
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+        execute(&self.client, request).await
+    }
+
+    // The fetch futures aren't `Send`, so the request runs on the local task queue of the browser
+    // and only its result crosses over.
+    #[cfg(target_arch = "wasm32")]
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+        let (sender, receiver) = futures::channel::oneshot::channel();
+        let client = self.client.clone();
+        wasm_bindgen_futures::spawn_local(async move {
+            sender.send(execute(&client, request).await).ok();
+        });
+        receiver.await.unwrap_or(Err(crate::errors::OpenLimitsError::SocketError()))
+    }
+}
+
+async fn execute(client: &reqwest::Client, request: HttpRequest) -> Result<HttpResponse> {
+    let mut builder = client
+        .request(request.method, request.url)
+        .headers(request.headers);
+    if let Some(body) = request.body {
+        builder = builder.body(body);
+    }
+    let response = builder.send().await?;
+    let status = response.status();
+    let headers = response.headers().clone();
+    let body = response.bytes().await?.to_vec();
+    Ok(HttpResponse { status, headers, body })
+}
@@ -1,8 +1,14 @@
+pub mod clock;
+pub mod credentials;
+pub mod http;
 pub mod message;
+pub mod metrics;
 pub mod errors;
 pub mod prelude;
 pub mod model;
 pub mod shared;
+pub mod signer;
+pub mod websocket;
 pub mod traits;
 pub mod exchange;
 
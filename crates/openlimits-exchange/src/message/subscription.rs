@@ -1,8 +1,12 @@
 use crate::model::market_pair::MarketPair;
+use crate::model::{Candle, Interval, OrderBookResponse, Ticker, Trade};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Subscription {
-    Tick(MarketPair)
+    Kline(MarketPair, Interval),
+    Depth(MarketPair),
+    Trades(MarketPair),
+    Ticker(MarketPair),
 }
 
 #[derive(Debug, Clone)]
@@ -10,5 +14,8 @@ pub struct SubscriptionResponse {}
 
 #[derive(Debug, Clone)]
 pub enum Publication {
-    Tick
+    Kline(Candle),
+    Depth(OrderBookResponse),
+    Trades(Vec<Trade>),
+    Ticker(Ticker),
 }
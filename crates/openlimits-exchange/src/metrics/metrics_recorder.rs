@@ -0,0 +1,9 @@
+/// The label names and values of a metric.
+pub type Labels<'a> = &'a [(&'static str, &'a str)];
+
+/// Where the metrics go, for instance an exporter.
+pub trait MetricsRecorder: Send + Sync {
+    fn increment_counter(&self, name: &'static str, labels: Labels);
+    fn record_histogram(&self, name: &'static str, value: f64, labels: Labels);
+    fn set_gauge(&self, name: &'static str, value: f64, labels: Labels);
+}
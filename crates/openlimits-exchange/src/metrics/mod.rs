@@ -0,0 +1,53 @@
+//! This module is a facade for the metrics of the REST and websocket calls. Nothing is recorded
+//! until a recorder is installed with `set_recorder`, such as the `PrometheusRecorder`.
+
+mod metrics_recorder;
+mod prometheus_recorder;
+
+use std::sync::{Arc, RwLock};
+use once_cell::sync::Lazy;
+
+pub use metrics_recorder::{Labels, MetricsRecorder};
+pub use prometheus_recorder::PrometheusRecorder;
+
+/// Counts the REST requests by venue, endpoint and status.
+pub const HTTP_REQUESTS: &str = "openlimits_http_requests_total";
+/// The REST request latencies in seconds, by venue and endpoint.
+pub const HTTP_REQUEST_DURATION: &str = "openlimits_http_request_duration_seconds";
+/// The request weight the venues report having used in their current rate limit window, by venue.
+pub const HTTP_USED_WEIGHT: &str = "openlimits_http_used_weight";
+/// Counts the websocket connections, disconnections and errors by venue and event.
+pub const WEBSOCKET_EVENTS: &str = "openlimits_websocket_events_total";
+/// Counts the websocket messages received by venue.
+pub const WEBSOCKET_MESSAGES: &str = "openlimits_websocket_messages_total";
+
+static RECORDER: Lazy<RwLock<Option<Arc<dyn MetricsRecorder>>>> = Lazy::new(Default::default);
+
+/// Installs the recorder every metric goes to, replacing the previous one.
+pub fn set_recorder(recorder: Arc<dyn MetricsRecorder>) {
+    if let Ok(mut current) = RECORDER.write() {
+        *current = Some(recorder);
+    }
+}
+
+pub fn increment_counter(name: &'static str, labels: Labels) {
+    if let Some(recorder) = recorder() {
+        recorder.increment_counter(name, labels);
+    }
+}
+
+pub fn record_histogram(name: &'static str, value: f64, labels: Labels) {
+    if let Some(recorder) = recorder() {
+        recorder.record_histogram(name, value, labels);
+    }
+}
+
+pub fn set_gauge(name: &'static str, value: f64, labels: Labels) {
+    if let Some(recorder) = recorder() {
+        recorder.set_gauge(name, value, labels);
+    }
+}
+
+fn recorder() -> Option<Arc<dyn MetricsRecorder>> {
+    RECORDER.read().ok().and_then(|recorder| recorder.clone())
+}
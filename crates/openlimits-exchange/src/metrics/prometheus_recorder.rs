@@ -0,0 +1,142 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::Mutex;
+use super::{Labels, MetricsRecorder};
+
+/// The histogram buckets, suited to latencies in seconds.
+const DEFAULT_BUCKETS: &[f64] = &[0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
+
+type Series = BTreeMap<&'static str, BTreeMap<String, Histogram>>;
+
+#[derive(Clone, Debug, Default)]
+struct Histogram {
+    buckets: Vec<u64>,
+    sum: f64,
+    count: u64,
+}
+
+/// Keeps the metrics in memory and renders them in the Prometheus text format, to be served on
+/// the scraped endpoint.
+#[derive(Debug)]
+pub struct PrometheusRecorder {
+    buckets: Vec<f64>,
+    counters: Mutex<BTreeMap<&'static str, BTreeMap<String, u64>>>,
+    gauges: Mutex<BTreeMap<&'static str, BTreeMap<String, f64>>>,
+    histograms: Mutex<Series>,
+}
+
+impl Default for PrometheusRecorder {
+    fn default() -> Self {
+        Self::with_buckets(DEFAULT_BUCKETS.to_vec())
+    }
+}
+
+impl PrometheusRecorder {
+    pub fn with_buckets(buckets: Vec<f64>) -> Self {
+        Self {
+            buckets,
+            counters: Default::default(),
+            gauges: Default::default(),
+            histograms: Default::default(),
+        }
+    }
+
+    pub fn render(&self) -> String {
+        let mut output = String::new();
+        if let Ok(counters) = self.counters.lock() {
+            for (name, series) in counters.iter() {
+                writeln!(output, "# TYPE {} counter", name).ok();
+                for (labels, value) in series {
+                    writeln!(output, "{}{} {}", name, braced(labels), value).ok();
+                }
+            }
+        }
+        if let Ok(gauges) = self.gauges.lock() {
+            for (name, series) in gauges.iter() {
+                writeln!(output, "# TYPE {} gauge", name).ok();
+                for (labels, value) in series {
+                    writeln!(output, "{}{} {}", name, braced(labels), value).ok();
+                }
+            }
+        }
+        if let Ok(histograms) = self.histograms.lock() {
+            for (name, series) in histograms.iter() {
+                writeln!(output, "# TYPE {} histogram", name).ok();
+                for (labels, histogram) in series {
+                    for (bound, count) in self.buckets.iter().zip(&histogram.buckets) {
+                        let labels = join(labels, &format!("le=\"{}\"", bound));
+                        writeln!(output, "{}_bucket{{{}}} {}", name, labels, count).ok();
+                    }
+                    let labels_inf = join(labels, "le=\"+Inf\"");
+                    writeln!(output, "{}_bucket{{{}}} {}", name, labels_inf, histogram.count).ok();
+                    writeln!(output, "{}_sum{} {}", name, braced(labels), histogram.sum).ok();
+                    writeln!(output, "{}_count{} {}", name, braced(labels), histogram.count).ok();
+                }
+            }
+        }
+        output
+    }
+}
+
+impl MetricsRecorder for PrometheusRecorder {
+    fn increment_counter(&self, name: &'static str, labels: Labels) {
+        if let Ok(mut counters) = self.counters.lock() {
+            *counters.entry(name).or_default().entry(format_labels(labels)).or_default() += 1;
+        }
+    }
+
+    fn record_histogram(&self, name: &'static str, value: f64, labels: Labels) {
+        if let Ok(mut histograms) = self.histograms.lock() {
+            let histogram = histograms
+                .entry(name)
+                .or_default()
+                .entry(format_labels(labels))
+                .or_insert_with(|| Histogram {
+                    buckets: vec![0; self.buckets.len()],
+                    ..Default::default()
+                });
+            // The buckets are cumulative, as Prometheus expects them.
+            for (bound, count) in self.buckets.iter().zip(histogram.buckets.iter_mut()) {
+                if value <= *bound {
+                    *count += 1;
+                }
+            }
+            histogram.sum += value;
+            histogram.count += 1;
+        }
+    }
+
+    fn set_gauge(&self, name: &'static str, value: f64, labels: Labels) {
+        if let Ok(mut gauges) = self.gauges.lock() {
+            gauges.entry(name).or_default().insert(format_labels(labels), value);
+        }
+    }
+}
+
+fn format_labels(labels: Labels) -> String {
+    labels
+        .iter()
+        .map(|(name, value)| format!("{}=\"{}\"", name, escape(value)))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+fn braced(labels: &str) -> String {
+    if labels.is_empty() {
+        String::new()
+    } else {
+        format!("{{{}}}", labels)
+    }
+}
+
+fn join(labels: &str, label: &str) -> String {
+    if labels.is_empty() {
+        label.to_string()
+    } else {
+        format!("{},{}", labels, label)
+    }
+}
@@ -5,7 +5,10 @@ use serde::Serialize;
 
 /// This struct represents the ask and bid
 #[derive(Serialize, Deserialize, Copy, Clone, Constructor, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 pub struct AskBid {
+    #[cfg_attr(feature = "typescript", ts(type = "string"))]
     pub price: Decimal,
+    #[cfg_attr(feature = "typescript", ts(type = "string"))]
     pub qty: Decimal,
 }
\ No newline at end of file
@@ -0,0 +1,149 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::{OnceLock, RwLock};
+use crate::errors::OpenLimitsError;
+use crate::shared::Result;
+use super::currency::Currency;
+
+/// Aliases registered by default, as (alias, canonical symbol).
+const DEFAULT_ALIASES: [(&str, &str); 2] = [("XBT", "BTC"), ("XDG", "DOGE")];
+
+/// Most symbols `parse` interns without a venue listing them, so parsed input can't grow the
+/// registry without bound.
+const MAX_UNLISTED: usize = 1024;
+
+/// Longest symbol `parse` accepts.
+const MAX_SYMBOL_LEN: usize = 20;
+
+#[derive(Default)]
+struct Assets {
+    symbols: HashSet<&'static str>,
+    unlisted: usize,
+    aliases: HashMap<String, &'static str>,
+    decimals: HashMap<(String, &'static str), u32>,
+}
+
+/// This struct represents the process-wide registry of assets. Symbols are interned once and
+/// never freed, which is bounded by the number of assets listed on the exchanges in use plus
+/// `MAX_UNLISTED` symbols parsed from other input.
+pub struct AssetRegistry {
+    assets: RwLock<Assets>,
+}
+
+impl AssetRegistry {
+    fn new() -> Self {
+        let mut assets = Assets::default();
+        let known = [
+            Currency::BTC, Currency::ETH, Currency::XRP, Currency::FIL, Currency::DASH,
+            Currency::CNY, Currency::SOL, Currency::USD, Currency::USDC, Currency::USDT,
+            Currency::BUSD,
+        ];
+        assets.symbols.extend(known.iter().map(|currency| currency.0));
+        let registry = Self { assets: RwLock::new(assets) };
+        for (alias, symbol) in DEFAULT_ALIASES.iter() {
+            let currency = registry.currency(symbol);
+            registry
+                .register_alias(alias, currency)
+                .expect("The default aliases are registered before any symbol is interned.");
+        }
+        registry
+    }
+
+    pub fn global() -> &'static AssetRegistry {
+        static REGISTRY: OnceLock<AssetRegistry> = OnceLock::new();
+        REGISTRY.get_or_init(AssetRegistry::new)
+    }
+
+    /// Resolves a symbol or alias, case-insensitively, interning it when it's new. Meant for the
+    /// symbols a venue lists; input from elsewhere goes through `parse`.
+    pub fn currency(&self, symbol: &str) -> Currency {
+        let symbol = symbol.trim().to_uppercase();
+        if let Some(currency) = self.resolve(&symbol) {
+            return currency;
+        }
+        let mut assets = self.assets.write().unwrap_or_else(|e| e.into_inner());
+        assets.intern(symbol)
+    }
+
+    /// Resolves a known symbol or alias, case-insensitively, without interning anything.
+    pub fn lookup(&self, symbol: &str) -> Option<Currency> {
+        self.resolve(&symbol.trim().to_uppercase())
+    }
+
+    /// Resolves a symbol or alias like `currency`, but only interns an unknown symbol if it is
+    /// alphanumeric, at most `MAX_SYMBOL_LEN` long and fewer than `MAX_UNLISTED` such symbols
+    /// were interned before.
+    pub fn parse(&self, symbol: &str) -> Result<Currency> {
+        let symbol = symbol.trim().to_uppercase();
+        if let Some(currency) = self.resolve(&symbol) {
+            return Ok(currency);
+        }
+        let valid = !symbol.is_empty()
+            && symbol.len() <= MAX_SYMBOL_LEN
+            && symbol.chars().all(|c| c.is_ascii_alphanumeric());
+        if !valid {
+            return Err(OpenLimitsError::InvalidParameter(format!("{:?} is not a valid currency.", symbol)));
+        }
+        let mut assets = self.assets.write().unwrap_or_else(|e| e.into_inner());
+        if !assets.symbols.contains(symbol.as_str()) {
+            if assets.unlisted >= MAX_UNLISTED {
+                return Err(OpenLimitsError::InvalidParameter(format!("{} is not a known currency.", symbol)));
+            }
+            assets.unlisted += 1;
+        }
+        Ok(assets.intern(symbol))
+    }
+
+    fn resolve(&self, symbol: &str) -> Option<Currency> {
+        let assets = self.assets.read().unwrap_or_else(|e| e.into_inner());
+        assets
+            .aliases
+            .get(symbol)
+            .copied()
+            .or_else(|| assets.symbols.get(symbol).copied())
+            .map(Currency)
+    }
+
+    /// Makes `alias` resolve to `currency`, e.g. "XBT" to BTC. Currencies are compared by their
+    /// interned symbol, so an alias already interned as a currency of its own can't be made equal
+    /// to another one and fails with `InvalidParameter`.
+    pub fn register_alias(&self, alias: &str, currency: Currency) -> Result<()> {
+        let alias = alias.trim().to_uppercase();
+        let mut assets = self.assets.write().unwrap_or_else(|e| e.into_inner());
+        if alias != currency.0 && assets.symbols.contains(alias.as_str()) {
+            return Err(OpenLimitsError::InvalidParameter(format!(
+                "{} is already a currency and can't become an alias of {}.",
+                alias, currency
+            )));
+        }
+        assets.aliases.insert(alias, currency.0);
+        Ok(())
+    }
+
+    pub fn set_decimals(&self, venue: &str, currency: Currency, decimals: u32) {
+        let mut assets = self.assets.write().unwrap_or_else(|e| e.into_inner());
+        assets.decimals.insert((venue.to_string(), currency.0), decimals);
+    }
+
+    /// Number of decimals the venue accepts for the currency, if it listed it.
+    pub fn decimals(&self, venue: &str, currency: Currency) -> Option<u32> {
+        let assets = self.assets.read().unwrap_or_else(|e| e.into_inner());
+        assets.decimals.get(&(venue.to_string(), currency.0)).copied()
+    }
+
+    pub fn currencies(&self) -> Vec<Currency> {
+        let assets = self.assets.read().unwrap_or_else(|e| e.into_inner());
+        assets.symbols.iter().map(|symbol| Currency(symbol)).collect()
+    }
+}
+
+impl Assets {
+    fn intern(&mut self, symbol: String) -> Currency {
+        // Another thread may have interned it since the read lock was released.
+        if let Some(interned) = self.symbols.get(symbol.as_str()) {
+            return Currency(interned);
+        }
+        let interned: &'static str = Box::leak(symbol.into_boxed_str());
+        self.symbols.insert(interned);
+        Currency(interned)
+    }
+}
@@ -5,8 +5,11 @@ use serde::Serialize;
 
 /// This struct represents the account balance
 #[derive(Serialize, Deserialize, Clone, Constructor, Debug, PartialEq)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 pub struct Balance {
     pub asset: String,
+    #[cfg_attr(feature = "typescript", ts(type = "string"))]
     pub total: Decimal,
+    #[cfg_attr(feature = "typescript", ts(type = "string"))]
     pub free: Decimal,
 }
\ No newline at end of file
@@ -2,14 +2,23 @@ use derive_more::Constructor;
 use rust_decimal::prelude::Decimal;
 use serde::Deserialize;
 use serde::Serialize;
+use chrono::{DateTime, Utc};
 
 /// This struct represents a candle
 #[derive(Serialize, Deserialize, Clone, Constructor, Debug, PartialEq)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 pub struct Candle {
-    pub time: u64,
+    #[serde(with = "crate::shared::timestamp")]
+    #[cfg_attr(feature = "typescript", ts(type = "string"))]
+    pub time: DateTime<Utc>,
+    #[cfg_attr(feature = "typescript", ts(type = "string"))]
     pub low: Decimal,
+    #[cfg_attr(feature = "typescript", ts(type = "string"))]
     pub high: Decimal,
+    #[cfg_attr(feature = "typescript", ts(type = "string"))]
     pub open: Decimal,
+    #[cfg_attr(feature = "typescript", ts(type = "string"))]
     pub close: Decimal,
+    #[cfg_attr(feature = "typescript", ts(type = "string"))]
     pub volume: Decimal,
 }
\ No newline at end of file
@@ -0,0 +1,108 @@
+use serde::Deserialize;
+use serde::Serialize;
+use crate::errors::{MissingImplementationContent, OpenLimitsError};
+use crate::shared::Result;
+use super::websocket::SubscriptionKind;
+use super::{Interval, OrderType, TimeInForce};
+
+/// This enum represents an operation of the exchange traits
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Operation {
+    OrderBook,
+    GetPriceTicker,
+    GetAllTickers,
+    GetHistoricRates,
+    GetHistoricTrades,
+    LimitBuy,
+    LimitSell,
+    MarketBuy,
+    MarketSell,
+    CancelOrder,
+    CancelAllOrders,
+    GetAllOpenOrders,
+    GetOrderHistory,
+    GetTradeHistory,
+    GetAccountBalances,
+    GetOrder,
+    GetLedger,
+    GetDepositAddress,
+    GetDepositHistory,
+    Withdraw,
+    GetWithdrawalHistory,
+}
+
+/// This enum represents a request a venue runs on several orders at once
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BatchOperation {
+    /// Cancels every open order of a market, or of the account, in one request.
+    CancelAllOrders,
+    /// Cancels a list of orders by id in one request.
+    CancelOrders,
+    /// Places several orders in one request.
+    PlaceOrders,
+}
+
+/// This struct represents a batch operation of a venue and how many orders one request takes.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+pub struct Batch {
+    pub operation: BatchOperation,
+    #[cfg_attr(feature = "typescript", ts(type = "number | null"))]
+    pub max_orders: Option<u64>,
+}
+
+impl Batch {
+    pub fn new(operation: BatchOperation, max_orders: Option<u64>) -> Self {
+        Self { operation, max_orders }
+    }
+}
+
+/// This struct describes what an exchange supports, so callers can plan around the gaps before
+/// sending a request. `GoodTillTime` entries list the durations the venue accepts, a zero
+/// duration meaning that any duration is accepted.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+pub struct Capabilities {
+    pub operations: Vec<Operation>,
+    pub order_types: Vec<OrderType>,
+    pub time_in_force: Vec<TimeInForce>,
+    pub intervals: Vec<Interval>,
+    pub subscriptions: Vec<SubscriptionKind>,
+    /// The operations the venue runs as one request. A `CancelAllOrders` missing here is done by
+    /// listing the open orders and canceling them.
+    pub batch_operations: Vec<Batch>,
+    #[cfg_attr(feature = "typescript", ts(type = "number | null"))]
+    pub max_book_depth: Option<u64>,
+}
+
+impl Capabilities {
+    pub fn supports(&self, operation: Operation) -> bool {
+        self.operations.contains(&operation)
+    }
+
+    pub fn batch(&self, operation: BatchOperation) -> Option<Batch> {
+        self.batch_operations.iter().copied().find(|batch| batch.operation == operation)
+    }
+
+    pub fn supports_time_in_force(&self, time_in_force: TimeInForce) -> bool {
+        self.time_in_force.iter().any(|supported| match (supported, time_in_force) {
+            (TimeInForce::GoodTillTime(supported), TimeInForce::GoodTillTime(duration)) => {
+                *supported == chrono::Duration::zero() || *supported == duration
+            }
+            (supported, time_in_force) => *supported == time_in_force,
+        })
+    }
+
+    /// Returns `MissingImplementation` when the operation isn't supported.
+    pub fn require(&self, operation: Operation) -> Result<()> {
+        if self.supports(operation) {
+            return Ok(());
+        }
+        let message = format!("{:?} is not supported by this exchange", operation);
+        Err(OpenLimitsError::MissingImplementation(MissingImplementationContent { message }))
+    }
+}
@@ -1,28 +1,95 @@
-use serde::{Serialize, Deserialize};
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt;
+use std::str::FromStr;
+use crate::errors::OpenLimitsError;
+use super::asset_registry::AssetRegistry;
+
+/// This struct represents an asset. Symbols are interned in the `AssetRegistry`, so aliases
+/// resolve to the same currency and equality and hashing only depend on the canonical symbol.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Currency(pub(crate) &'static str);
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
 #[allow(missing_docs)]
-pub enum Currency {
-    BTC,
-    ETH,
-    XRP,
-    FIL,
-    DASH,
-    CNY,
-    SOL,
-    USD,
-    USDC,
-    USDT,
-    BUSD,
-    Other(String)
+impl Currency {
+    pub const BTC: Currency = Currency("BTC");
+    pub const ETH: Currency = Currency("ETH");
+    pub const XRP: Currency = Currency("XRP");
+    pub const FIL: Currency = Currency("FIL");
+    pub const DASH: Currency = Currency("DASH");
+    pub const CNY: Currency = Currency("CNY");
+    pub const SOL: Currency = Currency("SOL");
+    pub const USD: Currency = Currency("USD");
+    pub const USDC: Currency = Currency("USDC");
+    pub const USDT: Currency = Currency("USDT");
+    pub const BUSD: Currency = Currency("BUSD");
+
+    /// Resolves the symbol, or one of its aliases, to its canonical currency, interning it when
+    /// it's new. Parse untrusted input instead, which bounds what it interns.
+    pub fn new(symbol: &str) -> Self {
+        AssetRegistry::global().currency(symbol)
+    }
+
+    /// The canonical symbol, e.g. "BTC".
+    pub fn symbol(&self) -> &'static str {
+        self.0
+    }
 }
 
 impl fmt::Display for Currency {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Self::Other(s) => write!(f, "{}", s),
-            _ => write!(f, "{:?}", self)
+        write!(f, "{}", self.0)
+    }
+}
+
+impl fmt::Debug for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for Currency {
+    type Err = OpenLimitsError;
+
+    /// Resolves a known symbol or alias, interning new symbols within the limits of
+    /// `AssetRegistry::parse`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        AssetRegistry::global().parse(s)
+    }
+}
+
+impl Serialize for Currency {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.0)
+    }
+}
+
+/// Deserializes a symbol string, resolved like `FromStr`.
+impl<'de> Deserialize<'de> for Currency {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct CurrencyVisitor;
+
+        impl<'de> Visitor<'de> for CurrencyVisitor {
+            type Value = Currency;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a currency symbol")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Currency, E>
+            where
+                E: de::Error,
+            {
+                value.parse().map_err(de::Error::custom)
+            }
         }
+
+        deserializer.deserialize_str(CurrencyVisitor)
     }
 }
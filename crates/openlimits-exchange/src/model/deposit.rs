@@ -0,0 +1,21 @@
+use derive_more::Constructor;
+use rust_decimal::prelude::Decimal;
+use serde::Deserialize;
+use serde::Serialize;
+use chrono::{DateTime, Utc};
+use super::FundingStatus;
+
+/// This struct represents a deposit
+#[derive(Serialize, Deserialize, Clone, Constructor, Debug, PartialEq)]
+pub struct Deposit {
+    pub id: String,
+    pub asset: String,
+    pub amount: Decimal,
+    pub network: Option<String>,
+    pub address: Option<String>,
+    pub tag: Option<String>,
+    pub tx_id: Option<String>,
+    pub status: FundingStatus,
+    #[serde(default, with = "crate::shared::opt_timestamp")]
+    pub created_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,12 @@
+use derive_more::Constructor;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This struct represents a deposit address
+#[derive(Serialize, Deserialize, Clone, Constructor, Debug, PartialEq)]
+pub struct DepositAddress {
+    pub asset: String,
+    pub address: String,
+    pub tag: Option<String>,
+    pub network: Option<String>,
+}
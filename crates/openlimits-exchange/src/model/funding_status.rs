@@ -0,0 +1,15 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This enum represents the status of a deposit or a withdrawal
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum FundingStatus {
+    Pending,
+    AwaitingApproval,
+    Processing,
+    Completed,
+    Canceled,
+    Rejected,
+    Failed,
+}
@@ -2,10 +2,11 @@ use chrono::Duration;
 use serde::Deserialize;
 use serde::Serialize;
 use std::convert::TryFrom;
-use crate::{OpenLimitsError, Result};
+use crate::{MissingImplementationContent, OpenLimitsError, Result};
 
 /// This enum represents a time interval
 #[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 pub enum Interval {
     #[serde(rename = "1m")]
     OneMinute,
@@ -75,10 +76,10 @@ impl TryFrom<Interval> for u32 {
             Interval::OneHour => Ok(3600),
             Interval::SixHours => Ok(21600),
             Interval::OneDay => Ok(86400),
-            _ => Err(OpenLimitsError::MissingParameter(format!(
-                "{:?} is not supported in Coinbase",
-                value,
-            ))),
+            _ => {
+                let message = format!("{:?} is not supported in Coinbase", value);
+                Err(OpenLimitsError::MissingImplementation(MissingImplementationContent { message }))
+            }
         }
     }
 }
@@ -0,0 +1,27 @@
+use derive_more::Constructor;
+use rust_decimal::prelude::Decimal;
+use serde::Deserialize;
+use serde::Serialize;
+use chrono::{DateTime, Utc};
+use super::LedgerEntryType;
+use super::market_pair::MarketPair;
+
+/// This struct represents a balance-affecting event. The amount is signed: credits are
+/// positive and debits are negative. The balance after the event is only filled when the
+/// exchange reports it.
+#[derive(Serialize, Deserialize, Clone, Constructor, Debug, PartialEq)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+pub struct LedgerEntry {
+    pub id: String,
+    pub asset: String,
+    pub entry_type: LedgerEntryType,
+    #[cfg_attr(feature = "typescript", ts(type = "string"))]
+    pub amount: Decimal,
+    #[cfg_attr(feature = "typescript", ts(type = "string | null"))]
+    pub balance: Option<Decimal>,
+    pub reference_id: Option<String>,
+    pub market_pair: Option<MarketPair>,
+    #[serde(default, with = "crate::shared::opt_timestamp")]
+    #[cfg_attr(feature = "typescript", ts(type = "string | null"))]
+    pub created_at: Option<DateTime<Utc>>,
+}
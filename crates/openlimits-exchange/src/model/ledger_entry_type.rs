@@ -0,0 +1,17 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This enum represents the kind of event that affected a balance
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum LedgerEntryType {
+    Trade,
+    Fee,
+    Rebate,
+    Deposit,
+    Withdrawal,
+    Transfer,
+    Conversion,
+    Other,
+}
@@ -3,6 +3,7 @@ use serde::Serialize;
 
 /// This enum represents the liquidity
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum Liquidity {
     Maker,
@@ -1,12 +1,39 @@
 use serde::{Serialize, Deserialize};
+use std::fmt;
+use std::str::FromStr;
+use crate::errors::OpenLimitsError;
 pub use crate::model::currency::Currency;
 
+/// Separators accepted when parsing a market pair.
+const SEPARATORS: [char; 3] = ['-', '_', '/'];
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 #[allow(missing_docs)]
 pub struct MarketPair(pub Currency, pub Currency);
 
 impl MarketPair {
     pub fn inverse(&self) -> MarketPair {
-        MarketPair(self.1.clone(), self.0.clone())
+        MarketPair(self.1, self.0)
+    }
+}
+
+impl fmt::Display for MarketPair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.0, self.1)
+    }
+}
+
+/// Parses symbols with a separator, like "BTC-USD", "eth_btc" or "BTC/USD". Symbols without a
+/// separator, like "btcusdt", can only be resolved with `ExchangeInfo::market_pair`.
+impl FromStr for MarketPair {
+    type Err = OpenLimitsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split(&SEPARATORS[..]);
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(base), Some(quote), None) => Ok(MarketPair(base.parse()?, quote.parse()?)),
+            _ => Err(OpenLimitsError::InvalidParameter(format!("{:?} is not a valid market pair.", s))),
+        }
     }
-}
\ No newline at end of file
+}
@@ -2,9 +2,16 @@
 
 pub mod request;
 pub mod ask_bid;
+pub mod asset_registry;
 pub mod balance;
 pub mod candle;
+pub mod capabilities;
+pub mod deposit;
+pub mod deposit_address;
+pub mod funding_status;
 pub mod interval;
+pub mod ledger_entry;
+pub mod ledger_entry_type;
 pub mod liquidity;
 pub mod order_canceled;
 pub mod order_filter;
@@ -13,19 +20,29 @@ pub mod order_type;
 pub mod order;
 pub mod paginator;
 pub mod side;
+pub mod symbol_format;
 pub mod ticker;
 pub mod time_in_force_visitor;
 pub mod time_in_force;
 pub mod trade;
 pub mod transaction;
+pub mod withdrawal;
 pub mod currency;
 pub mod market_pair;
 
 pub use request::*;
 pub use ask_bid::AskBid;
+pub use asset_registry::AssetRegistry;
 pub use balance::Balance;
 pub use candle::Candle;
+pub use capabilities::{Batch, BatchOperation, Capabilities, Operation};
+pub use currency::Currency;
+pub use deposit::Deposit;
+pub use deposit_address::DepositAddress;
+pub use funding_status::FundingStatus;
 pub use interval::Interval;
+pub use ledger_entry::LedgerEntry;
+pub use ledger_entry_type::LedgerEntryType;
 pub use liquidity::Liquidity;
 pub use order_canceled::OrderCanceled;
 pub use order_filter::OrderFilter;
@@ -34,12 +51,16 @@ pub use order_type::OrderType;
 pub use order::Order;
 pub use paginator::Paginator;
 pub use side::Side;
+pub use symbol_format::SymbolFormat;
 pub use ticker::Ticker;
 pub use time_in_force_visitor::TimeInForceVisitor;
 pub use time_in_force::TimeInForce;
 pub use trade::Trade;
 pub use transaction::Transaction;
+pub use withdrawal::Withdrawal;
 
 #[cfg(feature = "python")]
 pub mod python;
+#[cfg(feature = "typescript")]
+pub mod typescript;
 pub mod websocket;
@@ -2,23 +2,31 @@ use derive_more::Constructor;
 use rust_decimal::prelude::Decimal;
 use serde::Deserialize;
 use serde::Serialize;
+use chrono::{DateTime, Utc};
 use super::OrderStatus;
 use super::OrderType;
 use super::Side;
 use super::Trade;
+use super::market_pair::MarketPair;
 
 /// This struct represents an order
 #[derive(Serialize, Deserialize, Clone, Constructor, Debug)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 pub struct Order {
     pub id: String,
-    pub market_pair: String,
+    pub market_pair: MarketPair,
     pub client_order_id: Option<String>,
-    pub created_at: Option<u64>,
+    #[serde(default, with = "crate::shared::opt_timestamp")]
+    #[cfg_attr(feature = "typescript", ts(type = "string | null"))]
+    pub created_at: Option<DateTime<Utc>>,
     pub order_type: OrderType,
     pub side: Side,
     pub status: OrderStatus,
+    #[cfg_attr(feature = "typescript", ts(type = "string"))]
     pub size: Decimal,
+    #[cfg_attr(feature = "typescript", ts(type = "string | null"))]
     pub price: Option<Decimal>,
+    #[cfg_attr(feature = "typescript", ts(type = "string | null"))]
     pub remaining: Option<Decimal>,
     pub trades: Vec<Trade>,
 }
\ No newline at end of file
@@ -4,6 +4,7 @@ use serde::Serialize;
 
 /// This struct represents a canceled order
 #[derive(Serialize, Deserialize, Clone, Constructor, Debug)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 pub struct OrderCanceled {
     pub id: String,
 }
@@ -4,18 +4,20 @@ use serde::Serialize;
 use super::OrderStatus;
 use super::OrderType;
 use super::Side;
+use super::market_pair::MarketPair;
 use std::ops::Range;
+use chrono::{DateTime, Utc};
 
 /// This struct represents an order
 #[derive(Default, Serialize, Deserialize, Clone, Debug)]
 #[allow(missing_docs)]
 pub struct OrderFilter {
-    pub market_pair: Option<String>,
+    pub market_pair: Option<MarketPair>,
     pub client_order_id: Option<String>,
     pub order_type: Option<OrderType>,
     pub side: Option<Side>,
     pub status: Option<OrderStatus>,
-    pub created_at: Option<Range<u64>>,
+    pub created_at: Option<Range<DateTime<Utc>>>,
     pub size: Option<Range<Decimal>>,
     pub price: Option<Range<Decimal>>,
     pub remaining: Option<Range<Decimal>>,
@@ -28,7 +30,7 @@ impl OrderFilter {
     }
 
     /// Set market pair.
-    pub fn with_market_pair(mut self, market_pair: Option<String>) -> Self {
+    pub fn with_market_pair(mut self, market_pair: Option<MarketPair>) -> Self {
         self.market_pair = market_pair;
         self
     }
@@ -58,7 +60,7 @@ impl OrderFilter {
     }
 
     /// Set creation time.
-    pub fn with_created_at(mut self, created_at: Option<Range<u64>>) -> Self {
+    pub fn with_created_at(mut self, created_at: Option<Range<DateTime<Utc>>>) -> Self {
         self.created_at = created_at;
         self
     }
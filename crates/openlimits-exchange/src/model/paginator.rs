@@ -1,12 +1,19 @@
 use serde::Deserialize;
 use serde::Serialize;
+use chrono::{DateTime, Utc};
 
 /// This struct represents a paginator
 #[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS), ts(optional_fields = nullable))]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub struct Paginator {
-    pub start_time: Option<u64>,
-    pub end_time: Option<u64>,
+    #[serde(default, with = "crate::shared::opt_timestamp")]
+    #[cfg_attr(feature = "typescript", ts(type = "string | number | null", optional))]
+    pub start_time: Option<DateTime<Utc>>,
+    #[serde(default, with = "crate::shared::opt_timestamp")]
+    #[cfg_attr(feature = "typescript", ts(type = "string | number | null", optional))]
+    pub end_time: Option<DateTime<Utc>>,
+    #[cfg_attr(feature = "typescript", ts(as = "Option<f64>"))]
     pub limit: Option<u64>,
     pub before: Option<String>,
     pub after: Option<String>,
@@ -5,6 +5,7 @@ use crate::model::market_pair::MarketPair;
 
 /// This struct represents the cancellation of all orders
 #[derive(Serialize, Deserialize, Clone, Constructor, Debug, PartialEq)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS), ts(optional_fields = nullable))]
 pub struct CancelAllOrdersRequest {
     pub market_pair: Option<MarketPair>,
 }
\ No newline at end of file
@@ -0,0 +1,11 @@
+use derive_more::Constructor;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This struct represents a deposit address request. If no network is given the
+/// exchange default network for the asset is used.
+#[derive(Serialize, Deserialize, Clone, Constructor, Debug, PartialEq)]
+pub struct GetDepositAddressRequest {
+    pub asset: String,
+    pub network: Option<String>,
+}
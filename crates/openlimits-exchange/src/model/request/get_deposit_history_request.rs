@@ -0,0 +1,11 @@
+use derive_more::Constructor;
+use serde::Deserialize;
+use serde::Serialize;
+use crate::model::Paginator;
+
+/// This struct represents the historic of the deposits
+#[derive(Serialize, Deserialize, Clone, Constructor, Debug, Default)]
+pub struct GetDepositHistoryRequest {
+    pub asset: Option<String>,
+    pub paginator: Option<Paginator>,
+}
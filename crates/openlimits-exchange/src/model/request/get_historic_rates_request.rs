@@ -6,6 +6,7 @@ use crate::model::market_pair::MarketPair;
 
 /// This struct represents the historic of the rates
 #[derive(Serialize, Deserialize, Clone, Constructor, Debug)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS), ts(optional_fields = nullable))]
 pub struct GetHistoricRatesRequest {
     pub market_pair: MarketPair,
     pub paginator: Option<Paginator>,
@@ -5,6 +5,7 @@ use crate::model::Paginator;
 
 /// This struct represents the historic of the trades
 #[derive(Serialize, Deserialize, Clone, Constructor, Debug)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS), ts(optional_fields = nullable))]
 pub struct GetHistoricTradesRequest {
     pub market_pair: String,
     pub paginator: Option<Paginator>,
@@ -0,0 +1,15 @@
+use derive_more::Constructor;
+use serde::Deserialize;
+use serde::Serialize;
+use crate::model::Paginator;
+use crate::model::market_pair::MarketPair;
+
+/// This struct represents the ledger of an asset. The market only keeps its trades and fees,
+/// and Binance, which lists the account trades per market, requires one.
+#[derive(Serialize, Deserialize, Clone, Constructor, Debug)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS), ts(optional_fields = nullable))]
+pub struct GetLedgerRequest {
+    pub asset: String,
+    pub market_pair: Option<MarketPair>,
+    pub paginator: Option<Paginator>,
+}
@@ -6,6 +6,7 @@ use crate::model::market_pair::MarketPair;
 
 /// This struct represents the historic of the orders
 #[derive(Serialize, Deserialize, Clone, Constructor, Debug)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS), ts(optional_fields = nullable))]
 pub struct GetOrderHistoryRequest {
     pub market_pair: Option<MarketPair>,
     pub order_status: Option<Vec<OrderStatus>>,
@@ -1,10 +1,12 @@
 use derive_more::Constructor;
 use serde::Deserialize;
 use serde::Serialize;
+use crate::model::market_pair::MarketPair;
 
 /// This struct represents an order request
 #[derive(Serialize, Deserialize, Clone, Constructor, Debug)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS), ts(optional_fields = nullable))]
 pub struct GetOrderRequest {
     pub id: String,
-    pub market_pair: Option<String>,
+    pub market_pair: Option<MarketPair>,
 }
\ No newline at end of file
@@ -5,6 +5,7 @@ use crate::model::market_pair::MarketPair;
 
 /// This struct represents the ticker price.
 #[derive(Serialize, Deserialize, Clone, Constructor, Debug, PartialEq)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS), ts(optional_fields = nullable))]
 pub struct GetPriceTickerRequest {
     pub market_pair: MarketPair,
 }
\ No newline at end of file
@@ -0,0 +1,11 @@
+use derive_more::Constructor;
+use serde::Deserialize;
+use serde::Serialize;
+use crate::model::Paginator;
+
+/// This struct represents the historic of the withdrawals
+#[derive(Serialize, Deserialize, Clone, Constructor, Debug, Default)]
+pub struct GetWithdrawalHistoryRequest {
+    pub asset: Option<String>,
+    pub paginator: Option<Paginator>,
+}
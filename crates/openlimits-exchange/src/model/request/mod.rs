@@ -2,26 +2,36 @@
 
 mod cancel_all_order_request;
 mod cancel_order_request;
+mod get_deposit_address_request;
+mod get_deposit_history_request;
 mod get_historic_rates_request;
 mod get_historic_trades_request;
+mod get_ledger_request;
 mod get_order_history_request;
 mod get_order_request;
 mod get_price_ticker_request;
+mod get_withdrawal_history_request;
 mod open_limit_order_request;
 mod open_market_order_request;
 mod order_book_request;
 mod order_book_response;
 mod trade_history_request;
+mod withdraw_request;
 
 pub use cancel_all_order_request::CancelAllOrdersRequest;
 pub use cancel_order_request::CancelOrderRequest;
+pub use get_deposit_address_request::GetDepositAddressRequest;
+pub use get_deposit_history_request::GetDepositHistoryRequest;
 pub use get_historic_rates_request::GetHistoricRatesRequest;
 pub use get_historic_trades_request::GetHistoricTradesRequest;
+pub use get_ledger_request::GetLedgerRequest;
 pub use get_order_history_request::GetOrderHistoryRequest;
 pub use get_order_request::GetOrderRequest;
 pub use get_price_ticker_request::GetPriceTickerRequest;
+pub use get_withdrawal_history_request::GetWithdrawalHistoryRequest;
 pub use open_limit_order_request::OpenLimitOrderRequest;
 pub use open_market_order_request::OpenMarketOrderRequest;
 pub use order_book_request::OrderBookRequest;
 pub use order_book_response::OrderBookResponse;
-pub use trade_history_request::TradeHistoryRequest;
\ No newline at end of file
+pub use trade_history_request::TradeHistoryRequest;
+pub use withdraw_request::WithdrawRequest;
\ No newline at end of file
@@ -7,10 +7,13 @@ use crate::model::market_pair::MarketPair;
 
 /// This struct represents an open limit order
 #[derive(Serialize, Deserialize, Clone, Constructor, Debug, PartialEq)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS), ts(optional_fields = nullable))]
 pub struct OpenLimitOrderRequest {
     pub client_order_id: Option<String>,
     pub market_pair: MarketPair,
+    #[cfg_attr(feature = "typescript", ts(type = "string"))]
     pub size: Decimal,
+    #[cfg_attr(feature = "typescript", ts(type = "string"))]
     pub price: Decimal,
     pub time_in_force: TimeInForce,
     pub post_only: bool,
@@ -6,8 +6,10 @@ use crate::model::market_pair::MarketPair;
 
 /// This struct represents an open market order
 #[derive(Serialize, Deserialize, Clone, Constructor, Debug, PartialEq)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS), ts(optional_fields = nullable))]
 pub struct OpenMarketOrderRequest {
     pub client_order_id: Option<String>,
     pub market_pair: MarketPair,
+    #[cfg_attr(feature = "typescript", ts(type = "string"))]
     pub size: Decimal,
 }
\ No newline at end of file
@@ -5,8 +5,11 @@ use crate::model::AskBid;
 
 /// This struct represents an order book response
 #[derive(Serialize, Deserialize, Clone, Constructor, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 pub struct OrderBookResponse {
+    #[cfg_attr(feature = "typescript", ts(type = "number | null"))]
     pub update_id: Option<u64>,
+    #[cfg_attr(feature = "typescript", ts(type = "number | null"))]
     pub last_update_id: Option<u64>,
     pub bids: Vec<AskBid>,
     pub asks: Vec<AskBid>,
@@ -5,6 +5,7 @@ use crate::model::market_pair::MarketPair;
 
 /// This struct represents the trade history
 #[derive(Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS), ts(optional_fields = nullable))]
 pub struct TradeHistoryRequest {
     pub market_pair: Option<MarketPair>,
     pub order_id: Option<String>,
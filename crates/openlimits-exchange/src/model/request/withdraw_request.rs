@@ -0,0 +1,16 @@
+use derive_more::Constructor;
+use rust_decimal::prelude::Decimal;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This struct represents a withdrawal request. The tag is the memo/destination tag
+/// required by some assets and the network selects the chain used for the transfer.
+#[derive(Serialize, Deserialize, Clone, Constructor, Debug, PartialEq)]
+pub struct WithdrawRequest {
+    pub client_withdrawal_id: Option<String>,
+    pub asset: String,
+    pub amount: Decimal,
+    pub address: String,
+    pub tag: Option<String>,
+    pub network: Option<String>,
+}
@@ -0,0 +1,41 @@
+use crate::errors::OpenLimitsError;
+use crate::shared::Result;
+use super::market_pair::MarketPair;
+
+/// This struct represents how an exchange writes the symbol of a market pair
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SymbolFormat {
+    pub separator: Option<char>,
+    pub lowercase: bool,
+}
+
+impl SymbolFormat {
+    pub const fn new(separator: Option<char>, lowercase: bool) -> Self {
+        Self { separator, lowercase }
+    }
+
+    pub fn format(&self, market_pair: &MarketPair) -> String {
+        let symbol = match self.separator {
+            Some(separator) => format!("{}{}{}", market_pair.0, separator, market_pair.1),
+            None => format!("{}{}", market_pair.0, market_pair.1),
+        };
+        if self.lowercase {
+            symbol.to_lowercase()
+        } else {
+            symbol.to_uppercase()
+        }
+    }
+
+    /// Parses a symbol written in this format. Formats without a separator need the exchange
+    /// information to tell the base from the quote, see `ExchangeInfo::market_pair`.
+    pub fn parse(&self, symbol: &str) -> Result<MarketPair> {
+        let separator = self.separator.ok_or_else(|| {
+            OpenLimitsError::InvalidParameter(format!("{:?} has no separator to split on.", symbol))
+        })?;
+        let mut parts = symbol.split(separator);
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(base), Some(quote), None) => Ok(MarketPair(base.parse()?, quote.parse()?)),
+            _ => Err(OpenLimitsError::InvalidParameter(format!("{:?} is not a valid symbol.", symbol))),
+        }
+    }
+}
@@ -1,11 +1,36 @@
-use derive_more::Constructor;
 use rust_decimal::prelude::Decimal;
 use serde::Deserialize;
 use serde::Serialize;
+use chrono::{DateTime, Utc};
+use super::market_pair::MarketPair;
 
-/// This struct represents a ticker
-#[derive(Serialize, Deserialize, Clone, Constructor, Debug, PartialEq)]
+/// This struct represents a ticker. `price_24h` is the price 24 hours ago, that is the open of the
+/// rolling 24h window that `high_24h`, `low_24h` and the volumes cover.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 pub struct Ticker {
+    pub market_pair: MarketPair,
+    #[cfg_attr(feature = "typescript", ts(type = "string | null"))]
     pub price: Option<Decimal>,
+    #[cfg_attr(feature = "typescript", ts(type = "string | null"))]
     pub price_24h: Option<Decimal>,
-}
\ No newline at end of file
+    #[cfg_attr(feature = "typescript", ts(type = "string | null"))]
+    pub bid: Option<Decimal>,
+    #[cfg_attr(feature = "typescript", ts(type = "string | null"))]
+    pub bid_qty: Option<Decimal>,
+    #[cfg_attr(feature = "typescript", ts(type = "string | null"))]
+    pub ask: Option<Decimal>,
+    #[cfg_attr(feature = "typescript", ts(type = "string | null"))]
+    pub ask_qty: Option<Decimal>,
+    #[cfg_attr(feature = "typescript", ts(type = "string | null"))]
+    pub high_24h: Option<Decimal>,
+    #[cfg_attr(feature = "typescript", ts(type = "string | null"))]
+    pub low_24h: Option<Decimal>,
+    #[cfg_attr(feature = "typescript", ts(type = "string | null"))]
+    pub volume_24h: Option<Decimal>,
+    #[cfg_attr(feature = "typescript", ts(type = "string | null"))]
+    pub quote_volume_24h: Option<Decimal>,
+    #[serde(default, with = "crate::shared::opt_timestamp")]
+    #[cfg_attr(feature = "typescript", ts(type = "string | null"))]
+    pub created_at: Option<DateTime<Utc>>,
+}
@@ -2,20 +2,28 @@ use derive_more::Constructor;
 use rust_decimal::prelude::Decimal;
 use serde::Deserialize;
 use serde::Serialize;
+use chrono::{DateTime, Utc};
 use super::Liquidity;
 use super::Side;
+use super::market_pair::MarketPair;
 
 /// This struct represents a trade
 #[derive(Serialize, Deserialize, Clone, Constructor, Debug)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 pub struct Trade {
     pub id: String,
     pub buyer_order_id: Option<String>,
     pub seller_order_id: Option<String>,
-    pub market_pair: String,
+    pub market_pair: MarketPair,
+    #[cfg_attr(feature = "typescript", ts(type = "string"))]
     pub price: Decimal,
+    #[cfg_attr(feature = "typescript", ts(type = "string"))]
     pub qty: Decimal,
+    #[cfg_attr(feature = "typescript", ts(type = "string | null"))]
     pub fees: Option<Decimal>,
     pub side: Side,
     pub liquidity: Option<Liquidity>,
-    pub created_at: String,
+    #[serde(with = "crate::shared::timestamp")]
+    #[cfg_attr(feature = "typescript", ts(type = "string"))]
+    pub created_at: DateTime<Utc>,
 }
\ No newline at end of file
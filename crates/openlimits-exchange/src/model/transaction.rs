@@ -1,6 +1,7 @@
 use derive_more::Constructor;
 use serde::Deserialize;
 use serde::Serialize;
+use chrono::{DateTime, Utc};
 
 /// This struct represents a transaction
 #[derive(Serialize, Deserialize, Clone, Constructor, Debug)]
@@ -8,5 +9,6 @@ pub struct Transaction<T> {
     pub id: T,
     pub market_pair: String,
     pub client_order_id: Option<String>,
-    pub created_at: u64,
+    #[serde(with = "crate::shared::timestamp")]
+    pub created_at: DateTime<Utc>,
 }
@@ -0,0 +1,119 @@
+//! TypeScript declarations of the model, enabled by the `typescript` feature.
+//!
+//! The declarations describe the JSON of the models, which the Node.js bindings hand over as is:
+//! decimals and timestamps are strings and market pairs are `[base, quote]`.
+
+use super::websocket::{OpenLimitsWebSocketMessage, Subscription, SubscriptionKind};
+use super::{
+    AskBid, Balance, Batch, BatchOperation, CancelAllOrdersRequest, CancelOrderRequest, Candle,
+    Capabilities, GetHistoricRatesRequest, GetHistoricTradesRequest, GetLedgerRequest,
+    GetOrderHistoryRequest, GetOrderRequest, GetPriceTickerRequest, Interval, LedgerEntry,
+    LedgerEntryType, Liquidity, OpenLimitOrderRequest, OpenMarketOrderRequest, Operation, Order,
+    OrderBookRequest, OrderBookResponse, OrderCanceled, OrderStatus, OrderType, Paginator, Side,
+    Ticker, TimeInForce, Trade, TradeHistoryRequest,
+};
+use crate::model::market_pair::{Currency, MarketPair};
+use crate::traits::info::MarketPairInfo;
+use ts_rs::TS;
+
+/// Currencies are their symbols, such as "BTC".
+impl TS for Currency {
+    type WithoutGenerics = Self;
+    type OptionInnerType = Self;
+
+    fn name() -> String {
+        "string".into()
+    }
+
+    fn inline() -> String {
+        Self::name()
+    }
+
+    fn inline_flattened() -> String {
+        panic!("Currency cannot be flattened")
+    }
+
+    fn decl() -> String {
+        panic!("Currency cannot be declared")
+    }
+
+    fn decl_concrete() -> String {
+        panic!("Currency cannot be declared")
+    }
+}
+
+/// The time in force is serialized by hand, as "GTC", "IOC", "FOK" or "GTT,<milliseconds>".
+impl TS for TimeInForce {
+    type WithoutGenerics = Self;
+    type OptionInnerType = Self;
+
+    fn name() -> String {
+        "TimeInForce".into()
+    }
+
+    fn inline() -> String {
+        "\"GTC\" | \"IOC\" | \"FOK\" | `GTT,${number}`".into()
+    }
+
+    fn inline_flattened() -> String {
+        panic!("TimeInForce cannot be flattened")
+    }
+
+    fn decl() -> String {
+        format!("type TimeInForce = {};", Self::inline())
+    }
+
+    fn decl_concrete() -> String {
+        Self::decl()
+    }
+}
+
+macro_rules! declarations {
+    ($($model:ty),* $(,)?) => {
+        vec![$(format!("export {}", <$model as TS>::decl())),*]
+    };
+}
+
+/// The declarations of the models exchanged with the clients, one per line.
+pub fn declarations() -> Vec<String> {
+    declarations!(
+        MarketPair,
+        Side,
+        OrderType,
+        OrderStatus,
+        Liquidity,
+        TimeInForce,
+        Interval,
+        Operation,
+        BatchOperation,
+        LedgerEntryType,
+        SubscriptionKind,
+        Paginator,
+        AskBid,
+        Balance,
+        Batch,
+        Candle,
+        Capabilities,
+        LedgerEntry,
+        MarketPairInfo,
+        Order,
+        OrderBookResponse,
+        OrderCanceled,
+        Ticker,
+        Trade,
+        Subscription,
+        OpenLimitsWebSocketMessage,
+        CancelAllOrdersRequest,
+        CancelOrderRequest,
+        GetHistoricRatesRequest,
+        GetHistoricTradesRequest,
+        GetLedgerRequest,
+        GetOrderHistoryRequest,
+        GetOrderRequest,
+        GetPriceTickerRequest,
+        OpenLimitOrderRequest,
+        OpenMarketOrderRequest,
+        OrderBookRequest,
+        TradeHistoryRequest,
+    )
+}
@@ -16,6 +16,7 @@ pub struct AccountOrders {
 
 /// This enum represents a subscription
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 pub enum Subscription {
     // Ticker(MarketPair),           // symbol
     OrderBookUpdates(MarketPair), // symbol
@@ -25,6 +26,23 @@ pub enum Subscription {
     // AccountOrders(AccountOrders),
 }
 
+impl Subscription {
+    pub fn kind(&self) -> SubscriptionKind {
+        match self {
+            Subscription::OrderBookUpdates(_) => SubscriptionKind::OrderBookUpdates,
+            Subscription::Trades(_) => SubscriptionKind::Trades,
+        }
+    }
+}
+
+/// This enum represents the kind of a subscription, regardless of its market
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+pub enum SubscriptionKind {
+    OrderBookUpdates,
+    Trades,
+}
+
 /// This enum represents a websocket response
 #[derive(Debug, Clone)]
 pub enum WebSocketResponse<T> {
@@ -34,6 +52,7 @@ pub enum WebSocketResponse<T> {
 
 /// This enum represents a websocket message type
 #[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 pub enum OpenLimitsWebSocketMessage {
     Ping,
     OrderBook(OrderBookResponse),
@@ -0,0 +1,23 @@
+use derive_more::Constructor;
+use rust_decimal::prelude::Decimal;
+use serde::Deserialize;
+use serde::Serialize;
+use chrono::{DateTime, Utc};
+use super::FundingStatus;
+
+/// This struct represents a withdrawal
+#[derive(Serialize, Deserialize, Clone, Constructor, Debug, PartialEq)]
+pub struct Withdrawal {
+    pub id: String,
+    pub client_withdrawal_id: Option<String>,
+    pub asset: String,
+    pub amount: Decimal,
+    pub fee: Option<Decimal>,
+    pub network: Option<String>,
+    pub address: String,
+    pub tag: Option<String>,
+    pub tx_id: Option<String>,
+    pub status: FundingStatus,
+    #[serde(default, with = "crate::shared::opt_timestamp")]
+    pub created_at: Option<DateTime<Utc>>,
+}
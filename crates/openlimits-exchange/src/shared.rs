@@ -129,14 +129,95 @@ pub mod opt_naive_datetime_from_string {
     }
 }
 
-pub fn timestamp_to_naive_datetime(timestamp: u64) -> chrono::naive::NaiveDateTime {
-    let seconds = (timestamp / 1000) as i64;
-    let nanos = ((timestamp % 1000) * 1_000_000) as u32;
+/// Serializes a timestamp as RFC 3339 with milliseconds. Deserializes that, integer
+/// milliseconds and the string formats the generic model used before.
+pub mod timestamp {
+    use chrono::{DateTime, SecondsFormat, Utc};
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(value: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+    {
+        serializer.serialize_str(&value.to_rfc3339_opts(SecondsFormat::Millis, true))
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+        where
+            D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Timestamp {
+            Millis(u64),
+            String(String),
+        }
+
+        match Timestamp::deserialize(deserializer)? {
+            Timestamp::Millis(millis) => Ok(super::timestamp_to_utc_datetime(millis)),
+            Timestamp::String(s) => super::parse_timestamp(&s)
+                .ok_or_else(|| de::Error::custom(format!("{:?} is not a valid timestamp", s))),
+        }
+    }
+}
+
+pub mod opt_timestamp {
+    use chrono::{DateTime, Utc};
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(value: &Option<DateTime<Utc>>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+    {
+        if let Some(value) = value {
+            return super::timestamp::serialize(value, serializer);
+        }
+        serializer.serialize_none()
+    }
 
-    chrono::NaiveDateTime::from_timestamp(seconds, nanos)
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<DateTime<Utc>>, D::Error>
+        where
+            D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Timestamp(#[serde(with = "super::timestamp")] DateTime<Utc>);
+
+        let timestamp = Option::<Timestamp>::deserialize(deserializer)?;
+        Ok(timestamp.map(|Timestamp(timestamp)| timestamp))
+    }
+}
+
+/// Parses milliseconds, RFC 3339 and "yyyy-mm-dd hh:mm:ss[.fff]" (UTC) strings, truncating them
+/// to millisecond precision.
+pub fn parse_timestamp(s: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+    if let Ok(millis) = s.parse::<u64>() {
+        return Some(timestamp_to_utc_datetime(millis));
+    }
+    let datetime = chrono::DateTime::parse_from_rfc3339(s)
+        .map(|datetime| datetime.with_timezone(&chrono::Utc))
+        .or_else(|_| {
+            chrono::NaiveDateTime::parse_from_str(s.trim_end_matches(" UTC"), "%Y-%m-%d %H:%M:%S%.f")
+                .map(|datetime| datetime.and_utc())
+        })
+        .ok()?;
+    Some(truncate_to_millis(datetime))
+}
+
+pub fn truncate_to_millis(datetime: chrono::DateTime<chrono::Utc>) -> chrono::DateTime<chrono::Utc> {
+    timestamp_to_utc_datetime(datetime.timestamp_millis() as u64)
+}
+
+pub fn datetime_to_timestamp(datetime: &chrono::DateTime<chrono::Utc>) -> u64 {
+    datetime.timestamp_millis() as u64
+}
+
+pub fn timestamp_to_naive_datetime(timestamp: u64) -> chrono::naive::NaiveDateTime {
+    timestamp_to_utc_datetime(timestamp).naive_utc()
 }
 
 pub fn timestamp_to_utc_datetime(timestamp: u64) -> chrono::DateTime<chrono::Utc> {
-    let d = timestamp_to_naive_datetime(timestamp);
-    chrono::DateTime::<chrono::Utc>::from_utc(d, chrono::Utc)
+    let seconds = (timestamp / 1000) as i64;
+    let nanos = ((timestamp % 1000) * 1_000_000) as u32;
+
+    chrono::DateTime::from_timestamp(seconds, nanos).expect("invalid or out-of-range datetime")
 }
@@ -0,0 +1,38 @@
+use std::fmt;
+use async_trait::async_trait;
+use hmac::{Hmac, Mac, NewMac};
+use sha2::Sha256;
+use zeroize::Zeroizing;
+use super::RequestSigner;
+use super::shared::Result;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Signs with a secret kept in memory, which is overwritten with zeros when the signer is dropped.
+pub struct HmacSigner {
+    secret: Zeroizing<Vec<u8>>,
+}
+
+impl HmacSigner {
+    pub fn new(secret: impl Into<Vec<u8>>) -> Self {
+        Self {
+            secret: Zeroizing::new(secret.into()),
+        }
+    }
+}
+
+impl fmt::Debug for HmacSigner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HmacSigner").field("secret", &"<redacted>").finish()
+    }
+}
+
+#[async_trait]
+impl RequestSigner for HmacSigner {
+    async fn sign(&self, message: &[u8]) -> Result<Vec<u8>> {
+        let mut mac =
+            HmacSha256::new_varkey(&self.secret).expect("Couldn't construct hmac from bytes.");
+        mac.update(message);
+        Ok(mac.finalize().into_bytes().to_vec())
+    }
+}
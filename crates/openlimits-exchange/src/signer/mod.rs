@@ -0,0 +1,15 @@
+//! This module abstracts how the exchanges sign their authenticated requests, so the API secret
+//! can be kept out of the trading process. `HmacSigner` keeps it in memory and clears it on drop,
+//! while `SocketSigner` asks another process holding it to sign. Browsers can't open raw sockets,
+//! so `SocketSigner` isn't available on wasm32.
+
+mod hmac_signer;
+mod request_signer;
+#[cfg(not(target_arch = "wasm32"))]
+mod socket_signer;
+
+pub use hmac_signer::HmacSigner;
+pub use request_signer::RequestSigner;
+#[cfg(not(target_arch = "wasm32"))]
+pub use socket_signer::{SignerAddress, SocketSigner};
+pub use super::shared;
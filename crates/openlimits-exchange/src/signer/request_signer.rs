@@ -0,0 +1,10 @@
+use std::fmt::Debug;
+use async_trait::async_trait;
+use super::shared::Result;
+
+/// Signs the messages the exchanges build from their requests. The signature is the raw
+/// HMAC-SHA256 of the message, which the exchanges encode as their APIs expect.
+#[async_trait]
+pub trait RequestSigner: Debug + Send + Sync {
+    async fn sign(&self, message: &[u8]) -> Result<Vec<u8>>;
+}
@@ -0,0 +1,104 @@
+use std::net::SocketAddr;
+#[cfg(unix)]
+use std::path::PathBuf;
+use std::time::Duration;
+use async_trait::async_trait;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::net::TcpStream;
+#[cfg(unix)]
+use tokio::net::UnixStream;
+use super::RequestSigner;
+use super::shared::Result;
+use crate::errors::OpenLimitsError;
+
+/// Longest signature read back, well above the 64 bytes of an HMAC-SHA512 or Ed25519 signature.
+const MAX_SIGNATURE_LEN: usize = 1024;
+
+/// How long a signature can take, connection included, unless set with `with_timeout`.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Where the signing process listens.
+#[derive(Clone, Debug)]
+pub enum SignerAddress {
+    Tcp(SocketAddr),
+    #[cfg(unix)]
+    Unix(PathBuf),
+}
+
+/// Asks a signing process listening on a local socket to sign, so the secret never enters this
+/// process.
+///
+/// Every signature opens a connection, writes the key id and then the message, and reads the
+/// signature back. Each of them is framed by its length as a big endian `u32`, and the signing
+/// process answers an empty signature to refuse. A signature longer than 1 KiB, or one that
+/// takes longer than the timeout, fails the request.
+#[derive(Clone, Debug)]
+pub struct SocketSigner {
+    address: SignerAddress,
+    key_id: String,
+    timeout: Duration,
+}
+
+impl SocketSigner {
+    pub fn new(address: SignerAddress, key_id: impl Into<String>) -> Self {
+        Self {
+            address,
+            key_id: key_id.into(),
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        Self { timeout, ..self }
+    }
+
+    async fn connect_and_exchange(&self, message: &[u8]) -> Result<Vec<u8>> {
+        match &self.address {
+            SignerAddress::Tcp(address) => {
+                self.exchange(TcpStream::connect(address).await?, message).await
+            }
+            #[cfg(unix)]
+            SignerAddress::Unix(path) => {
+                self.exchange(UnixStream::connect(path).await?, message).await
+            }
+        }
+    }
+
+    async fn exchange<S>(&self, mut stream: S, message: &[u8]) -> Result<Vec<u8>>
+    where
+        S: AsyncRead + AsyncWrite + Unpin,
+    {
+        write_frame(&mut stream, self.key_id.as_bytes()).await?;
+        write_frame(&mut stream, message).await?;
+        stream.flush().await?;
+
+        let length = stream.read_u32().await? as usize;
+        if length > MAX_SIGNATURE_LEN {
+            return Err(OpenLimitsError::NotParsableResponse(format!(
+                "The signing process answered a signature of {} bytes",
+                length
+            )));
+        }
+        let mut signature = vec![0; length];
+        stream.read_exact(&mut signature).await?;
+        if signature.is_empty() {
+            return Err(OpenLimitsError::Unauthorized());
+        }
+        Ok(signature)
+    }
+}
+
+async fn write_frame<S: AsyncWrite + Unpin>(stream: &mut S, frame: &[u8]) -> Result<()> {
+    stream.write_u32(frame.len() as u32).await?;
+    stream.write_all(frame).await?;
+    Ok(())
+}
+
+#[async_trait]
+impl RequestSigner for SocketSigner {
+    async fn sign(&self, message: &[u8]) -> Result<Vec<u8>> {
+        cross_async::timeout(self.timeout, self.connect_and_exchange(message))
+            .await
+            .unwrap_or(Err(OpenLimitsError::RequestTimeout()))
+    }
+}
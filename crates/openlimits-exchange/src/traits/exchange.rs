@@ -1,5 +1,6 @@
 use async_trait::async_trait;
 use super::shared::Result;
+use crate::model::Capabilities;
 use super::info::ExchangeInfoRetrieval;
 use super::ExchangeAccount;
 use super::ExchangeMarketData;
@@ -10,4 +11,6 @@ pub trait Exchange: ExchangeInfoRetrieval + ExchangeAccount + ExchangeMarketData
     type InnerClient;
     async fn new(params: Self::InitParams) -> Result<Self>;
     fn inner_client(&self) -> Option<&Self::InnerClient>;
+    /// Describes the operations, order options, intervals and subscriptions this exchange supports.
+    fn capabilities(&self) -> Capabilities;
 }
\ No newline at end of file
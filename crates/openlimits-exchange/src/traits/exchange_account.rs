@@ -2,8 +2,8 @@ use async_trait::async_trait;
 use crate::{
     model::{
         Balance, CancelAllOrdersRequest, CancelOrderRequest, 
-        GetOrderHistoryRequest, GetOrderRequest, 
-        OpenLimitOrderRequest, OpenMarketOrderRequest, Order,
+        GetLedgerRequest, GetOrderHistoryRequest, GetOrderRequest,
+        LedgerEntry, OpenLimitOrderRequest, OpenMarketOrderRequest, Order,
         OrderCanceled, Paginator, Trade, TradeHistoryRequest,
     },
 };
@@ -23,6 +23,7 @@ pub trait ExchangeAccount {
     async fn get_trade_history(&self, req: &TradeHistoryRequest) -> Result<Vec<Trade>>;
     async fn get_account_balances(&self, paginator: Option<Paginator>) -> Result<Vec<Balance>>;
     async fn get_order(&self, req: &GetOrderRequest) -> Result<Order>;
+    async fn get_ledger(&self, req: &GetLedgerRequest) -> Result<Vec<LedgerEntry>>;
 
     async fn get_open_orders(&self, order_filter: &OrderFilter) -> Result<Vec<Order>> {
         Ok(self
@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use crate::{
+    model::{
+        Deposit, DepositAddress, GetDepositAddressRequest, GetDepositHistoryRequest,
+        GetWithdrawalHistoryRequest, WithdrawRequest, Withdrawal,
+    },
+};
+use super::shared::Result;
+
+/// Deposit and withdrawal operations. This trait is kept apart from `ExchangeAccount` because
+/// not every exchange exposes funding operations through its trading API.
+#[async_trait]
+pub trait ExchangeFunding {
+    async fn get_deposit_address(&self, req: &GetDepositAddressRequest) -> Result<DepositAddress>;
+    async fn get_deposit_history(&self, req: &GetDepositHistoryRequest) -> Result<Vec<Deposit>>;
+    async fn withdraw(&self, req: &WithdrawRequest) -> Result<Withdrawal>;
+    async fn get_withdrawal_history(&self, req: &GetWithdrawalHistoryRequest) -> Result<Vec<Withdrawal>>;
+}
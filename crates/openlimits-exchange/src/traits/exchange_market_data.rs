@@ -13,6 +13,9 @@ use super::shared::Result;
 pub trait ExchangeMarketData {
     async fn order_book(&self, req: &OrderBookRequest) -> Result<OrderBookResponse>;
     async fn get_price_ticker(&self, req: &GetPriceTickerRequest) -> Result<Ticker>;
+    /// The tickers of the markets in the exchange information. A market the venue fails to
+    /// answer for is logged and left out, so this only fails when no ticker could be read.
+    async fn get_all_tickers(&self) -> Result<Vec<Ticker>>;
     async fn get_historic_rates(&self, req: &GetHistoricRatesRequest) -> Result<Vec<Candle>>;
     async fn get_historic_trades(&self, req: &GetHistoricTradesRequest) -> Result<Vec<Trade>>;
 }
\ No newline at end of file
@@ -2,6 +2,8 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::sync::RwLock;
 use crate::errors::OpenLimitsError;
+use crate::model::market_pair::MarketPair;
+use crate::model::{AssetRegistry, Currency};
 use super::shared::Result;
 use super::ExchangeInfoRetrieval;
 use super::MarketPairHandle;
@@ -11,15 +13,37 @@ use super::MarketPairInfo;
 #[derive(Clone)]
 pub struct ExchangeInfo {
     pairs: Arc<RwLock<HashMap<String, Arc<RwLock<MarketPairInfo>>>>>,
+    venue: Option<String>,
 }
 
 impl ExchangeInfo {
     pub fn new() -> Self {
         Self {
             pairs: Arc::new(RwLock::new(HashMap::default())),
+            venue: None,
         }
     }
 
+    /// Creates an ExchangeInfo that records the decimals of the venue assets in the
+    /// `AssetRegistry` on every refresh.
+    pub fn with_venue(venue: &str) -> Self {
+        Self {
+            venue: Some(venue.to_string()),
+            ..Self::new()
+        }
+    }
+
+    pub fn venue(&self) -> Option<&str> {
+        self.venue.as_deref()
+    }
+
+    /// Number of decimals this venue accepts for the currency.
+    pub fn decimals(&self, currency: Currency) -> Option<u32> {
+        self.venue
+            .as_ref()
+            .and_then(|venue| AssetRegistry::global().decimals(venue, currency))
+    }
+
     pub fn get_pair(&self, name: &str) -> Result<MarketPairHandle> {
         let market_map = self.pairs.read().expect("Couldn't read pairs.");
         let market_pair = market_map.get(name);
@@ -28,6 +52,32 @@ impl ExchangeInfo {
         })
     }
 
+    /// Resolves a venue symbol like "BTC-USD", "btcusdt" or "eth_btc" into a market pair. The
+    /// lookup ignores case and separators, so it works for symbols without a separator too.
+    pub fn market_pair(&self, symbol: &str) -> Result<MarketPair> {
+        let normalize = |symbol: &str| {
+            symbol
+                .chars()
+                .filter(|c| c.is_alphanumeric())
+                .collect::<String>()
+                .to_uppercase()
+        };
+        let market_map = self.pairs.read().map_err(|_| OpenLimitsError::PoisonError())?;
+        let market = match market_map.get(symbol) {
+            Some(market) => Some(market),
+            None => {
+                let symbol = normalize(symbol);
+                market_map
+                    .iter()
+                    .find(|(name, _)| normalize(name) == symbol)
+                    .map(|(_, market)| market)
+            }
+        };
+        let market = market.ok_or(OpenLimitsError::SymbolNotFound())?;
+        let info = market.read().map_err(|_| OpenLimitsError::PoisonError())?;
+        info.market_pair()
+    }
+
     pub fn list_pairs(&self) -> Vec<MarketPairHandle> {
         let market_map = self.pairs.read().expect("Couldn't read pairs.");
         market_map
@@ -41,6 +91,9 @@ impl ExchangeInfo {
         retrieval: &dyn ExchangeInfoRetrieval,
     ) -> Result<Vec<MarketPairHandle>> {
         let pairs = retrieval.retrieve_pairs().await?;
+        if let Some(venue) = self.venue.as_ref() {
+            register_assets(venue, &pairs);
+        }
 
         if let Ok(mut writable_pairs) = self.pairs.write() {
             for pair in pairs {
@@ -57,6 +110,27 @@ impl ExchangeInfo {
     }
 }
 
+/// Interns the pair assets and records, for each one, the finest increment the venue lists.
+fn register_assets(venue: &str, pairs: &[MarketPairInfo]) {
+    let registry = AssetRegistry::global();
+    let mut decimals: HashMap<Currency, u32> = HashMap::new();
+    for pair in pairs {
+        let assets = [
+            (&pair.base, pair.base_increment),
+            (&pair.quote, pair.quote_increment),
+        ];
+        for (symbol, increment) in assets.iter() {
+            let currency = registry.currency(symbol);
+            let scale = increment.normalize().scale();
+            let entry = decimals.entry(currency).or_insert(scale);
+            *entry = (*entry).max(scale);
+        }
+    }
+    for (currency, decimals) in decimals {
+        registry.set_decimals(venue, currency, decimals);
+    }
+}
+
 impl Default for ExchangeInfo {
     fn default() -> Self {
         ExchangeInfo::new()
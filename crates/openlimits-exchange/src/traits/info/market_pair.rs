@@ -1,14 +1,27 @@
 use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use crate::model::market_pair::MarketPair;
+use super::shared::Result;
 
 // TODO: Use MarketPair inside MarketPairInfo.
 
-#[derive(Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 pub struct MarketPairInfo {
     pub base: String,
     pub quote: String,
     pub symbol: String,
+    #[cfg_attr(feature = "typescript", ts(type = "string"))]
     pub base_increment: Decimal,
+    #[cfg_attr(feature = "typescript", ts(type = "string"))]
     pub quote_increment: Decimal,
+    #[cfg_attr(feature = "typescript", ts(type = "string | null"))]
     pub min_base_trade_size: Option<Decimal>,
+    #[cfg_attr(feature = "typescript", ts(type = "string | null"))]
     pub min_quote_trade_size: Option<Decimal>,
-}
\ No newline at end of file
+}
+impl MarketPairInfo {
+    pub fn market_pair(&self) -> Result<MarketPair> {
+        Ok(MarketPair(self.base.parse()?, self.quote.parse()?))
+    }
+}
@@ -1,12 +1,14 @@
 //! This module cointains the traits that are used in the openlimits-exchange module.
 
 mod exchange_account;
+mod exchange_funding;
 mod exchange_market_data;
 mod exchange;
 pub mod info;
 pub mod stream;
 
 pub use exchange_account::ExchangeAccount;
+pub use exchange_funding::ExchangeFunding;
 pub use exchange_market_data::ExchangeMarketData;
 pub use self::exchange::Exchange;
 pub use super::shared;
@@ -1,7 +1,7 @@
 use std::convert::TryInto;
 use std::slice;
 use async_trait::async_trait;
-use futures::channel::mpsc::channel;
+use cross_async::channel::channel;
 use futures::stream::BoxStream;
 use futures::StreamExt;
 use std::fmt::Debug;
@@ -47,7 +47,7 @@ pub trait ExchangeStream: Send + Sync + Sized {
 
         let (mut tx, rx) = channel(1);
 
-        tokio::spawn(async move {
+        cross_async::spawn(async move {
             while let Some(Ok(message)) = stream.next().await {
                 let message = message.try_into();
                 callback(&message);
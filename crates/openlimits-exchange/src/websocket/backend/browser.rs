@@ -0,0 +1,117 @@
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use cross_async::channel::{oneshot, unbounded, UnboundedReceiver, UnboundedSender};
+use futures::{SinkExt, StreamExt};
+use js_sys::{ArrayBuffer, Uint8Array};
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+use web_sys::{BinaryType, CloseEvent, Event, MessageEvent, WebSocket};
+use crate::errors::OpenLimitsError;
+use super::{Message, WebSocketSink, WebSocketStream};
+use super::shared::Result;
+
+pub async fn connect(url: &str) -> Result<(WebSocketSink, WebSocketStream)> {
+    let (opened_sender, opened) = oneshot::channel();
+    let (incoming_sender, incoming) = unbounded();
+    let (outgoing, outgoing_receiver) = unbounded();
+    // The `WebSocket` isn't `Send`, so it lives in a local task which the halves reach through
+    // channels.
+    wasm_bindgen_futures::spawn_local(run(
+        url.to_string(),
+        opened_sender,
+        incoming_sender,
+        outgoing.clone(),
+        outgoing_receiver,
+    ));
+    opened.await.unwrap_or(Err(OpenLimitsError::SocketError()))?;
+    let sink = outgoing.sink_map_err(|_| OpenLimitsError::Disconnected());
+    Ok((Box::pin(sink), Box::pin(incoming)))
+}
+
+/// Writes the outgoing messages until the socket is closed, then releases its callbacks.
+async fn run(
+    url: String,
+    opened: oneshot::Sender<Result<()>>,
+    incoming: UnboundedSender<Result<Message>>,
+    outgoing: UnboundedSender<Message>,
+    mut outgoing_receiver: UnboundedReceiver<Message>,
+) {
+    let websocket = match WebSocket::new(&url) {
+        Ok(websocket) => websocket,
+        Err(_) => {
+            opened.send(Err(OpenLimitsError::SocketError())).ok();
+            return;
+        }
+    };
+    websocket.set_binary_type(BinaryType::Arraybuffer);
+    // Until the socket opens, its failures go to `connect`, and to the stream afterwards.
+    let opened = Rc::new(RefCell::new(Some(opened)));
+    let closed = Rc::new(Cell::new(false));
+
+    let onopen = {
+        let opened = opened.clone();
+        Closure::<dyn FnMut(Event)>::new(move |_| {
+            if let Some(opened) = opened.borrow_mut().take() {
+                opened.send(Ok(())).ok();
+            }
+        })
+    };
+    let onerror = {
+        let opened = opened.clone();
+        let incoming = incoming.clone();
+        Closure::<dyn FnMut(Event)>::new(move |_| match opened.borrow_mut().take() {
+            Some(opened) => {
+                opened.send(Err(OpenLimitsError::SocketError())).ok();
+            }
+            None => {
+                incoming.unbounded_send(Err(OpenLimitsError::SocketError())).ok();
+            }
+        })
+    };
+    let onmessage = {
+        let incoming = incoming.clone();
+        let websocket = websocket.clone();
+        Closure::<dyn FnMut(MessageEvent)>::new(move |event: MessageEvent| {
+            let message = match event.data().dyn_into::<ArrayBuffer>() {
+                Ok(buffer) => Message::Binary(Uint8Array::new(&buffer).to_vec()),
+                Err(data) => Message::Text(data.as_string().unwrap_or_default()),
+            };
+            // Nobody reads the stream anymore.
+            if incoming.unbounded_send(Ok(message)).is_err() {
+                websocket.close().ok();
+            }
+        })
+    };
+    let onclose = {
+        let closed = closed.clone();
+        Closure::<dyn FnMut(CloseEvent)>::new(move |_| {
+            closed.set(true);
+            if let Some(opened) = opened.borrow_mut().take() {
+                opened.send(Err(OpenLimitsError::SocketError())).ok();
+            }
+            incoming.close_channel();
+            // Wakes the task up so it releases the socket.
+            outgoing.unbounded_send(Message::Close).ok();
+        })
+    };
+    websocket.set_onopen(Some(onopen.as_ref().unchecked_ref()));
+    websocket.set_onerror(Some(onerror.as_ref().unchecked_ref()));
+    websocket.set_onmessage(Some(onmessage.as_ref().unchecked_ref()));
+    websocket.set_onclose(Some(onclose.as_ref().unchecked_ref()));
+
+    while let Some(message) = outgoing_receiver.next().await {
+        if closed.get() {
+            break;
+        }
+        match message {
+            Message::Text(text) => websocket.send_with_str(&text).ok(),
+            Message::Binary(binary) => websocket.send_with_u8_array(&binary).ok(),
+            Message::Close => websocket.close().ok(),
+            Message::Ping(_) | Message::Pong(_) => None,
+        };
+    }
+    websocket.set_onopen(None);
+    websocket.set_onerror(None);
+    websocket.set_onmessage(None);
+    websocket.set_onclose(None);
+}
@@ -0,0 +1,39 @@
+use futures::{future, SinkExt, StreamExt};
+use tokio_tungstenite::{connect_async, tungstenite};
+use crate::errors::OpenLimitsError;
+use super::{Message, WebSocketSink, WebSocketStream};
+use super::shared::Result;
+
+pub async fn connect(url: &str) -> Result<(WebSocketSink, WebSocketStream)> {
+    let (websocket, _) = connect_async(url).await?;
+    let (sink, stream) = websocket.split();
+    let sink = sink
+        .sink_map_err(OpenLimitsError::from)
+        .with(|message: Message| future::ready(Ok::<_, OpenLimitsError>(message.into())));
+    let stream = stream.map(|message| Ok(message?.into()));
+    Ok((Box::pin(sink), Box::pin(stream)))
+}
+
+impl From<Message> for tungstenite::Message {
+    fn from(message: Message) -> Self {
+        match message {
+            Message::Text(text) => tungstenite::Message::Text(text),
+            Message::Binary(binary) => tungstenite::Message::Binary(binary),
+            Message::Ping(payload) => tungstenite::Message::Ping(payload),
+            Message::Pong(payload) => tungstenite::Message::Pong(payload),
+            Message::Close => tungstenite::Message::Close(None),
+        }
+    }
+}
+
+impl From<tungstenite::Message> for Message {
+    fn from(message: tungstenite::Message) -> Self {
+        match message {
+            tungstenite::Message::Text(text) => Message::Text(text),
+            tungstenite::Message::Binary(binary) => Message::Binary(binary),
+            tungstenite::Message::Ping(payload) => Message::Ping(payload),
+            tungstenite::Message::Pong(payload) => Message::Pong(payload),
+            tungstenite::Message::Close(_) => Message::Close,
+        }
+    }
+}
@@ -0,0 +1,339 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::task::{Context, Poll};
+use std::time::Duration;
+use futures::lock::Mutex;
+use futures::{SinkExt, Stream, StreamExt};
+use cross_async::channel::{unbounded, UnboundedReceiver, UnboundedSender};
+use crate::errors::OpenLimitsError;
+use crate::message::subscription::{Publication, Subscription};
+use crate::metrics;
+use crate::shared::Result;
+use super::Message;
+
+type Requests<R> = Arc<Mutex<VecDeque<(u64, UnboundedSender<Result<R>>)>>>;
+type Subscriptions<C> = Arc<Mutex<HashMap<C, (Subscription, Vec<UnboundedSender<Result<Publication>>>)>>>;
+
+/// What a frame read by a `Codec` is to the connection.
+#[derive(Debug)]
+pub enum Frame<C, U, R> {
+    /// An update of a channel, published to its subscription once the codec read it.
+    Update(C, U),
+    /// The answer to the request of the id, or to the oldest pending request for a venue that
+    /// doesn't echo the ids. An error answering no request is reported to every subscription.
+    Response(Option<u64>, Result<R>),
+    /// A frame to send back, e.g. a pong written as text.
+    Reply(Message),
+    /// A frame that can't be read, along with its channel when the frame tells it.
+    Error(Option<C>, OpenLimitsError),
+    /// A frame that concerns neither the requests nor the subscriptions.
+    Ignored,
+}
+
+/// Reads the frames of a venue for a `Connection` and turns its channel updates into
+/// publications.
+pub trait Codec: 'static {
+    /// The key a subscription is published under, e.g. a stream name.
+    type Channel: Clone + Debug + Eq + Hash + Send + Sync + 'static;
+    /// A channel update, before the subscription it is published to is known.
+    type Update: Send + 'static;
+    /// The answer to a request.
+    type Response: Send + 'static;
+
+    /// The venue label of the logs and metrics.
+    const VENUE: &'static str;
+
+    /// Reads a text or binary frame, the pings and the close frame being answered by the
+    /// connection.
+    fn decode(message: Message) -> Frame<Self::Channel, Self::Update, Self::Response>;
+
+    fn publication(subscription: &Subscription, update: Self::Update) -> Result<Publication>;
+
+    /// Writes the request unsubscribing from the channel, numbered with the id for a venue that
+    /// echoes the ids.
+    fn unsubscribe(id: u64, channel: &Self::Channel) -> Result<Message>;
+}
+
+/// A websocket connection every subscription and request of a venue shares, the frames being
+/// read by the codec of the venue. Frames that can't be read are published as errors on the
+/// subscriptions they belong to, and every subscription and pending request receives
+/// `Disconnected` when the socket closes. A channel is unsubscribed from at the venue once the
+/// last of its streams is dropped.
+pub struct Connection<C: Codec> {
+    sender: UnboundedSender<Message>,
+    requests: Requests<C::Response>,
+    subscriptions: Subscriptions<C::Channel>,
+    dropped: UnboundedSender<C::Channel>,
+    connected: Arc<AtomicBool>,
+    next_id: Arc<AtomicU64>,
+    codec: PhantomData<C>,
+}
+
+impl<C: Codec> Connection<C> {
+    pub async fn new(url: &str) -> Result<Self> {
+        let (mut sink, mut stream) = super::connect(url).await.map_err(|error| {
+            tracing::warn!(venue = C::VENUE, error = %error, "websocket connection failed");
+            metrics::increment_counter(metrics::WEBSOCKET_EVENTS, &[("venue", C::VENUE), ("event", "error")]);
+            error
+        })?;
+        tracing::info!(venue = C::VENUE, url = %url, "websocket connected");
+        metrics::increment_counter(metrics::WEBSOCKET_EVENTS, &[("venue", C::VENUE), ("event", "connected")]);
+
+        // Requests and pongs are written by a single task, so the reader never waits on the sink.
+        let (sender, mut outgoing) = unbounded::<Message>();
+        cross_async::spawn(async move {
+            while let Some(message) = outgoing.next().await {
+                if sink.send(message).await.is_err() {
+                    break;
+                }
+            }
+        });
+
+        let requests: Requests<C::Response> = Default::default();
+        let subscriptions: Subscriptions<C::Channel> = Default::default();
+        let connected = Arc::new(AtomicBool::new(true));
+        let next_id = Arc::new(AtomicU64::new(0));
+
+        // The channels whose streams were dropped, unsubscribed from once none of them is left.
+        let (dropped, mut dropped_channels) = unbounded::<C::Channel>();
+        {
+            let sender = sender.clone();
+            let requests = requests.clone();
+            let subscriptions = subscriptions.clone();
+            let next_id = next_id.clone();
+            cross_async::spawn(async move {
+                while let Some(channel) = dropped_channels.next().await {
+                    let mut subscriptions = subscriptions.lock().await;
+                    match subscriptions.get_mut(&channel) {
+                        Some((_, senders)) => {
+                            senders.retain(|sender| !sender.is_closed());
+                            if !senders.is_empty() {
+                                continue;
+                            }
+                        }
+                        None => continue,
+                    }
+                    subscriptions.remove(&channel);
+                    // The answer is awaited by no one, but keeps a venue that doesn't echo the ids
+                    // from answering the next request with it.
+                    let id = next_id.fetch_add(1, Ordering::Relaxed);
+                    match C::unsubscribe(id, &channel) {
+                        Ok(message) => {
+                            tracing::debug!(venue = C::VENUE, channel = ?channel, "unsubscribed");
+                            requests.lock().await.push_back((id, unbounded().0));
+                            sender.unbounded_send(message).ok();
+                        }
+                        Err(error) => tracing::warn!(venue = C::VENUE, error = %error, "couldn't unsubscribe"),
+                    }
+                }
+            });
+        }
+        {
+            let sender = sender.clone();
+            let requests = requests.clone();
+            let subscriptions = subscriptions.clone();
+            let connected = connected.clone();
+            cross_async::spawn(async move {
+                while let Some(Ok(message)) = stream.next().await {
+                    metrics::increment_counter(metrics::WEBSOCKET_MESSAGES, &[("venue", C::VENUE)]);
+                    let message = match message {
+                        Message::Ping(payload) => {
+                            sender.unbounded_send(Message::Pong(payload)).ok();
+                            continue;
+                        }
+                        Message::Pong(_) => continue,
+                        Message::Close => break,
+                        message => message,
+                    };
+                    match C::decode(message) {
+                        Frame::Update(channel, update) => {
+                            let mut publication = match subscriptions.lock().await.get(&channel) {
+                                Some((subscription, _)) => Some(C::publication(subscription, update)),
+                                // Updates can still arrive for a subscription that was dropped.
+                                None => continue,
+                            };
+                            publish(&subscriptions, Some(&channel), || publication.take()).await;
+                        }
+                        Frame::Response(id, response) => {
+                            let pending = {
+                                let mut requests = requests.lock().await;
+                                match id {
+                                    Some(id) => requests
+                                        .iter()
+                                        .position(|(request_id, _)| *request_id == id)
+                                        .and_then(|index| requests.remove(index)),
+                                    None => requests.pop_front(),
+                                }
+                            };
+                            match (pending, response) {
+                                (Some((_, sender)), response) => {
+                                    sender.unbounded_send(response).ok();
+                                }
+                                // Errors can't be cloned, so each subscription receives its description.
+                                (None, Err(error)) => {
+                                    let message = format!("{:?}", error);
+                                    tracing::warn!(venue = C::VENUE, error = %message, "error answering no request");
+                                    publish(&subscriptions, None, || Some(Err(OpenLimitsError::UnkownResponse(message.clone())))).await;
+                                }
+                                (None, Ok(_)) => {}
+                            }
+                        }
+                        Frame::Reply(message) => {
+                            sender.unbounded_send(message).ok();
+                        }
+                        Frame::Error(channel, error) => {
+                            tracing::warn!(venue = C::VENUE, error = %error, "unreadable websocket frame");
+                            match channel {
+                                Some(channel) => {
+                                    let mut error = Some(Err(error));
+                                    publish(&subscriptions, Some(&channel), || error.take()).await;
+                                }
+                                // A frame that can't be attributed is reported to every subscription.
+                                None => {
+                                    let message = error.to_string();
+                                    publish(&subscriptions, None, || Some(Err(OpenLimitsError::NotParsableResponse(message.clone())))).await;
+                                }
+                            }
+                        }
+                        Frame::Ignored => {}
+                    }
+                }
+                connected.store(false, Ordering::SeqCst);
+                tracing::info!(venue = C::VENUE, "websocket disconnected");
+                metrics::increment_counter(metrics::WEBSOCKET_EVENTS, &[("venue", C::VENUE), ("event", "disconnected")]);
+                for (_, sender) in requests.lock().await.drain(..) {
+                    sender.unbounded_send(Err(OpenLimitsError::Disconnected())).ok();
+                }
+                publish(&subscriptions, None, || Some(Err(OpenLimitsError::Disconnected()))).await;
+                subscriptions.lock().await.clear();
+            });
+        }
+
+        Ok(Self {
+            sender,
+            requests,
+            subscriptions,
+            dropped,
+            connected,
+            next_id,
+            codec: PhantomData,
+        })
+    }
+
+    pub fn is_connected(&self) -> bool {
+        self.connected.load(Ordering::SeqCst)
+    }
+
+    /// Sends the frame `message` writes for the id of the request, and waits at most `timeout`
+    /// for its answer before failing with `RequestTimeout`.
+    pub async fn request<F>(&self, message: F, timeout: Duration) -> Result<C::Response>
+    where
+        F: FnOnce(u64) -> Result<Message>,
+    {
+        if !self.is_connected() {
+            return Err(OpenLimitsError::Disconnected());
+        }
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let message = message(id)?;
+        let (sender, mut receiver) = unbounded();
+        self.requests.lock().await.push_back((id, sender));
+        if self.sender.unbounded_send(message).is_err() {
+            self.remove_request(id).await;
+            return Err(OpenLimitsError::Disconnected());
+        }
+        match cross_async::timeout(timeout, receiver.next()).await {
+            Some(response) => response.ok_or(OpenLimitsError::Disconnected())?,
+            None => {
+                self.remove_request(id).await;
+                Err(OpenLimitsError::RequestTimeout())
+            }
+        }
+    }
+
+    /// Publishes the updates of the channel to the returned stream, along with every other stream
+    /// of the channel, until it is dropped or the socket closes.
+    pub async fn register_subscription(&self, channel: C::Channel, subscription: Subscription) -> Publications<C> {
+        tracing::debug!(venue = C::VENUE, channel = ?channel, "subscribed");
+        let (sender, receiver) = unbounded();
+        self.subscriptions
+            .lock()
+            .await
+            .entry(channel.clone())
+            .or_insert_with(|| (subscription, Vec::new()))
+            .1
+            .push(sender);
+        Publications {
+            receiver,
+            channel: Some(channel),
+            dropped: self.dropped.clone(),
+        }
+    }
+
+    /// Closes the socket, which ends every subscription.
+    pub fn close(&self) {
+        self.sender.unbounded_send(Message::Close).ok();
+    }
+
+    async fn remove_request(&self, id: u64) {
+        self.requests.lock().await.retain(|(request_id, _)| *request_id != id);
+    }
+}
+
+/// The publications of a subscription, streamed by a `Connection`. Dropping the last stream of
+/// a channel unsubscribes from it.
+pub struct Publications<C: Codec> {
+    receiver: UnboundedReceiver<Result<Publication>>,
+    channel: Option<C::Channel>,
+    dropped: UnboundedSender<C::Channel>,
+}
+
+impl<C: Codec> Unpin for Publications<C> {}
+
+impl<C: Codec> Stream for Publications<C> {
+    type Item = Result<Publication>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_next_unpin(cx)
+    }
+}
+
+impl<C: Codec> Drop for Publications<C> {
+    fn drop(&mut self) {
+        self.receiver.close();
+        if let Some(channel) = self.channel.take() {
+            self.dropped.unbounded_send(channel).ok();
+        }
+    }
+}
+
+/// Sends what `publication` returns to every stream of the channel, or of every channel without
+/// one. Errors can't be cloned, so the streams of a channel but the last receive its description.
+async fn publish<C, F>(subscriptions: &Subscriptions<C>, channel: Option<&C>, mut publication: F)
+where
+    C: Eq + Hash,
+    F: FnMut() -> Option<Result<Publication>>,
+{
+    let subscriptions = subscriptions.lock().await;
+    for (subscription_channel, (_, senders)) in subscriptions.iter() {
+        if matches!(channel, Some(channel) if channel != subscription_channel) {
+            continue;
+        }
+        let (publication, (last, senders)) = match (publication(), senders.split_last()) {
+            (Some(publication), Some(senders)) => (publication, senders),
+            _ => continue,
+        };
+        for sender in senders {
+            let copy = match &publication {
+                Ok(publication) => Ok(publication.clone()),
+                Err(error) => Err(OpenLimitsError::NotParsableResponse(error.to_string())),
+            };
+            sender.unbounded_send(copy).ok();
+        }
+        last.unbounded_send(publication).ok();
+    }
+}
@@ -0,0 +1,32 @@
+//! This module abstracts the websocket the exchanges stream from, so their connections are
+//! written once for every target: tungstenite opens it natively and the `WebSocket` of the
+//! browser opens it on wasm32. The browser answers the pings by itself, so none are read there.
+//! `Connection` multiplexes the subscriptions and requests of a venue over one websocket.
+
+mod connection;
+mod message;
+
+#[cfg(not(target_arch = "wasm32"))]
+#[path = "backend/tungstenite.rs"]
+mod backend;
+#[cfg(target_arch = "wasm32")]
+#[path = "backend/browser.rs"]
+mod backend;
+
+use std::pin::Pin;
+use futures::{Sink, Stream};
+use crate::errors::OpenLimitsError;
+
+pub use connection::{Codec, Connection, Frame, Publications};
+pub use message::Message;
+pub use super::shared;
+
+/// The half of a websocket its messages are written to.
+pub type WebSocketSink = Pin<Box<dyn Sink<Message, Error = OpenLimitsError> + Send>>;
+/// The half of a websocket its messages are read from, which ends once the socket is closed.
+pub type WebSocketStream = Pin<Box<dyn Stream<Item = shared::Result<Message>> + Send + Sync>>;
+
+/// Opens a websocket to the url, once its handshake succeeded.
+pub async fn connect(url: &str) -> shared::Result<(WebSocketSink, WebSocketStream)> {
+    backend::connect(url).await
+}
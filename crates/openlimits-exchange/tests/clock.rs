@@ -0,0 +1,69 @@
+use std::time::Duration;
+use openlimits_exchange::clock::ClockSync;
+use openlimits_exchange::errors::OpenLimitsError;
+
+#[test]
+fn offset() {
+    let clock = ClockSync::default();
+    assert_eq!(clock.offset_millis(), 0);
+    // Sent at 1000 and received at 1100: the server read 1550 at about 1050.
+    clock.update(1_000, 1_550, 1_100);
+    assert_eq!(clock.offset_millis(), 500);
+    assert_eq!(clock.latency_millis(), 100);
+    clock.update(2_000, 1_990, 2_020);
+    assert_eq!(clock.offset_millis(), -20);
+    assert_eq!(clock.latency_millis(), 20);
+}
+
+#[test]
+fn negative_round_trip() {
+    let clock = ClockSync::default();
+    // A local clock stepping back during the request counts as no latency.
+    clock.update(1_000, 1_200, 900);
+    assert_eq!(clock.latency_millis(), 0);
+    assert_eq!(clock.offset_millis(), 200);
+}
+
+#[test]
+fn needs_sync() {
+    let clock = ClockSync::new(Duration::from_secs(60));
+    assert!(clock.needs_sync());
+    clock.update(1_000, 1_000, 1_000);
+    assert!(!clock.needs_sync_at(1_000));
+    assert!(!clock.needs_sync_at(60_999));
+    assert!(clock.needs_sync_at(61_000));
+    clock.invalidate();
+    assert!(clock.needs_sync_at(1_000));
+}
+
+#[test]
+fn shared_by_clones() {
+    let clock = ClockSync::default();
+    let clone = clock.clone();
+    clock.update(1_000, 3_000, 1_000);
+    assert_eq!(clone.offset_millis(), 2_000);
+}
+
+#[tokio::test]
+async fn failed_sync() {
+    let clock = ClockSync::default();
+    clock.update(1_000, 1_500, 1_000);
+    clock.sync(async { Err(OpenLimitsError::ServiceUnavailable()) }).await;
+    assert_eq!(clock.offset_millis(), 500);
+}
+
+#[tokio::test]
+async fn failed_sync_backs_off() {
+    let clock = ClockSync::new(Duration::from_secs(60));
+    clock.sync(async { Err(OpenLimitsError::ServiceUnavailable()) }).await;
+    assert!(!clock.needs_sync());
+    assert!(clock.needs_sync_at(ClockSync::local_millis() + 60_000));
+}
+
+#[tokio::test]
+async fn refresh() {
+    let clock = ClockSync::new(Duration::from_millis(10));
+    clock.spawn_refresh(|| async { Ok(ClockSync::local_millis() + 60_000) });
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    assert!((clock.offset_millis() - 60_000).abs() < 1_000, "{}", clock.offset_millis());
+}
@@ -0,0 +1,203 @@
+use std::time::Duration;
+use futures::{SinkExt, StreamExt};
+use serde_json::Value;
+use tokio::net::{TcpListener, TcpStream};
+use tokio_tungstenite::{accept_async, tungstenite, WebSocketStream};
+use openlimits_exchange::errors::OpenLimitsError;
+use openlimits_exchange::message::subscription::{Publication, Subscription};
+use openlimits_exchange::model::Currency;
+use openlimits_exchange::model::market_pair::MarketPair;
+use openlimits_exchange::shared::Result;
+use openlimits_exchange::websocket::{Codec, Connection, Frame, Message};
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A venue whose JSON frames are answers `{"id": 1}`, unnumbered answers `{"answer": 1}` or
+/// `{"error": "..."}`, updates `{"ch": "trades", "count": 2}` and replies `{"reply": "..."}`. It is
+/// unsubscribed from with `{"unsub": "trades", "id": 1}`.
+struct TestCodec;
+
+impl Codec for TestCodec {
+    type Channel = String;
+    type Update = usize;
+    type Response = u64;
+
+    const VENUE: &'static str = "test";
+
+    fn decode(message: Message) -> Frame<String, usize, u64> {
+        let value: Value = match message {
+            Message::Text(text) => match serde_json::from_str(&text) {
+                Ok(value) => value,
+                Err(error) => return Frame::Error(None, error.into()),
+            },
+            _ => return Frame::Ignored,
+        };
+        if let Some(id) = value["id"].as_u64() {
+            return Frame::Response(Some(id), Ok(id));
+        }
+        if let Some(answer) = value["answer"].as_u64() {
+            return Frame::Response(None, Ok(answer));
+        }
+        if let Some(error) = value["error"].as_str() {
+            return Frame::Response(None, Err(OpenLimitsError::InvalidParameter(error.to_string())));
+        }
+        if let Some(reply) = value["reply"].as_str() {
+            return Frame::Reply(Message::Text(reply.to_string()));
+        }
+        match (value["ch"].as_str(), value["count"].as_u64()) {
+            (Some(channel), Some(count)) => Frame::Update(channel.to_string(), count as usize),
+            (Some(channel), None) => Frame::Error(Some(channel.to_string()), OpenLimitsError::NotParsableResponse(value.to_string())),
+            (None, _) => Frame::Ignored,
+        }
+    }
+
+    fn publication(_subscription: &Subscription, count: usize) -> Result<Publication> {
+        Ok(Publication::Trades(Vec::with_capacity(count)))
+    }
+
+    fn unsubscribe(id: u64, channel: &String) -> Result<Message> {
+        Ok(Message::Text(format!(r#"{{"unsub": "{}", "id": {}}}"#, channel, id)))
+    }
+}
+
+fn trades() -> Subscription {
+    Subscription::Trades(MarketPair(Currency::ETH, Currency::BTC))
+}
+
+/// Serves one connection on a local port, running `session` on it.
+async fn serve<F, S>(session: S) -> String
+where
+    S: FnOnce(WebSocketStream<TcpStream>) -> F + Send + 'static,
+    F: std::future::Future<Output = ()> + Send,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").await.expect("Couldn't listen");
+    let address = listener.local_addr().expect("Couldn't get the address");
+    tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.expect("Couldn't accept the connection");
+        session(accept_async(stream).await.expect("Couldn't accept the websocket")).await;
+    });
+    format!("ws://{}", address)
+}
+
+async fn next_text(websocket: &mut WebSocketStream<TcpStream>) -> String {
+    while let Some(Ok(message)) = websocket.next().await {
+        if let tungstenite::Message::Text(text) = message {
+            return text.to_string();
+        }
+    }
+    panic!("The connection closed")
+}
+
+async fn send(websocket: &mut WebSocketStream<TcpStream>, text: &str) {
+    websocket.send(tungstenite::Message::Text(text.into())).await.expect("Couldn't send");
+}
+
+#[tokio::test]
+async fn requests_and_publications() {
+    let url = serve(|mut websocket| async move {
+        // Answers the two requests out of order.
+        let first = next_text(&mut websocket).await;
+        let second = next_text(&mut websocket).await;
+        send(&mut websocket, &second).await;
+        send(&mut websocket, &first).await;
+        send(&mut websocket, r#"{"reply": "pong"}"#).await;
+        assert_eq!(next_text(&mut websocket).await, "pong");
+        send(&mut websocket, r#"{"ch": "unknown", "count": 1}"#).await;
+        send(&mut websocket, r#"{"ch": "trades"}"#).await;
+        send(&mut websocket, "not json").await;
+        send(&mut websocket, r#"{"ch": "trades", "count": 3}"#).await;
+        websocket.close(None).await.ok();
+    })
+    .await;
+    let connection = Connection::<TestCodec>::new(&url).await.expect("Couldn't connect");
+    let mut publications = connection.register_subscription(String::from("trades"), trades()).await;
+
+    let request = |id| Ok(Message::Text(format!(r#"{{"id": {}}}"#, id)));
+    let (first, second) = futures::join!(connection.request(request, TIMEOUT), connection.request(request, TIMEOUT));
+    assert_eq!(first.unwrap(), 0);
+    assert_eq!(second.unwrap(), 1);
+
+    assert!(matches!(publications.next().await, Some(Err(OpenLimitsError::NotParsableResponse(_)))));
+    assert!(matches!(publications.next().await, Some(Err(OpenLimitsError::NotParsableResponse(_)))));
+    match publications.next().await {
+        Some(Ok(Publication::Trades(trades))) => assert_eq!(trades.capacity(), 3),
+        publication => panic!("Unexpected publication {:?}", publication),
+    }
+    assert!(matches!(publications.next().await, Some(Err(OpenLimitsError::Disconnected()))));
+    assert!(publications.next().await.is_none());
+    assert!(!connection.is_connected());
+    assert!(matches!(connection.request(request, TIMEOUT).await, Err(OpenLimitsError::Disconnected())));
+}
+
+#[tokio::test]
+async fn unnumbered_answers() {
+    let url = serve(|mut websocket| async move {
+        next_text(&mut websocket).await;
+        next_text(&mut websocket).await;
+        send(&mut websocket, r#"{"answer": 7}"#).await;
+        send(&mut websocket, r#"{"error": "refused"}"#).await;
+        // An error answering no request goes to the subscriptions.
+        send(&mut websocket, r#"{"error": "unexpected"}"#).await;
+        next_text(&mut websocket).await;
+    })
+    .await;
+    let connection = Connection::<TestCodec>::new(&url).await.expect("Couldn't connect");
+    let mut publications = connection.register_subscription(String::from("trades"), trades()).await;
+
+    let request = |_| Ok(Message::Text(String::from("{}")));
+    let (first, second) = futures::join!(connection.request(request, TIMEOUT), connection.request(request, TIMEOUT));
+    assert_eq!(first.unwrap(), 7);
+    assert!(matches!(second, Err(OpenLimitsError::InvalidParameter(message)) if message == "refused"));
+    match publications.next().await {
+        Some(Err(OpenLimitsError::UnkownResponse(message))) => assert!(message.contains("unexpected")),
+        publication => panic!("Unexpected publication {:?}", publication),
+    }
+}
+
+#[tokio::test]
+async fn request_timeout() {
+    let url = serve(|mut websocket| async move { while let Some(Ok(_)) = websocket.next().await {} }).await;
+    let connection = Connection::<TestCodec>::new(&url).await.expect("Couldn't connect");
+    let result = connection.request(|id| Ok(Message::Text(format!(r#"{{"id": {}}}"#, id))), Duration::from_millis(100)).await;
+    assert!(matches!(result, Err(OpenLimitsError::RequestTimeout())));
+
+    // The subscriptions end once the connection is closed.
+    let mut publications = connection.register_subscription(String::from("trades"), trades()).await;
+    connection.close();
+    assert!(matches!(publications.next().await, Some(Err(OpenLimitsError::Disconnected()))));
+    assert!(publications.next().await.is_none());
+}
+
+#[tokio::test]
+async fn shared_channel() {
+    let url = serve(|mut websocket| async move {
+        next_text(&mut websocket).await;
+        send(&mut websocket, r#"{"answer": 0}"#).await;
+        send(&mut websocket, r#"{"ch": "trades"}"#).await;
+        send(&mut websocket, r#"{"ch": "trades", "count": 3}"#).await;
+        // The channel is unsubscribed from once both of its streams are dropped.
+        let unsubscription: Value = serde_json::from_str(&next_text(&mut websocket).await).unwrap();
+        assert_eq!(unsubscription["unsub"], "trades");
+        send(&mut websocket, r#"{"answer": 1}"#).await;
+        next_text(&mut websocket).await;
+        send(&mut websocket, r#"{"answer": 2}"#).await;
+        next_text(&mut websocket).await;
+    })
+    .await;
+    let connection = Connection::<TestCodec>::new(&url).await.expect("Couldn't connect");
+    let mut first = connection.register_subscription(String::from("trades"), trades()).await;
+    let mut second = connection.register_subscription(String::from("trades"), trades()).await;
+    let request = |_| Ok(Message::Text(String::from("{}")));
+    assert_eq!(connection.request(request, TIMEOUT).await.unwrap(), 0);
+
+    for publications in [&mut first, &mut second] {
+        assert!(matches!(publications.next().await, Some(Err(OpenLimitsError::NotParsableResponse(_)))));
+        assert!(matches!(publications.next().await, Some(Ok(Publication::Trades(_)))));
+    }
+    drop(first);
+    drop(second);
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    // The answer to the unsubscription isn't taken for the answer of the next request.
+    assert_eq!(connection.request(request, TIMEOUT).await.unwrap(), 2);
+}
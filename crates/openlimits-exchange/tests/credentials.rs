@@ -0,0 +1,42 @@
+use openlimits_exchange::credentials::{CredentialProvider, EnvProvider, KeystoreProvider, TomlProvider};
+use openlimits_exchange::OpenLimitsError;
+
+const PROFILES: &str = r#"
+[binance]
+api_key = "key"
+api_secret = "s3cr3t"
+"#;
+
+#[test]
+fn toml_provider() {
+    let provider = TomlProvider::parse(PROFILES).expect("Couldn't parse the profiles");
+    let profile = provider.profile("binance").expect("Couldn't get the profile");
+    assert_eq!(profile.get("api_key"), Some("key"));
+    assert_eq!(profile.require("api_secret").expect("Couldn't get the secret"), "s3cr3t");
+    assert!(matches!(profile.require("passphrase"), Err(OpenLimitsError::MissingParameter(_))));
+    assert!(!format!("{:?}", profile).contains("s3cr3t"));
+    assert!(provider.profile("coinbase").is_err());
+}
+
+#[test]
+fn env_provider() {
+    std::env::set_var("OPENLIMITS_TEST_API_KEY", "key");
+    std::env::set_var("OPENLIMITS_TEST_API_SECRET", "secret");
+    let profile = EnvProvider.profile("openlimits-test").expect("Couldn't get the profile");
+    assert_eq!(profile.get("api_key"), Some("key"));
+    assert_eq!(profile.get("api_secret"), Some("secret"));
+    assert!(EnvProvider.profile("openlimits-missing").is_err());
+}
+
+#[test]
+fn keystore_provider() {
+    let keystore = KeystoreProvider::seal_with_cost(PROFILES, "passphrase", 10).expect("Couldn't seal the keystore");
+    assert!(!keystore.contains("s3cr3t"));
+
+    let provider = KeystoreProvider::decrypt(&keystore, "passphrase").expect("Couldn't open the keystore");
+    let profile = provider.profile("binance").expect("Couldn't get the profile");
+    assert_eq!(profile.get("api_secret"), Some("s3cr3t"));
+
+    let result = KeystoreProvider::decrypt(&keystore, "wrong passphrase");
+    assert!(matches!(result, Err(OpenLimitsError::Unauthorized())));
+}
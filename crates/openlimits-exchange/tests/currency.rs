@@ -0,0 +1,67 @@
+use std::collections::HashSet;
+use openlimits_exchange::errors::OpenLimitsError;
+use openlimits_exchange::model::market_pair::MarketPair;
+use openlimits_exchange::model::{AssetRegistry, Currency};
+
+#[test]
+fn equality() {
+    assert_eq!("BTC".parse::<Currency>().unwrap(), Currency::BTC);
+    assert_eq!(" btc ".parse::<Currency>().unwrap(), Currency::BTC);
+    assert_eq!(Currency::new("usdt"), Currency::USDT);
+    assert_eq!("LINK".parse::<Currency>().unwrap(), "link".parse::<Currency>().unwrap());
+    assert_ne!("LINK".parse::<Currency>().unwrap(), Currency::ETH);
+    assert_eq!(Currency::BTC.symbol(), "BTC");
+}
+
+#[test]
+fn hashing() {
+    let pairs: HashSet<MarketPair> = vec![
+        MarketPair(Currency::BTC, Currency::USDT),
+        MarketPair("btc".parse().unwrap(), "usdt".parse().unwrap()),
+        MarketPair("XBT".parse().unwrap(), Currency::USDT),
+        MarketPair(Currency::ETH, Currency::USDT),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(pairs.len(), 2);
+    assert!(pairs.contains(&MarketPair(Currency::BTC, Currency::USDT)));
+}
+
+#[test]
+fn aliases() {
+    assert_eq!("XBT".parse::<Currency>().unwrap(), Currency::BTC);
+    assert_eq!("xdg".parse::<Currency>().unwrap(), "DOGE".parse::<Currency>().unwrap());
+
+    let registry = AssetRegistry::global();
+    let wrapped = registry.currency("WBTCALIAS");
+    registry.register_alias("WBTCALIAS2", wrapped).unwrap();
+    assert_eq!("wbtcalias2".parse::<Currency>().unwrap(), wrapped);
+    assert_eq!("WBTCALIAS2".parse::<Currency>().unwrap().symbol(), "WBTCALIAS");
+
+    // A symbol already handed out as a currency of its own can't become an alias.
+    let interned = registry.currency("LATEALIAS");
+    let result = registry.register_alias("LATEALIAS", Currency::BTC);
+    assert!(matches!(result, Err(OpenLimitsError::InvalidParameter(_))));
+    assert_eq!("LATEALIAS".parse::<Currency>().unwrap(), interned);
+}
+
+#[test]
+fn parse_bounds() {
+    assert!(matches!("".parse::<Currency>(), Err(OpenLimitsError::InvalidParameter(_))));
+    assert!("BTC USDT".parse::<Currency>().is_err());
+    assert!("A".repeat(21).parse::<Currency>().is_err());
+    let registry = AssetRegistry::global();
+    assert!(registry.lookup("NEVERPARSED").is_none());
+    assert!(registry.lookup("xbt").is_some());
+}
+
+#[test]
+fn serde() {
+    let pair = MarketPair(Currency::ETH, Currency::BTC);
+    let json = serde_json::to_string(&pair).unwrap();
+    assert_eq!(json, r#"["ETH","BTC"]"#);
+    assert_eq!(serde_json::from_str::<MarketPair>(&json).unwrap(), pair);
+    assert_eq!(serde_json::from_str::<Currency>(r#""xbt""#).unwrap(), Currency::BTC);
+    assert!(serde_json::from_str::<Currency>("1").is_err());
+    assert!(serde_json::from_str::<Currency>(r#"{"Other": "BTC"}"#).is_err());
+}
@@ -0,0 +1,24 @@
+use openlimits_exchange::http::{header, FakeTransport, HttpRequest, HttpResponse, HttpTransport, Method, StatusCode};
+use url::Url;
+
+fn request(path: &str) -> HttpRequest {
+    let url = Url::parse("https://localhost").and_then(|url| url.join(path)).expect("Couldn't parse the url");
+    HttpRequest::new(Method::GET, url)
+}
+
+#[tokio::test]
+async fn scripted_responses() {
+    let transport = FakeTransport::answering(StatusCode::NOT_FOUND, b"")
+        .with_response(HttpResponse { status: StatusCode::OK, headers: header::HeaderMap::new(), body: b"first".to_vec() })
+        .with_response(HttpResponse { status: StatusCode::OK, headers: header::HeaderMap::new(), body: b"second".to_vec() });
+
+    let first = transport.send(request("/first")).await.expect("Couldn't send the request");
+    let second = transport.send(request("/second")).await.expect("Couldn't send the request");
+    let third = transport.send(request("/third")).await.expect("Couldn't send the request");
+    assert_eq!(first.text(), "first");
+    assert_eq!(second.text(), "second");
+    assert_eq!(third.status, StatusCode::NOT_FOUND);
+
+    let paths: Vec<String> = transport.requests().iter().map(|request| request.url.path().to_string()).collect();
+    assert_eq!(paths, vec!["/first", "/second", "/third"]);
+}
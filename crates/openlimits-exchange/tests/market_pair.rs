@@ -0,0 +1,82 @@
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use openlimits_exchange::errors::OpenLimitsError;
+use openlimits_exchange::model::market_pair::MarketPair;
+use openlimits_exchange::model::{Currency, SymbolFormat};
+use openlimits_exchange::shared::Result;
+use openlimits_exchange::traits::info::{ExchangeInfo, ExchangeInfoRetrieval, MarketPairHandle, MarketPairInfo};
+
+struct Pairs(Vec<(&'static str, &'static str, &'static str)>);
+
+#[async_trait]
+impl ExchangeInfoRetrieval for Pairs {
+    async fn get_pair(&self, _market_pair: &MarketPair) -> Result<MarketPairHandle> {
+        Err(OpenLimitsError::SymbolNotFound())
+    }
+
+    async fn retrieve_pairs(&self) -> Result<Vec<MarketPairInfo>> {
+        Ok(self
+            .0
+            .iter()
+            .map(|(symbol, base, quote)| MarketPairInfo {
+                symbol: symbol.to_string(),
+                base: base.to_string(),
+                quote: quote.to_string(),
+                base_increment: Decimal::new(1, 8),
+                quote_increment: Decimal::new(1, 2),
+                min_base_trade_size: None,
+                min_quote_trade_size: None,
+            })
+            .collect())
+    }
+
+    async fn refresh_market_info(&self) -> Result<Vec<MarketPairHandle>> {
+        Ok(Vec::new())
+    }
+}
+
+#[test]
+fn from_str() {
+    let pair = MarketPair(Currency::BTC, Currency::USDT);
+    assert_eq!("BTC-USDT".parse::<MarketPair>().unwrap(), pair);
+    assert_eq!("btc_usdt".parse::<MarketPair>().unwrap(), pair);
+    assert_eq!("BTC/USDT".parse::<MarketPair>().unwrap(), pair);
+    assert!("btcusdt".parse::<MarketPair>().is_err());
+    assert!("BTC-USDT-ETH".parse::<MarketPair>().is_err());
+    assert!("BTC-".parse::<MarketPair>().is_err());
+}
+
+#[test]
+fn display() {
+    let pair = MarketPair(Currency::ETH, Currency::BTC);
+    assert_eq!(pair.to_string(), "ETH/BTC");
+    assert_eq!(pair.to_string().parse::<MarketPair>().unwrap(), pair);
+    assert_eq!(pair.inverse(), MarketPair(Currency::BTC, Currency::ETH));
+}
+
+#[test]
+fn symbol_format() {
+    let pair = MarketPair(Currency::ETH, Currency::BTC);
+    let nash = SymbolFormat::new(Some('_'), true);
+    let coinbase = SymbolFormat::new(Some('-'), false);
+    let binance = SymbolFormat::new(None, false);
+    assert_eq!(nash.format(&pair), "eth_btc");
+    assert_eq!(coinbase.format(&pair), "ETH-BTC");
+    assert_eq!(binance.format(&pair), "ETHBTC");
+    assert_eq!(nash.parse("eth_btc").unwrap(), pair);
+    assert_eq!(coinbase.parse("ETH-BTC").unwrap(), pair);
+    assert!(coinbase.parse("eth_btc").is_err());
+    assert!(binance.parse("ETHBTC").is_err());
+}
+
+#[tokio::test]
+async fn exchange_info_market_pair() {
+    let info = ExchangeInfo::new();
+    let pairs = Pairs(vec![("BTCUSDT", "BTC", "USDT"), ("ETHBTC", "ETH", "BTC")]);
+    info.refresh(&pairs).await.unwrap();
+    let pair = MarketPair(Currency::BTC, Currency::USDT);
+    assert_eq!(info.market_pair("BTCUSDT").unwrap(), pair);
+    assert_eq!(info.market_pair("btcusdt").unwrap(), pair);
+    assert_eq!(info.market_pair("btc-usdt").unwrap(), pair);
+    assert!(matches!(info.market_pair("ETHUSDT"), Err(OpenLimitsError::SymbolNotFound())));
+}
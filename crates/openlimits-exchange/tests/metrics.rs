@@ -0,0 +1,41 @@
+use std::sync::Arc;
+use openlimits_exchange::http::{header, FakeTransport, HttpRequest, HttpResponse, HttpTransport, InstrumentedTransport, Method, StatusCode};
+use openlimits_exchange::metrics::{self, MetricsRecorder, PrometheusRecorder};
+use url::Url;
+
+#[test]
+fn prometheus_recorder() {
+    let recorder = PrometheusRecorder::with_buckets(vec![0.1, 1.0]);
+    recorder.increment_counter("requests_total", &[("venue", "binance")]);
+    recorder.increment_counter("requests_total", &[("venue", "binance")]);
+    recorder.record_histogram("latency_seconds", 0.5, &[]);
+    recorder.set_gauge("used_weight", 10.0, &[("venue", "binance")]);
+    recorder.set_gauge("used_weight", 20.0, &[("venue", "binance")]);
+
+    let output = recorder.render();
+    assert!(output.contains("# TYPE requests_total counter\nrequests_total{venue=\"binance\"} 2\n"));
+    assert!(output.contains("# TYPE used_weight gauge\nused_weight{venue=\"binance\"} 20\n"));
+    assert!(output.contains("latency_seconds_bucket{le=\"0.1\"} 0\n"));
+    assert!(output.contains("latency_seconds_bucket{le=\"1\"} 1\n"));
+    assert!(output.contains("latency_seconds_bucket{le=\"+Inf\"} 1\n"));
+    assert!(output.contains("latency_seconds_sum 0.5\nlatency_seconds_count 1\n"));
+}
+
+#[tokio::test]
+async fn instrumented_transport() {
+    let recorder = Arc::new(PrometheusRecorder::default());
+    metrics::set_recorder(recorder.clone());
+
+    // Answers every request with an empty body and a used weight of 10.
+    let mut headers = header::HeaderMap::new();
+    headers.insert("x-used-weight", header::HeaderValue::from_static("10"));
+    let fake = FakeTransport::new(HttpResponse { status: StatusCode::OK, headers, body: Vec::new() });
+    let transport = InstrumentedTransport::new("fake", Arc::new(fake)).with_weight_header("x-used-weight");
+    let url = Url::parse("https://localhost/api/orders/123456").expect("Couldn't parse the url");
+    transport.send(HttpRequest::new(Method::GET, url)).await.expect("Couldn't send the request");
+
+    let output = recorder.render();
+    assert!(output.contains("openlimits_http_requests_total{venue=\"fake\",endpoint=\"/api/orders/{id}\",status=\"200\"} 1\n"));
+    assert!(output.contains("openlimits_http_request_duration_seconds_count{venue=\"fake\",endpoint=\"/api/orders/{id}\"} 1\n"));
+    assert!(output.contains("# TYPE openlimits_http_used_weight gauge\nopenlimits_http_used_weight{venue=\"fake\"} 10\n"));
+}
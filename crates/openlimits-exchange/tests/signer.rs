@@ -0,0 +1,90 @@
+use openlimits_exchange::signer::{HmacSigner, RequestSigner, SignerAddress, SocketSigner};
+use std::time::Duration;
+use openlimits_exchange::OpenLimitsError;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+const SECRET: &str = "Jefe";
+const MESSAGE: &[u8] = b"what do ya want for nothing?";
+// RFC 4231, test case 2.
+const SIGNATURE: &str = "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843";
+
+async fn read_frame(stream: &mut TcpStream) -> Vec<u8> {
+    let length = stream.read_u32().await.expect("Couldn't read the frame length") as usize;
+    let mut frame = vec![0; length];
+    stream.read_exact(&mut frame).await.expect("Couldn't read the frame");
+    frame
+}
+
+/// Serves one signature with the key named `key_id`, refusing any other key.
+async fn signing_process(listener: TcpListener) {
+    let (mut stream, _) = listener.accept().await.expect("Couldn't accept the connection");
+    let key_id = read_frame(&mut stream).await;
+    let message = read_frame(&mut stream).await;
+    let signature = if key_id == b"key_id" {
+        HmacSigner::new(SECRET).sign(&message).await.expect("Couldn't sign")
+    } else {
+        Vec::new()
+    };
+    stream.write_u32(signature.len() as u32).await.expect("Couldn't write the length");
+    stream.write_all(&signature).await.expect("Couldn't write the signature");
+}
+
+#[tokio::test]
+async fn hmac_signer() {
+    let signer = HmacSigner::new(SECRET);
+    let signature = signer.sign(MESSAGE).await.expect("Couldn't sign");
+    assert_eq!(hex::encode(signature), SIGNATURE);
+    assert!(!format!("{:?}", signer).contains(SECRET));
+}
+
+#[tokio::test]
+async fn socket_signer() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.expect("Couldn't listen");
+    let address = listener.local_addr().expect("Couldn't get the address");
+    tokio::spawn(signing_process(listener));
+
+    let signer = SocketSigner::new(SignerAddress::Tcp(address), "key_id");
+    let signature = signer.sign(MESSAGE).await.expect("Couldn't sign");
+    assert_eq!(hex::encode(signature), SIGNATURE);
+}
+
+#[tokio::test]
+async fn socket_signer_refusal() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.expect("Couldn't listen");
+    let address = listener.local_addr().expect("Couldn't get the address");
+    tokio::spawn(signing_process(listener));
+
+    let signer = SocketSigner::new(SignerAddress::Tcp(address), "unknown");
+    assert!(signer.sign(MESSAGE).await.is_err());
+}
+
+#[tokio::test]
+async fn socket_signer_oversized_signature() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.expect("Couldn't listen");
+    let address = listener.local_addr().expect("Couldn't get the address");
+    tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.expect("Couldn't accept the connection");
+        read_frame(&mut stream).await;
+        read_frame(&mut stream).await;
+        stream.write_u32(u32::MAX).await.expect("Couldn't write the length");
+    });
+
+    let signer = SocketSigner::new(SignerAddress::Tcp(address), "key_id");
+    assert!(matches!(signer.sign(MESSAGE).await, Err(OpenLimitsError::NotParsableResponse(_))));
+}
+
+#[tokio::test]
+async fn socket_signer_timeout() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.expect("Couldn't listen");
+    let address = listener.local_addr().expect("Couldn't get the address");
+    // Accepts the connection but never answers.
+    tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.expect("Couldn't accept the connection");
+        let mut buffer = Vec::new();
+        stream.read_to_end(&mut buffer).await.ok();
+    });
+
+    let signer = SocketSigner::new(SignerAddress::Tcp(address), "key_id").with_timeout(Duration::from_millis(100));
+    assert!(matches!(signer.sign(MESSAGE).await, Err(OpenLimitsError::RequestTimeout())));
+}
@@ -0,0 +1,69 @@
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use openlimits_exchange::shared;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Timestamped {
+    #[serde(with = "openlimits_exchange::shared::timestamp")]
+    time: DateTime<Utc>,
+    #[serde(default, with = "openlimits_exchange::shared::opt_timestamp")]
+    created_at: Option<DateTime<Utc>>,
+}
+
+fn time() -> DateTime<Utc> {
+    Utc.timestamp_millis_opt(1_616_432_112_345).unwrap()
+}
+
+#[test]
+fn round_trip() {
+    let value = Timestamped { time: time(), created_at: Some(time()) };
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(
+        json,
+        r#"{"time":"2021-03-22T16:55:12.345Z","created_at":"2021-03-22T16:55:12.345Z"}"#
+    );
+    assert_eq!(serde_json::from_str::<Timestamped>(&json).unwrap(), value);
+
+    let value = Timestamped { time: time(), created_at: None };
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(json, r#"{"time":"2021-03-22T16:55:12.345Z","created_at":null}"#);
+    assert_eq!(serde_json::from_str::<Timestamped>(&json).unwrap(), value);
+}
+
+#[test]
+fn legacy_input() {
+    let inputs = [
+        r#"1616432112345"#,
+        r#""1616432112345""#,
+        r#""2021-03-22T16:55:12.345Z""#,
+        r#""2021-03-22T17:55:12.345+01:00""#,
+        r#""2021-03-22 16:55:12.345""#,
+        r#""2021-03-22 16:55:12.345 UTC""#,
+    ];
+    for input in inputs.iter() {
+        let json = format!(r#"{{"time":{},"created_at":{}}}"#, input, input);
+        let value: Timestamped = serde_json::from_str(&json).unwrap_or_else(|e| panic!("{}: {}", input, e));
+        assert_eq!(value.time, time(), "{}", input);
+        assert_eq!(value.created_at, Some(time()), "{}", input);
+    }
+    let value: Timestamped = serde_json::from_str(r#"{"time":"2021-03-22 16:55:12 UTC"}"#).unwrap();
+    assert_eq!(value.time, Utc.timestamp_millis_opt(1_616_432_112_000).unwrap());
+    assert_eq!(value.created_at, None);
+}
+
+#[test]
+fn invalid_input() {
+    assert!(serde_json::from_str::<Timestamped>(r#"{"time":"yesterday"}"#).is_err());
+    assert!(serde_json::from_str::<Timestamped>(r#"{"time":-1}"#).is_err());
+    assert!(serde_json::from_str::<Timestamped>(r#"{"time":1,"created_at":"soon"}"#).is_err());
+}
+
+#[test]
+fn millisecond_precision() {
+    let parsed = shared::parse_timestamp("2021-03-22T16:55:12.345678901Z").unwrap();
+    assert_eq!(parsed, time());
+    let precise = Utc.timestamp_nanos(1_616_432_112_345_999_999);
+    assert_eq!(shared::truncate_to_millis(precise), time());
+    assert_eq!(shared::datetime_to_timestamp(&time()), 1_616_432_112_345);
+    assert_eq!(shared::timestamp_to_utc_datetime(1_616_432_112_345), time());
+}
@@ -0,0 +1,41 @@
+use futures::{SinkExt, StreamExt};
+use tokio::net::TcpListener;
+use tokio_tungstenite::{accept_async, tungstenite};
+use openlimits_exchange::websocket::{self, Message};
+
+/// Echoes the text frames of one connection after a ping, until it is closed.
+async fn echo_server(listener: TcpListener) {
+    let (stream, _) = listener.accept().await.expect("Couldn't accept the connection");
+    let mut websocket = accept_async(stream).await.expect("Couldn't accept the websocket");
+    websocket.send(tungstenite::Message::Ping(b"ping".to_vec())).await.expect("Couldn't ping");
+    while let Some(Ok(message)) = websocket.next().await {
+        if let tungstenite::Message::Text(text) = message {
+            websocket.send(tungstenite::Message::Text(text)).await.expect("Couldn't echo");
+        }
+    }
+}
+
+#[tokio::test]
+async fn echo() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.expect("Couldn't listen");
+    let address = listener.local_addr().expect("Couldn't get the address");
+    tokio::spawn(echo_server(listener));
+
+    let (mut sink, mut stream) = websocket::connect(&format!("ws://{}", address)).await.expect("Couldn't connect");
+    assert_eq!(stream.next().await.expect("No ping").expect("Unreadable ping"), Message::Ping(b"ping".to_vec()));
+    sink.send(Message::Text(String::from("hello"))).await.expect("Couldn't send");
+    assert_eq!(stream.next().await.expect("No echo").expect("Unreadable echo"), Message::Text(String::from("hello")));
+
+    sink.send(Message::Close).await.expect("Couldn't close");
+    assert_eq!(stream.next().await.expect("No close").expect("Unreadable close"), Message::Close);
+    assert!(stream.next().await.is_none());
+}
+
+#[tokio::test]
+async fn connection_refused() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.expect("Couldn't listen");
+    let address = listener.local_addr().expect("Couldn't get the address");
+    drop(listener);
+
+    assert!(websocket::connect(&format!("ws://{}", address)).await.is_err());
+}
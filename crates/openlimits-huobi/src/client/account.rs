@@ -0,0 +1,143 @@
+use rust_decimal::prelude::*;
+use serde_json::json;
+use crate::model::{Account, AccountBalance, BatchCancelResult, DataResponse, MatchResult, MatchResultsReq, Order, OrderRequest, OrdersReq};
+use openlimits_exchange::errors::OpenLimitsError;
+use openlimits_exchange::traits::info::MarketPairInfo;
+use super::BaseClient;
+use super::shared::Result;
+
+/// Huobi cancels at most 50 orders per batch.
+const BATCH_CANCEL_LIMIT: usize = 50;
+
+impl BaseClient {
+    pub async fn get_accounts(&self) -> Result<Vec<Account>> {
+        self.transport
+            .signed_get::<DataResponse<Vec<Account>>, ()>("/v1/account/accounts", None)
+            .await
+            .map(|response| response.data)
+    }
+
+    pub async fn get_spot_account_id(&self) -> Result<u64> {
+        if let Some(id) = *self.spot_account_id.read().map_err(|_| OpenLimitsError::PoisonError())? {
+            return Ok(id);
+        }
+        let id = self
+            .get_accounts()
+            .await?
+            .into_iter()
+            .find(|account| account.account_type == "spot")
+            .map(|account| account.id)
+            .ok_or_else(|| OpenLimitsError::UnkownResponse("No spot account found.".to_string()))?;
+        *self.spot_account_id.write().map_err(|_| OpenLimitsError::PoisonError())? = Some(id);
+        Ok(id)
+    }
+
+    pub async fn get_balances(&self) -> Result<AccountBalance> {
+        let account_id = self.get_spot_account_id().await?;
+        let endpoint = format!("/v1/account/accounts/{}/balance", account_id);
+        self.transport
+            .signed_get::<DataResponse<AccountBalance>, ()>(&endpoint, None)
+            .await
+            .map(|response| response.data)
+    }
+
+    // Current open orders, for ONE symbol or for all of them
+    pub async fn get_open_orders(&self, symbol: Option<&str>) -> Result<Vec<Order>> {
+        let account_id = self.get_spot_account_id().await?;
+        let mut params = vec![("account-id", account_id.to_string()), ("size", "500".to_string())];
+        if let Some(symbol) = symbol {
+            params.push(("symbol", symbol.to_string()));
+        }
+        self.transport
+            .signed_get::<DataResponse<Vec<Order>>, _>("/v1/order/openOrders", Some(&params))
+            .await
+            .map(|response| response.data)
+    }
+
+    pub async fn get_orders(&self, params: &OrdersReq) -> Result<Vec<Order>> {
+        self.transport
+            .signed_get::<DataResponse<Vec<Order>>, _>("/v1/order/orders", Some(params))
+            .await
+            .map(|response| response.data)
+    }
+
+    pub async fn get_order(&self, order_id: u64) -> Result<Order> {
+        let endpoint = format!("/v1/order/orders/{}", order_id);
+        self.transport
+            .signed_get::<DataResponse<Order>, ()>(&endpoint, None)
+            .await
+            .map(|response| response.data)
+    }
+
+    // Place an order, `order_type` being e.g. "buy-limit" or "sell-market". Returns the order id.
+    pub async fn place_order(
+        &self,
+        pair: MarketPairInfo,
+        order_type: &str,
+        amount: Decimal,
+        price: Option<Decimal>,
+        client_order_id: Option<String>,
+    ) -> Result<u64> {
+        let account_id = self.get_spot_account_id().await?;
+        let amount = amount.round_dp(pair.base_increment.normalize().scale());
+        let price = price.map(|price| {
+            price.round_dp_with_strategy(
+                pair.quote_increment.normalize().scale(),
+                RoundingStrategy::ToZero,
+            )
+        });
+        let order = OrderRequest {
+            account_id: account_id.to_string(),
+            symbol: pair.symbol,
+            order_type: order_type.to_string(),
+            amount: amount.to_string(),
+            price: price.map(|price| price.to_string()),
+            client_order_id,
+        };
+        let response: DataResponse<String> = self
+            .transport
+            .signed_post("/v1/order/orders/place", Some(&order))
+            .await?;
+        response.data.parse().map_err(|_| {
+            OpenLimitsError::NotParsableResponse(format!("{} is not a valid order id.", response.data))
+        })
+    }
+
+    pub async fn cancel_order(&self, order_id: u64) -> Result<String> {
+        let endpoint = format!("/v1/order/orders/{}/submitcancel", order_id);
+        self.transport
+            .signed_post::<(), DataResponse<String>>(&endpoint, None)
+            .await
+            .map(|response| response.data)
+    }
+
+    // Cancels the given orders, returning the ids Huobi accepted to cancel
+    pub async fn cancel_orders(&self, order_ids: &[u64]) -> Result<Vec<String>> {
+        let mut canceled = Vec::new();
+        for chunk in order_ids.chunks(BATCH_CANCEL_LIMIT) {
+            let order_ids: Vec<String> = chunk.iter().map(ToString::to_string).collect();
+            let params = json! {{"order-ids": order_ids}};
+            let response: DataResponse<BatchCancelResult> = self
+                .transport
+                .signed_post("/v1/order/orders/batchcancel", Some(&params))
+                .await?;
+            canceled.extend(response.data.success);
+        }
+        Ok(canceled)
+    }
+
+    pub async fn get_match_results(&self, params: &MatchResultsReq) -> Result<Vec<MatchResult>> {
+        self.transport
+            .signed_get::<DataResponse<Vec<MatchResult>>, _>("/v1/order/matchresults", Some(params))
+            .await
+            .map(|response| response.data)
+    }
+
+    pub async fn get_order_match_results(&self, order_id: u64) -> Result<Vec<MatchResult>> {
+        let endpoint = format!("/v1/order/orders/{}/matchresults", order_id);
+        self.transport
+            .signed_get::<DataResponse<Vec<MatchResult>>, ()>(&endpoint, None)
+            .await
+            .map(|response| response.data)
+    }
+}
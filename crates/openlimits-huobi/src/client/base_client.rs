@@ -0,0 +1,20 @@
+use std::sync::Arc;
+use std::sync::RwLock;
+use super::Transport;
+
+/// The openlimits-huobi client. Orders and balances belong to the spot account, whose id is
+/// looked up once and cached.
+#[derive(Clone)]
+pub struct BaseClient {
+    pub transport: Transport,
+    pub(crate) spot_account_id: Arc<RwLock<Option<u64>>>,
+}
+
+impl BaseClient {
+    pub fn new(transport: Transport) -> Self {
+        Self {
+            transport,
+            spot_account_id: Arc::new(RwLock::new(None)),
+        }
+    }
+}
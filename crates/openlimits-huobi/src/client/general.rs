@@ -0,0 +1,20 @@
+use super::BaseClient;
+use crate::model::{DataResponse, Symbol};
+use super::shared::Result;
+
+impl BaseClient {
+    // Check server time
+    pub async fn get_server_time(&self) -> Result<u64> {
+        self.transport
+            .get::<DataResponse<u64>, ()>("/v1/common/timestamp", None)
+            .await
+            .map(|response| response.data)
+    }
+
+    pub async fn get_symbols(&self) -> Result<Vec<Symbol>> {
+        self.transport
+            .get::<DataResponse<Vec<Symbol>>, ()>("/v1/common/symbols", None)
+            .await
+            .map(|response| response.data)
+    }
+}
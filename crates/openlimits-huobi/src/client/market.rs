@@ -0,0 +1,58 @@
+use serde_json::json;
+use super::BaseClient;
+use crate::model::{DataResponse, Depth, Kline, KlineParams, MarketPair, MarketTicker, MergedTicker, TickResponse, TradeDetail};
+use super::shared::Result;
+
+// Market Data endpoints
+impl BaseClient {
+    // Order book, up to 150 levels per side
+    pub async fn get_depth<S>(&self, symbol: S) -> Result<TickResponse<Depth>>
+    where
+        S: Into<MarketPair>
+    {
+        let symbol = symbol.into().0;
+        let params = json! {{"symbol": symbol, "type": "step0"}};
+
+        self.transport.get("/market/depth", Some(&params)).await
+    }
+
+    // 24hr ticker with the best bid and ask for ONE symbol
+    pub async fn get_merged_ticker(&self, symbol: &str) -> Result<TickResponse<MergedTicker>> {
+        let params = json! {{"symbol": symbol}};
+
+        self.transport.get("/market/detail/merged", Some(&params)).await
+    }
+
+    // 24hr ticker for ALL symbols
+    pub async fn get_all_market_tickers(&self) -> Result<(u64, Vec<MarketTicker>)> {
+        let response: TickersResponse = self
+            .transport
+            .get::<_, ()>("/market/tickers", None)
+            .await?;
+        Ok((response.ts, response.data))
+    }
+
+    // Latest candles, newest first (Default 150; max 2000)
+    pub async fn get_klines(&self, params: &KlineParams) -> Result<Vec<Kline>> {
+        self.transport
+            .get::<DataResponse<Vec<Kline>>, _>("/market/history/kline", Some(params))
+            .await
+            .map(|response| response.data)
+    }
+
+    // Latest trades, newest first (Default 1; max 2000)
+    pub async fn get_trades(&self, symbol: &str, size: u64) -> Result<Vec<TradeDetail>> {
+        let params = json! {{"symbol": symbol, "size": size}};
+
+        self.transport
+            .get::<DataResponse<Vec<TradeDetail>>, _>("/market/history/trade", Some(&params))
+            .await
+            .map(|response| response.data)
+    }
+}
+
+#[derive(serde::Deserialize)]
+struct TickersResponse {
+    ts: u64,
+    data: Vec<MarketTicker>,
+}
@@ -0,0 +1,9 @@
+//! This module is used to make calls to the api
+mod account;
+mod general;
+mod market;
+mod base_client;
+
+pub use base_client::BaseClient;
+pub (crate) use super::transport::Transport;
+pub use super::shared;
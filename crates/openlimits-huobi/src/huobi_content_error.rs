@@ -0,0 +1,21 @@
+use serde::Deserialize;
+use serde::Serialize;
+use std::fmt;
+use thiserror::Error;
+
+/// This struct represents a openlimits-huobi content error
+#[derive(Serialize, Deserialize, Debug, Error)]
+#[serde(rename_all = "kebab-case")]
+pub struct HuobiContentError {
+    pub status: String,
+    #[serde(default)]
+    pub err_code: String,
+    #[serde(default)]
+    pub err_msg: String,
+}
+
+impl fmt::Display for HuobiContentError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "error code: {} msg: {}", self.err_code, self.err_msg)
+    }
+}
@@ -0,0 +1,29 @@
+use std::sync::Arc;
+use super::HuobiCredentials;
+use openlimits_exchange::exchange::Environment;
+use openlimits_exchange::http::HttpTransport;
+use openlimits_exchange::signer::RequestSigner;
+
+/// This struct represents the type of environment that will be used and receives the credentials as parameters.
+/// Huobi has no sandbox, so only the production environment can be used.
+#[derive(Default, Clone, Debug)]
+pub struct HuobiParameters {
+    pub environment: Environment,
+    pub credentials: Option<HuobiCredentials>,
+    /// Signs the requests in place of the credentials' secret, which is then left unused.
+    pub signer: Option<Arc<dyn RequestSigner>>,
+    /// Sends the REST requests through this transport instead of the default reqwest client.
+    pub http_transport: Option<Arc<dyn HttpTransport>>,
+    /// Connects the websocket to this URL instead of Huobi's, e.g. a local server in tests.
+    pub websocket_url: Option<String>,
+}
+
+impl HuobiParameters {
+    /// Production environment
+    pub fn production() -> Self {
+        Self {
+            environment: Environment::Production,
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use std::convert::TryFrom;
+use std::time::Duration;
+use serde_json::Value;
+use openlimits_exchange::exchange::Environment;
+use openlimits_exchange::message::subscription::{Publication, Subscription};
+use crate::message::Connection;
+use crate::{model, to_candle, to_market_trade, to_ticker, HuobiParameters};
+use super::shared::Result;
+
+const WS_URL: &str = "wss://api.huobi.pro/ws";
+
+/// How long a request waits for its response by default.
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// This struct is used for websocket communications with openlimits-huobi. It implements the
+/// messaging `Requester` and `Subscriber` traits, all the subscriptions sharing one connection.
+pub struct HuobiWebsocket {
+    pub(crate) connection: Connection,
+    pub(crate) request_timeout: Duration,
+}
+
+impl HuobiWebsocket {
+    pub async fn new(parameters: HuobiParameters) -> Result<Self> {
+        if parameters.environment == Environment::Sandbox {
+            return Err(crate::no_sandbox());
+        }
+        let url = parameters.websocket_url.as_deref().unwrap_or(WS_URL);
+        Ok(Self {
+            connection: Connection::new(url).await?,
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
+        })
+    }
+
+    /// Sets how long requests, subscriptions included, wait for their response before failing
+    /// with `RequestTimeout`.
+    pub fn with_request_timeout(mut self, request_timeout: Duration) -> Self {
+        self.request_timeout = request_timeout;
+        self
+    }
+
+    /// Closes the websocket, which ends every subscription.
+    pub async fn disconnect(&self) {
+        self.connection.close();
+    }
+
+    /// The channel Huobi publishes the subscription on, e.g. "market.ethbtc.kline.1min".
+    pub fn channel(subscription: &Subscription) -> Result<String> {
+        Ok(match subscription {
+            Subscription::Kline(market_pair, interval) => {
+                let symbol = model::MarketPair::from(market_pair.clone()).0;
+                let period = model::Interval::try_from(*interval)?;
+                format!("market.{}.kline.{}", symbol, period.as_str())
+            }
+            Subscription::Depth(market_pair) => {
+                let symbol = model::MarketPair::from(market_pair.clone()).0;
+                format!("market.{}.depth.step0", symbol)
+            }
+            Subscription::Trades(market_pair) => {
+                let symbol = model::MarketPair::from(market_pair.clone()).0;
+                format!("market.{}.trade.detail", symbol)
+            }
+            Subscription::Ticker(market_pair) => {
+                let symbol = model::MarketPair::from(market_pair.clone()).0;
+                format!("market.{}.ticker", symbol)
+            }
+        })
+    }
+
+    pub(crate) fn publication(subscription: &Subscription, tick: Value, ts: u64) -> Result<Publication> {
+        Ok(match subscription {
+            Subscription::Kline(_, _) => {
+                let kline: model::Kline = serde_json::from_value(tick)?;
+                Publication::Kline(to_candle(kline))
+            }
+            Subscription::Depth(_) => {
+                let depth: model::Depth = serde_json::from_value(tick)?;
+                Publication::Depth(depth.into())
+            }
+            Subscription::Trades(market_pair) => {
+                let detail: model::TradeDetail = serde_json::from_value(tick)?;
+                Publication::Trades(
+                    detail
+                        .data
+                        .into_iter()
+                        .map(|trade| to_market_trade(trade, market_pair.clone()))
+                        .collect(),
+                )
+            }
+            Subscription::Ticker(market_pair) => {
+                let ticker: model::MarketTicker = serde_json::from_value(tick)?;
+                Publication::Ticker(to_ticker(ticker, market_pair.clone(), ts))
+            }
+        })
+    }
+}
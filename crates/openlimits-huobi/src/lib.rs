@@ -1,113 +1,674 @@
-pub mod prelude;
+//! This module provides functionality for communicating with the openlimits-huobi API.
 
-pub use exchange::*;
+pub mod model;
+pub mod prelude;
+pub mod message;
 
-use libflate::gzip::Decoder;
-use std::io::Read;
+pub use openlimits_exchange::shared;
 
 use async_trait::async_trait;
-use nash_ws::{WebSocket, Message};
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use rust_decimal::prelude::{Decimal, Zero};
+use transport::Transport;
+use client::BaseClient;
+use openlimits_exchange::{
+    errors::{MissingImplementationContent, OpenLimitsError},
+    model::{
+        AskBid, Balance, Batch, BatchOperation, CancelAllOrdersRequest, CancelOrderRequest, Candle,
+        Capabilities,
+        GetHistoricRatesRequest, GetHistoricTradesRequest, GetOrderHistoryRequest, GetOrderRequest,
+        GetLedgerRequest, GetPriceTickerRequest, Interval, LedgerEntry, Liquidity,
+        OpenLimitOrderRequest, OpenMarketOrderRequest, Operation, Order, OrderBookRequest,
+        OrderBookResponse, OrderCanceled, OrderStatus, OrderType, Paginator, Side, Ticker,
+        TimeInForce, Trade, TradeHistoryRequest,
+        websocket::SubscriptionKind,
+    }
+};
+use openlimits_exchange::Result;
 
-use uuid::Uuid;
+mod huobi_content_error;
+mod huobi_credentials;
+mod huobi_parameters;
+mod huobi_websocket;
+mod transport;
 
-pub mod message;
+pub use huobi_content_error::*;
+pub use huobi_credentials::*;
+pub use huobi_parameters::*;
+pub use huobi_websocket::*;
+
+pub mod client;
 
-use message::HuobiResponse;
-use exchange::message::subscription::Publication;
+use openlimits_exchange::traits::info::{ExchangeInfo, ExchangeInfoRetrieval, MarketPairInfo, MarketPairHandle};
+use openlimits_exchange::traits::{Exchange, ExchangeMarketData, ExchangeAccount};
+use openlimits_exchange::exchange::Environment;
+use openlimits_exchange::model::market_pair::MarketPair;
 
+/// The main struct of the openlimits-huobi module
+#[derive(Clone)]
 pub struct Huobi {
-    sender: nash_ws::WebSocketSender,
-    requests: Arc<Mutex<HashMap<Uuid, async_channel::Sender<HuobiResponse>>>>,
-    subscriptions: Arc<Mutex<HashMap<String, async_channel::Sender<Publication>>>>
+    pub exchange_info: ExchangeInfo,
+    pub client: BaseClient,
 }
 
-impl Huobi {
-    async fn register_request(&mut self) -> (Uuid, async_channel::Receiver<HuobiResponse>) {
-        let id = Uuid::new_v4();
-        let (sender, receiver) = async_channel::bounded(1);
-        self.requests.lock().await.insert(id, sender);
-        (id, receiver)
+#[async_trait]
+impl Exchange for Huobi {
+    type InitParams = HuobiParameters;
+    type InnerClient = BaseClient;
+
+    async fn new(parameters: Self::InitParams) -> Result<Self> {
+        if parameters.environment == Environment::Sandbox {
+            return Err(no_sandbox());
+        }
+        let mut transport = match (parameters.credentials, parameters.signer) {
+            (Some(credentials), Some(signer)) => Transport::with_signer(&credentials.api_key, signer)?,
+            (Some(credentials), None) => Transport::with_credential(&credentials.api_key, &credentials.api_secret)?,
+            (None, _) => Transport::new()?,
+        };
+        if let Some(http_transport) = parameters.http_transport {
+            transport = transport.with_http_transport(http_transport);
+        }
+        let huobi = Huobi {
+            exchange_info: ExchangeInfo::with_venue("huobi"),
+            client: BaseClient::new(transport),
+        };
+
+        huobi.refresh_market_info().await?;
+        Ok(huobi)
     }
 
-    async fn register_subscription(&mut self, subscription: String) -> async_channel::Receiver<Publication> {
-        let (sender, receiver) = async_channel::bounded(1);
-        self.subscriptions.lock().await.insert(subscription, sender);
-        receiver
+    fn inner_client(&self) -> Option<&Self::InnerClient> {
+        Some(&self.client)
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            operations: vec![
+                Operation::OrderBook,
+                Operation::GetPriceTicker,
+                Operation::GetAllTickers,
+                Operation::GetHistoricRates,
+                Operation::GetHistoricTrades,
+                Operation::LimitBuy,
+                Operation::LimitSell,
+                Operation::MarketSell,
+                Operation::CancelOrder,
+                Operation::CancelAllOrders,
+                Operation::GetAllOpenOrders,
+                Operation::GetOrderHistory,
+                Operation::GetTradeHistory,
+                Operation::GetAccountBalances,
+                Operation::GetOrder,
+            ],
+            order_types: vec![OrderType::Limit, OrderType::Market],
+            time_in_force: vec![
+                TimeInForce::GoodTillCancelled,
+                TimeInForce::ImmediateOrCancelled,
+                TimeInForce::FillOrKill,
+            ],
+            intervals: vec![
+                Interval::OneMinute,
+                Interval::FiveMinutes,
+                Interval::FifteenMinutes,
+                Interval::ThirtyMinutes,
+                Interval::OneHour,
+                Interval::FourHours,
+                Interval::OneDay,
+                Interval::OneWeek,
+                Interval::OneMonth,
+            ],
+            subscriptions: vec![SubscriptionKind::OrderBookUpdates, SubscriptionKind::Trades],
+            // cancel_all_orders lists the open orders and cancels them with batchcancel.
+            batch_operations: vec![Batch::new(BatchOperation::CancelOrders, Some(50))],
+            // The step0 order book has 150 levels per side.
+            max_book_depth: Some(150),
+        }
     }
 }
 
-pub struct HuobiParameters {
-    pub environment: Environment
+#[async_trait]
+impl ExchangeInfoRetrieval for Huobi {
+    async fn retrieve_pairs(&self) -> Result<Vec<MarketPairInfo>> {
+        self.client.get_symbols().await.map(|symbols| {
+            symbols
+                .into_iter()
+                .filter(|symbol| symbol.state == "online")
+                .map(|symbol| MarketPairInfo {
+                    base: symbol.base_currency.to_uppercase(),
+                    quote: symbol.quote_currency.to_uppercase(),
+                    symbol: symbol.symbol,
+                    base_increment: Decimal::new(1, symbol.amount_precision),
+                    quote_increment: Decimal::new(1, symbol.price_precision),
+                    min_base_trade_size: symbol.min_order_amt,
+                    min_quote_trade_size: symbol.min_order_value,
+                })
+                .collect()
+        })
+    }
+
+    async fn refresh_market_info(&self) -> Result<Vec<MarketPairHandle>> {
+        self.exchange_info
+            .refresh(self as &dyn ExchangeInfoRetrieval)
+            .await
+    }
+
+    async fn get_pair(&self, market_pair: &MarketPair) -> Result<MarketPairHandle> {
+        let name = crate::model::MarketPair::from(market_pair.clone()).0;
+        self.exchange_info.get_pair(&name)
+    }
 }
 
-impl HuobiParameters {
-    pub fn new(environment: Environment) -> Self {
-        Self { environment }
+#[async_trait]
+impl ExchangeMarketData for Huobi {
+    async fn order_book(&self, req: &OrderBookRequest) -> Result<OrderBookResponse> {
+        self.client
+            .get_depth(req.market_pair.clone())
+            .await
+            .map(|response| response.tick.into())
+    }
+
+    async fn get_price_ticker(&self, req: &GetPriceTickerRequest) -> Result<Ticker> {
+        let symbol = model::MarketPair::from(req.market_pair.clone()).0;
+        let response = self.client.get_merged_ticker(&symbol).await?;
+        let ticker = response.tick;
+        Ok(Ticker {
+            market_pair: req.market_pair.clone(),
+            price: Some(ticker.close),
+            price_24h: Some(ticker.open),
+            bid: Some(ticker.bid.price),
+            bid_qty: Some(ticker.bid.qty),
+            ask: Some(ticker.ask.price),
+            ask_qty: Some(ticker.ask.qty),
+            high_24h: Some(ticker.high),
+            low_24h: Some(ticker.low),
+            volume_24h: Some(ticker.amount),
+            quote_volume_24h: Some(ticker.vol),
+            created_at: Some(shared::timestamp_to_utc_datetime(response.ts)),
+        })
+    }
+
+    async fn get_all_tickers(&self) -> Result<Vec<Ticker>> {
+        let (ts, tickers) = self.client.get_all_market_tickers().await?;
+        // The tickers also list offline symbols, which are not in the exchange info.
+        Ok(tickers
+            .into_iter()
+            .filter_map(|ticker| match self.exchange_info.market_pair(&ticker.symbol) {
+                Ok(market_pair) => Some(to_ticker(ticker, market_pair, ts)),
+                Err(_) => {
+                    tracing::debug!(symbol = %ticker.symbol, "Skipping the ticker of a symbol missing from the exchange info");
+                    None
+                }
+            })
+            .collect())
+    }
+
+    // Huobi only serves the latest candles, so the time range of the paginator is applied to
+    // them rather than used to page back in time.
+    async fn get_historic_rates(&self, req: &GetHistoricRatesRequest) -> Result<Vec<Candle>> {
+        let paginator = req.paginator.as_ref();
+        let params = model::KlineParams {
+            symbol: model::MarketPair::from(req.market_pair.clone()).0,
+            period: model::Interval::try_from(req.interval)?,
+            size: paginator.and_then(|p| p.limit),
+        };
+        let start_time = paginator.and_then(|p| p.start_time);
+        let end_time = paginator.and_then(|p| p.end_time);
+        Ok(self
+            .client
+            .get_klines(&params)
+            .await?
+            .into_iter()
+            .map(to_candle)
+            .filter(|candle| start_time.is_none_or(|start_time| candle.time >= start_time))
+            .filter(|candle| end_time.is_none_or(|end_time| candle.time <= end_time))
+            .collect())
+    }
+
+    // Like the candles, only the latest trades are served, so the paginator filters them.
+    async fn get_historic_trades(&self, req: &GetHistoricTradesRequest) -> Result<Vec<Trade>> {
+        let market_pair = self.exchange_info.market_pair(&req.market_pair)?;
+        let paginator = req.paginator.as_ref();
+        let parse_id = |id: &String| {
+            id.parse::<u64>().map_err(|_| {
+                OpenLimitsError::InvalidParameter(format!("{} is not a valid trade id.", id))
+            })
+        };
+        let after = paginator.and_then(|p| p.after.as_ref()).map(parse_id).transpose()?;
+        let before = paginator.and_then(|p| p.before.as_ref()).map(parse_id).transpose()?;
+        let start_time = paginator.and_then(|p| p.start_time);
+        let end_time = paginator.and_then(|p| p.end_time);
+        let limit = paginator.and_then(|p| p.limit).unwrap_or(HISTORIC_TRADES_LIMIT);
+        let symbol = model::MarketPair::from(market_pair.clone()).0;
+        let trades = self.client.get_trades(&symbol, limit).await?;
+        Ok(trades
+            .into_iter()
+            .flat_map(|detail| detail.data)
+            .filter(|trade| after.is_none_or(|after| trade.trade_id > after))
+            .filter(|trade| before.is_none_or(|before| trade.trade_id < before))
+            .map(|trade| to_market_trade(trade, market_pair.clone()))
+            .filter(|trade| start_time.is_none_or(|start_time| trade.created_at >= start_time))
+            .filter(|trade| end_time.is_none_or(|end_time| trade.created_at <= end_time))
+            .take(limit as usize)
+            .collect())
     }
 }
 
 #[async_trait]
-impl Exchange for Huobi {
-    type InitializationParameters = HuobiParameters;
-    fn endpoint_url(environment: Environment) -> &'static str {
-        match environment {
-            Environment::Production => "wss://api.openlimits-huobi.pro/ws",
-            Environment::Sandbox => unimplemented!("Huobi doesn't have a sandbox endpoint.")
+impl ExchangeAccount for Huobi {
+    async fn limit_buy(&self, req: &OpenLimitOrderRequest) -> Result<Order> {
+        self.limit_order(req, Side::Buy).await
+    }
+
+    async fn limit_sell(&self, req: &OpenLimitOrderRequest) -> Result<Order> {
+        self.limit_order(req, Side::Sell).await
+    }
+
+    // Huobi market buys take the amount of quote currency to spend, not the base size.
+    async fn market_buy(&self, _req: &OpenMarketOrderRequest) -> Result<Order> {
+        let message = "Huobi client doesn't implement market_buy".into();
+        Err(OpenLimitsError::MissingImplementation(MissingImplementationContent { message }))
+    }
+
+    async fn market_sell(&self, req: &OpenMarketOrderRequest) -> Result<Order> {
+        let pair = self.get_pair(&req.market_pair).await?.read()?;
+        let id = self
+            .client
+            .place_order(pair, "sell-market", req.size, None, req.client_order_id.clone())
+            .await?;
+        Ok(Order {
+            id: id.to_string(),
+            market_pair: req.market_pair.clone(),
+            client_order_id: req.client_order_id.clone(),
+            created_at: None,
+            order_type: OrderType::Market,
+            side: Side::Sell,
+            status: OrderStatus::New,
+            size: req.size,
+            price: None,
+            remaining: Some(req.size),
+            trades: Vec::new(),
+        })
+    }
+
+    async fn cancel_order(&self, req: &CancelOrderRequest) -> Result<OrderCanceled> {
+        let id = self.client.cancel_order(parse_order_id(&req.id)?).await?;
+        Ok(OrderCanceled { id })
+    }
+
+    async fn cancel_all_orders(&self, req: &CancelAllOrdersRequest) -> Result<Vec<OrderCanceled>> {
+        let symbol = req
+            .market_pair
+            .clone()
+            .map(|market_pair| model::MarketPair::from(market_pair).0);
+        let order_ids: Vec<u64> = self
+            .client
+            .get_open_orders(symbol.as_deref())
+            .await?
+            .into_iter()
+            .map(|order| order.id)
+            .collect();
+        Ok(self
+            .client
+            .cancel_orders(&order_ids)
+            .await?
+            .into_iter()
+            .map(|id| OrderCanceled { id })
+            .collect())
+    }
+
+    async fn get_all_open_orders(&self) -> Result<Vec<Order>> {
+        self.client
+            .get_open_orders(None)
+            .await?
+            .into_iter()
+            .map(|order| self.order(order))
+            .collect()
+    }
+
+    async fn get_order_history(&self, req: &GetOrderHistoryRequest) -> Result<Vec<Order>> {
+        let market_pair = req.market_pair.clone().ok_or_else(|| {
+            OpenLimitsError::MissingParameter("market_pair parameter is required.".to_string())
+        })?;
+        let params = model::OrdersReq {
+            symbol: model::MarketPair::from(market_pair.clone()).0,
+            states: order_states(req.order_status.as_deref()),
+            paginator: to_paginator(req.paginator.as_ref()),
+        };
+        Ok(self
+            .client
+            .get_orders(&params)
+            .await?
+            .into_iter()
+            .map(|order| to_order(order, market_pair.clone()))
+            .collect())
+    }
+
+    async fn get_trade_history(&self, req: &TradeHistoryRequest) -> Result<Vec<Trade>> {
+        let match_results = match (req.order_id.as_ref(), req.market_pair.as_ref()) {
+            (Some(order_id), _) => self.client.get_order_match_results(parse_order_id(order_id)?).await?,
+            (None, Some(market_pair)) => {
+                let params = model::MatchResultsReq {
+                    symbol: model::MarketPair::from(market_pair.clone()).0,
+                    paginator: to_paginator(req.paginator.as_ref()),
+                };
+                self.client.get_match_results(&params).await?
+            }
+            (None, None) => {
+                return Err(OpenLimitsError::MissingParameter(
+                    "market_pair or order_id parameter is required.".to_string(),
+                ))
+            }
+        };
+        match_results
+            .into_iter()
+            .map(|match_result| self.trade(match_result))
+            .collect()
+    }
+
+    // Each currency is reported as an available and a frozen entry, which are merged here.
+    // Huobi lists every currency, so the empty balances are left out.
+    async fn get_account_balances(&self, _paginator: Option<Paginator>) -> Result<Vec<Balance>> {
+        let account = self.client.get_balances().await?;
+        let mut balances: BTreeMap<String, Balance> = BTreeMap::new();
+        for entry in account.list {
+            let asset = entry.currency.to_uppercase();
+            let balance = balances.entry(asset.clone()).or_insert_with(|| Balance {
+                asset,
+                total: Decimal::zero(),
+                free: Decimal::zero(),
+            });
+            match entry.balance_type {
+                model::BalanceType::Trade => {
+                    balance.free += entry.balance;
+                    balance.total += entry.balance;
+                }
+                model::BalanceType::Frozen => balance.total += entry.balance,
+                model::BalanceType::Other => {}
+            }
+        }
+        Ok(balances
+            .into_values()
+            .filter(|balance| !balance.total.is_zero())
+            .collect())
+    }
+
+    async fn get_order(&self, req: &GetOrderRequest) -> Result<Order> {
+        let order = self.client.get_order(parse_order_id(&req.id)?).await?;
+        self.order(order)
+    }
+
+    async fn get_ledger(&self, _req: &GetLedgerRequest) -> Result<Vec<LedgerEntry>> {
+        let message = "Huobi client doesn't implement get_ledger".into();
+        Err(OpenLimitsError::MissingImplementation(MissingImplementationContent { message }))
+    }
+}
+
+/// Huobi serves at most 2000 of the latest trades, 100 are fetched unless a limit is given.
+const HISTORIC_TRADES_LIMIT: u64 = 100;
+
+/// The order states queried when the order history request doesn't filter by status.
+const ORDER_HISTORY_STATES: &str = "created,submitted,partial-filled,filled,partial-canceled,canceled";
+
+impl Huobi {
+    async fn limit_order(&self, req: &OpenLimitOrderRequest, side: Side) -> Result<Order> {
+        let order_type = limit_order_type(side.clone(), req.time_in_force, req.post_only)?;
+        let pair = self.get_pair(&req.market_pair).await?.read()?;
+        let id = self
+            .client
+            .place_order(pair, order_type, req.size, Some(req.price), req.client_order_id.clone())
+            .await?;
+        Ok(Order {
+            id: id.to_string(),
+            market_pair: req.market_pair.clone(),
+            client_order_id: req.client_order_id.clone(),
+            created_at: None,
+            order_type: OrderType::Limit,
+            side,
+            status: OrderStatus::New,
+            size: req.size,
+            price: Some(req.price),
+            remaining: Some(req.size),
+            trades: Vec::new(),
+        })
+    }
+
+    fn order(&self, order: model::Order) -> Result<Order> {
+        let market_pair = self.exchange_info.market_pair(&order.symbol)?;
+        Ok(to_order(order, market_pair))
+    }
+
+    fn trade(&self, match_result: model::MatchResult) -> Result<Trade> {
+        let market_pair = self.exchange_info.market_pair(&match_result.symbol)?;
+        Ok(to_trade(match_result, market_pair))
+    }
+}
+
+pub(crate) fn no_sandbox() -> OpenLimitsError {
+    let message = "Huobi doesn't have a sandbox environment".to_string();
+    OpenLimitsError::MissingImplementation(MissingImplementationContent { message })
+}
+
+fn parse_order_id(id: &str) -> Result<u64> {
+    id.parse::<u64>()
+        .map_err(|_| OpenLimitsError::InvalidParameter(format!("{} is not a valid order id.", id)))
+}
+
+fn limit_order_type(side: Side, time_in_force: TimeInForce, post_only: bool) -> Result<&'static str> {
+    let buy = side == Side::Buy;
+    Ok(match (time_in_force, post_only) {
+        (_, true) => if buy { "buy-limit-maker" } else { "sell-limit-maker" },
+        (TimeInForce::GoodTillCancelled, false) => if buy { "buy-limit" } else { "sell-limit" },
+        (TimeInForce::ImmediateOrCancelled, false) => if buy { "buy-ioc" } else { "sell-ioc" },
+        (TimeInForce::FillOrKill, false) => if buy { "buy-limit-fok" } else { "sell-limit-fok" },
+        (TimeInForce::GoodTillTime(_), false) => {
+            let message = "GoodTillTime is not supported by Huobi".to_string();
+            return Err(OpenLimitsError::MissingImplementation(MissingImplementationContent { message }));
+        }
+    })
+}
+
+/// Splits a Huobi order type like "buy-limit-maker" into its side and kind.
+fn side_and_order_type(order_type: &str) -> (Side, OrderType) {
+    let mut parts = order_type.splitn(2, '-');
+    let side = match parts.next() {
+        Some("buy") => Side::Buy,
+        _ => Side::Sell,
+    };
+    let order_type = match parts.next() {
+        Some("market") => OrderType::Market,
+        Some("limit") | Some("ioc") | Some("limit-maker") | Some("limit-fok") => OrderType::Limit,
+        Some("stop-limit") | Some("stop-limit-fok") => OrderType::StopLimit,
+        _ => OrderType::Unknown,
+    };
+    (side, order_type)
+}
+
+fn order_states(statuses: Option<&[OrderStatus]>) -> String {
+    let mut states: Vec<&str> = Vec::new();
+    for status in statuses.unwrap_or_default() {
+        let status_states: &[&str] = match status {
+            OrderStatus::New | OrderStatus::Open | OrderStatus::Active => &["submitted"],
+            OrderStatus::Pending => &["created"],
+            OrderStatus::PartiallyFilled => &["partial-filled"],
+            OrderStatus::Filled => &["filled"],
+            OrderStatus::Canceled => &["partial-canceled", "canceled"],
+            OrderStatus::PendingCancel => &["canceling"],
+            OrderStatus::Rejected | OrderStatus::Expired => &[],
+        };
+        for state in status_states {
+            if !states.contains(state) {
+                states.push(state);
+            }
+        }
+    }
+    match states.is_empty() {
+        true => ORDER_HISTORY_STATES.to_string(),
+        false => states.join(","),
+    }
+}
+
+// Huobi pages from an id, "prev" walking towards the newer records and "next" towards the older.
+fn to_paginator(paginator: Option<&Paginator>) -> model::Paginator {
+    let (from, direct) = match paginator {
+        Some(Paginator { after: Some(after), .. }) => (Some(after.clone()), Some("prev".to_string())),
+        Some(Paginator { before: Some(before), .. }) => (Some(before.clone()), Some("next".to_string())),
+        _ => (None, None),
+    };
+    model::Paginator {
+        start_time: paginator.and_then(|p| p.start_time.as_ref().map(shared::datetime_to_timestamp)),
+        end_time: paginator.and_then(|p| p.end_time.as_ref().map(shared::datetime_to_timestamp)),
+        from,
+        direct,
+        size: paginator.and_then(|p| p.limit),
+    }
+}
+
+impl From<model::Depth> for OrderBookResponse {
+    fn from(depth: model::Depth) -> Self {
+        Self {
+            last_update_id: None,
+            update_id: Some(depth.version),
+            bids: depth.bids.into_iter().map(Into::into).collect(),
+            asks: depth.asks.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<model::AskBid> for AskBid {
+    fn from(ask_bid: model::AskBid) -> Self {
+        Self {
+            price: ask_bid.price,
+            qty: ask_bid.qty,
         }
     }
+}
+
+impl From<model::Side> for Side {
+    fn from(side: model::Side) -> Self {
+        match side {
+            model::Side::Buy => Side::Buy,
+            model::Side::Sell => Side::Sell,
+        }
+    }
+}
+
+impl From<model::OrderState> for OrderStatus {
+    fn from(state: model::OrderState) -> Self {
+        match state {
+            model::OrderState::Created | model::OrderState::PreSubmitted => OrderStatus::Pending,
+            model::OrderState::Submitted => OrderStatus::New,
+            model::OrderState::PartialFilled => OrderStatus::PartiallyFilled,
+            model::OrderState::Filled => OrderStatus::Filled,
+            model::OrderState::PartialCanceled | model::OrderState::Canceled => OrderStatus::Canceled,
+            model::OrderState::Canceling => OrderStatus::PendingCancel,
+            model::OrderState::Rejected => OrderStatus::Rejected,
+        }
+    }
+}
 
-    async fn new(parameters: Self::InitializationParameters) -> Result<Self> {
-        let websocket = WebSocket::new(Self::endpoint_url(parameters.environment)).await;
-        websocket.map(|websocket| {
-            let requests: Arc<Mutex<HashMap<_, async_channel::Sender<HuobiResponse>>>> = Arc::new(Mutex::new(HashMap::new()));
-            let subscriptions: Arc<Mutex<HashMap<String, async_channel::Sender<Publication>>>> = Arc::new(Mutex::new(HashMap::new()));
-            let (sender, mut receiver) = websocket;
-            {
-                let mut websocket = sender.clone();
-                let subscriptions = subscriptions.clone();
-                let requests = requests.clone();
-                cross_async::spawn(async move {
-                    while let Some(Ok(message)) = receiver.next().await {
-                        if let Message::Binary(binary) = message {
-                            let mut decoder = Decoder::new(&binary[..]).expect("Couldn't create Decoder.");
-                            let mut str = String::new();
-                            decoder.read_to_string(&mut str).expect("Couldn't read to String.");
-                            let message = serde_json::from_str(&str).expect("Couldn't parse");
-                            match message {
-                                HuobiMessage::Ping(ping) => {
-                                    websocket.send(&Message::Text(format!("{{\"pong\": {}}}", ping.ping))).await.ok();
-                                },
-                                HuobiMessage::ChannelUpdate(update) => {
-                                    let mut subscriptions = subscriptions.lock().await;
-                                    let sender = subscriptions.get(&update.ch).expect("Getting an unregistered subscription should never happend.");
-                                    if sender.send(Publication::Tick).await.is_err() {
-                                        subscriptions.remove(&update.ch);
-                                    }
-                                },
-                                HuobiMessage::Response(response) => {
-                                    if let Some((_key, sender)) = requests.lock().await.remove_entry(&response.id) {
-                                        sender.send(response).await.expect("Couldn't send.");
-                                    }
-                                }
-                            }
-                        }
-                    }
-                    subscriptions.lock().await.clear();
-                });
+impl TryFrom<Interval> for model::Interval {
+    type Error = OpenLimitsError;
+    fn try_from(interval: Interval) -> Result<Self> {
+        match interval {
+            Interval::OneMinute => Ok(model::Interval::OneMinute),
+            Interval::FiveMinutes => Ok(model::Interval::FiveMinutes),
+            Interval::FifteenMinutes => Ok(model::Interval::FifteenMinutes),
+            Interval::ThirtyMinutes => Ok(model::Interval::ThirtyMinutes),
+            Interval::OneHour => Ok(model::Interval::OneHour),
+            Interval::FourHours => Ok(model::Interval::FourHours),
+            Interval::OneDay => Ok(model::Interval::OneDay),
+            Interval::OneWeek => Ok(model::Interval::OneWeek),
+            Interval::OneMonth => Ok(model::Interval::OneMonth),
+            _ => {
+                let message = format!("{:?} is not supported in Huobi", interval);
+                Err(OpenLimitsError::MissingImplementation(MissingImplementationContent { message }))
             }
-            Self { sender, requests, subscriptions }
-        }).map_err(|error| format!("{:#?}", error))
+        }
+    }
+}
+
+pub(crate) fn to_candle(kline: model::Kline) -> Candle {
+    Candle {
+        time: shared::timestamp_to_utc_datetime(kline.id * 1000),
+        low: kline.low,
+        high: kline.high,
+        open: kline.open,
+        close: kline.close,
+        volume: kline.amount,
+    }
+}
+
+pub(crate) fn to_ticker(ticker: model::MarketTicker, market_pair: MarketPair, ts: u64) -> Ticker {
+    Ticker {
+        market_pair,
+        price: Some(ticker.close),
+        price_24h: Some(ticker.open),
+        bid: Some(ticker.bid),
+        bid_qty: Some(ticker.bid_size),
+        ask: Some(ticker.ask),
+        ask_qty: Some(ticker.ask_size),
+        high_24h: Some(ticker.high),
+        low_24h: Some(ticker.low),
+        volume_24h: Some(ticker.amount),
+        quote_volume_24h: Some(ticker.vol),
+        created_at: Some(shared::timestamp_to_utc_datetime(ts)),
     }
 }
 
-use serde::{Serialize,Deserialize};
-use std::collections::HashMap;
-use std::sync::Arc;
-use futures::lock::Mutex;
-use crate::message::HuobiMessage;
+pub(crate) fn to_market_trade(trade: model::MarketTrade, market_pair: MarketPair) -> Trade {
+    Trade {
+        id: trade.trade_id.to_string(),
+        buyer_order_id: None,
+        seller_order_id: None,
+        market_pair,
+        price: trade.price,
+        qty: trade.amount,
+        fees: None,
+        side: trade.direction.into(),
+        liquidity: None,
+        created_at: shared::timestamp_to_utc_datetime(trade.ts),
+    }
+}
 
-#[derive(Serialize,Deserialize)]
-struct HuobiSubscription {
-    id: Uuid,
-    sub: String
-}
\ No newline at end of file
+fn to_order(order: model::Order, market_pair: MarketPair) -> Order {
+    let (side, order_type) = side_and_order_type(&order.order_type);
+    Order {
+        id: order.id.to_string(),
+        market_pair,
+        client_order_id: order.client_order_id.filter(|id| !id.is_empty()),
+        created_at: Some(shared::timestamp_to_utc_datetime(order.created_at)),
+        order_type,
+        side,
+        status: order.state.into(),
+        size: order.amount,
+        // Market orders are reported with a zero price.
+        price: Some(order.price).filter(|price| !price.is_zero()),
+        remaining: Some(order.amount - order.field_amount),
+        trades: Vec::new(),
+    }
+}
+
+fn to_trade(match_result: model::MatchResult, market_pair: MarketPair) -> Trade {
+    let (side, _) = side_and_order_type(&match_result.order_type);
+    let order_id = Some(match_result.order_id.to_string());
+    let (buyer_order_id, seller_order_id) = match side {
+        Side::Buy => (order_id, None),
+        Side::Sell => (None, order_id),
+    };
+    Trade {
+        id: match_result.trade_id.to_string(),
+        buyer_order_id,
+        seller_order_id,
+        market_pair,
+        price: match_result.price,
+        qty: match_result.filled_amount,
+        fees: Some(match_result.filled_fees),
+        side,
+        liquidity: match match_result.role.as_str() {
+            "maker" => Some(Liquidity::Maker),
+            _ => Some(Liquidity::Taker),
+        },
+        created_at: shared::timestamp_to_utc_datetime(match_result.created_at),
+    }
+}
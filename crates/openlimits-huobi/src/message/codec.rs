@@ -0,0 +1,46 @@
+use openlimits_exchange::message::subscription::{Publication, Subscription};
+use openlimits_exchange::websocket::{self, Codec, Frame, Message};
+use crate::HuobiWebsocket;
+use crate::shared::Result;
+use super::{decode, ChannelUpdate, HuobiMessage, HuobiResponse, HuobiUnsubscription};
+
+/// The connection every subscription of a `HuobiWebsocket` shares.
+pub(crate) type Connection = websocket::Connection<HuobiCodec>;
+
+/// Reads the gzipped frames of Huobi, whose updates are keyed by channel. Huobi pings in text
+/// within the frames, which are answered with a pong of the same timestamp.
+pub(crate) struct HuobiCodec;
+
+impl Codec for HuobiCodec {
+    type Channel = String;
+    type Update = ChannelUpdate;
+    type Response = HuobiResponse;
+
+    const VENUE: &'static str = "huobi";
+
+    fn decode(message: Message) -> Frame<String, ChannelUpdate, HuobiResponse> {
+        let binary = match message {
+            Message::Binary(binary) => binary,
+            _ => return Frame::Ignored,
+        };
+        match decode(&binary) {
+            Ok(HuobiMessage::Ping(ping)) => Frame::Reply(Message::Text(format!("{{\"pong\": {}}}", ping.ping))),
+            Ok(HuobiMessage::ChannelUpdate(update)) => Frame::Update(update.ch.clone(), update),
+            // The ids are the ones the requests were sent with.
+            Ok(HuobiMessage::Response(response)) => match response.id.parse() {
+                Ok(id) => Frame::Response(Some(id), Ok(response)),
+                Err(_) => Frame::Ignored,
+            },
+            Err((channel, error)) => Frame::Error(channel, error),
+        }
+    }
+
+    fn publication(subscription: &Subscription, update: ChannelUpdate) -> Result<Publication> {
+        HuobiWebsocket::publication(subscription, update.tick, update.ts)
+    }
+
+    fn unsubscribe(id: u64, channel: &String) -> Result<Message> {
+        let request = HuobiUnsubscription { id: id.to_string(), unsub: channel.clone() };
+        Ok(Message::Text(serde_json::to_string(&request)?))
+    }
+}
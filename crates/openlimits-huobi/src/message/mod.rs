@@ -1,13 +1,14 @@
-use uuid::Uuid;
-use rust_decimal::Decimal;
-
+mod codec;
 mod requester;
 mod subscriber;
 
-pub use requester::*;
-pub use subscriber::*;
+pub(crate) use codec::Connection;
 
+use std::io::Read;
+use libflate::gzip::Decoder;
 use serde::{Serialize,Deserialize};
+use serde_json::Value;
+use openlimits_exchange::OpenLimitsError;
 
 #[derive(Debug,Serialize,Deserialize)]
 #[serde(untagged)]
@@ -17,34 +18,58 @@ pub enum HuobiMessage {
     Ping(Ping)
 }
 
+/// The answer to a request, `subbed` being the channel on success and the error fields being set
+/// on failure.
 #[derive(Debug,Serialize,Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct HuobiResponse {
-    pub id: Uuid,
+    pub id: String,
     pub status: String,
-    pub subbed: String,
-    pub ts: u64
+    #[serde(default)]
+    pub subbed: Option<String>,
+    pub ts: u64,
+    #[serde(default)]
+    pub err_code: Option<String>,
+    #[serde(default)]
+    pub err_msg: Option<String>
 }
 
+/// An update of a subscribed channel. The shape of the tick depends on the channel kind.
 #[derive(Debug,Serialize,Deserialize)]
 pub struct ChannelUpdate {
     pub ch: String,
     pub ts: u64,
-    pub tick: Tick
+    pub tick: Value
 }
 
 #[derive(Debug,Serialize,Deserialize)]
-pub struct Tick {
-    pub id: u64,
-    pub open: Decimal,
-    pub close: Decimal,
-    pub low: Decimal,
-    pub high: Decimal,
-    pub amount: Decimal,
-    pub vol: Decimal,
-    pub count: u64
+pub struct Ping {
+    pub ping: u64
 }
 
 #[derive(Debug,Serialize,Deserialize)]
-pub struct Ping {
-    pub ping: u64
+pub struct HuobiSubscription {
+    pub id: String,
+    pub sub: String
+}
+
+#[derive(Debug,Serialize,Deserialize)]
+pub struct HuobiUnsubscription {
+    pub id: String,
+    pub unsub: String
+}
+
+/// Reads a gzipped frame. On failure the channel of the frame is returned along with the error
+/// when the frame is readable enough to tell it.
+pub fn decode(binary: &[u8]) -> std::result::Result<HuobiMessage, (Option<String>, OpenLimitsError)> {
+    let mut text = String::new();
+    Decoder::new(binary)
+        .and_then(|mut decoder| decoder.read_to_string(&mut text))
+        .map_err(|error| (None, error.into()))?;
+    serde_json::from_str(&text).map_err(|error| {
+        let channel = serde_json::from_str::<Value>(&text)
+            .ok()
+            .and_then(|value| value.get("ch").and_then(Value::as_str).map(String::from));
+        (channel, error.into())
+    })
 }
@@ -1,37 +1,31 @@
-use messaging::prelude::*;
-use exchange::message::request::{Request};
-use exchange::message::request::Response;
-use exchange::message::subscription::SubscriptionResponse;
-use crate::{Huobi, HuobiSubscription};
-use nash_ws::Message;
+use openlimits_messaging::prelude::*;
+use openlimits_exchange::OpenLimitsError;
+use openlimits_exchange::message::request::{Request, Response};
+use openlimits_exchange::message::subscription::SubscriptionResponse;
+use openlimits_exchange::websocket::Message;
+use crate::{HuobiContentError, HuobiWebsocket};
+use super::HuobiSubscription;
 
 #[async_trait]
-impl Requester for Huobi {
+impl Requester for HuobiWebsocket {
     type Request = Request;
     type Response = Response;
-    type Error = String;
+    type Error = OpenLimitsError;
     async fn request(&mut self, request: &Self::Request) -> Result<Self::Response, Self::Error> {
-        let (id, mut receiver) = self.register_request().await;
         let request_message = match request {
-            Request::Subscription(_subscription) => {
-                let sub = format!("market.{}{}.kline.1min", "eth", "btc");
-                let request = HuobiSubscription { id, sub };
-                serde_json::to_string(&request)
-                    .map_err(|error| {
-                        format!("{:#?}", error)
-                    })?
-            }
+            Request::Subscription(subscription) => HuobiWebsocket::channel(subscription)?,
         };
-        self.sender.send(&Message::Text(request_message)).await.expect("Couldn't send message.");
-        let response = receiver.next().await;
-        response
-            .filter(|response| response.status == "ok")
-            .map(|_response| {
-                Response::Subscription(SubscriptionResponse{})
-            })
-            .ok_or_else(|| {
-                // FIXME: Also implement timeout.
-                "Failed to receive resposne".into()
-            })
+        let request = |id: u64| -> openlimits_exchange::Result<Message> {
+            Ok(Message::Text(serde_json::to_string(&HuobiSubscription { id: id.to_string(), sub: request_message })?))
+        };
+        let response = self.connection.request(request, self.request_timeout).await?;
+        match response.status.as_str() {
+            "ok" => Ok(Response::Subscription(SubscriptionResponse {})),
+            status => Err(OpenLimitsError::Generic(Box::new(HuobiContentError {
+                status: status.to_string(),
+                err_code: response.err_code.unwrap_or_default(),
+                err_msg: response.err_msg.unwrap_or_default(),
+            }))),
+        }
     }
 }
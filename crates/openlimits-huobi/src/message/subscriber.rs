@@ -1,15 +1,18 @@
-use messaging::prelude::*;
-use crate::Huobi;
-use exchange::message::request::Request;
+use openlimits_messaging::prelude::*;
+use openlimits_exchange::OpenLimitsError;
+use openlimits_exchange::message::request::Request;
+use crate::HuobiWebsocket;
 
 #[async_trait]
-impl Subscriber for Huobi {
-    type SubscriptionRequest = exchange::message::subscription::Subscription;
-    type Publication = exchange::message::subscription::Publication;
-    type Error = String;
+impl Subscriber for HuobiWebsocket {
+    type SubscriptionRequest = openlimits_exchange::message::subscription::Subscription;
+    type Publication = openlimits_exchange::Result<openlimits_exchange::message::subscription::Publication>;
+    type Error = OpenLimitsError;
     async fn subscribe(&mut self, subscription: &Self::SubscriptionRequest) -> Result<Subscription<Self::Publication>, Self::Error> {
-        let receiver = self.register_subscription("market.ethbtc.kline.1min".to_string()).await;
-        let _response = self.request(&Request::Subscription(subscription.clone())).await?;
-        Ok(Box::pin(receiver))
+        let channel = HuobiWebsocket::channel(subscription)?;
+        // A failed subscription drops its stream, which unsubscribes unless the channel has others.
+        let publications = self.connection.register_subscription(channel, subscription.clone()).await;
+        self.request(&Request::Subscription(subscription.clone())).await?;
+        Ok(Box::pin(publications))
     }
-}
\ No newline at end of file
+}
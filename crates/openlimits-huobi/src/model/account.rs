@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This struct represents an account of the user, trading happens on the "spot" one
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Account {
+    pub id: u64,
+    #[serde(rename = "type")]
+    pub account_type: String,
+    pub state: String,
+}
@@ -0,0 +1,10 @@
+use serde::Deserialize;
+use serde::Serialize;
+use super::Balance;
+
+/// This struct represents the balances of an account
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AccountBalance {
+    pub id: u64,
+    pub list: Vec<Balance>,
+}
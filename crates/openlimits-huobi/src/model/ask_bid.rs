@@ -0,0 +1,10 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+
+/// This struct represents a price level, sent by Huobi as a `[price, qty]` pair
+#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+pub struct AskBid {
+    pub price: Decimal,
+    pub qty: Decimal,
+}
@@ -0,0 +1,25 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_decimal;
+
+/// This struct represents the balance of a currency. Each currency is listed once as "trade",
+/// the available amount, and once as "frozen", the amount held by open orders.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Balance {
+    pub currency: String,
+    #[serde(rename = "type")]
+    pub balance_type: BalanceType,
+    #[serde(with = "string_to_decimal")]
+    pub balance: Decimal,
+}
+
+/// This enum represents the kind of a balance entry
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum BalanceType {
+    Trade,
+    Frozen,
+    #[serde(other)]
+    Other,
+}
@@ -0,0 +1,20 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This struct represents the outcome of a batch cancellation
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BatchCancelResult {
+    pub success: Vec<String>,
+    pub failed: Vec<BatchCancelFailure>,
+}
+
+/// This struct represents an order that couldn't be canceled
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct BatchCancelFailure {
+    pub order_id: String,
+    #[serde(default)]
+    pub err_code: Option<String>,
+    #[serde(default)]
+    pub err_msg: Option<String>,
+}
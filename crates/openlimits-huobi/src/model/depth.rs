@@ -0,0 +1,12 @@
+use serde::Deserialize;
+use serde::Serialize;
+use super::AskBid;
+
+/// This struct represents an order book snapshot
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Depth {
+    pub version: u64,
+    pub ts: u64,
+    pub bids: Vec<AskBid>,
+    pub asks: Vec<AskBid>,
+}
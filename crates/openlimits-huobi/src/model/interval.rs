@@ -0,0 +1,41 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This enum represents the candle periods supported by Huobi
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum Interval {
+    #[serde(rename = "1min")]
+    OneMinute,
+    #[serde(rename = "5min")]
+    FiveMinutes,
+    #[serde(rename = "15min")]
+    FifteenMinutes,
+    #[serde(rename = "30min")]
+    ThirtyMinutes,
+    #[serde(rename = "60min")]
+    OneHour,
+    #[serde(rename = "4hour")]
+    FourHours,
+    #[serde(rename = "1day")]
+    OneDay,
+    #[serde(rename = "1week")]
+    OneWeek,
+    #[serde(rename = "1mon")]
+    OneMonth,
+}
+
+impl Interval {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Interval::OneMinute => "1min",
+            Interval::FiveMinutes => "5min",
+            Interval::FifteenMinutes => "15min",
+            Interval::ThirtyMinutes => "30min",
+            Interval::OneHour => "60min",
+            Interval::FourHours => "4hour",
+            Interval::OneDay => "1day",
+            Interval::OneWeek => "1week",
+            Interval::OneMonth => "1mon",
+        }
+    }
+}
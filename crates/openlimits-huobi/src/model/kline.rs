@@ -0,0 +1,17 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+
+/// This struct represents a candle. `id` is the open time in seconds, `amount` the base volume
+/// and `vol` the quote volume.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Kline {
+    pub id: u64,
+    pub open: Decimal,
+    pub close: Decimal,
+    pub low: Decimal,
+    pub high: Decimal,
+    pub amount: Decimal,
+    pub vol: Decimal,
+    pub count: u64,
+}
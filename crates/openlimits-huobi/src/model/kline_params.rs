@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use serde::Serialize;
+use super::Interval;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct KlineParams {
+    pub symbol: String,
+    pub period: Interval,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+}
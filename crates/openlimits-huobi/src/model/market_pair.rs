@@ -0,0 +1,15 @@
+use openlimits_exchange::model::market_pair::MarketPair as OMarketPair;
+use openlimits_exchange::model::SymbolFormat;
+use serde::{Serialize, Deserialize};
+
+/// Huobi concatenates the lowercase base and quote, e.g. "ethbtc".
+pub const SYMBOL_FORMAT: SymbolFormat = SymbolFormat::new(None, true);
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MarketPair(pub String);
+
+impl From<OMarketPair> for MarketPair {
+    fn from(from: OMarketPair) -> MarketPair {
+        MarketPair(SYMBOL_FORMAT.format(&from))
+    }
+}
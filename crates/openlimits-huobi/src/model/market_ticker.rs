@@ -0,0 +1,23 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+
+/// This struct represents the 24h ticker of a market as listed by `/market/tickers`. The
+/// `market.<symbol>.ticker` channel publishes the same fields without the symbol.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarketTicker {
+    #[serde(default)]
+    pub symbol: String,
+    pub open: Decimal,
+    pub close: Decimal,
+    pub low: Decimal,
+    pub high: Decimal,
+    pub amount: Decimal,
+    pub vol: Decimal,
+    pub count: u64,
+    pub bid: Decimal,
+    pub bid_size: Decimal,
+    pub ask: Decimal,
+    pub ask_size: Decimal,
+}
@@ -0,0 +1,16 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::Side;
+
+/// This struct represents a public trade. The `id` Huobi also sends can overflow a u64, so only
+/// the `trade-id` is kept. `direction` is the taker side.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MarketTrade {
+    #[serde(rename = "trade-id", alias = "tradeId")]
+    pub trade_id: u64,
+    pub ts: u64,
+    pub amount: Decimal,
+    pub price: Decimal,
+    pub direction: Side,
+}
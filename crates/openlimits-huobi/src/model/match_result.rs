@@ -0,0 +1,25 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::shared::string_to_decimal;
+
+/// This struct represents a fill of one of the user orders. `role` is "maker" or "taker".
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct MatchResult {
+    pub id: u64,
+    pub order_id: u64,
+    pub trade_id: u64,
+    pub symbol: String,
+    #[serde(with = "string_to_decimal")]
+    pub price: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub filled_amount: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub filled_fees: Decimal,
+    pub fee_currency: String,
+    #[serde(rename = "type")]
+    pub order_type: String,
+    pub role: String,
+    pub created_at: u64,
+}
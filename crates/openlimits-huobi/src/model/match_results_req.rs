@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use serde::Serialize;
+use super::Paginator;
+
+/// This struct represents the query of the trade history
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MatchResultsReq {
+    pub symbol: String,
+    #[serde(flatten)]
+    pub paginator: Paginator,
+}
@@ -0,0 +1,19 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::AskBid;
+
+/// This struct represents the aggregated 24h ticker of one market. `amount` is the base volume
+/// and `vol` the quote volume.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MergedTicker {
+    pub open: Decimal,
+    pub close: Decimal,
+    pub low: Decimal,
+    pub high: Decimal,
+    pub amount: Decimal,
+    pub vol: Decimal,
+    pub count: u64,
+    pub bid: AskBid,
+    pub ask: AskBid,
+}
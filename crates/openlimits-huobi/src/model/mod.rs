@@ -0,0 +1,52 @@
+//! This module provides models that are used in the openlimits-huobi module
+
+mod market_pair;
+mod account;
+mod account_balance;
+mod ask_bid;
+mod balance;
+mod batch_cancel_result;
+mod depth;
+mod interval;
+mod kline;
+mod kline_params;
+mod market_ticker;
+mod market_trade;
+mod match_result;
+mod match_results_req;
+mod merged_ticker;
+mod order;
+mod order_request;
+mod order_state;
+mod orders_req;
+mod paginator;
+mod response;
+mod side;
+mod symbol;
+mod trade_detail;
+
+pub use market_pair::{MarketPair, SYMBOL_FORMAT};
+pub use account::Account;
+pub use account_balance::AccountBalance;
+pub use ask_bid::AskBid;
+pub use balance::{Balance, BalanceType};
+pub use batch_cancel_result::{BatchCancelFailure, BatchCancelResult};
+pub use depth::Depth;
+pub use interval::Interval;
+pub use kline::Kline;
+pub use kline_params::KlineParams;
+pub use market_ticker::MarketTicker;
+pub use market_trade::MarketTrade;
+pub use match_result::MatchResult;
+pub use match_results_req::MatchResultsReq;
+pub use merged_ticker::MergedTicker;
+pub use order::Order;
+pub use order_request::OrderRequest;
+pub use order_state::OrderState;
+pub use orders_req::OrdersReq;
+pub use paginator::Paginator;
+pub use response::{DataResponse, TickResponse};
+pub use side::Side;
+pub use symbol::Symbol;
+pub use trade_detail::TradeDetail;
+pub use super::shared;
@@ -0,0 +1,26 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+use super::OrderState;
+use super::shared::string_to_decimal;
+
+/// This struct represents an order. `order_type` joins the side and the kind, e.g. "buy-limit".
+/// Open orders report the executed amount as `filled-amount`, the others as `field-amount`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct Order {
+    pub id: u64,
+    pub symbol: String,
+    #[serde(default)]
+    pub client_order_id: Option<String>,
+    #[serde(with = "string_to_decimal")]
+    pub amount: Decimal,
+    #[serde(with = "string_to_decimal")]
+    pub price: Decimal,
+    pub created_at: u64,
+    #[serde(rename = "type")]
+    pub order_type: String,
+    #[serde(alias = "filled-amount", with = "string_to_decimal")]
+    pub field_amount: Decimal,
+    pub state: OrderState,
+}
@@ -0,0 +1,33 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This enum represents the state of an order
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum OrderState {
+    Created,
+    PreSubmitted,
+    Submitted,
+    PartialFilled,
+    Filled,
+    PartialCanceled,
+    Canceling,
+    Canceled,
+    Rejected,
+}
+
+impl OrderState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderState::Created => "created",
+            OrderState::PreSubmitted => "pre-submitted",
+            OrderState::Submitted => "submitted",
+            OrderState::PartialFilled => "partial-filled",
+            OrderState::Filled => "filled",
+            OrderState::PartialCanceled => "partial-canceled",
+            OrderState::Canceling => "canceling",
+            OrderState::Canceled => "canceled",
+            OrderState::Rejected => "rejected",
+        }
+    }
+}
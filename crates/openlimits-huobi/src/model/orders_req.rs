@@ -0,0 +1,12 @@
+use serde::Deserialize;
+use serde::Serialize;
+use super::Paginator;
+
+/// This struct represents the query of the order history. `states` is a comma separated list.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OrdersReq {
+    pub symbol: String,
+    pub states: String,
+    #[serde(flatten)]
+    pub paginator: Paginator,
+}
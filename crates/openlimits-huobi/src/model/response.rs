@@ -0,0 +1,15 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This struct represents the envelope of the REST responses carrying a `data` field
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DataResponse<T> {
+    pub data: T,
+}
+
+/// This struct represents the envelope of the market data responses carrying a `tick` field
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TickResponse<T> {
+    pub ts: u64,
+    pub tick: T,
+}
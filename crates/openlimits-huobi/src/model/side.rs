@@ -0,0 +1,10 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// This enum represents the side of a trade
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Side {
+    Buy,
+    Sell,
+}
@@ -0,0 +1,19 @@
+use serde::Deserialize;
+use serde::Serialize;
+use rust_decimal::prelude::Decimal;
+
+/// This struct represents a market listed by Huobi
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct Symbol {
+    pub symbol: String,
+    pub base_currency: String,
+    pub quote_currency: String,
+    pub price_precision: u32,
+    pub amount_precision: u32,
+    pub state: String,
+    #[serde(default)]
+    pub min_order_amt: Option<Decimal>,
+    #[serde(default)]
+    pub min_order_value: Option<Decimal>,
+}
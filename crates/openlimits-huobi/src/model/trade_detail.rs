@@ -0,0 +1,10 @@
+use serde::Deserialize;
+use serde::Serialize;
+use super::MarketTrade;
+
+/// This struct represents the trades executed at the same timestamp
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TradeDetail {
+    pub ts: u64,
+    pub data: Vec<MarketTrade>,
+}
@@ -1,2 +1,2 @@
-pub use exchange::Exchange;
-pub use futures_util::stream::StreamExt;
+pub use openlimits_exchange::traits::Exchange;
+pub use openlimits_messaging::prelude::*;
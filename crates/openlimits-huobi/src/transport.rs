@@ -0,0 +1,212 @@
+use std::sync::Arc;
+use base64::encode as base64_encode;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use url::Url;
+use url::form_urlencoded;
+use crate::HuobiContentError;
+use crate::model::DataResponse;
+use openlimits_exchange::clock::ClockSync;
+use openlimits_exchange::http::{header, HttpRequest, HttpResponse, HttpTransport, InstrumentedTransport, Method, ReqwestTransport, StatusCode};
+use openlimits_exchange::signer::{HmacSigner, RequestSigner};
+use openlimits_exchange::OpenLimitsError;
+use openlimits_exchange::shared::timestamp_to_utc_datetime;
+use super::shared::Result;
+
+static HOST: &str = "api.huobi.pro";
+
+// Huobi reports a request timestamp outside of its 5 minutes window as an invalid signature.
+const INVALID_SIGNATURE_CODE: &str = "api-signature-not-valid";
+
+#[derive(Clone, Debug)]
+pub struct Transport {
+    credential: Option<(String, Arc<dyn RequestSigner>)>,
+    http: Arc<dyn HttpTransport>,
+    clock: ClockSync,
+    host: String,
+}
+
+impl Transport {
+    pub fn new() -> Result<Self> {
+        Ok(Transport {
+            credential: None,
+            http: Transport::instrumented(Arc::new(ReqwestTransport::default())),
+            clock: ClockSync::default(),
+            host: String::from(HOST),
+        })
+    }
+
+    pub fn with_credential(api_key: &str, api_secret: &str) -> Result<Self> {
+        Transport::with_signer(api_key, Arc::new(HmacSigner::new(api_secret)))
+    }
+
+    /// Returns a transport whose requests are signed by the given signer instead of a secret.
+    pub fn with_signer(api_key: &str, signer: Arc<dyn RequestSigner>) -> Result<Self> {
+        Ok(Transport {
+            credential: Some((api_key.into(), signer)),
+            http: Transport::instrumented(Arc::new(ReqwestTransport::default())),
+            clock: ClockSync::default(),
+            host: String::from(HOST),
+        })
+    }
+
+    /// Returns a transport that sends its requests through the given HTTP transport.
+    pub fn with_http_transport(self, http: Arc<dyn HttpTransport>) -> Self {
+        Self {
+            http: Transport::instrumented(http),
+            ..self
+        }
+    }
+
+    fn instrumented(http: Arc<dyn HttpTransport>) -> Arc<dyn HttpTransport> {
+        Arc::new(InstrumentedTransport::new("huobi", http))
+    }
+
+    async fn send(&self, method: Method, url: Url, body: Option<Vec<u8>>) -> Result<HttpResponse> {
+        let mut request = HttpRequest::new(method, url);
+        request.headers.insert(
+            header::USER_AGENT,
+            header::HeaderValue::from_static("open_limit"),
+        );
+        if body.is_some() {
+            request.headers.insert(
+                header::CONTENT_TYPE,
+                header::HeaderValue::from_static("application/json"),
+            );
+        }
+        request.body = body;
+        self.http.send(request).await
+    }
+
+    pub fn clock(&self) -> &ClockSync {
+        &self.clock
+    }
+
+    /// Measures the offset against the server time when the last measurement is stale. A failed
+    /// measurement keeps the previous offset rather than failing the signed request.
+    pub async fn sync_clock(&self) {
+        if self.clock.needs_sync() {
+            self.clock.sync(self.server_time()).await;
+        }
+    }
+
+    async fn server_time(&self) -> Result<i64> {
+        let time: DataResponse<u64> = self.get::<_, ()>("/v1/common/timestamp", None).await?;
+        Ok(time.data as i64)
+    }
+
+    pub async fn get<O, S>(&self, endpoint: &str, params: Option<&S>) -> Result<O>
+    where
+        O: DeserializeOwned,
+        S: Serialize,
+    {
+        let mut url = Url::parse(&format!("https://{}{}", self.host, endpoint))?;
+        if params.is_some() {
+            let query = serde_urlencoded::to_string(params)?;
+            url.set_query(Some(&query));
+        }
+        let response = self.send(Method::GET, url, None).await?;
+
+        self.response_handler(response)
+    }
+
+    pub async fn signed_get<O, S>(&self, endpoint: &str, params: Option<&S>) -> Result<O>
+    where
+        O: DeserializeOwned,
+        S: Serialize,
+    {
+        self.sync_clock().await;
+        let url = self.signed_url("GET", endpoint, params).await?;
+        let response = self.send(Method::GET, url, None).await?;
+
+        self.response_handler(response)
+    }
+
+    pub async fn signed_post<D, O>(&self, endpoint: &str, data: Option<&D>) -> Result<O>
+    where
+        O: DeserializeOwned,
+        D: Serialize,
+    {
+        self.sync_clock().await;
+        let url = self.signed_url::<()>("POST", endpoint, None).await?;
+        let body = match data {
+            Some(data) => serde_json::to_vec(data)?,
+            None => serde_json::to_vec(&serde_json::json!({}))?,
+        };
+        let response = self.send(Method::POST, url, Some(body)).await?;
+
+        self.response_handler(response)
+    }
+
+    fn check_key(&self) -> Result<(&str, &dyn RequestSigner)> {
+        match self.credential.as_ref() {
+            None => Err(OpenLimitsError::NoApiKeySet()),
+            Some((k, s)) => Ok((k, s.as_ref())),
+        }
+    }
+
+    /// Signs the request with the version 2 signature: the sorted query, including the key and
+    /// the timestamp, is signed together with the method, the host and the path.
+    pub async fn signed_url<Q>(&self, method: &str, endpoint: &str, params: Option<&Q>) -> Result<Url>
+    where
+        Q: Serialize,
+    {
+        let (key, signer) = self.check_key()?;
+        let timestamp = timestamp_to_utc_datetime(self.clock.now_millis() as u64)
+            .format("%Y-%m-%dT%H:%M:%S")
+            .to_string();
+
+        let query = match params {
+            Some(params) => serde_urlencoded::to_string(params)?,
+            None => String::new(),
+        };
+        let mut pairs: Vec<(String, String)> = form_urlencoded::parse(query.as_bytes())
+            .into_owned()
+            .collect();
+        pairs.push(("AccessKeyId".into(), key.into()));
+        pairs.push(("SignatureMethod".into(), "HmacSHA256".into()));
+        pairs.push(("SignatureVersion".into(), "2".into()));
+        pairs.push(("Timestamp".into(), timestamp));
+        pairs.sort();
+        let query = form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(pairs)
+            .finish();
+
+        let sign_message = format!("{}\n{}\n{}\n{}", method, self.host, endpoint, query);
+        let signature = base64_encode(signer.sign(sign_message.as_bytes()).await?);
+
+        let mut url = Url::parse(&format!("https://{}{}", self.host, endpoint))?;
+        url.set_query(Some(&query));
+        url.query_pairs_mut().append_pair("Signature", &signature);
+        Ok(url)
+    }
+
+    // Huobi answers errors with a 200 and a `status` of "error", so the body is checked before
+    // being deserialized.
+    fn response_handler<O>(&self, response: HttpResponse) -> Result<O>
+    where
+        O: DeserializeOwned,
+    {
+        match response.status {
+            StatusCode::OK => {
+                let body: Value = response.json()?;
+                if body.get("status").and_then(Value::as_str) == Some("error") {
+                    let error: HuobiContentError = serde_json::from_value(body)?;
+                    if error.err_code == INVALID_SIGNATURE_CODE {
+                        self.clock.invalidate();
+                    }
+                    return Err(OpenLimitsError::Generic(Box::new(error)));
+                }
+                Ok(serde_json::from_value(body)?)
+            }
+            StatusCode::INTERNAL_SERVER_ERROR => Err(OpenLimitsError::InternalServerError()),
+            StatusCode::SERVICE_UNAVAILABLE => Err(OpenLimitsError::ServiceUnavailable()),
+            StatusCode::UNAUTHORIZED => Err(OpenLimitsError::Unauthorized()),
+            s => Err(OpenLimitsError::UnkownResponse(format!(
+                "Received response: {:?}",
+                s
+            ))),
+        }
+    }
+}
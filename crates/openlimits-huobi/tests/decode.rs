@@ -0,0 +1,47 @@
+use std::io::Write;
+use libflate::gzip::Encoder;
+use openlimits_exchange::OpenLimitsError;
+use openlimits_huobi::message::{decode, HuobiMessage};
+
+fn gzip(text: &str) -> Vec<u8> {
+    let mut encoder = Encoder::new(Vec::new()).unwrap();
+    encoder.write_all(text.as_bytes()).unwrap();
+    encoder.finish().into_result().unwrap()
+}
+
+#[test]
+fn messages() {
+    assert!(matches!(decode(&gzip(r#"{"ping":1616432112345}"#)), Ok(HuobiMessage::Ping(ping)) if ping.ping == 1_616_432_112_345));
+    let response = r#"{"id":"0","status":"ok","subbed":"market.ethbtc.trade.detail","ts":1}"#;
+    assert!(matches!(decode(&gzip(response)), Ok(HuobiMessage::Response(response)) if response.id == "0"));
+}
+
+#[test]
+fn malformed_gzip() {
+    assert!(matches!(decode(b"not gzipped"), Err((None, OpenLimitsError::IoError(_)))));
+    let mut truncated = gzip(r#"{"ping":1}"#);
+    truncated.truncate(truncated.len() / 2);
+    assert!(matches!(decode(&truncated), Err((None, _))));
+}
+
+#[test]
+fn malformed_json() {
+    assert!(matches!(decode(&gzip("{\"ping\":")), Err((None, OpenLimitsError::JsonError(_)))));
+    assert!(matches!(decode(&gzip(r#"{"unknown":true}"#)), Err((None, OpenLimitsError::JsonError(_)))));
+    // The channel is still told when the frame is JSON of an unexpected shape.
+    let update = r#"{"ch":"market.ethbtc.trade.detail","ts":"yesterday","tick":{}}"#;
+    match decode(&gzip(update)) {
+        Err((Some(channel), OpenLimitsError::JsonError(_))) => assert_eq!(channel, "market.ethbtc.trade.detail"),
+        result => panic!("Unexpected result {:?}", result),
+    }
+}
+
+#[test]
+fn unknown_channel() {
+    // Decoding doesn't know the subscriptions, the connection drops updates nobody subscribed to.
+    let update = r#"{"ch":"market.unknown.bbo","ts":1,"tick":{"anything":[]}}"#;
+    match decode(&gzip(update)) {
+        Ok(HuobiMessage::ChannelUpdate(update)) => assert_eq!(update.ch, "market.unknown.bbo"),
+        result => panic!("Unexpected result {:?}", result),
+    }
+}
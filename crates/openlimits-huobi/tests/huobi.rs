@@ -4,21 +4,98 @@ cross_test::configure!();
 mod test {
     use cross_test::prelude::*;
 
-    use huobi::prelude::*;
-    use huobi::{HuobiParameters, Environment};
-    use huobi::Huobi;
-    use exchange::prelude::*;
-    use exchange::message::subscription::Subscription;
-    use exchange::market::MarketPair;
-    use exchange::market::symbol::Symbol;
+    use openlimits_huobi::prelude::*;
+    use openlimits_huobi::{Huobi, HuobiParameters, HuobiWebsocket};
+    use openlimits_exchange::message::subscription::{Publication, Subscription};
+    use openlimits_exchange::model::{GetHistoricRatesRequest, Interval, OrderBookRequest};
+    use openlimits_exchange::model::market_pair::MarketPair;
+    use openlimits_exchange::model::currency::Currency;
+    use openlimits_exchange::traits::ExchangeMarketData;
+    use openlimits_exchange::http::{FakeTransport, StatusCode};
+    use openlimits_exchange::OpenLimitsError;
+    use std::sync::Arc;
+
+    fn eth_btc() -> MarketPair {
+        MarketPair(Currency::ETH, Currency::BTC)
+    }
+
+    #[cross_test::test]
+    async fn http_transport() {
+        // Answers every request with a Huobi error.
+        let body = br#"{"status":"error","err-code":"bad-request","err-msg":"fake"}"#;
+        let transport = Arc::new(FakeTransport::answering(StatusCode::OK, body));
+        let parameters = HuobiParameters {
+            http_transport: Some(transport.clone()),
+            ..HuobiParameters::production()
+        };
+        let result = Huobi::new(parameters).await;
+        assert!(matches!(result, Err(OpenLimitsError::Generic(_))));
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].url.path(), "/v1/common/symbols");
+    }
+
+    #[cross_test::test]
+    async fn order_book() {
+        let huobi = Huobi::new(HuobiParameters::production()).await.expect("Couldn't create Huobi");
+        let order_book = huobi.order_book(&OrderBookRequest { market_pair: eth_btc() }).await.expect("Couldn't get order book");
+        assert!(!order_book.bids.is_empty());
+        assert!(!order_book.asks.is_empty());
+    }
+
+    #[cross_test::test]
+    async fn get_historic_rates() {
+        let huobi = Huobi::new(HuobiParameters::production()).await.expect("Couldn't create Huobi");
+        let req = GetHistoricRatesRequest {
+            market_pair: eth_btc(),
+            interval: Interval::OneHour,
+            paginator: None,
+        };
+        assert!(!huobi.get_historic_rates(&req).await.expect("Couldn't get candles").is_empty());
+
+        let req = GetHistoricRatesRequest {
+            interval: Interval::ThreeMinutes,
+            ..req
+        };
+        assert!(huobi.get_historic_rates(&req).await.is_err());
+    }
+
+    #[cross_test::test]
+    async fn kline() {
+        let mut huobi = HuobiWebsocket::new(HuobiParameters::production()).await.expect("Couldn't connect to Huobi");
+        let mut subscription = huobi.subscribe(&Subscription::Kline(eth_btc(), Interval::OneMinute)).await.expect("Couldn't subscribe");
+        let publication = subscription.next().await.expect("Couldn't get a publication").expect("Couldn't read the publication");
+        assert!(matches!(publication, Publication::Kline(_)));
+    }
+
+    #[cross_test::test]
+    async fn depth() {
+        let mut huobi = HuobiWebsocket::new(HuobiParameters::production()).await.expect("Couldn't connect to Huobi");
+        let mut subscription = huobi.subscribe(&Subscription::Depth(eth_btc())).await.expect("Couldn't subscribe");
+        match subscription.next().await.expect("Couldn't get a publication").expect("Couldn't read the publication") {
+            Publication::Depth(order_book) => assert!(!order_book.bids.is_empty()),
+            publication => panic!("Unexpected publication {:?}", publication),
+        }
+    }
+
+    #[cross_test::test]
+    async fn trades() {
+        let mut huobi = HuobiWebsocket::new(HuobiParameters::production()).await.expect("Couldn't connect to Huobi");
+        let mut subscription = huobi.subscribe(&Subscription::Trades(eth_btc())).await.expect("Couldn't subscribe");
+        match subscription.next().await.expect("Couldn't get a publication").expect("Couldn't read the publication") {
+            Publication::Trades(trades) => assert!(trades.iter().all(|trade| trade.market_pair == eth_btc())),
+            publication => panic!("Unexpected publication {:?}", publication),
+        }
+    }
 
     #[cross_test::test]
-    async fn huobi() {
-        let mut huobi = Huobi::new(HuobiParameters::new(Environment::Production)).await.expect("Couldn't connect to Huobi");
-        let mut subscription = huobi.subscribe(&Subscription::Tick(MarketPair(Symbol::ETH, Symbol::BTC))).await.expect("Couldn't subscribe");
-        while let Some(message) = subscription.next().await {
-            println!("{:#?}", message);
+    async fn ticker() {
+        let mut huobi = HuobiWebsocket::new(HuobiParameters::production()).await.expect("Couldn't connect to Huobi");
+        let mut subscription = huobi.subscribe(&Subscription::Ticker(eth_btc())).await.expect("Couldn't subscribe");
+        match subscription.next().await.expect("Couldn't get a publication").expect("Couldn't read the publication") {
+            Publication::Ticker(ticker) => assert_eq!(ticker.market_pair, eth_btc()),
+            publication => panic!("Unexpected publication {:?}", publication),
         }
-        println!("Ended.")
     }
 }
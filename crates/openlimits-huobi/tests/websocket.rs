@@ -0,0 +1,105 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use std::io::Write;
+use std::time::Duration;
+use futures::{SinkExt, StreamExt};
+use libflate::gzip::Encoder;
+use serde_json::Value;
+use tokio::net::TcpListener;
+use tokio_tungstenite::{accept_async, tungstenite, WebSocketStream};
+use openlimits_exchange::OpenLimitsError;
+use openlimits_exchange::message::subscription::{Publication, Subscription};
+use openlimits_exchange::model::market_pair::MarketPair;
+use openlimits_exchange::model::currency::Currency;
+use openlimits_huobi::prelude::*;
+use openlimits_huobi::{HuobiParameters, HuobiWebsocket};
+
+const TRADES: &str = "market.ethbtc.trade.detail";
+
+fn eth_btc() -> MarketPair {
+    MarketPair(Currency::ETH, Currency::BTC)
+}
+
+fn gzip(text: &str) -> tungstenite::Message {
+    let mut encoder = Encoder::new(Vec::new()).unwrap();
+    encoder.write_all(text.as_bytes()).unwrap();
+    tungstenite::Message::Binary(encoder.finish().into_result().unwrap())
+}
+
+fn trades(id: u64) -> tungstenite::Message {
+    gzip(&format!(
+        r#"{{"ch":"{}","ts":1,"tick":{{"ts":1,"data":[{{"trade-id":{},"ts":1,"amount":"1","price":"0.03","direction":"buy"}}]}}}}"#,
+        TRADES, id
+    ))
+}
+
+/// Serves one Huobi websocket connection on a local port, running `session` on it.
+async fn serve<F, S>(session: S) -> HuobiParameters
+where
+    S: FnOnce(WebSocketStream<tokio::net::TcpStream>) -> F + Send + 'static,
+    F: std::future::Future<Output = ()> + Send,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").await.expect("Couldn't listen");
+    let address = listener.local_addr().expect("Couldn't get the address");
+    tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.expect("Couldn't accept the connection");
+        session(accept_async(stream).await.expect("Couldn't accept the websocket")).await;
+    });
+    HuobiParameters {
+        websocket_url: Some(format!("ws://{}", address)),
+        ..HuobiParameters::production()
+    }
+}
+
+/// Answers the next subscription request with success.
+async fn subscribed(websocket: &mut WebSocketStream<tokio::net::TcpStream>) {
+    while let Some(Ok(message)) = websocket.next().await {
+        if let tungstenite::Message::Text(text) = message {
+            let request: Value = serde_json::from_str(&text).expect("Unreadable request");
+            let response = format!(r#"{{"id":{},"status":"ok","subbed":{},"ts":1}}"#, request["id"], request["sub"]);
+            websocket.send(gzip(&response)).await.expect("Couldn't answer");
+            return;
+        }
+    }
+}
+
+#[tokio::test]
+async fn request_timeout() {
+    // The server reads the requests but never answers them.
+    let parameters = serve(|mut websocket| async move { while let Some(Ok(_)) = websocket.next().await {} }).await;
+    let mut huobi = HuobiWebsocket::new(parameters)
+        .await
+        .expect("Couldn't connect")
+        .with_request_timeout(Duration::from_millis(100));
+    let result = huobi.subscribe(&Subscription::Trades(eth_btc())).await;
+    assert!(matches!(result, Err(OpenLimitsError::RequestTimeout())));
+}
+
+#[tokio::test]
+async fn bad_frames() {
+    let parameters = serve(|mut websocket| async move {
+        subscribed(&mut websocket).await;
+        websocket.send(tungstenite::Message::Binary(b"not gzipped".to_vec())).await.unwrap();
+        websocket.send(gzip(&format!(r#"{{"ch":"{}","ts":"yesterday","tick":{{}}}}"#, TRADES))).await.unwrap();
+        websocket.send(gzip(r#"{"ch":"market.unknown.bbo","ts":1,"tick":{}}"#)).await.unwrap();
+        websocket.send(trades(1)).await.unwrap();
+        websocket.send(tungstenite::Message::Close(None)).await.unwrap();
+        while let Some(Ok(_)) = websocket.next().await {}
+    })
+    .await;
+    let mut huobi = HuobiWebsocket::new(parameters).await.expect("Couldn't connect");
+    let mut subscription = huobi.subscribe(&Subscription::Trades(eth_btc())).await.expect("Couldn't subscribe");
+
+    let next = subscription.next().await.expect("No publication for the gzip error");
+    assert!(matches!(next, Err(OpenLimitsError::NotParsableResponse(_))));
+    let next = subscription.next().await.expect("No publication for the JSON error");
+    assert!(matches!(next, Err(OpenLimitsError::JsonError(_))));
+    // The update of the unknown channel is dropped and the connection keeps going.
+    match subscription.next().await.expect("No trades") {
+        Ok(Publication::Trades(trades)) => assert_eq!(trades[0].id, "1"),
+        publication => panic!("Unexpected publication {:?}", publication),
+    }
+    let next = subscription.next().await.expect("No disconnection");
+    assert!(matches!(next, Err(OpenLimitsError::Disconnected())));
+    assert!(subscription.next().await.is_none());
+}
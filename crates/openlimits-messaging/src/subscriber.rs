@@ -3,7 +3,8 @@ use async_trait::async_trait;
 use std::pin::Pin;
 use futures::stream::Stream;
 
-pub type Subscription<T> = Pin<Box<dyn Stream<Item = T>>>;
+/// The publications of a subscription, which can be moved to another task.
+pub type Subscription<T> = Pin<Box<dyn Stream<Item = T> + Send>>;
 
 #[async_trait]
 pub trait Subscriber {
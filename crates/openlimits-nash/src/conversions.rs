@@ -0,0 +1,364 @@
+//! Conversions between the nash-protocol types and the generic model. Both are foreign to this
+//! crate, so they are functions rather than `From` implementations.
+
+use std::convert::TryFrom;
+use std::str::FromStr;
+use nash_protocol::protocol::cancel_all_orders::CancelAllOrders;
+use nash_protocol::protocol::cancel_order::{CancelOrderRequest as NashCancelOrderRequest, CancelOrderResponse};
+use nash_protocol::protocol::get_account_order::GetAccountOrderRequest;
+use nash_protocol::protocol::get_ticker::{TickerRequest, TickerResponse};
+use nash_protocol::protocol::list_candles::ListCandlesRequest;
+use nash_protocol::protocol::list_trades::ListTradesRequest;
+use nash_protocol::protocol::list_account_orders::ListAccountOrdersRequest;
+use nash_protocol::protocol::list_account_trades::ListAccountTradesRequest;
+use nash_protocol::protocol::orderbook::{OrderbookRequest, OrderbookResponse};
+use nash_protocol::protocol::place_order::PlaceOrderResponse;
+use nash_protocol::protocol::subscriptions::{SubscriptionRequest, SubscriptionResponse};
+use nash_protocol::protocol::subscriptions::trades::SubscribeTrades;
+use nash_protocol::protocol::subscriptions::updated_orderbook::SubscribeOrderbook;
+use nash_protocol::types::{self as nash, AccountTradeSide, BuyOrSell, CandleInterval, DateTimeRange, OrderCancellationPolicy};
+use chrono::Utc;
+use rust_decimal::prelude::Decimal;
+use openlimits_exchange::errors::{MissingImplementationContent, OpenLimitsError};
+use openlimits_exchange::message::subscription::{Publication, Subscription};
+use openlimits_exchange::model::market_pair::MarketPair;
+use openlimits_exchange::model::{
+    AskBid, CancelAllOrdersRequest, CancelOrderRequest, Candle, GetHistoricRatesRequest,
+    GetHistoricTradesRequest, GetOrderHistoryRequest, GetOrderRequest, GetPriceTickerRequest, Interval, Liquidity, Order, OrderBookRequest, OrderBookResponse, OrderCanceled, OrderStatus, OrderType, Paginator, Side,
+    SymbolFormat, Ticker, TimeInForce, Trade, TradeHistoryRequest,
+};
+use openlimits_exchange::shared::{self, Result};
+
+/// Nash separates the lowercase base and quote with an underscore, e.g. "eth_btc".
+pub const SYMBOL_FORMAT: SymbolFormat = SymbolFormat::new(Some('_'), true);
+
+/// The Nash market of a pair, such as "eth_btc".
+pub fn market(market_pair: &MarketPair) -> String {
+    SYMBOL_FORMAT.format(market_pair)
+}
+
+pub fn market_pair(market: &str) -> Result<MarketPair> {
+    SYMBOL_FORMAT.parse(market)
+}
+
+/// Nash amounts and prices are written as decimal strings.
+pub fn decimal(value: impl ToString) -> Result<Decimal> {
+    let value = value.to_string();
+    Decimal::from_str(&value)
+        .map_err(|_| OpenLimitsError::NotParsableResponse(format!("{} is not a valid decimal.", value)))
+}
+
+fn required_market(market_pair: &Option<MarketPair>) -> Result<String> {
+    market_pair
+        .as_ref()
+        .map(market)
+        .ok_or_else(|| OpenLimitsError::MissingParameter("market_pair parameter is required.".to_string()))
+}
+
+/// The cursor, limit and time range of a paginator. Nash only filters on a range with both ends.
+pub fn split_paginator(paginator: Option<&Paginator>) -> Result<(Option<String>, Option<i64>, Option<DateTimeRange>)> {
+    let paginator = match paginator {
+        Some(paginator) => paginator,
+        None => return Ok((None, None, None)),
+    };
+    let limit = match paginator.limit {
+        Some(limit) => Some(i64::try_from(limit).map_err(|_| {
+            OpenLimitsError::InvalidParameter(format!("{} is not a valid limit.", limit))
+        })?),
+        None => None,
+    };
+    let range = match (paginator.start_time, paginator.end_time) {
+        (Some(start), Some(stop)) => Some(DateTimeRange { start, stop }),
+        _ => None,
+    };
+    Ok((paginator.before.clone(), limit, range))
+}
+
+pub fn candles_request(req: &GetHistoricRatesRequest) -> Result<ListCandlesRequest> {
+    let (before, limit, range) = split_paginator(req.paginator.as_ref())?;
+    Ok(ListCandlesRequest {
+        market: market(&req.market_pair),
+        before,
+        chronological: None,
+        interval: Some(candle_interval(req.interval)?),
+        limit,
+        range,
+    })
+}
+
+/// The intervals listed in the Nash capabilities.
+pub fn candle_interval(interval: Interval) -> Result<CandleInterval> {
+    match interval {
+        Interval::OneMinute => Ok(CandleInterval::OneMinute),
+        Interval::FiveMinutes => Ok(CandleInterval::FiveMinute),
+        Interval::FifteenMinutes => Ok(CandleInterval::FifteenMinute),
+        Interval::ThirtyMinutes => Ok(CandleInterval::ThirtyMinute),
+        Interval::OneHour => Ok(CandleInterval::OneHour),
+        Interval::SixHours => Ok(CandleInterval::SixHour),
+        Interval::TwelveHours => Ok(CandleInterval::TwelveHour),
+        Interval::OneDay => Ok(CandleInterval::OneDay),
+        Interval::OneWeek => Ok(CandleInterval::OneWeek),
+        Interval::OneMonth => Ok(CandleInterval::OneMonth),
+        interval => Err(OpenLimitsError::InvalidParameter(format!(
+            "Nash doesn't list {:?} candles.",
+            interval
+        ))),
+    }
+}
+
+/// A candle, timed at the start of its interval.
+pub fn candle(candle: nash::Candle) -> Result<Candle> {
+    Ok(Candle {
+        time: shared::truncate_to_millis(candle.interval_start),
+        low: decimal(&candle.low_price.amount)?,
+        high: decimal(&candle.high_price.amount)?,
+        open: decimal(&candle.open_price.amount)?,
+        close: decimal(&candle.close_price.amount)?,
+        volume: decimal(&candle.a_volume.amount)?,
+    })
+}
+
+/// The public trades of a market, which Nash pages with a cursor but doesn't filter by time.
+pub fn trades_request(req: &GetHistoricTradesRequest) -> Result<ListTradesRequest> {
+    let (before, limit, _) = split_paginator(req.paginator.as_ref())?;
+    Ok(ListTradesRequest {
+        market: market(&req.market_pair.parse()?),
+        limit,
+        before,
+    })
+}
+
+pub fn ticker_request(req: &GetPriceTickerRequest) -> TickerRequest {
+    TickerRequest {
+        market: market(&req.market_pair),
+    }
+}
+
+/// A ticker, whose price 24 hours ago is the last price minus its 24h change.
+pub fn ticker(resp: TickerResponse) -> Result<Ticker> {
+    let price = resp.last_price.map(|price| decimal(&price.amount)).transpose()?;
+    let change = resp.price_change_24h.map(|change| decimal(&change.amount)).transpose()?;
+    Ok(Ticker {
+        market_pair: market_pair(&resp.market_name)?,
+        price,
+        price_24h: price.zip(change).map(|(price, change)| price - change),
+        bid: resp.best_bid_price.map(|bid| decimal(&bid.amount)).transpose()?,
+        bid_qty: resp.best_bid_size.map(|size| decimal(&size.amount)).transpose()?,
+        ask: resp.best_ask_price.map(|ask| decimal(&ask.amount)).transpose()?,
+        ask_qty: resp.best_ask_size.map(|size| decimal(&size.amount)).transpose()?,
+        high_24h: resp.high_price_24h.map(|high| decimal(&high.amount)).transpose()?,
+        low_24h: resp.low_price_24h.map(|low| decimal(&low.amount)).transpose()?,
+        volume_24h: Some(decimal(&resp.a_volume_24h.amount)?),
+        quote_volume_24h: Some(decimal(&resp.b_volume_24h.amount)?),
+        created_at: None,
+    })
+}
+
+pub fn order_book_request(req: &OrderBookRequest) -> OrderbookRequest {
+    OrderbookRequest {
+        market: market(&req.market_pair),
+    }
+}
+
+pub fn order_book(book: OrderbookResponse) -> Result<OrderBookResponse> {
+    Ok(OrderBookResponse {
+        update_id: Some(book.update_id as u64),
+        last_update_id: Some(book.last_update_id as u64),
+        bids: book.bids.into_iter().map(ask_bid).collect::<Result<_>>()?,
+        asks: book.asks.into_iter().map(ask_bid).collect::<Result<_>>()?,
+    })
+}
+
+pub fn ask_bid(order: nash::OrderbookOrder) -> Result<AskBid> {
+    Ok(AskBid {
+        price: decimal(&order.price)?,
+        qty: decimal(&order.amount)?,
+    })
+}
+
+/// Nash publishes neither candles nor tickers.
+pub fn subscription_request(subscription: &Subscription) -> Result<SubscriptionRequest> {
+    Ok(match subscription {
+        Subscription::Depth(market_pair) => SubscriptionRequest::Orderbook(SubscribeOrderbook { market: market(market_pair) }),
+        Subscription::Trades(market_pair) => SubscriptionRequest::Trades(SubscribeTrades { market: market(market_pair) }),
+        Subscription::Kline(_, _) | Subscription::Ticker(_) => {
+            let message = "Nash doesn't publish klines nor tickers".into();
+            return Err(OpenLimitsError::MissingImplementation(MissingImplementationContent { message }));
+        }
+    })
+}
+
+pub fn publication(response: SubscriptionResponse) -> Result<Publication> {
+    Ok(match response {
+        SubscriptionResponse::Orderbook(book) => Publication::Depth(OrderBookResponse {
+            update_id: Some(book.update_id as u64),
+            last_update_id: Some(book.last_update_id as u64),
+            bids: book.bids.into_iter().map(ask_bid).collect::<Result<_>>()?,
+            asks: book.asks.into_iter().map(ask_bid).collect::<Result<_>>()?,
+        }),
+        SubscriptionResponse::Trades(trades) => {
+            Publication::Trades(trades.trades.into_iter().map(trade).collect::<Result<_>>()?)
+        }
+        response => return Err(OpenLimitsError::UnkownResponse(format!("Unexpected message {:?}", response))),
+    })
+}
+
+pub fn cancel_order_request(req: &CancelOrderRequest) -> Result<NashCancelOrderRequest> {
+    Ok(NashCancelOrderRequest {
+        market: required_market(&req.market_pair)?,
+        order_id: req.id.clone(),
+    })
+}
+
+pub fn order_canceled(resp: CancelOrderResponse) -> OrderCanceled {
+    OrderCanceled { id: resp.order_id }
+}
+
+pub fn cancel_all_orders_request(req: &CancelAllOrdersRequest) -> Result<CancelAllOrders> {
+    Ok(CancelAllOrders {
+        market: required_market(&req.market_pair)?,
+    })
+}
+
+pub fn account_trades_request(req: &TradeHistoryRequest) -> Result<ListAccountTradesRequest> {
+    let (before, limit, range) = split_paginator(req.paginator.as_ref())?;
+    Ok(ListAccountTradesRequest {
+        market: req.market_pair.as_ref().map(market),
+        before,
+        limit,
+        range,
+    })
+}
+
+pub fn account_orders_request(req: &GetOrderHistoryRequest) -> Result<ListAccountOrdersRequest> {
+    let (before, limit, range) = split_paginator(req.paginator.as_ref())?;
+    let status = match &req.order_status {
+        Some(statuses) => Some(statuses.iter().map(nash_order_status).collect::<Result<_>>()?),
+        None => None,
+    };
+    Ok(ListAccountOrdersRequest {
+        market: req.market_pair.as_ref().map(market),
+        before,
+        buy_or_sell: None,
+        limit,
+        status,
+        order_type: None,
+        range,
+    })
+}
+
+pub fn order_request(req: &GetOrderRequest) -> GetAccountOrderRequest {
+    GetAccountOrderRequest {
+        order_id: req.id.clone(),
+    }
+}
+
+/// Nash expires orders at a time rather than after a duration, counted here from now.
+pub fn cancellation_policy(time_in_force: TimeInForce) -> OrderCancellationPolicy {
+    match time_in_force {
+        TimeInForce::GoodTillCancelled => OrderCancellationPolicy::GoodTilCancelled,
+        TimeInForce::ImmediateOrCancelled => OrderCancellationPolicy::ImmediateOrCancel,
+        TimeInForce::FillOrKill => OrderCancellationPolicy::FillOrKill,
+        TimeInForce::GoodTillTime(duration) => OrderCancellationPolicy::GoodTilTime(Utc::now() + duration),
+    }
+}
+
+pub fn order(order: nash::Order) -> Result<Order> {
+    Ok(Order {
+        id: order.id,
+        market_pair: market_pair(&order.market)?,
+        client_order_id: None,
+        created_at: Some(shared::truncate_to_millis(order.placed_at)),
+        order_type: order_type(order.order_type),
+        side: side(order.buy_or_sell),
+        status: order_status(order.status),
+        size: decimal(&order.amount_placed)?,
+        price: order.limit_price.as_ref().map(decimal).transpose()?,
+        remaining: Some(decimal(&order.amount_remaining)?),
+        trades: order.trades.into_iter().map(trade).collect::<Result<_>>()?,
+    })
+}
+
+/// A placed order, of which Nash only answers the id, type, side and status.
+pub fn placed_order(order: PlaceOrderResponse) -> Result<Order> {
+    Ok(Order {
+        id: order.order_id,
+        market_pair: market_pair(&order.market_name)?,
+        client_order_id: None,
+        created_at: Some(shared::truncate_to_millis(order.placed_at)),
+        order_type: order_type(order.order_type),
+        side: side(order.buy_or_sell),
+        status: order_status(order.status),
+        size: Decimal::ZERO,
+        price: None,
+        remaining: None,
+        trades: Vec::new(),
+    })
+}
+
+pub fn trade(trade: nash::Trade) -> Result<Trade> {
+    // The account only pays the fee of its own side of the trade.
+    let fees = match trade.account_side {
+        AccountTradeSide::Taker => Some(decimal(&trade.taker_fee)?),
+        AccountTradeSide::Maker => Some(decimal(&trade.maker_fee)?),
+        _ => None,
+    };
+    let (buyer_order_id, seller_order_id) = match trade.direction {
+        BuyOrSell::Buy => (trade.taker_order_id, trade.maker_order_id),
+        BuyOrSell::Sell => (trade.maker_order_id, trade.taker_order_id),
+    };
+    Ok(Trade {
+        id: trade.id,
+        buyer_order_id: Some(buyer_order_id),
+        seller_order_id: Some(seller_order_id),
+        market_pair: market_pair(&trade.market)?,
+        price: decimal(&trade.limit_price)?,
+        qty: decimal(&trade.amount)?,
+        fees,
+        side: side(trade.direction),
+        liquidity: match trade.account_side {
+            AccountTradeSide::Maker => Some(Liquidity::Maker),
+            AccountTradeSide::Taker => Some(Liquidity::Taker),
+            _ => None,
+        },
+        created_at: shared::truncate_to_millis(trade.executed_at),
+    })
+}
+
+pub fn side(side: BuyOrSell) -> Side {
+    match side {
+        BuyOrSell::Buy => Side::Buy,
+        BuyOrSell::Sell => Side::Sell,
+    }
+}
+
+pub fn order_type(order_type: nash::OrderType) -> OrderType {
+    match order_type {
+        nash::OrderType::Limit => OrderType::Limit,
+        nash::OrderType::Market => OrderType::Market,
+        nash::OrderType::StopLimit => OrderType::StopLimit,
+        nash::OrderType::StopMarket => OrderType::StopMarket,
+    }
+}
+
+pub fn order_status(status: nash::OrderStatus) -> OrderStatus {
+    match status {
+        nash::OrderStatus::Open => OrderStatus::Open,
+        nash::OrderStatus::Filled => OrderStatus::Filled,
+        nash::OrderStatus::Canceled => OrderStatus::Canceled,
+        nash::OrderStatus::Pending => OrderStatus::Pending,
+    }
+}
+
+fn nash_order_status(status: &OrderStatus) -> Result<nash::OrderStatus> {
+    match status {
+        OrderStatus::Open => Ok(nash::OrderStatus::Open),
+        OrderStatus::Filled => Ok(nash::OrderStatus::Filled),
+        OrderStatus::Canceled => Ok(nash::OrderStatus::Canceled),
+        OrderStatus::Pending => Ok(nash::OrderStatus::Pending),
+        status => Err(OpenLimitsError::InvalidParameter(format!(
+            "Nash doesn't filter orders on {:?}.",
+            status
+        ))),
+    }
+}
@@ -1,6 +1,9 @@
 //! This module provides functionality for communicating with the nash API.
+//! The nash client spawns its tasks on tokio, so this crate needs a tokio runtime whatever the
+//! runtime feature of the other exchanges.
 
 
+mod conversions;
 mod nash_credentials;
 mod nash_parameters;
 mod nash_stream;
@@ -12,18 +15,19 @@ pub use nash_stream::NashWebsocket;
 pub use utils::client_from_params_failable;
 pub use openlimits_exchange::shared;
 
-use std::convert::TryInto;
 use async_trait::async_trait;
 use nash_native_client::Client;
 use rust_decimal::prelude::*;
 use openlimits_exchange::{
     errors::OpenLimitsError,
     model::{
-        Balance, CancelAllOrdersRequest, CancelOrderRequest, Candle, GetHistoricRatesRequest,
-        GetHistoricTradesRequest, GetOrderHistoryRequest, GetOrderRequest, GetPriceTickerRequest,
-        OpenLimitOrderRequest, OpenMarketOrderRequest, Order,
-        OrderBookRequest, OrderBookResponse, OrderCanceled, Paginator,
-        Ticker, Trade, TradeHistoryRequest,
+        Balance, Batch, BatchOperation, CancelAllOrdersRequest, CancelOrderRequest, Candle, Capabilities,
+        GetHistoricRatesRequest,
+        GetHistoricTradesRequest, GetLedgerRequest, GetOrderHistoryRequest, GetOrderRequest,
+        GetPriceTickerRequest, LedgerEntry, OpenLimitOrderRequest, OpenMarketOrderRequest, Order,
+        Interval, Operation, OrderBookRequest, OrderBookResponse, OrderCanceled, OrderType, Paginator,
+        Ticker, TimeInForce, Trade, TradeHistoryRequest,
+        websocket::SubscriptionKind,
     },
 };
 use openlimits_exchange::shared::Result;
@@ -50,7 +54,7 @@ impl Exchange for Nash {
 
     async fn new(params: Self::InitParams) -> Result<Self> {
         let nash = Self {
-            exchange_info: ExchangeInfo::new(),
+            exchange_info: ExchangeInfo::with_venue("nash"),
             transport: client_from_params_failable(params).await?,
         };
         nash.refresh_market_info().await.ok();
@@ -60,12 +64,58 @@ impl Exchange for Nash {
     fn inner_client(&self) -> Option<&Self::InnerClient> {
         Some(&self.transport)
     }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            operations: vec![
+                Operation::OrderBook,
+                Operation::GetPriceTicker,
+                Operation::GetAllTickers,
+                Operation::GetHistoricRates,
+                Operation::GetHistoricTrades,
+                Operation::LimitBuy,
+                Operation::LimitSell,
+                Operation::MarketSell,
+                Operation::CancelOrder,
+                Operation::CancelAllOrders,
+                Operation::GetAllOpenOrders,
+                Operation::GetOrderHistory,
+                Operation::GetTradeHistory,
+                Operation::GetAccountBalances,
+                Operation::GetOrder,
+            ],
+            order_types: vec![OrderType::Limit, OrderType::Market],
+            // Nash turns any good till time duration into an expiration timestamp.
+            time_in_force: vec![
+                TimeInForce::GoodTillCancelled,
+                TimeInForce::ImmediateOrCancelled,
+                TimeInForce::FillOrKill,
+                TimeInForce::GoodTillTime(chrono::Duration::zero()),
+            ],
+            // These mirror the candle intervals nash-protocol accepts.
+            intervals: vec![
+                Interval::OneMinute,
+                Interval::FiveMinutes,
+                Interval::FifteenMinutes,
+                Interval::ThirtyMinutes,
+                Interval::OneHour,
+                Interval::SixHours,
+                Interval::TwelveHours,
+                Interval::OneDay,
+                Interval::OneWeek,
+                Interval::OneMonth,
+            ],
+            subscriptions: vec![SubscriptionKind::OrderBookUpdates, SubscriptionKind::Trades],
+            batch_operations: vec![Batch::new(BatchOperation::CancelAllOrders, None)],
+            max_book_depth: None,
+        }
+    }
 }
 
 #[async_trait]
 impl ExchangeMarketData for Nash {
     async fn get_historic_rates(&self, req: &GetHistoricRatesRequest) -> Result<Vec<Candle>> {
-        let req: nash_protocol::protocol::list_candles::ListCandlesRequest = req.try_into()?;
+        let req = conversions::candles_request(req)?;
 
         let resp = self.transport.run(req).await;
 
@@ -74,56 +124,71 @@ impl ExchangeMarketData for Nash {
                 resp,
             )?;
 
-        Ok(resp.candles.into_iter().map(Into::into).collect())
+        resp.candles.into_iter().map(conversions::candle).collect()
     }
 
     async fn get_historic_trades(&self, req: &GetHistoricTradesRequest) -> Result<Vec<Trade>> {
-        let req: nash_protocol::protocol::list_trades::ListTradesRequest = req.try_into()?;
+        let req = conversions::trades_request(req)?;
         let resp = self.transport.run(req).await;
 
         let resp: nash_protocol::protocol::list_trades::ListTradesResponse = Nash::unwrap_response::<
             nash_protocol::protocol::list_trades::ListTradesResponse,
         >(resp)?;
 
-        Ok(resp.trades.into_iter().map(Into::into).collect())
+        resp.trades.into_iter().map(conversions::trade).collect()
     }
 
     async fn get_price_ticker(&self, req: &GetPriceTickerRequest) -> Result<Ticker> {
-        let req: nash_protocol::protocol::get_ticker::TickerRequest = req.into();
+        let req = conversions::ticker_request(req);
         let resp = self.transport.run(req).await;
-        Ok(
-            Nash::unwrap_response::<nash_protocol::protocol::get_ticker::TickerResponse>(resp)?
-                .into(),
-        )
+        conversions::ticker(Nash::unwrap_response::<
+            nash_protocol::protocol::get_ticker::TickerResponse,
+        >(resp)?)
+    }
+
+    async fn get_all_tickers(&self) -> Result<Vec<Ticker>> {
+        let mut tickers = Vec::new();
+        let mut last_error = None;
+        for pair in self.exchange_info.list_pairs() {
+            let market_pair = pair.read()?.market_pair()?;
+            let req = GetPriceTickerRequest { market_pair };
+            match self.get_price_ticker(&req).await {
+                Ok(ticker) => tickers.push(ticker),
+                Err(error) => {
+                    tracing::warn!(market_pair = %req.market_pair, ?error, "Skipping the ticker of a market");
+                    last_error = Some(error);
+                }
+            }
+        }
+        match last_error {
+            Some(error) if tickers.is_empty() => Err(error),
+            _ => Ok(tickers),
+        }
     }
 
     async fn order_book(&self, req: &OrderBookRequest) -> Result<OrderBookResponse> {
-        let req: nash_protocol::protocol::orderbook::OrderbookRequest = req.into();
+        let req = conversions::order_book_request(req);
         let resp = self.transport.run(req).await;
-        Ok(
-            Nash::unwrap_response::<nash_protocol::protocol::orderbook::OrderbookResponse>(resp)?
-                .into(),
-        )
+        conversions::order_book(Nash::unwrap_response::<
+            nash_protocol::protocol::orderbook::OrderbookResponse,
+        >(resp)?)
     }
 }
 
 #[async_trait]
 impl ExchangeAccount for Nash {
     async fn cancel_all_orders(&self, req: &CancelAllOrdersRequest) -> Result<Vec<OrderCanceled>> {
-        let req: nash_protocol::protocol::cancel_all_orders::CancelAllOrders = req.into();
+        let req = conversions::cancel_all_orders_request(req)?;
         self.transport.run_http(req).await?;
         Ok(vec![])
     }
 
     async fn cancel_order(&self, req: &CancelOrderRequest) -> Result<OrderCanceled> {
-        let req: nash_protocol::protocol::cancel_order::CancelOrderRequest = req.into();
+        let req = conversions::cancel_order_request(req)?;
         let resp = self.transport.run_http(req).await;
-        Ok(
-            Nash::unwrap_response::<nash_protocol::protocol::cancel_order::CancelOrderResponse>(
-                resp,
-            )?
-            .into(),
-        )
+        Ok(conversions::order_canceled(Nash::unwrap_response::<
+            nash_protocol::protocol::cancel_order::CancelOrderResponse,
+        >(resp)?))
     }
 
     async fn get_account_balances(&self, _paginator: Option<Paginator>) -> Result<Vec<Balance>> {
@@ -184,12 +249,11 @@ impl ExchangeAccount for Nash {
                 nash_protocol::protocol::list_account_orders::ListAccountOrdersResponse,
             >(resp)?;
 
-        Ok(resp.orders.into_iter().map(Into::into).collect())
+        resp.orders.into_iter().map(conversions::order).collect()
     }
 
     async fn get_order_history(&self, req: &GetOrderHistoryRequest) -> Result<Vec<Order>> {
-        let req: nash_protocol::protocol::list_account_orders::ListAccountOrdersRequest =
-            req.try_into()?;
+        let req = conversions::account_orders_request(req)?;
 
         let resp = self.transport.run(req).await;
 
@@ -198,12 +262,11 @@ impl ExchangeAccount for Nash {
                 nash_protocol::protocol::list_account_orders::ListAccountOrdersResponse,
             >(resp)?;
 
-        Ok(resp.orders.into_iter().map(Into::into).collect())
+        resp.orders.into_iter().map(conversions::order).collect()
     }
 
     async fn get_trade_history(&self, req: &TradeHistoryRequest) -> Result<Vec<Trade>> {
-        let req: nash_protocol::protocol::list_account_trades::ListAccountTradesRequest =
-            req.try_into()?;
+        let req = conversions::account_trades_request(req)?;
 
         let resp = self.transport.run(req).await;
 
@@ -212,7 +275,7 @@ impl ExchangeAccount for Nash {
                 nash_protocol::protocol::list_account_trades::ListAccountTradesResponse,
             >(resp)?;
 
-        Ok(resp.trades.into_iter().map(Into::into).collect())
+        resp.trades.into_iter().map(conversions::trade).collect()
     }
 
     async fn limit_buy(&self, req: &OpenLimitOrderRequest) -> Result<Order> {
@@ -221,12 +284,9 @@ impl ExchangeAccount for Nash {
 
         let resp = self.transport.run_http(req).await;
 
-        Ok(
-            Nash::unwrap_response::<nash_protocol::protocol::place_order::PlaceOrderResponse>(
-                resp,
-            )?
-            .into(),
-        )
+        conversions::placed_order(Nash::unwrap_response::<
+            nash_protocol::protocol::place_order::PlaceOrderResponse,
+        >(resp)?)
     }
 
     async fn limit_sell(&self, req: &OpenLimitOrderRequest) -> Result<Order> {
@@ -234,25 +294,18 @@ impl ExchangeAccount for Nash {
             Nash::convert_limit_order(req, nash_protocol::types::BuyOrSell::Sell);
         let resp = self.transport.run_http(req).await;
 
-        Ok(
-            Nash::unwrap_response::<nash_protocol::protocol::place_order::PlaceOrderResponse>(
-                resp,
-            )?
-            .into(),
-        )
+        conversions::placed_order(Nash::unwrap_response::<
+            nash_protocol::protocol::place_order::PlaceOrderResponse,
+        >(resp)?)
     }
 
     async fn market_sell(&self, req: &OpenMarketOrderRequest) -> Result<Order> {
         let req: nash_protocol::protocol::place_order::MarketOrderRequest =
             Nash::convert_market_request(req);
-        println!("{:#?}", req);
         let resp = self.transport.run_http(req).await;
-        Ok(
-            Nash::unwrap_response::<nash_protocol::protocol::place_order::PlaceOrderResponse>(
-                resp,
-            )?
-            .into(),
-        )
+        conversions::placed_order(Nash::unwrap_response::<
+            nash_protocol::protocol::place_order::PlaceOrderResponse,
+        >(resp)?)
     }
 
     async fn market_buy(&self, _req: &OpenMarketOrderRequest) -> Result<Order> {
@@ -260,13 +313,18 @@ impl ExchangeAccount for Nash {
         Err(OpenLimitsError::MissingImplementation(MissingImplementationContent { message }))
     }
 
+    async fn get_ledger(&self, _req: &GetLedgerRequest) -> Result<Vec<LedgerEntry>> {
+        let message = "Nash client doesn't implement get_ledger".into();
+        Err(OpenLimitsError::MissingImplementation(MissingImplementationContent { message }))
+    }
+
     async fn get_order(&self, req: &GetOrderRequest) -> Result<Order> {
-        let req: nash_protocol::protocol::get_account_order::GetAccountOrderRequest = req.into();
+        let req = conversions::order_request(req);
         let resp = self.transport.run(req).await;
         let resp = Nash::unwrap_response::<
             nash_protocol::protocol::get_account_order::GetAccountOrderResponse,
         >(resp)?;
-        Ok(resp.order.into())
+        conversions::order(resp.order)
     }
 }
 
@@ -289,13 +347,11 @@ impl Nash {
         req: &OpenLimitOrderRequest,
         buy_or_sell: nash_protocol::types::BuyOrSell,
     ) -> nash_protocol::protocol::place_order::LimitOrderRequest {
-        let market = req.market_pair.clone();
-        let market = nash_protocol::types::market_pair::MarketPair::from(market).0;
         nash_protocol::protocol::place_order::LimitOrderRequest {
             client_order_id: req.client_order_id.clone(),
-            cancellation_policy: req.time_in_force.into(),
+            cancellation_policy: conversions::cancellation_policy(req.time_in_force),
             allow_taker: !req.post_only,
-            market,
+            market: conversions::market(&req.market_pair),
             buy_or_sell,
             amount: format!("{}", req.size),
             price: format!("{}", req.price),
@@ -305,11 +361,9 @@ impl Nash {
     pub fn convert_market_request(
         req: &OpenMarketOrderRequest,
     ) -> nash_protocol::protocol::place_order::MarketOrderRequest {
-        let market = req.market_pair.clone();
-        let market = nash_protocol::types::market_pair::MarketPair::from(market).0;
         nash_protocol::protocol::place_order::MarketOrderRequest {
             client_order_id: req.client_order_id.clone(),
-            market,
+            market: conversions::market(&req.market_pair),
             amount: format!("{}", req.size),
         }
     }
@@ -364,7 +418,6 @@ impl ExchangeInfoRetrieval for Nash {
     }
 
     async fn get_pair(&self, name: &MarketPair) -> Result<MarketPairHandle> {
-        let name = nash_protocol::types::market_pair::MarketPair::from(name.clone()).0;
-        self.exchange_info.get_pair(&name)
+        self.exchange_info.get_pair(&conversions::market(name))
     }
 }
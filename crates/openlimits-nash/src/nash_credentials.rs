@@ -1,6 +1,32 @@
-/// This structure represents the Nash account credentials
+use std::convert::TryFrom;
+use std::fmt;
+use openlimits_exchange::credentials::{Profile, Zeroizing, REDACTED};
+use openlimits_exchange::errors::OpenLimitsError;
+
+/// This structure represents the Nash account credentials, overwritten when they are dropped.
 #[derive(Clone)]
 pub struct NashCredentials {
-    pub api_secret: String,
-    pub api_key: String,
-}
\ No newline at end of file
+    pub api_secret: Zeroizing<String>,
+    pub api_key: Zeroizing<String>,
+}
+
+// The Nash API key holds key material as well, so neither field is shown.
+impl fmt::Debug for NashCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NashCredentials")
+            .field("api_secret", &REDACTED)
+            .field("api_key", &REDACTED)
+            .finish()
+    }
+}
+
+impl TryFrom<&Profile> for NashCredentials {
+    type Error = OpenLimitsError;
+
+    fn try_from(profile: &Profile) -> Result<Self, Self::Error> {
+        Ok(Self {
+            api_secret: Zeroizing::new(profile.require("api_secret")?.into()),
+            api_key: Zeroizing::new(profile.require("api_key")?.into()),
+        })
+    }
+}
@@ -1,7 +1,7 @@
 use tokio::time::Duration;
 pub use nash_native_client::Client;
 use super::NashCredentials;
-use openlimits_exchange::exchange::Environment;
+use openlimits_exchange::exchange::{Endpoints, Environment};
 
 /// This struct represents the parameters
 #[derive(Clone)]
@@ -10,6 +10,9 @@ pub struct NashParameters {
     pub credentials: Option<NashCredentials>,
     pub client_id: u64,
     pub environment: Environment,
+    /// Overrides the host of the environment. When both URLs are set they must name the same host,
+    /// with the https and wss schemes the client connects with.
+    pub endpoints: Endpoints,
     pub timeout: Duration,
     pub sign_states_loop_interval: Option<Duration>,
 }
@@ -21,6 +24,7 @@ impl NashParameters {
             credentials: None,
             client_id: 1,
             environment: Environment::Production,
+            endpoints: Endpoints::default(),
             timeout: Duration::new(10, 0),
             sign_states_loop_interval: None
         }
@@ -32,6 +36,7 @@ impl NashParameters {
             credentials: None,
             client_id: 1,
             environment: Environment::Sandbox,
+            endpoints: Endpoints::default(),
             timeout: Duration::new(10, 0),
             sign_states_loop_interval: None
         }
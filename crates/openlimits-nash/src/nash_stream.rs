@@ -5,7 +5,9 @@ pub use nash_native_client::{Client, Environment};
 use nash_protocol::protocol::ResponseOrError;
 use openlimits_exchange::errors::OpenLimitsError;
 use openlimits_exchange::traits::stream::{ExchangeStream, Subscriptions};
+use openlimits_messaging::{self as messaging, Subscriber};
 use super::NashParameters;
+use super::conversions;
 use super::utils::*;
 use openlimits_exchange::shared::Result;
 use nash_protocol::protocol::subscriptions::{SubscriptionRequest, SubscriptionResponse};
@@ -53,22 +55,39 @@ impl ExchangeStream for NashWebsocket {
             streams.push(tokio_stream::wrappers::UnboundedReceiverStream::new(stream));
         }
 
-        let s = streams.map(|message| match message {
-            Ok(msg) => match msg {
-                ResponseOrError::Response(resp) => Ok(resp.data),
-                ResponseOrError::Error(resp) => {
-                    let f = resp
-                        .errors
-                        .iter()
-                        .map(|f| f.message.clone())
-                        .collect::<Vec<String>>()
-                        .join("\n");
-                    Err(OpenLimitsError::NotParsableResponse(f))
-                }
-            },
-            Err(_) => Err(OpenLimitsError::SocketError()),
-        });
+        Ok(streams.map(response).boxed())
+    }
+}
+
+// Every subscription is multiplexed on the connection of the client.
+#[async_trait]
+impl Subscriber for NashWebsocket {
+    type SubscriptionRequest = openlimits_exchange::message::subscription::Subscription;
+    type Publication = Result<openlimits_exchange::message::subscription::Publication>;
+    type Error = OpenLimitsError;
+    async fn subscribe(&mut self, subscription: &Self::SubscriptionRequest) -> Result<messaging::Subscription<Self::Publication>> {
+        let request = conversions::subscription_request(subscription)?;
+        let receiver = self.client.subscribe_protocol(request).await?;
+        let publications = tokio_stream::wrappers::UnboundedReceiverStream::new(receiver)
+            .map(|message| response(message).and_then(conversions::publication));
+        Ok(Box::pin(publications))
+    }
+}
 
-        Ok(s.boxed())
+fn response(
+    message: std::result::Result<ResponseOrError<SubscriptionResponse>, nash_protocol::errors::ProtocolError>,
+) -> Result<SubscriptionResponse> {
+    match message {
+        Ok(ResponseOrError::Response(response)) => Ok(response.data),
+        Ok(ResponseOrError::Error(response)) => {
+            let errors = response
+                .errors
+                .iter()
+                .map(|error| error.message.clone())
+                .collect::<Vec<String>>()
+                .join("\n");
+            Err(OpenLimitsError::NotParsableResponse(errors))
+        }
+        Err(_) => Err(OpenLimitsError::SocketError()),
     }
-}
\ No newline at end of file
+}
@@ -1,8 +1,64 @@
-use nash_native_client::Client;
+use std::collections::HashSet;
+use std::sync::{Mutex, OnceLock};
+use nash_native_client::{Client, Environment};
+use url::Url;
 use super::NashParameters;
+use openlimits_exchange::errors::OpenLimitsError;
 use openlimits_exchange::shared::Result;
 
+/// Nash serves its REST and websocket APIs from one host, over https and wss, so an overridden
+/// endpoint replaces the host of both and the endpoints must agree on it.
+fn environment(params: &NashParameters) -> Result<Environment> {
+    let rest = params.endpoints.rest.as_deref().map(|endpoint| host(endpoint, "https")).transpose()?;
+    let websocket = params.endpoints.websocket.as_deref().map(|endpoint| host(endpoint, "wss")).transpose()?;
+    let host = match (rest, websocket) {
+        (Some(rest), Some(websocket)) if rest != websocket => {
+            return Err(OpenLimitsError::InvalidParameter(format!(
+                "Nash serves REST and websocket from one host, but the endpoints name {} and {}",
+                rest, websocket
+            )))
+        }
+        (Some(host), _) | (None, Some(host)) => host,
+        (None, None) => return Ok(params.environment.into()),
+    };
+    Ok(Environment::Dev(intern(host)))
+}
+
+/// The host and port of the endpoint, which the client connects to with `scheme`.
+fn host(endpoint: &str, scheme: &str) -> Result<String> {
+    let url = Url::parse(endpoint)?;
+    if url.scheme() != scheme {
+        return Err(OpenLimitsError::InvalidParameter(format!(
+            "{} must use {}, the only scheme the Nash client connects with",
+            endpoint, scheme
+        )));
+    }
+    let host = url
+        .host_str()
+        .ok_or_else(|| OpenLimitsError::InvalidParameter(format!("{} has no host", endpoint)))?;
+    Ok(match url.port() {
+        Some(port) => format!("{}:{}", host, port),
+        None => host.to_string(),
+    })
+}
+
+/// The client only takes a static host, so each host is leaked once and reused by every client
+/// connecting to it.
+fn intern(host: String) -> &'static str {
+    static HOSTS: OnceLock<Mutex<HashSet<&'static str>>> = OnceLock::new();
+    let mut hosts = HOSTS.get_or_init(Default::default).lock().unwrap_or_else(|e| e.into_inner());
+    match hosts.get(host.as_str()) {
+        Some(host) => *host,
+        None => {
+            let host: &'static str = Box::leak(host.into_boxed_str());
+            hosts.insert(host);
+            host
+        }
+    }
+}
+
 pub async fn client_from_params_failable(params: NashParameters) -> Result<Client> {
+    let environment = environment(&params)?;
     let client = match params.credentials {
         Some(credentials) => {
             Client::from_keys(
@@ -11,7 +67,7 @@ pub async fn client_from_params_failable(params: NashParameters) -> Result<Clien
                 params.affiliate_code,
                 false,
                 params.client_id,
-                params.environment.into(),
+                environment,
                 params.timeout,
             )
             .await?
@@ -22,7 +78,7 @@ pub async fn client_from_params_failable(params: NashParameters) -> Result<Clien
                 None,
                 false,
                 params.client_id,
-                params.environment.into(),
+                environment,
                 params.timeout,
             )
             .await?
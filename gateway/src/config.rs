@@ -0,0 +1,123 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use serde::Deserialize;
+use openlimits::exchange::config::ExchangeConfig;
+use openlimits::exchange::credentials::{CredentialProvider, EnvProvider, KeystoreProvider, Profile, TomlProvider};
+use openlimits::exchange::errors::OpenLimitsError;
+use openlimits::exchange::shared::Result;
+
+/// The environment variable the passphrase of the keystore is read from.
+const PASSPHRASE_VARIABLE: &str = "OPENLIMITS_PASSPHRASE";
+
+/// The gateway configuration, a TOML file naming the exchanges it hosts:
+///
+/// ```toml
+/// listen = "127.0.0.1:8080"
+/// credentials = "credentials.toml"
+///
+/// [exchanges.binance]
+/// exchange = "binance"
+/// sandbox = true
+/// weight_per_minute = 1200
+/// ```
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct GatewayConfig {
+    #[serde(default = "default_listen")]
+    pub listen: SocketAddr,
+    /// A TOML file of credentials profiles.
+    #[serde(default)]
+    pub credentials: Option<PathBuf>,
+    /// A keystore of credentials profiles, decrypted with `$OPENLIMITS_PASSPHRASE`.
+    #[serde(default)]
+    pub keystore: Option<PathBuf>,
+    pub exchanges: BTreeMap<String, VenueConfig>,
+}
+
+/// An exchange hosted by the gateway, under the name of its table.
+#[derive(Deserialize, Debug)]
+pub struct VenueConfig {
+    #[serde(flatten)]
+    pub exchange: ExchangeConfig,
+    /// The credentials profile, the name of the table by default. Without credentials in the
+    /// config, it is read from the credentials file, the keystore or the environment.
+    #[serde(default)]
+    pub profile: Option<String>,
+    /// The request weight the REST calls to the exchange may spend per minute, Binance's limit
+    /// by default.
+    #[serde(default = "default_weight_per_minute")]
+    pub weight_per_minute: u32,
+}
+
+fn default_listen() -> SocketAddr {
+    SocketAddr::from(([127, 0, 0, 1], 8080))
+}
+
+fn default_weight_per_minute() -> u32 {
+    1200
+}
+
+impl GatewayConfig {
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    pub fn parse(document: &str) -> Result<Self> {
+        let config: Self = toml::from_str(document)
+            .map_err(|error| OpenLimitsError::InvalidParameter(format!("Gateway config: {}", error)))?;
+        if config.credentials.is_some() && config.keystore.is_some() {
+            return Err(OpenLimitsError::InvalidParameter(
+                "Gateway config: either credentials or keystore".into(),
+            ));
+        }
+        Ok(config)
+    }
+
+    /// Opens the credentials file or the keystore, or falls back to the environment.
+    pub fn provider(&self) -> Result<Provider> {
+        if let Some(path) = &self.credentials {
+            return Ok(Provider::Toml(TomlProvider::from_path(path)?));
+        }
+        if let Some(path) = &self.keystore {
+            let passphrase = env::var(PASSPHRASE_VARIABLE)
+                .map_err(|_| OpenLimitsError::MissingParameter(PASSPHRASE_VARIABLE.into()))?;
+            return Ok(Provider::Keystore(KeystoreProvider::open(path, &passphrase)?));
+        }
+        Ok(Provider::Env(EnvProvider))
+    }
+}
+
+/// The credential provider picked by the config.
+pub enum Provider {
+    Toml(TomlProvider),
+    Keystore(KeystoreProvider),
+    Env(EnvProvider),
+}
+
+impl CredentialProvider for Provider {
+    fn profile(&self, name: &str) -> Result<Profile> {
+        match self {
+            Self::Toml(provider) => provider.profile(name),
+            Self::Keystore(provider) => provider.profile(name),
+            Self::Env(provider) => provider.profile(name),
+        }
+    }
+}
+
+impl VenueConfig {
+    /// The credentials of the exchange, if it has any. Only a profile named in the config has to
+    /// exist.
+    pub fn profile(&self, name: &str, provider: &Provider) -> Result<Option<Profile>> {
+        if let Some(profile) = self.exchange.profile() {
+            return Ok(Some(profile));
+        }
+        match provider.profile(self.profile.as_deref().unwrap_or(name)) {
+            Ok(profile) => Ok(Some(profile)),
+            Err(_) if self.profile.is_none() => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use serde_json::json;
+use openlimits::exchange::errors::OpenLimitsError;
+
+/// An error of a request, answered with its status and `{"error": message}`.
+#[derive(Debug)]
+pub struct GatewayError {
+    pub status: StatusCode,
+    pub message: String,
+}
+
+impl GatewayError {
+    pub fn new(status: StatusCode, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<OpenLimitsError> for GatewayError {
+    fn from(error: OpenLimitsError) -> Self {
+        let status = match error {
+            OpenLimitsError::MissingImplementation(_) => StatusCode::NOT_IMPLEMENTED,
+            OpenLimitsError::InvalidParameter(_) | OpenLimitsError::MissingParameter(_) => StatusCode::BAD_REQUEST,
+            OpenLimitsError::NoApiKeySet() => StatusCode::UNAUTHORIZED,
+            _ => StatusCode::BAD_GATEWAY,
+        };
+        Self::new(status, error_message(&error))
+    }
+}
+
+impl IntoResponse for GatewayError {
+    fn into_response(self) -> Response {
+        (self.status, Json(json!({ "error": self.message }))).into_response()
+    }
+}
+
+/// Most of the `OpenLimitsError` variants display as an empty string, so those fall back to `Debug`.
+pub fn error_message(error: &OpenLimitsError) -> String {
+    let message = error.to_string();
+    if message.is_empty() {
+        format!("{:?}", error)
+    } else {
+        message
+    }
+}
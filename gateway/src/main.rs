@@ -0,0 +1,87 @@
+//! `openlimits-gateway`, a local REST and websocket server hosting the exchanges of its config, so
+//! that services in any language, and several processes, share one set of exchange connections,
+//! rate limits and credentials.
+
+mod config;
+mod error;
+mod routes;
+mod venue;
+
+use std::collections::BTreeMap;
+use std::env;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::process;
+use std::sync::Arc;
+use clap::Parser;
+use tokio::net::TcpListener;
+use openlimits::exchange::errors::OpenLimitsError;
+use openlimits::exchange::metrics::{self, PrometheusRecorder};
+use openlimits::exchange::shared::Result;
+use crate::config::GatewayConfig;
+use crate::error::error_message;
+use crate::routes::Gateway;
+use crate::venue::Venue;
+
+/// The environment variable of the bearer token the account and order routes require, which
+/// hosting credentials requires.
+const TOKEN_VARIABLE: &str = "OPENLIMITS_GATEWAY_TOKEN";
+
+#[derive(Parser, Debug)]
+#[command(name = "openlimits-gateway", version, about = "Hosts the exchanges of its config behind a local REST and websocket API")]
+struct Args {
+    /// The gateway config
+    #[arg(short, long, value_name = "FILE", default_value = "gateway.toml")]
+    config: PathBuf,
+    /// Overrides the address of the config to listen on
+    #[arg(long, value_name = "ADDRESS")]
+    listen: Option<SocketAddr>,
+}
+
+async fn run(args: Args) -> Result<()> {
+    let config = GatewayConfig::from_path(&args.config)?;
+    let token = env::var(TOKEN_VARIABLE).ok().filter(|token| !token.is_empty());
+    let provider = config.provider()?;
+    let mut profiles = BTreeMap::new();
+    for (name, venue) in &config.exchanges {
+        profiles.insert(name, venue.profile(name, &provider)?);
+    }
+    // Any process reaching the gateway, even on loopback, could trade with its credentials.
+    if token.is_none() && profiles.values().any(Option::is_some) {
+        return Err(OpenLimitsError::InvalidParameter(format!(
+            "Hosting credentials requires a bearer token in ${}",
+            TOKEN_VARIABLE
+        )));
+    }
+    let recorder = Arc::new(PrometheusRecorder::default());
+    metrics::set_recorder(recorder.clone());
+
+    let mut venues = BTreeMap::new();
+    for (name, venue) in &config.exchanges {
+        let init = venue.exchange.init_with(profiles[name].as_ref())?;
+        let venue = Venue::new(venue.exchange.exchange, init, venue.weight_per_minute).await?;
+        venues.insert(name.clone(), Arc::new(venue));
+    }
+    let gateway = Gateway {
+        venues: Arc::new(venues),
+        recorder,
+        token: token.map(Arc::from),
+    };
+
+    let listener = TcpListener::bind(args.listen.unwrap_or(config.listen)).await?;
+    println!("Listening on {}", listener.local_addr()?);
+    axum::serve(listener, routes::router(gateway))
+        .with_graceful_shutdown(async {
+            tokio::signal::ctrl_c().await.ok();
+        })
+        .await?;
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() {
+    if let Err(error) = run(Args::parse()).await {
+        eprintln!("error: {}", error_message(&error));
+        process::exit(1);
+    }
+}
@@ -0,0 +1,334 @@
+//! The REST and websocket API of the gateway. The exchanges are addressed by the name of their
+//! table in the config, such as `GET /binance/book?market=BTC-USDT`, and the requests and
+//! responses are the JSON of the `openlimits::model` types. When the gateway has a token, the
+//! account and order routes require it as `Authorization: Bearer <token>`. Each REST route spends
+//! its weight from the budget of its exchange before calling it.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::extract::{Path, Query, Request, State};
+use axum::http::{header, StatusCode};
+use axum::middleware::{self, Next};
+use axum::response::Response;
+use axum::routing::{delete, get};
+use axum::{Json, Router};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use tokio::sync::{broadcast, mpsc};
+use tokio::task::JoinHandle;
+use openlimits::exchange::config::ExchangeKind;
+use openlimits::exchange::metrics::PrometheusRecorder;
+use openlimits::exchange::traits::info::{ExchangeInfoRetrieval, MarketPairInfo};
+use openlimits::exchange::traits::{ExchangeAccount, ExchangeMarketData};
+use openlimits::model::market_pair::MarketPair;
+use openlimits::model::websocket::Subscription;
+use openlimits::model::{
+    Balance, CancelAllOrdersRequest, CancelOrderRequest, GetPriceTickerRequest, OpenLimitOrderRequest,
+    OpenMarketOrderRequest, Order, OrderBookRequest, OrderBookResponse, OrderCanceled, Side, Ticker,
+    TimeInForce,
+};
+use crate::error::GatewayError;
+use crate::venue::Venue;
+
+type Result<T> = std::result::Result<Json<T>, GatewayError>;
+
+/// The messages of a websocket client waiting to be written.
+const CLIENT_CAPACITY: usize = 1024;
+
+/// The shared state of the handlers.
+#[derive(Clone)]
+pub struct Gateway {
+    pub venues: Arc<BTreeMap<String, Arc<Venue>>>,
+    pub recorder: Arc<PrometheusRecorder>,
+    /// The bearer token of the account and order routes, which are open without one since the
+    /// gateway then hosts no credentials.
+    pub token: Option<Arc<str>>,
+}
+
+impl Gateway {
+    fn venue(&self, name: &str) -> std::result::Result<Arc<Venue>, GatewayError> {
+        self.venues
+            .get(name)
+            .cloned()
+            .ok_or_else(|| GatewayError::new(StatusCode::NOT_FOUND, format!("unknown exchange {}", name)))
+    }
+
+    /// The venue of a REST call, once the weight of the call is spent from its budget.
+    fn call(&self, name: &str, weight: u32) -> std::result::Result<Arc<Venue>, GatewayError> {
+        let venue = self.venue(name)?;
+        venue.spend(weight)?;
+        Ok(venue)
+    }
+}
+
+/// The weights of the REST routes, those of the Binance endpoints they call.
+mod weight {
+    pub const PAIRS: u32 = 10;
+    pub const BOOK: u32 = 5;
+    pub const TICKER: u32 = 2;
+    pub const BALANCES: u32 = 10;
+    pub const OPEN_ORDERS: u32 = 40;
+    pub const ORDER: u32 = 1;
+}
+
+pub fn router(gateway: Gateway) -> Router {
+    let account = Router::new()
+        .route("/{exchange}/balances", get(balances))
+        .route("/{exchange}/orders", get(open_orders).post(place_order).delete(cancel_all_orders))
+        .route("/{exchange}/orders/{id}", delete(cancel_order))
+        .route_layer(middleware::from_fn_with_state(gateway.clone(), authorize));
+    Router::new()
+        .route("/exchanges", get(exchanges))
+        .route("/metrics", get(metrics))
+        .route("/{exchange}/pairs", get(pairs))
+        .route("/{exchange}/book", get(book))
+        .route("/{exchange}/ticker", get(ticker))
+        .route("/{exchange}/ws", get(stream))
+        .merge(account)
+        .with_state(gateway)
+}
+
+/// Refuses a request without the bearer token of the gateway, if it has one.
+async fn authorize(
+    State(gateway): State<Gateway>,
+    request: Request,
+    next: Next,
+) -> std::result::Result<Response, GatewayError> {
+    if let Some(token) = &gateway.token {
+        let bearer = request
+            .headers()
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "));
+        if !bearer.is_some_and(|bearer| same_token(bearer, token)) {
+            return Err(GatewayError::new(StatusCode::UNAUTHORIZED, "missing or invalid bearer token"));
+        }
+    }
+    Ok(next.run(request).await)
+}
+
+/// Compares the tokens in a time that doesn't depend on where they differ.
+fn same_token(given: &str, token: &str) -> bool {
+    given.len() == token.len()
+        && given.bytes().zip(token.bytes()).fold(0, |difference, (a, b)| difference | (a ^ b)) == 0
+}
+
+#[derive(Serialize)]
+struct ExchangeEntry {
+    name: String,
+    exchange: ExchangeKind,
+}
+
+/// The market of a request, written as `BTC-USDT`, `btc_usdt` or `BTC/USDT`.
+#[derive(Deserialize)]
+struct MarketQuery {
+    market: Option<String>,
+}
+
+impl MarketQuery {
+    fn optional(&self) -> std::result::Result<Option<MarketPair>, GatewayError> {
+        Ok(self.market.as_deref().map(str::parse).transpose()?)
+    }
+
+    fn required(&self) -> std::result::Result<MarketPair, GatewayError> {
+        self.optional()?
+            .ok_or_else(|| GatewayError::new(StatusCode::BAD_REQUEST, "missing market"))
+    }
+}
+
+/// An order to place, a limit order if it has a price and a market order otherwise.
+#[derive(Deserialize)]
+struct PlaceOrder {
+    side: Side,
+    market_pair: MarketPair,
+    size: Decimal,
+    #[serde(default)]
+    price: Option<Decimal>,
+    #[serde(default)]
+    time_in_force: TimeInForce,
+    #[serde(default)]
+    post_only: bool,
+    #[serde(default)]
+    client_order_id: Option<String>,
+}
+
+async fn exchanges(State(gateway): State<Gateway>) -> Json<Vec<ExchangeEntry>> {
+    let exchanges = gateway
+        .venues
+        .iter()
+        .map(|(name, venue)| ExchangeEntry { name: name.clone(), exchange: venue.kind })
+        .collect();
+    Json(exchanges)
+}
+
+async fn metrics(State(gateway): State<Gateway>) -> String {
+    gateway.recorder.render()
+}
+
+async fn pairs(State(gateway): State<Gateway>, Path(name): Path<String>) -> Result<Vec<MarketPairInfo>> {
+    Ok(Json(gateway.call(&name, weight::PAIRS)?.exchange.retrieve_pairs().await?))
+}
+
+async fn book(
+    State(gateway): State<Gateway>,
+    Path(name): Path<String>,
+    Query(query): Query<MarketQuery>,
+) -> Result<OrderBookResponse> {
+    let request = OrderBookRequest { market_pair: query.required()? };
+    Ok(Json(gateway.call(&name, weight::BOOK)?.exchange.order_book(&request).await?))
+}
+
+async fn ticker(
+    State(gateway): State<Gateway>,
+    Path(name): Path<String>,
+    Query(query): Query<MarketQuery>,
+) -> Result<Ticker> {
+    let request = GetPriceTickerRequest { market_pair: query.required()? };
+    Ok(Json(gateway.call(&name, weight::TICKER)?.exchange.get_price_ticker(&request).await?))
+}
+
+async fn balances(State(gateway): State<Gateway>, Path(name): Path<String>) -> Result<Vec<Balance>> {
+    Ok(Json(gateway.call(&name, weight::BALANCES)?.exchange.get_account_balances(None).await?))
+}
+
+async fn open_orders(
+    State(gateway): State<Gateway>,
+    Path(name): Path<String>,
+    Query(query): Query<MarketQuery>,
+) -> Result<Vec<Order>> {
+    let market = query.optional()?;
+    let mut orders = gateway.call(&name, weight::OPEN_ORDERS)?.exchange.get_all_open_orders().await?;
+    if let Some(market) = market {
+        orders.retain(|order| order.market_pair == market);
+    }
+    Ok(Json(orders))
+}
+
+async fn place_order(
+    State(gateway): State<Gateway>,
+    Path(name): Path<String>,
+    Json(order): Json<PlaceOrder>,
+) -> Result<Order> {
+    let exchange = &gateway.call(&name, weight::ORDER)?.exchange;
+    let placed = match order.price {
+        Some(price) => {
+            let request = OpenLimitOrderRequest {
+                client_order_id: order.client_order_id,
+                market_pair: order.market_pair,
+                size: order.size,
+                price,
+                time_in_force: order.time_in_force,
+                post_only: order.post_only,
+            };
+            match order.side {
+                Side::Buy => exchange.limit_buy(&request).await?,
+                Side::Sell => exchange.limit_sell(&request).await?,
+            }
+        }
+        None => {
+            let request = OpenMarketOrderRequest {
+                client_order_id: order.client_order_id,
+                market_pair: order.market_pair,
+                size: order.size,
+            };
+            match order.side {
+                Side::Buy => exchange.market_buy(&request).await?,
+                Side::Sell => exchange.market_sell(&request).await?,
+            }
+        }
+    };
+    Ok(Json(placed))
+}
+
+async fn cancel_order(
+    State(gateway): State<Gateway>,
+    Path((name, id)): Path<(String, String)>,
+    Query(query): Query<MarketQuery>,
+) -> Result<OrderCanceled> {
+    let request = CancelOrderRequest { id, market_pair: query.optional()? };
+    Ok(Json(gateway.call(&name, weight::ORDER)?.exchange.cancel_order(&request).await?))
+}
+
+async fn cancel_all_orders(
+    State(gateway): State<Gateway>,
+    Path(name): Path<String>,
+    Query(query): Query<MarketQuery>,
+) -> Result<Vec<OrderCanceled>> {
+    let request = CancelAllOrdersRequest { market_pair: query.optional()? };
+    Ok(Json(gateway.call(&name, weight::ORDER)?.exchange.cancel_all_orders(&request).await?))
+}
+
+/// Streams the subscriptions a client sends as JSON arrays, such as
+/// `[{"Trades": ["BTC", "USDT"]}]`, as the JSON of their `OpenLimitsWebSocketMessage`s.
+async fn stream(
+    State(gateway): State<Gateway>,
+    Path(name): Path<String>,
+    upgrade: WebSocketUpgrade,
+) -> std::result::Result<Response, GatewayError> {
+    let venue = gateway.venue(&name)?;
+    Ok(upgrade.on_upgrade(move |socket| serve_stream(venue, socket)))
+}
+
+async fn serve_stream(venue: Arc<Venue>, mut socket: WebSocket) {
+    let (sender, mut receiver) = mpsc::channel::<Arc<str>>(CLIENT_CAPACITY);
+    let mut forwarders: HashMap<Subscription, JoinHandle<()>> = HashMap::new();
+    loop {
+        let text = tokio::select! {
+            frame = socket.recv() => match frame {
+                Some(Ok(Message::Text(text))) => text,
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                Some(Ok(_)) => continue,
+            },
+            Some(message) = receiver.recv() => {
+                if socket.send(Message::Text(message.as_ref().into())).await.is_err() {
+                    break;
+                }
+                continue;
+            }
+        };
+        let mut errors = Vec::new();
+        match serde_json::from_str::<Vec<Subscription>>(&text) {
+            Ok(subscriptions) => {
+                for subscription in subscriptions {
+                    // A subscription is only streamed once to a client, unless its stream ended.
+                    if forwarders.get(&subscription).is_some_and(|forwarder| !forwarder.is_finished()) {
+                        continue;
+                    }
+                    match venue.subscribe(subscription.clone()).await {
+                        Ok(messages) => {
+                            forwarders.insert(subscription, tokio::spawn(forward(messages, sender.clone())));
+                        }
+                        Err(error) => errors.push(GatewayError::from(error).message),
+                    }
+                }
+            }
+            Err(error) => errors.push(error.to_string()),
+        }
+        for error in errors {
+            let error = json!({ "error": error }).to_string();
+            if socket.send(Message::Text(error.into())).await.is_err() {
+                break;
+            }
+        }
+    }
+    for forwarder in forwarders.values() {
+        forwarder.abort();
+    }
+}
+
+/// Forwards the messages of a subscription to a client, skipping those it was too slow for.
+async fn forward(mut messages: broadcast::Receiver<Arc<str>>, client: mpsc::Sender<Arc<str>>) {
+    loop {
+        match messages.recv().await {
+            Ok(message) => {
+                if client.send(message).await.is_err() {
+                    return;
+                }
+            }
+            Err(broadcast::error::RecvError::Lagged(_)) => continue,
+            Err(broadcast::error::RecvError::Closed) => return,
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Instant;
+use axum::http::StatusCode;
+use futures::StreamExt;
+use serde_json::json;
+use tokio::sync::{broadcast, Mutex};
+use openlimits::exchange::any::{AnyExchange, AnyWebsocket, InitAnyExchange};
+use openlimits::exchange::config::ExchangeKind;
+use openlimits::exchange::errors::OpenLimitsError;
+use openlimits::exchange::shared::Result;
+use openlimits::exchange::traits::Exchange;
+use openlimits::model::websocket::Subscription;
+use crate::error::GatewayError;
+
+/// The messages of a subscription buffered for a slow client before it starts skipping some.
+const STREAM_CAPACITY: usize = 1024;
+
+/// An exchange hosted by the gateway. Its client, and so its rate limits, are shared by every
+/// request, and each subscription is streamed from the exchange once, whatever the number of
+/// clients it is fanned out to.
+pub struct Venue {
+    pub kind: ExchangeKind,
+    pub exchange: AnyExchange,
+    init: InitAnyExchange,
+    budget: WeightBudget,
+    websocket: Mutex<Option<AnyWebsocket>>,
+    streams: Mutex<HashMap<Subscription, broadcast::Sender<Arc<str>>>>,
+}
+
+impl Venue {
+    pub async fn new(kind: ExchangeKind, init: InitAnyExchange, weight_per_minute: u32) -> Result<Self> {
+        Ok(Self {
+            kind,
+            exchange: AnyExchange::new(init.clone()).await?,
+            init,
+            budget: WeightBudget::new(weight_per_minute),
+            websocket: Mutex::new(None),
+            streams: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Spends the weight of a REST call from the budget of the exchange, refusing the call with
+    /// `429 Too Many Requests` while the budget is exhausted.
+    pub fn spend(&self, weight: u32) -> std::result::Result<(), GatewayError> {
+        if self.budget.spend(weight) {
+            Ok(())
+        } else {
+            Err(GatewayError::new(StatusCode::TOO_MANY_REQUESTS, "the request weight budget of the exchange is exhausted"))
+        }
+    }
+
+    /// Receives the messages of a subscription as JSON, streaming it from the exchange unless
+    /// another client already does. An error of the stream is sent as `{"error": ...}`, after
+    /// which it is closed, and the exchange is unsubscribed from once the last client left.
+    pub async fn subscribe(self: &Arc<Self>, subscription: Subscription) -> Result<broadcast::Receiver<Arc<str>>> {
+        let mut streams = self.streams.lock().await;
+        if let Some(sender) = streams.get(&subscription) {
+            return Ok(sender.subscribe());
+        }
+        let mut websocket = self.websocket.lock().await;
+        let websocket = match websocket.as_mut() {
+            Some(websocket) => websocket,
+            None => websocket.insert(AnyWebsocket::new(self.init.clone()).await?),
+        };
+        let mut messages = websocket.create_stream(std::slice::from_ref(&subscription)).await?;
+        let (sender, receiver) = broadcast::channel(STREAM_CAPACITY);
+        streams.insert(subscription.clone(), sender.clone());
+
+        let venue = self.clone();
+        tokio::spawn(async move {
+            loop {
+                let (message, last) = tokio::select! {
+                    message = messages.next() => match message {
+                        Some(Ok(message)) => match serde_json::to_string(&message) {
+                            Ok(message) => (Some(message), false),
+                            Err(error) => (Some(error_frame(&error.into())), true),
+                        },
+                        Some(Err(error)) => (Some(error_frame(&error)), true),
+                        None => (None, true),
+                    },
+                    _ = sender.closed() => (None, false),
+                };
+                // The lock keeps clients from subscribing to a stream that is being closed.
+                let mut streams = venue.streams.lock().await;
+                let delivered = message.is_some_and(|message| sender.send(Arc::from(message)).is_ok());
+                // Dropping the messages once no client is left unsubscribes from the exchange.
+                if last || (!delivered && sender.receiver_count() == 0) {
+                    streams.remove(&subscription);
+                    break;
+                }
+            }
+        });
+        Ok(receiver)
+    }
+}
+
+/// A budget of request weight, refilled continuously up to the weight of one minute.
+struct WeightBudget {
+    per_minute: f64,
+    /// The weight left, as of the instant it was refilled.
+    left: std::sync::Mutex<(f64, Instant)>,
+}
+
+impl WeightBudget {
+    fn new(per_minute: u32) -> Self {
+        let per_minute = f64::from(per_minute);
+        Self {
+            per_minute,
+            left: std::sync::Mutex::new((per_minute, Instant::now())),
+        }
+    }
+
+    /// Spends the weight if the budget has it left.
+    fn spend(&self, weight: u32) -> bool {
+        let mut left = self.left.lock().unwrap();
+        let (weight_left, refilled) = &mut *left;
+        let now = Instant::now();
+        let refill = now.duration_since(*refilled).as_secs_f64() * self.per_minute / 60.0;
+        *weight_left = (*weight_left + refill).min(self.per_minute);
+        *refilled = now;
+        if *weight_left < f64::from(weight) {
+            return false;
+        }
+        *weight_left -= f64::from(weight);
+        true
+    }
+}
+
+/// The last message of a stream that failed.
+fn error_frame(error: &OpenLimitsError) -> String {
+    json!({ "error": crate::error::error_message(error) }).to_string()
+}
@@ -0,0 +1,295 @@
+//! Runs the `openlimits-gateway` binary against a local fake of Binance, without a network.
+
+use std::io::{BufRead, BufReader as LineReader};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use futures::{SinkExt, StreamExt};
+use serde_json::{json, Value};
+use tokio_tungstenite::tungstenite::Message;
+use openlimits_test_support::FakeBinance;
+
+/// The bearer token of the gateways hosting credentials.
+const TOKEN: &str = "s3cr3t";
+
+/// The settings of the `binance` exchange hosting the credentials of the config.
+const CREDENTIALS: &str = "profile = \"desk\"\n";
+
+/// A gateway hosting the fake as `binance`, stopped when dropped.
+struct Gateway {
+    child: Child,
+    url: String,
+    directory: PathBuf,
+}
+
+impl Gateway {
+    fn start(fake: &FakeBinance) -> Self {
+        Self::start_with(fake, CREDENTIALS, Some(TOKEN))
+    }
+
+    /// Starts the gateway with the `settings` of `binance` and `token` in
+    /// `$OPENLIMITS_GATEWAY_TOKEN`.
+    fn start_with(fake: &FakeBinance, settings: &str, token: Option<&str>) -> Self {
+        let directory = configure(fake, settings);
+        let mut command = command(&directory);
+        if let Some(token) = token {
+            command.env("OPENLIMITS_GATEWAY_TOKEN", token);
+        }
+        let mut child = command.stdout(Stdio::piped()).spawn().expect("Couldn't run the gateway");
+        let mut line = String::new();
+        LineReader::new(child.stdout.take().unwrap()).read_line(&mut line).expect("Couldn't read the address");
+        let address = line.trim().strip_prefix("Listening on ").expect("The gateway didn't start");
+        Self {
+            child,
+            url: format!("http://{}", address),
+            directory,
+        }
+    }
+}
+
+/// Writes a config hosting the fake as `binance`, with the `settings` of its table, and the
+/// credentials of the config to a new directory.
+fn configure(fake: &FakeBinance, settings: &str) -> PathBuf {
+    let directory = std::env::temp_dir().join(format!("openlimits-gateway-{}-{}", std::process::id(), next_id()));
+    std::fs::create_dir_all(&directory).unwrap();
+    let credentials = directory.join("credentials.toml");
+    std::fs::write(&credentials, "[desk]\napi_key = \"key\"\napi_secret = \"secret\"\n").unwrap();
+    let document = format!(
+        "listen = \"127.0.0.1:0\"\ncredentials = {:?}\n\n[exchanges.binance]\nexchange = \"binance\"\nsandbox = true\nrest_endpoint = {:?}\nwebsocket_endpoint = {:?}\n{}",
+        credentials.to_str().unwrap(),
+        fake.rest,
+        fake.websocket,
+        settings,
+    );
+    std::fs::write(directory.join("gateway.toml"), document).unwrap();
+    directory
+}
+
+/// Runs the gateway on the config of the directory, without a token.
+fn command(directory: &Path) -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_openlimits-gateway"));
+    command
+        .arg("--config")
+        .arg(directory.join("gateway.toml"))
+        .env_remove("OPENLIMITS_GATEWAY_TOKEN");
+    command
+}
+
+impl Drop for Gateway {
+    fn drop(&mut self) {
+        self.child.kill().ok();
+        self.child.wait().ok();
+        std::fs::remove_dir_all(&self.directory).ok();
+    }
+}
+
+/// Tells apart the gateways of the tests of one process.
+fn next_id() -> usize {
+    static NEXT: AtomicUsize = AtomicUsize::new(0);
+    NEXT.fetch_add(1, Ordering::SeqCst)
+}
+
+/// Gets the url with the bearer token.
+async fn get(url: String) -> (u16, Value) {
+    let response = reqwest::Client::new()
+        .get(&url)
+        .bearer_auth(TOKEN)
+        .send()
+        .await
+        .expect("Couldn't send the request");
+    let status = response.status().as_u16();
+    (status, response.json().await.expect("The response isn't JSON"))
+}
+
+#[tokio::test]
+async fn rest() {
+    let fake = FakeBinance::start();
+    let gateway = Gateway::start(&fake);
+    let url = &gateway.url;
+
+    let (status, exchanges) = get(format!("{}/exchanges", url)).await;
+    assert_eq!(status, 200);
+    assert_eq!(exchanges, json!([{"name": "binance", "exchange": "binance"}]));
+
+    let (status, book) = get(format!("{}/binance/book?market=BTC-USDT", url)).await;
+    assert_eq!(status, 200);
+    assert_eq!(book["bids"][0]["price"], "50000.00");
+
+    let (status, ticker) = get(format!("{}/binance/ticker?market=btc_usdt", url)).await;
+    assert_eq!(status, 200);
+    assert_eq!(ticker["price"], "50000.50");
+
+    let (status, balances) = get(format!("{}/binance/balances", url)).await;
+    assert_eq!(status, 200);
+    assert_eq!(balances, json!([{"asset": "BTC", "total": "2.0", "free": "1.5"}]));
+
+    let (status, orders) = get(format!("{}/binance/orders?market=BTC-USDT", url)).await;
+    assert_eq!(status, 200);
+    assert_eq!(orders[0]["id"], "42");
+
+    let client = reqwest::Client::new();
+    let order = json!({"side": "BUY", "market_pair": ["BTC", "USDT"], "size": "0.1", "price": "50000"});
+    let response = client
+        .post(format!("{}/binance/orders", url))
+        .bearer_auth(TOKEN)
+        .json(&order)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status().as_u16(), 200);
+    let order: Value = response.json().await.unwrap();
+    assert_eq!(order["order_type"], "LIMIT");
+
+    let response = client
+        .delete(format!("{}/binance/orders/42?market=BTC-USDT", url))
+        .bearer_auth(TOKEN)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status().as_u16(), 200);
+    assert_eq!(response.json::<Value>().await.unwrap(), json!({"id": "42"}));
+
+    let metrics = reqwest::get(format!("{}/metrics", url)).await.unwrap().text().await.unwrap();
+    assert!(metrics.contains("openlimits_http_requests_total"), "{}", metrics);
+    assert!(fake.served("POST /api/v3/order"));
+    assert!(fake.served("DELETE /api/v3/order"));
+}
+
+#[tokio::test]
+async fn errors() {
+    let fake = FakeBinance::start();
+    let gateway = Gateway::start(&fake);
+
+    let (status, error) = get(format!("{}/kraken/book?market=BTC-USDT", gateway.url)).await;
+    assert_eq!(status, 404);
+    assert_eq!(error["error"], "unknown exchange kraken");
+
+    let (status, error) = get(format!("{}/binance/book?market=BTCUSDT", gateway.url)).await;
+    assert_eq!(status, 400);
+    assert!(error["error"].as_str().unwrap().contains("not a valid market pair"));
+
+    let (status, _) = get(format!("{}/binance/ticker", gateway.url)).await;
+    assert_eq!(status, 400);
+}
+
+async fn next_trade<S>(websocket: &mut S) -> Value
+where
+    S: futures::Stream<Item = Result<Message, tokio_tungstenite::tungstenite::Error>> + Unpin,
+{
+    let message = tokio::time::timeout(Duration::from_secs(10), websocket.next())
+        .await
+        .expect("No message was received")
+        .expect("The stream ended")
+        .expect("Unreadable message");
+    serde_json::from_str(message.to_text().unwrap()).expect("The message isn't JSON")
+}
+
+#[tokio::test]
+async fn shared_stream() {
+    let fake = FakeBinance::start();
+    let gateway = Gateway::start(&fake);
+    let url = gateway.url.replace("http://", "ws://") + "/binance/ws";
+    let subscription = Message::text(r#"[{"Trades": ["BTC", "USDT"]}]"#);
+
+    let (mut first, _) = tokio_tungstenite::connect_async(url.as_str()).await.expect("Couldn't connect");
+    first.send(subscription.clone()).await.unwrap();
+    assert_eq!(next_trade(&mut first).await["Trades"][0]["id"], "12345");
+
+    let (mut second, _) = tokio_tungstenite::connect_async(url.as_str()).await.expect("Couldn't connect");
+    second.send(subscription).await.unwrap();
+    assert_eq!(next_trade(&mut second).await["Trades"][0]["qty"], "0.25");
+    assert_eq!(fake.connections(), 1);
+
+    second.send(Message::text("not json")).await.unwrap();
+    loop {
+        let message = next_trade(&mut second).await;
+        if let Some(error) = message.get("error") {
+            assert!(error.is_string());
+            break;
+        }
+    }
+}
+
+#[tokio::test]
+async fn unsubscribe() {
+    let fake = FakeBinance::start();
+    let gateway = Gateway::start(&fake);
+    let url = gateway.url.replace("http://", "ws://") + "/binance/ws";
+
+    let (mut client, _) = tokio_tungstenite::connect_async(url.as_str()).await.expect("Couldn't connect");
+    client.send(Message::text(r#"[{"Trades": ["BTC", "USDT"]}]"#)).await.unwrap();
+    next_trade(&mut client).await;
+    assert!(!fake.served("UNSUBSCRIBE btcusdt@trade"));
+
+    client.close(None).await.unwrap();
+    for _ in 0..100 {
+        if fake.served("UNSUBSCRIBE btcusdt@trade") {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    panic!("The exchange wasn't unsubscribed from");
+}
+
+#[tokio::test]
+async fn rate_limit() {
+    let fake = FakeBinance::start();
+    let gateway = Gateway::start_with(&fake, "profile = \"desk\"\nweight_per_minute = 12\n", Some(TOKEN));
+    let book = format!("{}/binance/book?market=BTC-USDT", gateway.url);
+
+    assert_eq!(get(book.clone()).await.0, 200);
+    assert_eq!(get(book.clone()).await.0, 200);
+    let (status, error) = get(book).await;
+    assert_eq!(status, 429);
+    assert_eq!(error["error"], "the request weight budget of the exchange is exhausted");
+}
+
+#[tokio::test]
+async fn token() {
+    let fake = FakeBinance::start();
+    let gateway = Gateway::start(&fake);
+    let client = reqwest::Client::new();
+
+    let response = client.get(format!("{}/binance/book?market=BTC-USDT", gateway.url)).send().await.unwrap();
+    assert_eq!(response.status().as_u16(), 200);
+
+    let response = client.get(format!("{}/binance/balances", gateway.url)).send().await.unwrap();
+    assert_eq!(response.status().as_u16(), 401);
+    assert_eq!(response.json::<Value>().await.unwrap()["error"], "missing or invalid bearer token");
+
+    let order = json!({"side": "BUY", "market_pair": ["BTC", "USDT"], "size": "0.1", "price": "50000"});
+    let response = client
+        .post(format!("{}/binance/orders", gateway.url))
+        .bearer_auth("wrong")
+        .json(&order)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status().as_u16(), 401);
+    assert!(!fake.served("POST /api/v3/order"));
+
+    let (status, _) = get(format!("{}/binance/balances", gateway.url)).await;
+    assert_eq!(status, 200);
+}
+
+#[test]
+fn credentials_without_token() {
+    let fake = FakeBinance::start();
+    let directory = configure(&fake, CREDENTIALS);
+    let output = command(&directory).output().expect("Couldn't run the gateway");
+    std::fs::remove_dir_all(&directory).ok();
+
+    assert!(!output.status.success());
+    let errors = String::from_utf8(output.stderr).unwrap();
+    assert!(errors.contains("OPENLIMITS_GATEWAY_TOKEN"), "{}", errors);
+}
+
+#[tokio::test]
+async fn public_without_token() {
+    let fake = FakeBinance::start();
+    let gateway = Gateway::start_with(&fake, "", None);
+
+    let response = reqwest::get(format!("{}/binance/book?market=BTC-USDT", gateway.url)).await.unwrap();
+    assert_eq!(response.status().as_u16(), 200);
+}
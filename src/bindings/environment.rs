@@ -1,5 +0,0 @@
-#[repr(u32)]
-pub enum Environment {
-    Sandbox,
-    Production
-}
@@ -1,4 +0,0 @@
-// pub enum FFIOption<T> {
-//     Some(T),
-//     None
-// }
@@ -0,0 +1,266 @@
+//! This module provides `AnyExchange` and `AnyWebsocket`, which dispatch to an exchange picked at
+//! runtime, so that the language bindings and tools can work with every exchange through one type.
+
+use async_trait::async_trait;
+use futures::stream::{BoxStream, StreamExt};
+use openlimits_exchange::errors::OpenLimitsError;
+use openlimits_exchange::message::{self, subscription::Publication};
+use openlimits_exchange::model::websocket::{OpenLimitsWebSocketMessage, Subscription};
+use crate::exchange::binance::{Binance, BinanceParameters, BinanceWebsocket};
+use crate::exchange::coinbase::{Coinbase, CoinbaseParameters, CoinbaseWebsocket};
+use crate::exchange::huobi::{Huobi, HuobiParameters, HuobiWebsocket};
+use crate::exchange::messaging::{self, Subscriber};
+use crate::exchange::nash::{Nash, NashParameters, NashWebsocket};
+use crate::exchange::shared::Result;
+use crate::exchange::traits::info::{ExchangeInfoRetrieval, MarketPairHandle, MarketPairInfo};
+use crate::exchange::traits::stream::ExchangeStream;
+use crate::exchange::traits::{Exchange, ExchangeAccount, ExchangeMarketData};
+use crate::model::market_pair::MarketPair;
+use crate::model::{
+    Balance, CancelAllOrdersRequest, CancelOrderRequest, Candle, Capabilities,
+    GetHistoricRatesRequest, GetHistoricTradesRequest, GetLedgerRequest, GetOrderHistoryRequest,
+    GetOrderRequest, GetPriceTickerRequest, LedgerEntry, OpenLimitOrderRequest,
+    OpenMarketOrderRequest, Order, OrderBookRequest, OrderBookResponse, OrderCanceled, Paginator,
+    Ticker, Trade, TradeHistoryRequest,
+};
+
+/// The parameters of the exchange `AnyExchange` and `AnyWebsocket` are created for.
+#[derive(Clone)]
+pub enum InitAnyExchange {
+    Binance(BinanceParameters),
+    Coinbase(CoinbaseParameters),
+    Huobi(HuobiParameters),
+    Nash(NashParameters),
+}
+
+/// An exchange client of any of the supported exchanges.
+pub enum AnyExchange {
+    Binance(Binance),
+    Coinbase(Coinbase),
+    Huobi(Huobi),
+    Nash(Nash),
+}
+
+macro_rules! dispatch {
+    ($any:expr, $exchange:ident => $call:expr) => {
+        match $any {
+            AnyExchange::Binance($exchange) => $call,
+            AnyExchange::Coinbase($exchange) => $call,
+            AnyExchange::Huobi($exchange) => $call,
+            AnyExchange::Nash($exchange) => $call,
+        }
+    };
+}
+
+impl From<Binance> for AnyExchange {
+    fn from(exchange: Binance) -> Self {
+        Self::Binance(exchange)
+    }
+}
+
+impl From<Coinbase> for AnyExchange {
+    fn from(exchange: Coinbase) -> Self {
+        Self::Coinbase(exchange)
+    }
+}
+
+impl From<Huobi> for AnyExchange {
+    fn from(exchange: Huobi) -> Self {
+        Self::Huobi(exchange)
+    }
+}
+
+impl From<Nash> for AnyExchange {
+    fn from(exchange: Nash) -> Self {
+        Self::Nash(exchange)
+    }
+}
+
+#[async_trait]
+impl Exchange for AnyExchange {
+    type InitParams = InitAnyExchange;
+    type InnerClient = ();
+
+    async fn new(params: InitAnyExchange) -> Result<Self> {
+        Ok(match params {
+            InitAnyExchange::Binance(params) => Binance::new(params).await?.into(),
+            InitAnyExchange::Coinbase(params) => Coinbase::new(params).await?.into(),
+            InitAnyExchange::Huobi(params) => Huobi::new(params).await?.into(),
+            InitAnyExchange::Nash(params) => Nash::new(params).await?.into(),
+        })
+    }
+
+    fn inner_client(&self) -> Option<&Self::InnerClient> {
+        None
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        dispatch!(self, exchange => exchange.capabilities())
+    }
+}
+
+#[async_trait]
+impl ExchangeInfoRetrieval for AnyExchange {
+    async fn get_pair(&self, market_pair: &MarketPair) -> Result<MarketPairHandle> {
+        dispatch!(self, exchange => exchange.get_pair(market_pair).await)
+    }
+
+    async fn retrieve_pairs(&self) -> Result<Vec<MarketPairInfo>> {
+        dispatch!(self, exchange => exchange.retrieve_pairs().await)
+    }
+
+    async fn refresh_market_info(&self) -> Result<Vec<MarketPairHandle>> {
+        dispatch!(self, exchange => exchange.refresh_market_info().await)
+    }
+}
+
+#[async_trait]
+impl ExchangeMarketData for AnyExchange {
+    async fn order_book(&self, req: &OrderBookRequest) -> Result<OrderBookResponse> {
+        dispatch!(self, exchange => exchange.order_book(req).await)
+    }
+
+    async fn get_price_ticker(&self, req: &GetPriceTickerRequest) -> Result<Ticker> {
+        dispatch!(self, exchange => exchange.get_price_ticker(req).await)
+    }
+
+    async fn get_all_tickers(&self) -> Result<Vec<Ticker>> {
+        dispatch!(self, exchange => exchange.get_all_tickers().await)
+    }
+
+    async fn get_historic_rates(&self, req: &GetHistoricRatesRequest) -> Result<Vec<Candle>> {
+        dispatch!(self, exchange => exchange.get_historic_rates(req).await)
+    }
+
+    async fn get_historic_trades(&self, req: &GetHistoricTradesRequest) -> Result<Vec<Trade>> {
+        dispatch!(self, exchange => exchange.get_historic_trades(req).await)
+    }
+}
+
+#[async_trait]
+impl ExchangeAccount for AnyExchange {
+    async fn limit_buy(&self, req: &OpenLimitOrderRequest) -> Result<Order> {
+        dispatch!(self, exchange => exchange.limit_buy(req).await)
+    }
+
+    async fn limit_sell(&self, req: &OpenLimitOrderRequest) -> Result<Order> {
+        dispatch!(self, exchange => exchange.limit_sell(req).await)
+    }
+
+    async fn market_buy(&self, req: &OpenMarketOrderRequest) -> Result<Order> {
+        dispatch!(self, exchange => exchange.market_buy(req).await)
+    }
+
+    async fn market_sell(&self, req: &OpenMarketOrderRequest) -> Result<Order> {
+        dispatch!(self, exchange => exchange.market_sell(req).await)
+    }
+
+    async fn cancel_order(&self, req: &CancelOrderRequest) -> Result<OrderCanceled> {
+        dispatch!(self, exchange => exchange.cancel_order(req).await)
+    }
+
+    async fn cancel_all_orders(&self, req: &CancelAllOrdersRequest) -> Result<Vec<OrderCanceled>> {
+        dispatch!(self, exchange => exchange.cancel_all_orders(req).await)
+    }
+
+    async fn get_all_open_orders(&self) -> Result<Vec<Order>> {
+        dispatch!(self, exchange => exchange.get_all_open_orders().await)
+    }
+
+    async fn get_order_history(&self, req: &GetOrderHistoryRequest) -> Result<Vec<Order>> {
+        dispatch!(self, exchange => exchange.get_order_history(req).await)
+    }
+
+    async fn get_trade_history(&self, req: &TradeHistoryRequest) -> Result<Vec<Trade>> {
+        dispatch!(self, exchange => exchange.get_trade_history(req).await)
+    }
+
+    async fn get_account_balances(&self, paginator: Option<Paginator>) -> Result<Vec<Balance>> {
+        dispatch!(self, exchange => exchange.get_account_balances(paginator).await)
+    }
+
+    async fn get_order(&self, req: &GetOrderRequest) -> Result<Order> {
+        dispatch!(self, exchange => exchange.get_order(req).await)
+    }
+
+    async fn get_ledger(&self, req: &GetLedgerRequest) -> Result<Vec<LedgerEntry>> {
+        dispatch!(self, exchange => exchange.get_ledger(req).await)
+    }
+}
+
+/// A websocket of any of the exchanges, subscribing through the messaging `Subscriber` of the
+/// exchange.
+pub enum AnyWebsocket {
+    Binance(BinanceWebsocket),
+    Coinbase(CoinbaseWebsocket),
+    Huobi(HuobiWebsocket),
+    Nash(NashWebsocket),
+}
+
+macro_rules! dispatch_websocket {
+    ($any:expr, $websocket:ident => $call:expr) => {
+        match $any {
+            AnyWebsocket::Binance($websocket) => $call,
+            AnyWebsocket::Coinbase($websocket) => $call,
+            AnyWebsocket::Huobi($websocket) => $call,
+            AnyWebsocket::Nash($websocket) => $call,
+        }
+    };
+}
+
+impl AnyWebsocket {
+    pub async fn new(params: InitAnyExchange) -> Result<Self> {
+        Ok(match params {
+            InitAnyExchange::Binance(params) => Self::Binance(BinanceWebsocket::new(params).await?),
+            InitAnyExchange::Coinbase(params) => Self::Coinbase(CoinbaseWebsocket::new(params).await?),
+            InitAnyExchange::Huobi(params) => Self::Huobi(HuobiWebsocket::new(params).await?),
+            InitAnyExchange::Nash(params) => Self::Nash(NashWebsocket::new(params).await?),
+        })
+    }
+
+    /// Streams the generic messages of the subscriptions, merged into one stream.
+    pub async fn create_stream(
+        &mut self,
+        subscriptions: &[Subscription],
+    ) -> Result<BoxStream<'static, Result<OpenLimitsWebSocketMessage>>> {
+        let mut streams = Vec::with_capacity(subscriptions.len());
+        for subscription in subscriptions {
+            streams.push(self.subscribe(&message_subscription(subscription)).await?);
+        }
+        Ok(futures::stream::select_all(streams).filter_map(|publication| async move { generic_message(publication) }).boxed())
+    }
+
+    /// Closes the websocket, which ends every subscription.
+    pub async fn disconnect(&self) {
+        dispatch_websocket!(self, websocket => websocket.disconnect().await)
+    }
+}
+
+#[async_trait]
+impl Subscriber for AnyWebsocket {
+    type SubscriptionRequest = message::subscription::Subscription;
+    type Publication = Result<Publication>;
+    type Error = OpenLimitsError;
+
+    async fn subscribe(&mut self, subscription: &Self::SubscriptionRequest) -> Result<messaging::Subscription<Self::Publication>> {
+        dispatch_websocket!(self, websocket => Subscriber::subscribe(websocket, subscription).await)
+    }
+}
+
+fn message_subscription(subscription: &Subscription) -> message::subscription::Subscription {
+    match subscription {
+        Subscription::OrderBookUpdates(market_pair) => message::subscription::Subscription::Depth(market_pair.clone()),
+        Subscription::Trades(market_pair) => message::subscription::Subscription::Trades(market_pair.clone()),
+    }
+}
+
+/// The generic message of a publication. The subscriptions of `create_stream` publish neither
+/// candles nor tickers.
+fn generic_message(publication: Result<Publication>) -> Option<Result<OpenLimitsWebSocketMessage>> {
+    match publication {
+        Ok(Publication::Depth(order_book)) => Some(Ok(OpenLimitsWebSocketMessage::OrderBook(order_book))),
+        Ok(Publication::Trades(trades)) => Some(Ok(OpenLimitsWebSocketMessage::Trades(trades))),
+        Ok(Publication::Kline(_)) | Ok(Publication::Ticker(_)) => None,
+        Err(error) => Some(Err(error)),
+    }
+}
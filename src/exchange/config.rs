@@ -0,0 +1,200 @@
+//! This module provides `ExchangeConfig`, a serializable description of the exchange to create, so
+//! the language bindings and tools can read it from JSON or TOML and turn it into `InitAnyExchange`.
+
+use std::collections::BTreeMap;
+use std::convert::{TryFrom, TryInto};
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+use serde::{Deserialize, Serialize};
+use openlimits_exchange::credentials::{Profile, REDACTED};
+use openlimits_exchange::errors::OpenLimitsError;
+use openlimits_exchange::exchange::Endpoints;
+use crate::exchange::any::InitAnyExchange;
+use crate::exchange::binance::BinanceParameters;
+use crate::exchange::coinbase::CoinbaseParameters;
+use crate::exchange::huobi::HuobiParameters;
+use crate::exchange::nash::NashParameters;
+use crate::exchange::shared::Result;
+
+/// The supported exchanges.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[serde(rename_all = "lowercase")]
+pub enum ExchangeKind {
+    Binance,
+    Coinbase,
+    Huobi,
+    Nash,
+}
+
+impl ExchangeKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Binance => "binance",
+            Self::Coinbase => "coinbase",
+            Self::Huobi => "huobi",
+            Self::Nash => "nash",
+        }
+    }
+}
+
+impl fmt::Display for ExchangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for ExchangeKind {
+    type Err = OpenLimitsError;
+
+    fn from_str(name: &str) -> Result<Self> {
+        match name.to_lowercase().as_str() {
+            "binance" => Ok(Self::Binance),
+            "coinbase" => Ok(Self::Coinbase),
+            "huobi" => Ok(Self::Huobi),
+            "nash" => Ok(Self::Nash),
+            _ => Err(OpenLimitsError::InvalidParameter(format!("unknown exchange {}", name))),
+        }
+    }
+}
+
+/// The exchange to create, e.g. `{"exchange": "binance", "sandbox": true}`. The credentials are
+/// the fields of a credentials `Profile`, such as `api_key`, `api_secret` and `passphrase`.
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS), ts(optional_fields = nullable))]
+pub struct ExchangeConfig {
+    pub exchange: ExchangeKind,
+    #[serde(default)]
+    pub sandbox: bool,
+    #[serde(default)]
+    pub credentials: Option<BTreeMap<String, String>>,
+    /// Overrides the REST base URL of the environment.
+    #[serde(default)]
+    pub rest_endpoint: Option<String>,
+    /// Overrides the websocket URL of the environment.
+    #[serde(default)]
+    pub websocket_endpoint: Option<String>,
+    /// The client id of a Nash session.
+    #[serde(default)]
+    #[cfg_attr(feature = "typescript", ts(as = "Option<f64>"))]
+    pub client_id: Option<u64>,
+    /// The request timeout of a Nash session, in milliseconds.
+    #[serde(default)]
+    #[cfg_attr(feature = "typescript", ts(as = "Option<f64>"))]
+    pub timeout_ms: Option<u64>,
+    /// The Nash affiliate code credited with the orders.
+    #[serde(default)]
+    pub affiliate_code: Option<String>,
+}
+
+impl ExchangeConfig {
+    pub fn new(exchange: ExchangeKind) -> Self {
+        Self {
+            exchange,
+            sandbox: false,
+            credentials: None,
+            rest_endpoint: None,
+            websocket_endpoint: None,
+            client_id: None,
+            timeout_ms: None,
+            affiliate_code: None,
+        }
+    }
+
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// The credentials as a profile named after the exchange, if any were given.
+    pub fn profile(&self) -> Option<Profile> {
+        self.credentials.as_ref().map(|credentials| {
+            credentials.iter().fold(Profile::new(self.exchange.name()), |profile, (field, value)| {
+                profile.with(field.as_str(), value.as_str())
+            })
+        })
+    }
+
+    /// Creates the parameters of the exchange with the credentials of `profile`, such as one of a
+    /// `CredentialProvider`, in place of those of the config.
+    pub fn init_with(&self, profile: Option<&Profile>) -> Result<InitAnyExchange> {
+        let endpoints = self.endpoints();
+        if self.exchange != ExchangeKind::Nash && self.has_nash_options() {
+            return Err(OpenLimitsError::InvalidParameter(format!(
+                "client_id, timeout_ms and affiliate_code only apply to nash, not {}",
+                self.exchange
+            )));
+        }
+        Ok(match self.exchange {
+            ExchangeKind::Binance => {
+                let mut parameters = if self.sandbox { BinanceParameters::sandbox() } else { BinanceParameters::production() };
+                parameters.credentials = profile.map(TryInto::try_into).transpose()?;
+                parameters.endpoints = endpoints;
+                InitAnyExchange::Binance(parameters)
+            }
+            ExchangeKind::Coinbase => {
+                let mut parameters = if self.sandbox { CoinbaseParameters::sandbox() } else { CoinbaseParameters::production() };
+                parameters.credentials = profile.map(TryInto::try_into).transpose()?;
+                parameters.endpoints = endpoints;
+                InitAnyExchange::Coinbase(parameters)
+            }
+            ExchangeKind::Huobi => {
+                if self.sandbox || endpoints != Endpoints::default() {
+                    return Err(OpenLimitsError::InvalidParameter(
+                        "huobi has neither a sandbox nor endpoint overrides".into(),
+                    ));
+                }
+                let mut parameters = HuobiParameters::production();
+                parameters.credentials = profile.map(TryInto::try_into).transpose()?;
+                InitAnyExchange::Huobi(parameters)
+            }
+            ExchangeKind::Nash => {
+                let mut parameters = if self.sandbox { NashParameters::sandbox() } else { NashParameters::production() };
+                parameters.credentials = profile.map(TryInto::try_into).transpose()?;
+                parameters.endpoints = endpoints;
+                if let Some(client_id) = self.client_id {
+                    parameters.client_id = client_id;
+                }
+                if let Some(timeout_ms) = self.timeout_ms {
+                    parameters.timeout = Duration::from_millis(timeout_ms);
+                }
+                parameters.affiliate_code = self.affiliate_code.clone();
+                InitAnyExchange::Nash(parameters)
+            }
+        })
+    }
+
+    fn endpoints(&self) -> Endpoints {
+        Endpoints {
+            rest: self.rest_endpoint.clone(),
+            websocket: self.websocket_endpoint.clone(),
+        }
+    }
+
+    fn has_nash_options(&self) -> bool {
+        self.client_id.is_some() || self.timeout_ms.is_some() || self.affiliate_code.is_some()
+    }
+}
+
+impl fmt::Debug for ExchangeConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExchangeConfig")
+            .field("exchange", &self.exchange)
+            .field("sandbox", &self.sandbox)
+            .field("credentials", &self.credentials.as_ref().map(|_| REDACTED))
+            .field("rest_endpoint", &self.rest_endpoint)
+            .field("websocket_endpoint", &self.websocket_endpoint)
+            .field("client_id", &self.client_id)
+            .field("timeout_ms", &self.timeout_ms)
+            .field("affiliate_code", &self.affiliate_code)
+            .finish()
+    }
+}
+
+impl TryFrom<&ExchangeConfig> for InitAnyExchange {
+    type Error = OpenLimitsError;
+
+    fn try_from(config: &ExchangeConfig) -> Result<Self> {
+        config.init_with(config.profile().as_ref())
+    }
+}
@@ -2,8 +2,16 @@
 
 pub use openlimits_binance as binance;
 pub use openlimits_coinbase as coinbase;
+pub use openlimits_huobi as huobi;
 pub use openlimits_nash as nash;
 
+pub mod any;
+pub mod config;
+
+pub use openlimits_messaging as messaging;
+
+pub use openlimits_exchange::credentials;
+pub use openlimits_exchange::metrics;
 pub use openlimits_exchange::traits;
 pub use openlimits_exchange::shared;
 pub use openlimits_exchange::model;
@@ -40,8 +40,6 @@
 
 pub mod exchange;
 pub mod prelude;
-#[cfg(feature = "bindings")]
-pub mod bindings;
 
 pub use crate::exchange::errors;
 pub use crate::exchange::model;
@@ -1,8 +1,3 @@
-#[cfg(feature = "bindings")]
-use ligen_macro::inner_ligen;
-#[cfg(feature = "bindings")]
-inner_ligen!(ignore);
-
 use crate::prelude::*;
 use crate::exchange::shared::Result;
 
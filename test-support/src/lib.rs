@@ -0,0 +1,201 @@
+//! A local fake of the Binance REST and websocket APIs, so the bindings, the CLI and the gateway
+//! are tested end to end without a network. The `fake-binance` binary serves it to the Node.js
+//! tests.
+
+mod responses;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use futures::{SinkExt, StreamExt};
+use serde_json::{json, Value};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio_tungstenite::tungstenite::Message;
+use responses::*;
+
+type OnRequest = Arc<dyn Fn(&str) + Send + Sync>;
+
+/// A fake of the Binance REST and websocket APIs on local ports, served from its own thread and
+/// recording the requests it served.
+pub struct FakeBinance {
+    pub rest: String,
+    pub websocket: String,
+    requests: Arc<Mutex<Vec<String>>>,
+    connections: Arc<AtomicUsize>,
+}
+
+impl FakeBinance {
+    pub fn start() -> Self {
+        Self::start_with(|_| {})
+    }
+
+    /// Starts the fake, calling `on_request` with each REST request, e.g. `GET /api/v3/depth`,
+    /// before answering it.
+    pub fn start_with(on_request: impl Fn(&str) + Send + Sync + 'static) -> Self {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let connections = Arc::new(AtomicUsize::new(0));
+        let (sender, receiver) = mpsc::channel();
+        let served = requests.clone();
+        let connected = connections.clone();
+        let requested = requests.clone();
+        let on_request: OnRequest = Arc::new(on_request);
+        thread::spawn(move || {
+            let runtime = tokio::runtime::Runtime::new().expect("Couldn't start the runtime");
+            runtime.block_on(async move {
+                let rest = TcpListener::bind("127.0.0.1:0").await.expect("Couldn't listen");
+                let websocket = TcpListener::bind("127.0.0.1:0").await.expect("Couldn't listen");
+                sender
+                    .send((rest.local_addr().unwrap(), websocket.local_addr().unwrap()))
+                    .unwrap();
+                tokio::spawn(async move {
+                    while let Ok((stream, _)) = websocket.accept().await {
+                        connected.fetch_add(1, Ordering::SeqCst);
+                        tokio::spawn(serve_websocket(stream, requested.clone()));
+                    }
+                });
+                while let Ok((stream, _)) = rest.accept().await {
+                    tokio::spawn(serve_rest(stream, served.clone(), on_request.clone()));
+                }
+            });
+        });
+        let (rest, websocket) = receiver.recv().expect("Couldn't start the fake");
+        Self {
+            rest: format!("http://{}", rest),
+            websocket: format!("ws://{}/stream", websocket),
+            requests,
+            connections,
+        }
+    }
+
+    /// The exchange config of a client of the fake, as the bindings take it.
+    pub fn config(&self) -> Value {
+        json!({
+            "exchange": "binance",
+            "sandbox": true,
+            "credentials": {"api_key": "key", "api_secret": "secret"},
+            "rest_endpoint": self.rest,
+            "websocket_endpoint": self.websocket,
+        })
+    }
+
+    /// Whether the fake served the request, e.g. `POST /api/v3/order`, or was sent the websocket
+    /// request, e.g. `UNSUBSCRIBE btcusdt@trade`.
+    pub fn served(&self, request: &str) -> bool {
+        self.requests.lock().unwrap().iter().any(|served| served == request)
+    }
+
+    /// How many websocket connections the fake accepted.
+    pub fn connections(&self) -> usize {
+        self.connections.load(Ordering::SeqCst)
+    }
+}
+
+async fn serve_rest(stream: TcpStream, requests: Arc<Mutex<Vec<String>>>, on_request: OnRequest) {
+    let mut stream = BufReader::new(stream);
+    loop {
+        let mut request_line = String::new();
+        if stream.read_line(&mut request_line).await.unwrap_or(0) == 0 {
+            return;
+        }
+        let mut content_length = 0;
+        loop {
+            let mut header = String::new();
+            stream.read_line(&mut header).await.expect("Couldn't read a header");
+            let header = header.trim_end();
+            if header.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = header.split_once(':') {
+                if name.eq_ignore_ascii_case("content-length") {
+                    content_length = value.trim().parse().expect("Invalid content length");
+                }
+            }
+        }
+        let mut body = vec![0; content_length];
+        stream.read_exact(&mut body).await.expect("Couldn't read the body");
+
+        let mut parts = request_line.split_whitespace();
+        let method = parts.next().unwrap_or_default();
+        let target = parts.next().unwrap_or_default();
+        let path = target.split('?').next().unwrap_or_default();
+        let request = format!("{} {}", method, path);
+        let server_time;
+        let (status, response) = match request.as_str() {
+            "GET /api/v1/exchangeInfo" => ("200 OK", EXCHANGE_INFO),
+            "GET /api/v3/time" => {
+                let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis();
+                server_time = format!(r#"{{"serverTime": {}}}"#, now);
+                ("200 OK", server_time.as_str())
+            }
+            "GET /api/v3/depth" => ("200 OK", DEPTH),
+            "GET /api/v3/ticker/24hr" => ("200 OK", PRICE_STATS),
+            "GET /api/v3/klines" => ("200 OK", KLINES),
+            "GET /api/v3/account" => ("200 OK", ACCOUNT),
+            "GET /api/v3/openOrders" => ("200 OK", OPEN_ORDERS),
+            "POST /api/v3/order" => ("200 OK", ORDER),
+            "DELETE /api/v3/order" => ("200 OK", ORDER_CANCELED),
+            _ => ("404 Not Found", r#"{"code": -1, "msg": "not found"}"#),
+        };
+        on_request(&request);
+        requests.lock().unwrap().push(request);
+        let response = format!(
+            "HTTP/1.1 {}\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{}",
+            status,
+            response.len(),
+            response
+        );
+        if stream.get_mut().write_all(response.as_bytes()).await.is_err() {
+            return;
+        }
+    }
+}
+
+/// Answers each subscription and publishes a trade on it, then a trade every 50ms, so the
+/// clients sharing the connection after the first one get some too.
+async fn serve_websocket(stream: TcpStream, requests: Arc<Mutex<Vec<String>>>) {
+    let mut websocket = match tokio_tungstenite::accept_async(stream).await {
+        Ok(websocket) => websocket,
+        Err(_) => return,
+    };
+    let mut interval = tokio::time::interval(Duration::from_millis(50));
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {
+                if websocket.send(Message::text(TRADE)).await.is_err() {
+                    return;
+                }
+            }
+            message = websocket.next() => match message {
+                Some(Ok(message)) if !message.is_close() => {
+                    requests.lock().unwrap().extend(websocket_requests(&message));
+                    if let Some(answer) = subscription_answer(&message) {
+                        if websocket.send(Message::text(answer)).await.is_err() || websocket.send(Message::text(TRADE)).await.is_err() {
+                            return;
+                        }
+                    }
+                }
+                _ => return,
+            },
+        }
+    }
+}
+
+/// The answer to a subscription request of the client.
+fn subscription_answer(message: &Message) -> Option<String> {
+    let request: Value = serde_json::from_str(message.to_text().ok()?).ok()?;
+    Some(format!(r#"{{"result": null, "id": {}}}"#, request.get("id")?))
+}
+
+/// The websocket requests of a message of the client, one per stream, e.g. `SUBSCRIBE btcusdt@trade`.
+fn websocket_requests(message: &Message) -> Vec<String> {
+    let request: Value = match message.to_text().ok().and_then(|text| serde_json::from_str(text).ok()) {
+        Some(request) => request,
+        None => return Vec::new(),
+    };
+    let method = request["method"].as_str().unwrap_or_default();
+    let streams = request["params"].as_array().map(Vec::as_slice).unwrap_or_default();
+    streams.iter().filter_map(Value::as_str).map(|stream| format!("{} {}", method, stream)).collect()
+}
@@ -0,0 +1,12 @@
+//! Serves the fake of Binance to tests written in other languages. It prints the exchange config
+//! of a client on the first line, then each REST request it serves, and stops once its stdin is
+//! closed.
+
+use std::io::{self, Read};
+use openlimits_test_support::FakeBinance;
+
+fn main() {
+    let fake = FakeBinance::start_with(|request| println!("{}", request));
+    println!("{}", fake.config());
+    io::stdin().read_to_end(&mut Vec::new()).ok();
+}
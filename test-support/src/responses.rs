@@ -0,0 +1,97 @@
+//! The bodies the fake answers with.
+
+pub(crate) const EXCHANGE_INFO: &str = r#"{
+    "timezone": "UTC",
+    "serverTime": 1609459200000,
+    "rateLimits": [],
+    "exchangeFilters": [],
+    "symbols": [{
+        "symbol": "BTCUSDT",
+        "status": "TRADING",
+        "baseAsset": "BTC",
+        "baseAssetPrecision": 8,
+        "quoteAsset": "USDT",
+        "quotePrecision": 8,
+        "orderTypes": ["LIMIT", "MARKET"],
+        "icebergAllowed": true,
+        "filters": [
+            {"filterType": "PRICE_FILTER", "minPrice": "0.01", "maxPrice": "1000000.00", "tickSize": "0.01"},
+            {"filterType": "LOT_SIZE", "minQty": "0.000001", "maxQty": "9000.00", "stepSize": "0.000001"}
+        ]
+    }]
+}"#;
+
+pub(crate) const DEPTH: &str = r#"{"lastUpdateId": 7, "bids": [["50000.00", "1.5"]], "asks": [["50001.00", "2.0"]]}"#;
+
+pub(crate) const PRICE_STATS: &str = r#"{
+    "symbol": "BTCUSDT",
+    "priceChange": "100.00",
+    "priceChangePercent": "0.2",
+    "weightedAvgPrice": "49950.00",
+    "prevClosePrice": "49900.00",
+    "lastPrice": "50000.50",
+    "lastQty": "0.1",
+    "bidPrice": "50000.00",
+    "bidQty": "1.5",
+    "askPrice": "50001.00",
+    "askQty": "2.0",
+    "openPrice": "49900.00",
+    "highPrice": "50500.00",
+    "lowPrice": "49500.00",
+    "volume": "1000.0",
+    "quoteVolume": "50000000.0",
+    "openTime": 1609372800000,
+    "closeTime": 1609459200000,
+    "firstId": 1,
+    "lastId": 1000,
+    "count": 1000
+}"#;
+
+pub(crate) const KLINES: &str = r#"[[1609459200000, "50000.00", "50500.00", "49500.00", "50100.00", "10.0",
+    1609459259999, "500000.0", 42, "5.0", "250000.0", "0"]]"#;
+
+pub(crate) const ORDER: &str = r#"{
+    "symbol": "BTCUSDT",
+    "orderId": 42,
+    "clientOrderId": "harness",
+    "price": "50000.00",
+    "origQty": "0.1",
+    "executedQty": "0.0",
+    "status": "NEW",
+    "timeInForce": "GTC",
+    "type": "LIMIT",
+    "side": "BUY",
+    "transactTime": 1609459200000
+}"#;
+
+pub(crate) const ACCOUNT: &str = r#"{
+    "makerCommission": 10,
+    "takerCommission": 10,
+    "buyerCommission": 0,
+    "sellerCommission": 0,
+    "canTrade": true,
+    "canWithdraw": true,
+    "canDeposit": true,
+    "balances": [{"asset": "BTC", "free": "1.5", "locked": "0.5"}]
+}"#;
+
+pub(crate) const OPEN_ORDERS: &str = r#"[{
+    "symbol": "BTCUSDT",
+    "orderId": 42,
+    "clientOrderId": "harness",
+    "price": "50000.00",
+    "origQty": "0.1",
+    "executedQty": "0.0",
+    "status": "NEW",
+    "timeInForce": "GTC",
+    "type": "LIMIT",
+    "side": "BUY",
+    "time": 1609459200000
+}]"#;
+
+pub(crate) const ORDER_CANCELED: &str = r#"{"symbol": "BTCUSDT", "origClientOrderId": "harness", "orderId": 42, "clientOrderId": "canceled"}"#;
+
+pub(crate) const TRADE: &str = r#"{"stream": "btcusdt@trade", "data": {
+    "e": "trade", "E": 1609459200000, "s": "BTCUSDT", "t": 12345, "p": "50000.00", "q": "0.25",
+    "b": 88, "a": 50, "T": 1609459200000, "m": true, "M": true
+}}"#;
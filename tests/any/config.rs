@@ -0,0 +1,70 @@
+use std::convert::TryFrom;
+use std::time::Duration;
+use openlimits::exchange::any::InitAnyExchange;
+use openlimits::exchange::config::{ExchangeConfig, ExchangeKind};
+use openlimits::errors::OpenLimitsError;
+use openlimits_exchange::exchange::{Endpoints, Environment};
+
+#[test]
+fn binance_config() {
+    let config = ExchangeConfig::from_json(r#"{
+        "exchange": "binance",
+        "sandbox": true,
+        "credentials": {"api_key": "key", "api_secret": "s3cr3t"},
+        "rest_endpoint": "http://127.0.0.1:8080"
+    }"#).expect("Couldn't parse the config.");
+    match InitAnyExchange::try_from(&config).expect("Couldn't convert the config.") {
+        InitAnyExchange::Binance(parameters) => {
+            assert_eq!(parameters.environment, Environment::Sandbox);
+            assert_eq!(parameters.credentials.map(|credentials| credentials.api_key), Some("key".to_string()));
+            assert_eq!(parameters.endpoints, Endpoints { rest: Some("http://127.0.0.1:8080".into()), websocket: None });
+        }
+        _ => panic!("Expected binance parameters."),
+    }
+}
+
+#[test]
+fn incomplete_credentials() {
+    let config = ExchangeConfig::from_json(r#"{"exchange": "coinbase", "credentials": {"api_key": "key", "api_secret": "s3cr3t"}}"#)
+        .expect("Couldn't parse the config.");
+    let error = InitAnyExchange::try_from(&config).err().expect("The passphrase is missing.");
+    assert!(matches!(error, OpenLimitsError::MissingParameter(_)));
+}
+
+#[test]
+fn huobi_sandbox() {
+    let config = ExchangeConfig { sandbox: true, ..ExchangeConfig::new(ExchangeKind::Huobi) };
+    let error = InitAnyExchange::try_from(&config).err().expect("Huobi has no sandbox.");
+    assert!(matches!(error, OpenLimitsError::InvalidParameter(_)));
+}
+
+#[test]
+fn nash_options() {
+    let config = ExchangeConfig::from_json(r#"{"exchange": "nash", "client_id": 7, "timeout_ms": 2500, "affiliate_code": "desk"}"#)
+        .expect("Couldn't parse the config.");
+    match InitAnyExchange::try_from(&config).expect("Couldn't convert the config.") {
+        InitAnyExchange::Nash(parameters) => {
+            assert_eq!(parameters.client_id, 7);
+            assert_eq!(parameters.timeout, Duration::from_millis(2500));
+            assert_eq!(parameters.affiliate_code, Some("desk".to_string()));
+        }
+        _ => panic!("Expected nash parameters."),
+    }
+    let config = ExchangeConfig { client_id: Some(7), ..ExchangeConfig::new(ExchangeKind::Binance) };
+    let error = InitAnyExchange::try_from(&config).err().expect("Binance has no client id.");
+    assert!(matches!(error, OpenLimitsError::InvalidParameter(_)));
+}
+
+#[test]
+fn exchange_kind() {
+    assert_eq!("Nash".parse::<ExchangeKind>().ok(), Some(ExchangeKind::Nash));
+    assert!("kraken".parse::<ExchangeKind>().is_err());
+    assert!(ExchangeConfig::from_json(r#"{"exchange": "kraken"}"#).is_err());
+}
+
+#[test]
+fn redacted_debug() {
+    let config = ExchangeConfig::from_json(r#"{"exchange": "binance", "credentials": {"api_key": "key", "api_secret": "s3cr3t"}}"#)
+        .expect("Couldn't parse the config.");
+    assert!(!format!("{:?}", config).contains("s3cr3t"));
+}
@@ -0,0 +1,19 @@
+use openlimits::exchange::any::{AnyWebsocket, InitAnyExchange};
+use openlimits::exchange::huobi::HuobiParameters;
+use crate::template::subscriptions;
+
+async fn init() -> AnyWebsocket {
+    AnyWebsocket::new(InitAnyExchange::Huobi(HuobiParameters::production()))
+        .await
+        .expect("Couldn't connect to Huobi")
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn huobi_trades() {
+    subscriptions::trades(&mut init().await).await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn huobi_multiplexed() {
+    subscriptions::multiplexed(&mut init().await).await;
+}
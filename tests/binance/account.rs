@@ -55,3 +55,8 @@ async fn get_account_balances() {
 async fn get_trade_history() {
     account::get_trade_history(&init().await).await;
 }
+
+#[tokio::test]
+async fn get_ledger() {
+    account::get_ledger(&init().await).await;
+}
@@ -27,9 +27,10 @@ pub async fn init_signed() -> Binance {
     let parameters = BinanceParameters {
         credentials: Some(BinanceCredentials {
             api_key:    std::env::var("BINANCE_API_KEY").expect("Couldn't get environment variable."),
-            api_secret: std::env::var("BINANCE_API_SECRET").expect("Couldn't get environment variable."),
+            api_secret: std::env::var("BINANCE_API_SECRET").expect("Couldn't get environment variable.").into(),
         }),
         environment: Environment::Sandbox,
+        ..Default::default()
     };
 
     OpenLimits::instantiate(parameters)
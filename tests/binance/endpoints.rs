@@ -0,0 +1,35 @@
+use std::sync::Arc;
+use openlimits::exchange::binance::{Binance, BinanceParameters};
+use openlimits::exchange::traits::Exchange;
+use openlimits_exchange::exchange::Endpoints;
+use openlimits_exchange::http::{FakeTransport, StatusCode};
+
+async fn requested_url(parameters: BinanceParameters) -> String {
+    let transport = Arc::new(FakeTransport::answering(StatusCode::SERVICE_UNAVAILABLE, b""));
+    let parameters = BinanceParameters {
+        http_transport: Some(transport.clone()),
+        ..parameters
+    };
+    assert!(Binance::new(parameters).await.is_err());
+    let requests = transport.requests();
+    requests.first().expect("Couldn't get a request").url.to_string()
+}
+
+#[tokio::test]
+async fn rest_endpoint() {
+    let parameters = BinanceParameters {
+        endpoints: Endpoints {
+            rest: Some(String::from("http://localhost:8080")),
+            websocket: None,
+        },
+        ..BinanceParameters::production()
+    };
+    let url = requested_url(parameters).await;
+    assert!(url.starts_with("http://localhost:8080/api/"), "{}", url);
+}
+
+#[tokio::test]
+async fn regional_endpoint() {
+    let url = requested_url(BinanceParameters::regional("binance.us")).await;
+    assert!(url.starts_with("https://api.binance.us/api/"), "{}", url);
+}
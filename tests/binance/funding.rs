@@ -0,0 +1,17 @@
+use crate::template::funding;
+use super::client::init_signed as init;
+
+#[tokio::test]
+async fn get_deposit_address() {
+    funding::get_deposit_address(&init().await).await;
+}
+
+#[tokio::test]
+async fn get_deposit_history() {
+    funding::get_deposit_history(&init().await).await;
+}
+
+#[tokio::test]
+async fn get_withdrawal_history() {
+    funding::get_withdrawal_history(&init().await).await;
+}
@@ -1,5 +1,8 @@
 mod account;
+mod funding;
 mod market;
 mod callbacks;
 mod streams;
+mod subscriptions;
+mod endpoints;
 pub mod client;
\ No newline at end of file
@@ -0,0 +1,27 @@
+use crate::template::subscriptions;
+use super::client::init_ws as init;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn kline() {
+    subscriptions::kline(&mut init().await).await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn depth() {
+    subscriptions::depth(&mut init().await).await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn trades() {
+    subscriptions::trades(&mut init().await).await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn ticker() {
+    subscriptions::ticker(&mut init().await).await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn multiplexed() {
+    subscriptions::multiplexed(&mut init().await).await;
+}
@@ -24,10 +24,11 @@ pub async fn init_signed() -> Coinbase {
     let parameters = CoinbaseParameters {
         credentials: Some(CoinbaseCredentials {
             api_key:    std::env::var("COINBASE_API_KEY").expect("Couldn't get environment variable."),
-            api_secret: std::env::var("COINBASE_API_SECRET").expect("Couldn't get environment variable."),
-            passphrase: std::env::var("COINBASE_PASSPHRASE").expect("Couldn't get environment variable.")
+            api_secret: std::env::var("COINBASE_API_SECRET").expect("Couldn't get environment variable.").into(),
+            passphrase: std::env::var("COINBASE_PASSPHRASE").expect("Couldn't get environment variable.").into()
         }),
         environment: Environment::Sandbox,
+        ..Default::default()
     };
 
     OpenLimits::instantiate(parameters)
@@ -1,5 +1,7 @@
 mod account;
+mod funding;
 mod market;
 mod callbacks;
 mod streams;
+mod subscriptions;
 pub mod client;
\ No newline at end of file
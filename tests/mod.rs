@@ -1,6 +1,7 @@
 extern crate openlimits;
 
 mod template;
+mod any;
 // mod exchange;
 // mod apis;
 mod binance;
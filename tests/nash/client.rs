@@ -24,10 +24,11 @@ pub async fn init_signed() -> Nash {
 
     let parameters = NashParameters {
         credentials: Some(NashCredentials {
-            api_key:    std::env::var("NASH_API_KEY").expect("Couldn't get environment variable."),
-            api_secret: std::env::var("NASH_API_SECRET").expect("Couldn't get environment variable."),
+            api_key:    std::env::var("NASH_API_KEY").expect("Couldn't get environment variable.").into(),
+            api_secret: std::env::var("NASH_API_SECRET").expect("Couldn't get environment variable.").into(),
         }),
         environment: Environment::Sandbox,
+        endpoints: Default::default(),
         affiliate_code: None,
         client_id: 1,
         sign_states_loop_interval: None,
@@ -11,6 +11,11 @@ async fn get_price_ticker() {
     market::get_price_ticker(&init().await).await;
 }
 
+#[tokio::test]
+async fn get_all_tickers() {
+    market::get_all_tickers(&init().await).await;
+}
+
 #[tokio::test]
 async fn get_historic_rates() {
     market::get_historic_rates(&init().await).await;
@@ -2,4 +2,5 @@ mod account;
 mod market;
 mod callbacks;
 mod streams;
+mod subscriptions;
 pub mod client;
\ No newline at end of file
@@ -0,0 +1,25 @@
+use openlimits::exchange::messaging::Subscriber;
+use openlimits_exchange::message::subscription::Subscription;
+use openlimits_exchange::model::Interval;
+use openlimits_exchange::model::market_pair::MarketPair;
+use openlimits_exchange::OpenLimitsError;
+use openlimits::exchange::model::currency::Currency;
+use crate::template::subscriptions;
+use super::client::init_ws as init;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn kline() {
+    let market_pair = MarketPair(Currency::ETH, Currency::BTC);
+    let result = init().await.subscribe(&Subscription::Kline(market_pair, Interval::OneMinute)).await;
+    assert!(matches!(result, Err(OpenLimitsError::MissingImplementation(_))));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn depth() {
+    subscriptions::depth(&mut init().await).await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn trades() {
+    subscriptions::trades(&mut init().await).await;
+}
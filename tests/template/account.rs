@@ -4,7 +4,8 @@ use openlimits::{
     prelude::*,
     model::{
         CancelAllOrdersRequest, CancelOrderRequest, GetOrderHistoryRequest, OpenLimitOrderRequest,
-        OpenMarketOrderRequest, TimeInForce, TradeHistoryRequest, GetPriceTickerRequest
+        OpenMarketOrderRequest, TimeInForce, TradeHistoryRequest, GetPriceTickerRequest,
+        GetLedgerRequest,
     },
 };
 use rust_decimal::prelude::*;
@@ -240,4 +241,16 @@ pub async fn get_trade_history(exchange: &impl Exchange) {
         .await
         .expect("Couldn't get trade history.");
     println!("{:?}", resp);
-}
\ No newline at end of file
+}
+pub async fn get_ledger(exchange: &impl Exchange) {
+    let req = GetLedgerRequest {
+        asset: String::from("BTC"),
+        market_pair: Some(MarketPair(Currency::BTC, Currency::USDT)),
+        paginator: None,
+    };
+    let resp = exchange
+        .get_ledger(&req)
+        .await
+        .expect("Couldn't get ledger.");
+    println!("{:?}", resp);
+}
@@ -0,0 +1,40 @@
+use openlimits::{
+    prelude::*,
+    model::{GetDepositAddressRequest, GetDepositHistoryRequest, GetWithdrawalHistoryRequest},
+};
+
+pub async fn get_deposit_address(exchange: &(impl Exchange + ExchangeFunding)) {
+    let req = GetDepositAddressRequest {
+        asset: String::from("BTC"),
+        network: None,
+    };
+    let resp = exchange
+        .get_deposit_address(&req)
+        .await
+        .expect("Couldn't get deposit address.");
+    println!("{:?}", resp);
+}
+
+pub async fn get_deposit_history(exchange: &(impl Exchange + ExchangeFunding)) {
+    let req = GetDepositHistoryRequest {
+        asset: Some(String::from("BTC")),
+        paginator: None,
+    };
+    let resp = exchange
+        .get_deposit_history(&req)
+        .await
+        .expect("Couldn't get deposit history.");
+    println!("{:?}", resp);
+}
+
+pub async fn get_withdrawal_history(exchange: &(impl Exchange + ExchangeFunding)) {
+    let req = GetWithdrawalHistoryRequest {
+        asset: None,
+        paginator: None,
+    };
+    let resp = exchange
+        .get_withdrawal_history(&req)
+        .await
+        .expect("Couldn't get withdrawal history.");
+    println!("{:?}", resp);
+}
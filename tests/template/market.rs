@@ -1,7 +1,11 @@
 use openlimits::{
     prelude::*,
-    model::{GetHistoricRatesRequest, GetPriceTickerRequest, Interval, OrderBookRequest},
+    model::{
+        GetHistoricRatesRequest, GetHistoricTradesRequest, GetPriceTickerRequest, Interval,
+        Operation, OrderBookRequest, Paginator,
+    },
 };
+use openlimits_exchange::errors::OpenLimitsError;
 use openlimits_exchange::model::market_pair::MarketPair;
 use openlimits_exchange::model::currency::Currency;
 
@@ -23,6 +27,15 @@ pub async fn get_price_ticker(exchange: &impl Exchange) {
         .expect("Couldn't get price ticker.");
 }
 
+pub async fn get_all_tickers(exchange: &impl Exchange) {
+    let tickers = exchange
+        .get_all_tickers()
+        .await
+        .expect("Couldn't get all tickers.");
+    let market_pair = MarketPair(Currency::ETH, Currency::BTC);
+    assert!(tickers.iter().any(|ticker| ticker.market_pair == market_pair));
+}
+
 pub async fn get_historic_rates(exchange: &impl Exchange) {
     let market_pair = MarketPair(Currency::ETH, Currency::BTC);
     let req = GetHistoricRatesRequest {
@@ -36,6 +49,56 @@ pub async fn get_historic_rates(exchange: &impl Exchange) {
         .expect("Couldn't get historic rates.");
 }
 
+pub async fn capabilities(exchange: &impl Exchange) {
+    let capabilities = exchange.capabilities();
+    assert!(capabilities.supports(Operation::GetHistoricRates));
+    let intervals = [
+        Interval::OneMinute,
+        Interval::ThreeMinutes,
+        Interval::FiveMinutes,
+        Interval::FifteenMinutes,
+        Interval::ThirtyMinutes,
+        Interval::OneHour,
+        Interval::TwoHours,
+        Interval::FourHours,
+        Interval::SixHours,
+        Interval::EightHours,
+        Interval::TwelveHours,
+        Interval::OneDay,
+        Interval::ThreeDays,
+        Interval::OneWeek,
+        Interval::OneMonth,
+    ];
+    for interval in intervals.iter().filter(|interval| !capabilities.intervals.contains(interval)) {
+        let req = GetHistoricRatesRequest {
+            market_pair: MarketPair(Currency::ETH, Currency::BTC),
+            interval: *interval,
+            paginator: None,
+        };
+        match exchange.get_historic_rates(&req).await {
+            Err(OpenLimitsError::MissingImplementation(_)) => {}
+            other => panic!("{:?} should be unsupported, got {:?}", interval, other.map(|_| ())),
+        }
+    }
+}
+
+pub async fn get_historic_trades(exchange: &impl Exchange) {
+    let req = GetHistoricTradesRequest {
+        market_pair: "eth_btc".to_string(),
+        paginator: Some(Paginator {
+            limit: Some(100),
+            ..Default::default()
+        }),
+    };
+    let trades = exchange
+        .get_historic_trades(&req)
+        .await
+        .expect("Couldn't get historic trades.");
+    assert!(trades.len() <= 100);
+    let market_pair = MarketPair(Currency::ETH, Currency::BTC);
+    assert!(trades.iter().all(|trade| trade.market_pair == market_pair));
+}
+
 pub async fn pair(exchange: &impl Exchange) {
     let market_pair = MarketPair(Currency::ETH, Currency::BTC);
     let response = exchange
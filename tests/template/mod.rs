@@ -1,4 +1,6 @@
 pub mod account;
+pub mod funding;
 pub mod market;
 pub mod streams;
+pub mod subscriptions;
 pub mod callbacks;
\ No newline at end of file
@@ -0,0 +1,99 @@
+use futures::stream::StreamExt;
+
+use openlimits::exchange::messaging::Subscriber;
+use openlimits_exchange::message::subscription::{Publication, Subscription};
+use openlimits_exchange::model::market_pair::MarketPair;
+use openlimits_exchange::model::Interval;
+use openlimits_exchange::OpenLimitsError;
+use openlimits::exchange::model::currency::Currency;
+use tokio::time::timeout;
+use std::time::Duration;
+
+/// The subscriber of any exchange, so the same checks run against all of them.
+pub trait MarketSubscriber:
+    Subscriber<
+        SubscriptionRequest = Subscription,
+        Publication = openlimits_exchange::Result<Publication>,
+        Error = OpenLimitsError,
+    >
+{
+}
+
+impl<T> MarketSubscriber for T where
+    T: Subscriber<
+        SubscriptionRequest = Subscription,
+        Publication = openlimits_exchange::Result<Publication>,
+        Error = OpenLimitsError,
+    >
+{
+}
+
+fn eth_btc() -> MarketPair {
+    MarketPair(Currency::ETH, Currency::BTC)
+}
+
+async fn first_publication(subscriber: &mut impl MarketSubscriber, subscription: Subscription) -> Publication {
+    let mut publications = subscriber
+        .subscribe(&subscription)
+        .await
+        .expect("Couldn't subscribe.");
+    timeout(Duration::new(10, 0), publications.next())
+        .await
+        .expect("No publication in time.")
+        .expect("Subscription ended.")
+        .expect("Couldn't read the publication.")
+}
+
+pub async fn kline(subscriber: &mut impl MarketSubscriber) {
+    match first_publication(subscriber, Subscription::Kline(eth_btc(), Interval::OneMinute)).await {
+        Publication::Kline(candle) => println!("{:#?}", candle),
+        publication => panic!("Incorrect publication: {:#?}", publication),
+    }
+}
+
+pub async fn depth(subscriber: &mut impl MarketSubscriber) {
+    match first_publication(subscriber, Subscription::Depth(eth_btc())).await {
+        Publication::Depth(order_book) => println!("{:#?}", order_book),
+        publication => panic!("Incorrect publication: {:#?}", publication),
+    }
+}
+
+pub async fn trades(subscriber: &mut impl MarketSubscriber) {
+    match first_publication(subscriber, Subscription::Trades(eth_btc())).await {
+        Publication::Trades(trades) => assert!(trades.iter().all(|trade| trade.market_pair == eth_btc())),
+        publication => panic!("Incorrect publication: {:#?}", publication),
+    }
+}
+
+pub async fn ticker(subscriber: &mut impl MarketSubscriber) {
+    match first_publication(subscriber, Subscription::Ticker(eth_btc())).await {
+        Publication::Ticker(ticker) => assert_eq!(ticker.market_pair, eth_btc()),
+        publication => panic!("Incorrect publication: {:#?}", publication),
+    }
+}
+
+/// Subscribes twice on the same subscriber, each subscription only getting its own publications.
+pub async fn multiplexed(subscriber: &mut impl MarketSubscriber) {
+    let mut depth = subscriber
+        .subscribe(&Subscription::Depth(eth_btc()))
+        .await
+        .expect("Couldn't subscribe.");
+    let mut ticker = subscriber
+        .subscribe(&Subscription::Ticker(eth_btc()))
+        .await
+        .expect("Couldn't subscribe.");
+    for _ in 0..2 {
+        let publication = timeout(Duration::new(10, 0), depth.next())
+            .await
+            .expect("No publication in time.")
+            .expect("Subscription ended.")
+            .expect("Couldn't read the publication.");
+        assert!(matches!(publication, Publication::Depth(_)), "Incorrect publication: {:#?}", publication);
+        let publication = timeout(Duration::new(10, 0), ticker.next())
+            .await
+            .expect("No publication in time.")
+            .expect("Subscription ended.")
+            .expect("Couldn't read the publication.");
+        assert!(matches!(publication, Publication::Ticker(_)), "Incorrect publication: {:#?}", publication);
+    }
+}